@@ -1,8 +1,14 @@
 use std::env;
+use std::fs;
 use std::process;
 
 const VERSION_SUFFIX_ENV: &str = "PERSPECTA_VERSION_SUFFIX";
 const DISPLAY_VERSION_ENV: &str = "PERSPECTA_DISPLAY_VERSION";
+/// Locked dependency versions shown in the About panel.
+const REPORTED_DEPENDENCIES: [(&str, &str); 2] = [
+    ("egui", "PERSPECTA_EGUI_VERSION"),
+    ("eframe", "PERSPECTA_EFRAME_VERSION"),
+];
 
 fn main() {
     println!("cargo:rerun-if-changed=build.rs");
@@ -18,6 +24,64 @@ fn main() {
     }
 
     println!("cargo:rustc-env={DISPLAY_VERSION_ENV}={package_version}{version_suffix}");
+
+    println!("cargo:rerun-if-changed=Cargo.lock");
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    // Cargo.lock is not tracked, so a source tree built without one reports
+    // the Cargo.toml requirement instead of a locked version.
+    let lock_file = match fs::read_to_string("Cargo.lock") {
+        Ok(lock_file) => Some(lock_file),
+        Err(err) => {
+            println!(
+                "cargo:warning=Cannot read Cargo.lock ({err}); the About panel will show the Cargo.toml version requirements"
+            );
+            None
+        }
+    };
+    let manifest = fs::read_to_string("Cargo.toml").unwrap_or_default();
+    for (package, env_name) in REPORTED_DEPENDENCIES {
+        let version = lock_file
+            .as_deref()
+            .and_then(|lock_file| locked_version(lock_file, package))
+            .map(str::to_string)
+            .or_else(|| {
+                required_version(&manifest, package)
+                    .map(|requirement| format!("{requirement} (required)"))
+            })
+            .unwrap_or_else(|| "unknown".to_string());
+        println!("cargo:rustc-env={env_name}={version}");
+    }
+}
+
+fn locked_version<'a>(lock_file: &'a str, package: &str) -> Option<&'a str> {
+    let name_line = format!("name = \"{package}\"");
+    let mut lines = lock_file.lines();
+    lines.find(|line| line.trim() == name_line)?;
+    lines
+        .next()?
+        .trim()
+        .strip_prefix("version = \"")?
+        .strip_suffix('"')
+}
+
+/// Version requirement of the first `package = ...` dependency line, written
+/// either as `"0.35"` or as an inline table with `version = "0.35"`.
+fn required_version<'a>(manifest: &'a str, package: &str) -> Option<&'a str> {
+    let value = manifest.lines().find_map(|line| {
+        let (name, value) = line.split_once('=')?;
+        (name.trim() == package).then_some(value.trim())
+    })?;
+    let quoted = match value.strip_prefix('"') {
+        Some(quoted) => quoted,
+        None => value
+            .split_once("version")?
+            .1
+            .trim_start()
+            .strip_prefix('=')?
+            .trim_start()
+            .strip_prefix('"')?,
+    };
+    quoted.split_once('"').map(|(version, _)| version)
 }
 
 fn is_timestamp_suffix(value: &str) -> bool {
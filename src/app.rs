@@ -1,60 +1,94 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::env;
 use std::fs;
 use std::io::Read;
+use std::ops::RangeInclusive;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use std::thread;
 use std::time::{Duration, Instant};
 
+use anyhow::Context;
 use eframe::egui::{
     self, ColorImage, ResizeDirection, Sense, TextureHandle, TextureOptions, ViewportCommand,
 };
 
 use crate::dicom::{
-    classify_dicom_path, detect_dicom_prefix_offset, load_dicom, load_gsps_overlays,
-    load_mammography_cad_sr_overlays, load_parametric_map, load_parametric_map_overlays,
-    load_structured_report, read_sop_instance_uid, DicomImage, DicomPathKind, DicomSource,
-    DicomSourceMeta, FullMetadataField, GspsGraphic, GspsOverlay, GspsUnits, ParametricMapOverlay,
-    SrOverlay, SrOverlayLabel, StructuredReportDocument, StructuredReportNode,
+    can_open_dicom_source, classify_dicom_path, detect_dicom_prefix_offset, load_dicom,
+    load_dicom_placeholder, load_gsps_overlays, load_mammography_cad_sr_overlays,
+    load_parametric_map, load_parametric_map_overlays, load_structured_report,
+    parse_view_fallback_tags, read_sop_instance_uid, request_shutdown, DicomImage,
+    DicomLoadOptions, DicomPathKind, DicomSource, DicomSourceMeta, FullMetadataField, GspsGraphic,
+    GspsOverlay, GspsShutter, GspsUnits, ParametricMapOverlay, PatientOrientation, SrOverlay,
+    SrOverlayLabel, StructuredReportDocument, StructuredReportNode, WindowPreset,
     METADATA_FIELD_NAMES,
 };
 use crate::dicomweb::{
-    download_dicomweb_group_request, download_dicomweb_request, DicomWebDownloadResult,
-    DicomWebGroupStreamUpdate,
+    download_dicomweb_group_request, download_dicomweb_request, download_remote_file,
+    DicomWebDownloadResult, DicomWebGroupStreamUpdate, DicomWebSeriesTab, InstanceRedownload,
+};
+use crate::launch::{
+    build_perspecta_uri, DicomWebGroupedLaunchRequest, DicomWebLaunchRequest, LaunchRequest,
+    RemoteFileLaunchRequest, UiTheme,
+};
+use crate::mammo::{
+    mammo_image_align, mammo_label, mammo_view_code, order_mammo_indices, preferred_mammo_slot,
 };
-use crate::launch::{DicomWebGroupedLaunchRequest, DicomWebLaunchRequest, LaunchRequest};
-use crate::mammo::{mammo_image_align, mammo_label, order_mammo_indices, preferred_mammo_slot};
 use crate::renderer::{blend_rgba_overlay, render_rgb, render_window_level};
 
+mod context_menu;
+mod diagnostics;
+mod export;
+mod filmstrip;
 mod history;
 mod load;
 mod measurement;
 mod metadata;
+mod montage;
 mod overlay;
+mod subtraction;
+mod thumbnails;
 
-#[cfg(test)]
+use self::context_menu::view_context_menu_requested;
+use self::export::{ExportFormat, FrameExportDialog, JpegQuality};
+use self::filmstrip::FrameFilmstrip;
 use self::history::{
-    history_id_from_paths, HistoryGroupData, HistoryGroupViewportData, HistoryReportData,
-    HistoryThumb,
+    downsample_color_image, history_id_from_paths, HistoryEntry, HistoryKind, HistoryPreloadJob,
+    HistoryPreloadJobKey, HistoryPreloadResult, HistorySingleData,
 };
+#[cfg(test)]
 use self::history::{
-    HistoryEntry, HistoryKind, HistoryPreloadJob, HistoryPreloadJobKey, HistoryPreloadResult,
-    HistorySingleData,
+    history_sort_key, mismatched_thumb_members, HistoryGroupData, HistoryGroupViewportData,
+    HistoryReportData, HistoryThumb,
 };
-use self::load::{LocalPrepareResult, PendingLoad, PendingSingleLoad, PreparedLoadPaths};
-use self::measurement::{LiveMeasurement, MeasurementGeometry, MeasurementTarget};
+use self::load::{
+    BackgroundGroupDownload, FailedInstanceDownload, FailedMammoView, LocalPrepareResult,
+    PendingLoad, PendingSingleLoad, PreparedLoadPaths,
+};
+use self::measurement::{zoom_to_region, LiveMeasurement, MeasurementGeometry, MeasurementTarget};
+use self::montage::FrameMontage;
+use self::subtraction::FrameSubtraction;
+use self::thumbnails::FrameThumbnails;
 
 const APP_TITLE: &str = "Perspecta Viewer";
 const APP_VERSION: &str = env!("PERSPECTA_DISPLAY_VERSION");
 const TITLE_TEXT_SIZE: f32 = 14.0;
 const HISTORY_MAX_ENTRIES: usize = 24;
 const HISTORY_THUMB_MAX_DIM: usize = 96;
+const HISTORY_VIEW_THUMB_MAX_DIM: usize = 40;
+const HISTORY_VIEW_THUMBS_MAX: usize = 4;
 const HISTORY_LIST_THUMB_MAX_DIM: f32 = 56.0;
+const HISTORY_ENTRY_PADDING: i8 = 8;
 const DEFAULT_CINE_FPS: f32 = 24.0;
+const MAX_MONITOR_DPI: f32 = 1000.0;
+const MAX_GRID_GAP: f32 = 16.0;
+const MAX_GRID_BORDER_WIDTH: f32 = 8.0;
+const SINGLE_VIEW_MAX_ZOOM: f32 = 12.0;
+const SINGLE_VIEW_MIN_TRUE_SIZE_ZOOM: f32 = 0.05;
 const VALID_GROUP_SIZES: &[usize] = &[1, 2, 3, 4, 8];
+const PACKED_MAMMO_VIEW_COUNT: usize = 4;
 const PERSPECTA_BRAND_BLUE: egui::Color32 = egui::Color32::from_rgb(14, 165, 233);
 const ICON_STROKE_WIDTH: f32 = 1.25;
 const CLOSE_ICON_SIZE_FACTOR: f32 = 0.36;
@@ -71,13 +105,199 @@ const ERROR_OVERLAY_CLOSE_BUTTON_SIZE: f32 = 18.0;
 const CONTROL_VALUE_WIDTH: f32 = 64.0;
 const CONTROL_ACTION_BUTTON_WIDTH: f32 = 110.0;
 const FILE_DROP_OVERLAY_WIDTH: f32 = 420.0;
+const RESIZE_EDGE_WIDTH: f32 = 4.0;
 const DICOMWEB_ACTIVE_PENDING_BATCH_SIZE: usize = 8;
+// Loads the user is waiting on poll at frame rate so results show on the next
+// frame; background work such as history preload polls at the idle interval.
+const ACTIVE_REPAINT_INTERVAL: Duration = Duration::from_millis(16);
+const IDLE_POLL_REPAINT_INTERVAL: Duration = Duration::from_millis(100);
 const SR_OVERLAY_LABEL_FONT_SIZE: f32 = 11.0;
 const SR_OVERLAY_LABEL_OFFSET_X: f32 = 8.0;
 const SR_OVERLAY_LABEL_OFFSET_Y: f32 = 8.0;
 const SR_OVERLAY_LABEL_PADDING_X: f32 = 6.0;
 const SR_OVERLAY_LABEL_PADDING_Y: f32 = 4.0;
 const SR_OVERLAY_LABEL_LINE_GAP: f32 = 2.0;
+/// Lifts the single-view cine ring above the compact info line.
+const COMPACT_HUD_RING_INSET: f32 = 32.0;
+
+/// Scalar viewer preferences persisted alongside the metadata field selection.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct ViewerPreferences {
+    /// Advance cine by exactly one frame per tick instead of catching up to
+    /// wall-clock time, trading playback speed for never skipping a frame.
+    cine_every_frame: bool,
+    /// Start cine as soon as a multi-frame image or a complete multi-frame
+    /// group opens.
+    autoplay_cine: bool,
+    /// Draw a small ring in the view corner showing how far cine is through
+    /// the loop.
+    cine_progress_ring: bool,
+    /// Tab/Shift+Tab open history entries whose frames are already decoded in
+    /// the same frame, instead of waiting one frame like every other open.
+    instant_history_cycling: bool,
+    /// Open local files, and history entries loaded from them, in the frame
+    /// they are requested instead of first arming the progress cursor for a
+    /// frame. Network sources keep the delay.
+    instant_local_open: bool,
+    /// Physical pixels per inch of the display, used by True Size. There is no
+    /// reliable way to detect a monitor's physical size, so it must be set.
+    monitor_dpi: Option<f32>,
+    /// Fit the single view to the first frame's non-black region, hiding film
+    /// borders. Display-only; pixel data and measurements are unaffected.
+    auto_crop_borders: bool,
+    /// Open an image with the last manual window used on an image of the same
+    /// `Modality` instead of its own default window.
+    sticky_window: bool,
+    /// Keep a zoomed single view at the same on-screen size when the window is
+    /// resized instead of zooming with the fitted image.
+    keep_zoom_on_resize: bool,
+    /// Replace the metadata side overlay with a one-line info HUD.
+    compact_info_hud: bool,
+    /// Hide the view code badges and orientation labels of multi-view cells
+    /// (`L`).
+    hide_view_labels: bool,
+    /// Clean presentation (`Shift+L`): no text over any view, neither labels
+    /// nor the metadata overlay or info HUD.
+    presentation_mode: bool,
+    /// Render images that lack `PhotometricInterpretation` as MONOCHROME1,
+    /// for sites whose equipment omits the tag on inverted data.
+    assume_monochrome1_when_missing: bool,
+    /// Show a strip of frame thumbnails below multi-frame images for jumping
+    /// to a frame.
+    frame_filmstrip: bool,
+    /// Order the history list by study date and time, newest first, instead
+    /// of by when each entry was last opened.
+    sort_history_by_date: bool,
+    /// Show group history entries as one small thumbnail per view instead of
+    /// a composite grid, for groups of up to four views.
+    history_view_thumbnails: bool,
+    window_level_gesture: WindowLevelGesture,
+    /// Format the export dialog starts with.
+    export_format: ExportFormat,
+    /// Quality the export dialog starts with for JPEG.
+    export_jpeg_quality: JpegQuality,
+    grid_style: MultiViewGridStyle,
+    /// Extra tags, by keyword, read in order for view and laterality when the
+    /// standard mammography tags are empty.
+    view_fallback_tags: Vec<String>,
+}
+
+/// Spacing and cell borders of the multi-view grid. The selected cell's
+/// border is always drawn in the brand blue.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct MultiViewGridStyle {
+    /// Points between neighboring cells.
+    gap: f32,
+    /// Cell border width in points; 0 hides unselected borders.
+    border_width: f32,
+    border_color: egui::Color32,
+}
+
+impl Default for MultiViewGridStyle {
+    fn default() -> Self {
+        Self {
+            gap: 2.0,
+            border_width: 1.0,
+            border_color: egui::Color32::BLACK,
+        }
+    }
+}
+
+impl MultiViewGridStyle {
+    fn parse_gap(value: f32) -> Option<f32> {
+        (0.0..=MAX_GRID_GAP).contains(&value).then_some(value)
+    }
+
+    fn parse_border_width(value: f32) -> Option<f32> {
+        (0.0..=MAX_GRID_BORDER_WIDTH)
+            .contains(&value)
+            .then_some(value)
+    }
+
+    /// Reads an opaque `#rrggbb` color.
+    fn parse_border_color(value: &str) -> Option<egui::Color32> {
+        let hex = value.trim().strip_prefix('#')?;
+        if hex.len() != 6 {
+            return None;
+        }
+        let channel = |range| u8::from_str_radix(hex.get(range)?, 16).ok();
+        Some(egui::Color32::from_rgb(
+            channel(0..2)?,
+            channel(2..4)?,
+            channel(4..6)?,
+        ))
+    }
+
+    fn border_color_setting_value(self) -> String {
+        let [r, g, b, _] = self.border_color.to_array();
+        format!("#{r:02x}{g:02x}{b:02x}")
+    }
+}
+
+/// Pointer gesture that adjusts window/level on monochrome images.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum WindowLevelGesture {
+    /// Shift + left drag; plain left drag pans and a right click anchors a
+    /// measurement on press.
+    #[default]
+    ShiftPrimaryDrag,
+    /// Right drag with no modifier, as in many PACS. A right click without
+    /// dragging still anchors a measurement, on release.
+    SecondaryDrag,
+}
+
+impl WindowLevelGesture {
+    const ALL: [Self; 2] = [Self::ShiftPrimaryDrag, Self::SecondaryDrag];
+
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "shift_left" => Some(Self::ShiftPrimaryDrag),
+            "right" => Some(Self::SecondaryDrag),
+            _ => None,
+        }
+    }
+
+    fn setting_value(self) -> &'static str {
+        match self {
+            Self::ShiftPrimaryDrag => "shift_left",
+            Self::SecondaryDrag => "right",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::ShiftPrimaryDrag => "Shift + Left Drag",
+            Self::SecondaryDrag => "Right Drag",
+        }
+    }
+
+    fn adjusts_window_level(
+        self,
+        primary_dragged: bool,
+        secondary_dragged: bool,
+        shift: bool,
+    ) -> bool {
+        match self {
+            Self::ShiftPrimaryDrag => primary_dragged && shift,
+            Self::SecondaryDrag => secondary_dragged,
+        }
+    }
+
+    fn measurement_anchor_requested(self, response: &egui::Response, ui: &egui::Ui) -> bool {
+        // Ctrl/Cmd + right click opens the view context menu instead.
+        if ui.input(|input| input.modifiers.command) {
+            return false;
+        }
+        match self {
+            Self::ShiftPrimaryDrag => {
+                response.contains_pointer()
+                    && ui
+                        .input(|input| input.pointer.button_pressed(egui::PointerButton::Secondary))
+            }
+            Self::SecondaryDrag => response.secondary_clicked(),
+        }
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 struct WlOverlayLayout {
@@ -87,15 +307,70 @@ struct WlOverlayLayout {
     area_width: f32,
 }
 
+/// Frames cine actually put on screen recently, for comparing the achieved
+/// rate with the requested one when decoding or rendering falls behind.
+#[derive(Debug, Default)]
+struct CineRateMeter {
+    ticks: VecDeque<Instant>,
+}
+
+impl CineRateMeter {
+    /// Averaging window; long enough to smooth jitter, short enough to show
+    /// a slowdown within a second or two.
+    const WINDOW: Duration = Duration::from_secs(2);
+
+    fn record(&mut self, now: Instant) {
+        self.ticks.push_back(now);
+        while self
+            .ticks
+            .front()
+            .is_some_and(|tick| now.saturating_duration_since(*tick) > Self::WINDOW)
+        {
+            self.ticks.pop_front();
+        }
+    }
+
+    fn clear(&mut self) {
+        self.ticks.clear();
+    }
+
+    /// Displayed frames per second over the window ending at `now`, once a
+    /// few frames have been shown. Measuring up to `now` rather than the last
+    /// frame lets a stall pull the rate down instead of freezing it.
+    fn fps(&self, now: Instant) -> Option<f32> {
+        let first = self.ticks.front()?;
+        let span = now
+            .saturating_duration_since(*first)
+            .min(Self::WINDOW)
+            .as_secs_f32();
+        let recent = self
+            .ticks
+            .iter()
+            .filter(|tick| now.saturating_duration_since(**tick) <= Self::WINDOW)
+            .count();
+        (self.ticks.len() >= 3 && span > 0.0).then(|| recent.saturating_sub(1) as f32 / span)
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum WlOverlayRow {
+    Preset,
     Center,
     Width,
+    LockWindowLevel,
     Frame,
     CineFps,
     ToggleCine,
     ToggleOverlay,
     NextOverlay,
+    TrueSize,
+}
+
+impl WlOverlayRow {
+    /// Rows disabled while window/level is locked.
+    fn adjusts_window_level(self) -> bool {
+        matches!(self, Self::Preset | Self::Center | Self::Width)
+    }
 }
 
 #[derive(Clone)]
@@ -111,6 +386,21 @@ struct MammoViewport {
     zoom: f32,
     pan: egui::Vec2,
     frame_scroll_accum: f32,
+    /// Embedded window preset last applied with `W`.
+    window_preset_index: Option<usize>,
+}
+
+/// Multi-view group set aside while one of its cells is shown in the single
+/// view, so returning to the grid does not reload anything.
+struct PromotedCell {
+    group: Vec<Option<MammoViewport>>,
+    selected_index: usize,
+    history_thumb: ColorImage,
+    label: String,
+    zoom: f32,
+    pan: egui::Vec2,
+    window_preset_index: Option<usize>,
+    overlay_visible: bool,
 }
 
 struct ActiveViewportState {
@@ -123,6 +413,7 @@ struct ActiveViewportState {
     default_width: f32,
     window_center: f32,
     window_width: f32,
+    window_presets: Vec<WindowPreset>,
     current_frame: usize,
 }
 
@@ -143,35 +434,91 @@ pub struct DicomViewerApp {
     texture: Option<TextureHandle>,
     mammo_group: Vec<Option<MammoViewport>>,
     mammo_selected_index: usize,
+    /// Group whose selected cell is shown alone in the single view (`F`).
+    promoted_cell: Option<PromotedCell>,
     history_entries: Vec<HistoryEntry>,
-    visible_metadata_fields: HashSet<String>,
+    visible_metadata_fields: Vec<String>,
+    preferences: ViewerPreferences,
+    /// Last cine FPS chosen per `Modality`, used when an image has no
+    /// recommended frame rate of its own.
+    cine_fps_by_modality: BTreeMap<String, f32>,
+    /// Last manual window (center, width) per `Modality` for the sticky
+    /// window setting. Kept for the session only.
+    manual_window_by_modality: BTreeMap<String, (f32, f32)>,
+    /// Text of the View Tags setting while it is being edited.
+    view_fallback_tags_text: String,
+    ui_theme: UiTheme,
+    applied_ui_theme: Option<UiTheme>,
+    reset_settings_confirm_open: bool,
+    export_dialog: Option<FrameExportDialog>,
+    about_panel_open: bool,
+    renderer_name: Option<String>,
+    /// Kept alive after a frame copy: on X11 the copied image is only
+    /// served while the clipboard handle that set it exists.
+    frame_clipboard: Option<arboard::Clipboard>,
     full_metadata_popup_open: bool,
     settings_path: Option<PathBuf>,
     history_nonce: u64,
     pending_history_open_id: Option<String>,
     pending_history_open_armed: bool,
+    /// History entry shown as of the last frame.
+    displayed_history_id: Option<String>,
+    /// Most recently shown history entry other than the displayed one, the
+    /// target of the toggle-previous key.
+    previous_history_id: Option<String>,
     pending_local_open_paths: Option<Vec<PathBuf>>,
     pending_local_open_armed: bool,
     pending_launch_request: Option<LaunchRequest>,
+    /// Studies from `--worklist`, stepped through with `[` and `]`.
+    worklist: Vec<LaunchRequest>,
+    worklist_index: usize,
+    /// Launch behind the in-flight DICOMweb or URL download.
+    remote_launch_request: Option<LaunchRequest>,
+    /// Originating launch of each completed DICOMweb or URL download, keyed
+    /// by source identity, so a study link survives later launches.
+    remote_launch_requests: HashMap<String, LaunchRequest>,
     dicomweb_receiver: Option<Receiver<Result<DicomWebDownloadResult, String>>>,
     dicomweb_active_path_receiver: Option<Receiver<DicomWebGroupStreamUpdate>>,
     dicomweb_active_group_expected: Option<usize>,
     dicomweb_active_group_paths: Vec<DicomSourceMeta>,
     dicomweb_completed_background_groups: HashSet<usize>,
+    /// Non-open groups of a grouped launch whose instances are still arriving.
+    dicomweb_background_group_downloads: BTreeMap<usize, BackgroundGroupDownload>,
+    /// Background group the user opened while it was downloading; it takes the
+    /// launch's open group's place as the streamed active group.
+    dicomweb_open_group_override: Option<usize>,
     dicomweb_active_pending_paths: VecDeque<DicomSource>,
+    /// Series of a multi-series `series=` launch, shown as switchable tabs.
+    series_tabs: Vec<DicomWebSeriesTab>,
+    /// History ids of a multi-group launch's groups in launch order, for
+    /// flipping between pages such as a current and a prior study.
+    group_pages: Vec<String>,
+    frame_filmstrip: Option<FrameFilmstrip>,
+    /// Tiled frames of the single image, shown instead of the single view.
+    montage: Option<FrameMontage>,
+    /// Mask frame subtraction of the single image, once a mask is chosen.
+    subtraction: Option<FrameSubtraction>,
     local_prepare_receiver: Option<Receiver<LocalPrepareResult>>,
     local_prepare_cancel: Option<Arc<AtomicBool>>,
     full_metadata_receiver: Option<Receiver<FullMetadataLoadResult>>,
     full_metadata_sender: Option<Sender<FullMetadataLoadResult>>,
     single_load_receiver: Option<Receiver<Result<PendingSingleLoad, String>>>,
-    mammo_load_receiver: Option<Receiver<Result<PendingLoad, String>>>,
-    mammo_load_sender: Option<Sender<Result<PendingLoad, String>>>,
+    mammo_load_receiver: Option<Receiver<PendingLoad>>,
+    mammo_load_sender: Option<Sender<PendingLoad>>,
+    /// Placeholder cells of the active group that can be retried, by source identity.
+    mammo_failed_views: BTreeMap<String, FailedMammoView>,
+    /// Images of the open DICOMweb group that failed to download, by stand-in
+    /// identity, until their placeholder cells take them over.
+    dicomweb_failed_downloads: HashMap<String, FailedInstanceDownload>,
     history_pushed_for_active_group: bool,
     history_preload_receiver: Option<Receiver<Result<HistoryPreloadResult, String>>>,
     history_preload_queue: VecDeque<HistoryPreloadJob>,
     history_preload_active_key: Option<HistoryPreloadJobKey>,
     window_center: f32,
     window_width: f32,
+    /// Ignore window/level drags and disable the window sliders, so a tuned
+    /// window survives panning and measuring. Applies to every view.
+    window_level_locked: bool,
     pending_gsps_overlays: HashMap<String, GspsOverlay>,
     authoritative_gsps_overlay_keys: HashSet<String>,
     pending_sr_overlays: HashMap<String, SrOverlay>,
@@ -179,33 +526,86 @@ pub struct DicomViewerApp {
     pending_pm_overlays: HashMap<String, ParametricMapOverlay>,
     authoritative_pm_overlay_keys: HashSet<String>,
     overlay_visible: bool,
+    /// Window each image showed before overlays switched it to its presentation
+    /// state's window, keyed by source identity, restored when overlays turn off.
+    gsps_saved_windows: HashMap<String, (f32, f32)>,
     current_frame: usize,
     cine_mode: bool,
     cine_fps: f32,
     last_cine_advance: Option<Instant>,
+    cine_rate_meter: CineRateMeter,
+    /// Whether cine starts on open for the rest of this session. Seeded from
+    /// the `autoplay_cine` setting or the `cine=` launch parameter, and turned
+    /// off when the user stops a cine that started by itself.
+    session_autoplay_cine: bool,
+    /// The running cine was started by autoplay rather than by the user.
+    cine_autoplayed: bool,
+    /// `frames=` range from the launch URL, waiting for its image to open.
+    launch_frame_range: Option<RangeInclusive<usize>>,
+    /// Frames the single view is trimmed to; cine, the frame slider and frame
+    /// steps stay inside it.
+    single_frame_range: Option<RangeInclusive<usize>>,
     single_view_zoom: f32,
+    /// The user zoomed the single view away from fit, so
+    /// `keep_zoom_on_resize` holds its drawn size even if a resize brings
+    /// the zoom factor back to exactly 1.
+    single_view_zoomed: bool,
+    /// Content size and fit scale of the last single-view layout, for
+    /// `keep_zoom_on_resize`.
+    single_view_last_fit: Option<(egui::Vec2, f32)>,
     single_view_pan: egui::Vec2,
     single_view_frame_scroll_accum: f32,
+    /// Embedded window preset last applied to the single view with `W`.
+    single_window_preset_index: Option<usize>,
+    true_size_requested: bool,
+    measurement_zoom_requested: bool,
     live_measurement: Option<LiveMeasurement>,
     block_primary_interactions_until_release: bool,
+    /// Outer size of the window when last seen unmaximized, used to place it
+    /// under the cursor when a titlebar drag un-maximizes it.
+    restored_window_size: Option<egui::Vec2>,
     frame_wait_pending: bool,
     load_error_message: Option<String>,
+    /// Full error chain behind `load_error_message`, shown in a collapsible.
+    load_error_detail: Option<String>,
+    /// Cached auto-crop region of the single image in texture UV space;
+    /// `Some(None)` means detection ran and found no border.
+    single_view_content_uv: Option<Option<egui::Rect>>,
+    /// Id of the history entry highlighted with the arrow keys, opened with
+    /// Enter. An id survives the list reordering under it.
+    history_focus_id: Option<String>,
+    /// Screen area of the history list as of the last frame.
+    history_panel_rect: Option<egui::Rect>,
+    /// Whether the last primary click landed in the history list, which then
+    /// keeps the arrow keys after the pointer leaves it.
+    history_panel_focused: bool,
+    history_focus_scroll_pending: bool,
 }
 
 impl Default for DicomViewerApp {
     fn default() -> Self {
-        Self::new(None)
+        Self::new(None, None)
     }
 }
 
 impl DicomViewerApp {
-    pub fn new(initial_request: Option<LaunchRequest>) -> Self {
+    pub fn new(initial_request: Option<LaunchRequest>, launch_theme: Option<UiTheme>) -> Self {
         let settings_path = metadata_settings_file_path();
         let (full_metadata_sender, full_metadata_receiver) = mpsc::channel();
         let visible_metadata_fields = settings_path
             .as_deref()
             .and_then(load_visible_metadata_fields)
             .unwrap_or_else(default_visible_metadata_fields);
+        let preferences = settings_path
+            .as_deref()
+            .map(load_viewer_preferences)
+            .unwrap_or_default();
+        let cine_fps_by_modality = settings_path
+            .as_deref()
+            .map(load_cine_fps_by_modality)
+            .unwrap_or_default();
+        let view_fallback_tags_text = preferences.view_fallback_tags.join(", ");
+        let session_autoplay_cine = preferences.autoplay_cine;
 
         Self {
             image: None,
@@ -214,21 +614,46 @@ impl DicomViewerApp {
             texture: None,
             mammo_group: Vec::new(),
             mammo_selected_index: 0,
+            promoted_cell: None,
             history_entries: Vec::new(),
             visible_metadata_fields,
+            preferences,
+            cine_fps_by_modality,
+            manual_window_by_modality: BTreeMap::new(),
+            view_fallback_tags_text,
+            ui_theme: launch_theme.unwrap_or_default(),
+            applied_ui_theme: None,
+            reset_settings_confirm_open: false,
+            export_dialog: None,
+            about_panel_open: false,
+            renderer_name: None,
+            frame_clipboard: None,
             full_metadata_popup_open: false,
             settings_path,
             history_nonce: 0,
             pending_history_open_id: None,
             pending_history_open_armed: false,
+            displayed_history_id: None,
+            previous_history_id: None,
             pending_local_open_paths: None,
             pending_local_open_armed: false,
             pending_launch_request: initial_request,
+            worklist: Vec::new(),
+            worklist_index: 0,
+            remote_launch_request: None,
+            remote_launch_requests: HashMap::new(),
             dicomweb_receiver: None,
             dicomweb_active_path_receiver: None,
             dicomweb_active_group_expected: None,
             dicomweb_active_group_paths: Vec::new(),
             dicomweb_completed_background_groups: HashSet::new(),
+            dicomweb_background_group_downloads: BTreeMap::new(),
+            dicomweb_open_group_override: None,
+            series_tabs: Vec::new(),
+            group_pages: Vec::new(),
+            frame_filmstrip: None,
+            montage: None,
+            subtraction: None,
             dicomweb_active_pending_paths: VecDeque::new(),
             local_prepare_receiver: None,
             local_prepare_cancel: None,
@@ -237,12 +662,15 @@ impl DicomViewerApp {
             single_load_receiver: None,
             mammo_load_receiver: None,
             mammo_load_sender: None,
+            mammo_failed_views: BTreeMap::new(),
+            dicomweb_failed_downloads: HashMap::new(),
             history_pushed_for_active_group: false,
             history_preload_receiver: None,
             history_preload_queue: VecDeque::new(),
             history_preload_active_key: None,
             window_center: 0.0,
             window_width: 1.0,
+            window_level_locked: false,
             pending_gsps_overlays: HashMap::new(),
             authoritative_gsps_overlay_keys: HashSet::new(),
             pending_sr_overlays: HashMap::new(),
@@ -250,21 +678,69 @@ impl DicomViewerApp {
             pending_pm_overlays: HashMap::new(),
             authoritative_pm_overlay_keys: HashSet::new(),
             overlay_visible: false,
+            gsps_saved_windows: HashMap::new(),
             current_frame: 0,
             cine_mode: false,
             cine_fps: DEFAULT_CINE_FPS,
             last_cine_advance: None,
+            cine_rate_meter: CineRateMeter::default(),
+            session_autoplay_cine,
+            cine_autoplayed: false,
+            launch_frame_range: None,
+            single_frame_range: None,
             single_view_zoom: 1.0,
+            single_view_zoomed: false,
+            single_view_last_fit: None,
             single_view_pan: egui::Vec2::ZERO,
             single_view_frame_scroll_accum: 0.0,
+            single_window_preset_index: None,
+            true_size_requested: false,
+            measurement_zoom_requested: false,
             live_measurement: None,
             block_primary_interactions_until_release: false,
+            restored_window_size: None,
             frame_wait_pending: false,
             load_error_message: None,
+            load_error_detail: None,
+            single_view_content_uv: None,
+            history_focus_id: None,
+            history_panel_rect: None,
+            history_panel_focused: false,
+            history_focus_scroll_pending: false,
+        }
+    }
+
+    /// Applies the UI theme only when it has not been applied yet, so the
+    /// visuals are not rebuilt on every frame. Returns whether styling changed.
+    fn ensure_ui_theme_applied(&mut self, ctx: &egui::Context) -> bool {
+        if self.applied_ui_theme == Some(self.ui_theme) {
+            return false;
+        }
+        Self::apply_ui_theme(ctx, self.ui_theme);
+        self.applied_ui_theme = Some(self.ui_theme);
+        true
+    }
+
+    fn apply_ui_theme(ctx: &egui::Context, theme: UiTheme) {
+        // Pin the theme preference as well, otherwise egui would switch to the
+        // system theme's visuals on an OS theme change and never switch back.
+        match theme {
+            UiTheme::Black => {
+                ctx.set_theme(egui::Theme::Dark);
+                ctx.set_visuals_of(egui::Theme::Dark, Self::black_background_visuals());
+            }
+            UiTheme::Dark => {
+                ctx.set_theme(egui::Theme::Dark);
+                ctx.set_visuals_of(egui::Theme::Dark, egui::Visuals::dark());
+            }
+            UiTheme::Light => {
+                ctx.set_theme(egui::Theme::Light);
+                ctx.set_visuals_of(egui::Theme::Light, egui::Visuals::light());
+            }
         }
     }
 
-    fn apply_black_background(ctx: &egui::Context) {
+    fn black_background_visuals() -> egui::Visuals {
         let mut visuals = egui::Visuals::dark();
         let line_base = egui::Color32::from_gray(28);
         let line_hover = egui::Color32::from_gray(42);
@@ -280,7 +756,7 @@ impl DicomViewerApp {
         visuals.widgets.hovered.bg_stroke = egui::Stroke::new(1.0, line_hover);
         visuals.widgets.active.bg_stroke = egui::Stroke::new(1.0, line_active);
         visuals.widgets.open.bg_stroke = egui::Stroke::new(1.0, line_base);
-        ctx.set_visuals(visuals);
+        visuals
     }
 
     fn is_loading(&self) -> bool {
@@ -466,11 +942,58 @@ impl DicomViewerApp {
         self.loaded_mammo_viewports().count()
     }
 
+    fn undecodable_mammo_count(&self) -> usize {
+        self.loaded_mammo_viewports()
+            .filter(|viewport| viewport.image.undecodable_reason().is_some())
+            .count()
+    }
+
     fn mammo_group_complete(&self) -> bool {
         Self::is_supported_multi_view_group_size(self.mammo_group.len())
             && self.loaded_mammo_count() == self.mammo_group.len()
     }
 
+    /// Cine FPS for a newly opened image: its own recommended rate, else the
+    /// last rate used for its modality, else the global default.
+    fn initial_cine_fps_for_image(&self, image: &DicomImage) -> f32 {
+        image
+            .recommended_cine_fps
+            .or_else(|| {
+                image_modality(image)
+                    .and_then(|modality| self.cine_fps_by_modality.get(&modality).copied())
+            })
+            .unwrap_or(DEFAULT_CINE_FPS)
+            .clamp(1.0, 120.0)
+    }
+
+    fn active_image_modality(&self) -> Option<String> {
+        self.image
+            .as_ref()
+            .or_else(|| {
+                self.selected_mammo_viewport()
+                    .map(|viewport| &viewport.image)
+            })
+            .and_then(image_modality)
+    }
+
+    fn remember_cine_fps_for_active_modality(&mut self) {
+        let Some(modality) = self.active_image_modality() else {
+            return;
+        };
+        self.cine_fps_by_modality
+            .insert(modality, self.cine_fps.clamp(1.0, 120.0));
+        self.persist_settings();
+    }
+
+    fn forget_cine_fps_for_active_modality(&mut self) {
+        let Some(modality) = self.active_image_modality() else {
+            return;
+        };
+        if self.cine_fps_by_modality.remove(&modality).is_some() {
+            self.persist_settings();
+        }
+    }
+
     fn default_cine_fps_for_active_image(&self) -> f32 {
         self.image
             .as_ref()
@@ -486,6 +1009,7 @@ impl DicomViewerApp {
 
     fn mammo_group_common_frame_count(&self) -> usize {
         self.loaded_mammo_viewports()
+            .filter(|viewport| viewport.image.undecodable_reason().is_none())
             .map(|viewport| viewport.image.frame_count())
             .min()
             .unwrap_or(0)
@@ -544,7 +1068,7 @@ impl DicomViewerApp {
                     jobs.push((
                         index,
                         scope.spawn(move || {
-                            Self::render_image_frame(
+                            Self::render_display_frame(
                                 image,
                                 *safe_frame,
                                 *center,
@@ -594,37 +1118,131 @@ impl DicomViewerApp {
         let mut changed = false;
         ui.horizontal(|ui| {
             if ui.small_button("All").clicked() {
-                self.visible_metadata_fields = default_visible_metadata_fields();
+                for field in METADATA_FIELD_NAMES {
+                    if !self
+                        .visible_metadata_fields
+                        .iter()
+                        .any(|name| name == field)
+                    {
+                        self.visible_metadata_fields.push((*field).to_string());
+                    }
+                }
                 changed = true;
             }
             if ui.small_button("None").clicked() {
                 self.visible_metadata_fields.clear();
                 changed = true;
             }
+            if ui.small_button("Default").clicked() {
+                self.visible_metadata_fields = default_visible_metadata_fields();
+                changed = true;
+            }
         });
         ui.add_space(4.0);
         egui::ScrollArea::vertical()
             .id_salt("metadata-fields-menu")
             .max_height(220.0)
             .show(ui, |ui| {
+                let mut hidden_field = None;
+                let mut moved_field = None;
+                for (index, field) in self.visible_metadata_fields.iter().enumerate() {
+                    let row_response = ui
+                        .horizontal(|ui| {
+                            ui.dnd_drag_source(
+                                egui::Id::new(("metadata-field-drag", index)),
+                                index,
+                                |ui| {
+                                    ui.label("≡");
+                                },
+                            )
+                            .response
+                            .on_hover_text("Drag to reorder");
+                            let mut checked = true;
+                            if ui.checkbox(&mut checked, field.as_str()).changed() && !checked {
+                                hidden_field = Some(index);
+                            }
+                        })
+                        .response;
+                    if let Some(from) = row_response.dnd_release_payload::<usize>() {
+                        moved_field = Some((*from, index));
+                    }
+                }
+                if !self.visible_metadata_fields.is_empty() {
+                    ui.separator();
+                }
+                let mut shown_field = None;
                 for field in METADATA_FIELD_NAMES {
-                    let mut checked = self.visible_metadata_fields.contains(*field);
-                    if ui.checkbox(&mut checked, *field).changed() {
-                        if checked {
-                            self.visible_metadata_fields.insert((*field).to_string());
-                        } else {
-                            self.visible_metadata_fields.remove(*field);
-                        }
-                        changed = true;
+                    if self
+                        .visible_metadata_fields
+                        .iter()
+                        .any(|name| name == field)
+                    {
+                        continue;
+                    }
+                    let mut checked = false;
+                    if ui.checkbox(&mut checked, *field).changed() && checked {
+                        shown_field = Some((*field).to_string());
                     }
                 }
+
+                if let Some((from, to)) = moved_field {
+                    changed |= move_metadata_field(&mut self.visible_metadata_fields, from, to);
+                }
+                if let Some(index) = hidden_field {
+                    self.visible_metadata_fields.remove(index);
+                    changed = true;
+                }
+                if let Some(field) = shown_field {
+                    self.visible_metadata_fields.push(field);
+                    changed = true;
+                }
             });
         if changed {
-            self.persist_metadata_settings();
+            self.persist_settings();
+        }
+    }
+
+    fn reset_all_settings(&mut self) {
+        self.visible_metadata_fields = default_visible_metadata_fields();
+        self.preferences = ViewerPreferences::default();
+        self.session_autoplay_cine = self.preferences.autoplay_cine;
+        self.cine_fps_by_modality.clear();
+        self.view_fallback_tags_text.clear();
+        self.persist_settings();
+        log::info!("Reset all settings to defaults.");
+    }
+
+    fn show_reset_settings_confirmation(&mut self, ctx: &egui::Context) {
+        if !self.reset_settings_confirm_open {
+            return;
+        }
+
+        let mut confirmed = false;
+        let mut cancelled = false;
+        let modal = egui::Modal::new(egui::Id::new("reset-settings-confirmation")).show(ctx, |ui| {
+            ui.label(egui::RichText::new("Reset all settings?").size(TITLE_TEXT_SIZE));
+            ui.add_space(4.0);
+            ui.label("Metadata field selection and viewer preferences will return to their defaults.");
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if ui.button("Reset").clicked() {
+                    confirmed = true;
+                }
+                if ui.button("Cancel").clicked() {
+                    cancelled = true;
+                }
+            });
+        });
+
+        if confirmed {
+            self.reset_all_settings();
+        }
+        if confirmed || cancelled || modal.should_close() {
+            self.reset_settings_confirm_open = false;
         }
     }
 
-    fn persist_metadata_settings(&self) {
+    fn persist_settings(&self) {
         let Some(path) = self.settings_path.as_ref() else {
             return;
         };
@@ -639,8 +1257,11 @@ impl DicomViewerApp {
             return;
         }
 
-        let fields = ordered_visible_metadata_fields(&self.visible_metadata_fields);
-        let contents = render_settings_toml(&fields);
+        let contents = render_settings_toml(
+            &self.visible_metadata_fields,
+            &self.preferences,
+            &self.cine_fps_by_modality,
+        );
         if let Err(err) = fs::write(path, contents) {
             log::warn!("Could not write settings file: {err}");
         }
@@ -665,7 +1286,13 @@ impl DicomViewerApp {
             return;
         };
 
-        if !self.pending_history_open_armed {
+        let open_now = self.preferences.instant_local_open
+            && self
+                .history_entries
+                .iter()
+                .find(|entry| entry.id == entry_id)
+                .is_some_and(HistoryEntry::is_local);
+        if !self.pending_history_open_armed && !open_now {
             self.pending_history_open_armed = true;
             ctx.request_repaint();
             return;
@@ -712,14 +1339,17 @@ impl DicomViewerApp {
             return true;
         }
 
-        Self::path_has_dicom_prefix(path)
+        Self::path_opens_as_dicom(path)
     }
 
     fn is_known_dicom_extension(extension: &str) -> bool {
         extension.eq_ignore_ascii_case("dcm") || extension.eq_ignore_ascii_case("dicom")
     }
 
-    fn path_has_dicom_prefix(path: &Path) -> bool {
+    /// A `DICM` prefix is accepted from the first bytes alone; anything else
+    /// is probed the way it would be opened, so raw data sets without a
+    /// preamble are found too.
+    fn path_opens_as_dicom(path: &Path) -> bool {
         let mut file = match fs::File::open(path) {
             Ok(file) => file,
             Err(err) => {
@@ -734,7 +1364,7 @@ impl DicomViewerApp {
             .take(PICKER_DICOM_PREFIX_READ_BYTES as u64)
             .read_to_end(&mut bytes)
         {
-            Ok(_) => detect_dicom_prefix_offset(&bytes).is_some(),
+            Ok(_) => detect_dicom_prefix_offset(&bytes).is_some() || can_open_dicom_source(path),
             Err(err) => {
                 log::debug!(
                     "Could not read DICOM prefix from selected file {}: {err}",
@@ -826,7 +1456,7 @@ impl DicomViewerApp {
             return;
         };
 
-        if !self.pending_local_open_armed {
+        if !self.pending_local_open_armed && !self.preferences.instant_local_open {
             self.pending_local_open_armed = true;
             ctx.request_repaint();
             return;
@@ -840,125 +1470,551 @@ impl DicomViewerApp {
         self.start_local_paths_prepare(paths, ctx);
     }
 
+    /// Remembers the single image's window for the sticky window setting: a
+    /// window moved off the image default is kept for its modality, while one
+    /// left at the default forgets it. Subtraction windows are not kept.
+    fn remember_manual_window(&mut self) {
+        let Some(image) = self.image.as_ref().filter(|image| image.is_monochrome()) else {
+            return;
+        };
+        if self.active_subtraction_mask().is_some() {
+            return;
+        }
+        let Some(modality) = image_modality(image) else {
+            return;
+        };
+        let window = (self.window_center, self.window_width);
+        if window == (image.window_center, image.window_width) {
+            self.manual_window_by_modality.remove(&modality);
+        } else {
+            self.manual_window_by_modality.insert(modality, window);
+        }
+    }
+
+    /// Window a newly opened image starts with under the sticky window
+    /// setting, if one was set on an image of the same modality.
+    fn sticky_window_for_image(&self, image: &DicomImage) -> Option<(f32, f32)> {
+        if !self.preferences.sticky_window || !image.is_monochrome() {
+            return None;
+        }
+        image_modality(image)
+            .and_then(|modality| self.manual_window_by_modality.get(&modality).copied())
+    }
+
     fn clear_single_viewer(&mut self) {
+        self.discard_subtraction();
+        self.remember_manual_window();
         self.image = None;
         self.report = None;
         self.current_single_path = None;
         self.texture = None;
         self.overlay_visible = false;
+        self.gsps_saved_windows.clear();
         self.current_frame = 0;
         self.cine_mode = false;
         self.last_cine_advance = None;
+        self.launch_frame_range = None;
+        self.single_frame_range = None;
+        self.montage = None;
+        self.promoted_cell = None;
         self.mammo_selected_index = 0;
         self.reset_single_view_transform();
         self.single_view_frame_scroll_accum = 0.0;
+        self.single_view_content_uv = None;
         self.reset_live_measurement();
         self.frame_wait_pending = false;
     }
 
     fn reset_single_view_transform(&mut self) {
         self.single_view_zoom = 1.0;
+        self.single_view_zoomed = false;
+        self.center_single_view();
+    }
+
+    fn center_single_view(&mut self) {
         self.single_view_pan = egui::Vec2::ZERO;
     }
 
-    fn clear_load_error(&mut self) {
-        self.load_error_message = None;
+    /// Resets zoom and pan of the single view or the selected cell, as a
+    /// double-click does.
+    fn reset_active_view(&mut self) {
+        if self.image.is_some() {
+            self.reset_single_view_transform();
+        } else if let Some(viewport) = self.selected_mammo_viewport_mut() {
+            viewport.zoom = 1.0;
+            viewport.pan = egui::Vec2::ZERO;
+        }
     }
 
-    fn set_load_error(&mut self, message: impl Into<String>) {
-        self.load_error_message = Some(message.into());
+    /// Re-centers the active view on the fitted image without changing zoom.
+    fn center_active_view(&mut self) {
+        if self.image.is_some() {
+            self.center_single_view();
+        } else if let Some(viewport) = self.selected_mammo_viewport_mut() {
+            viewport.pan = egui::Vec2::ZERO;
+        }
     }
 
-    fn open_dicoms(&mut self, ctx: &egui::Context) {
-        let picked = rfd::FileDialog::new().pick_files();
+    fn can_toggle_promoted_cell(&self) -> bool {
+        self.promoted_cell.is_some()
+            || (self.mammo_group_complete()
+                && self.mammo_load_receiver.is_none()
+                && self.dicomweb_active_path_receiver.is_none())
+    }
 
-        if let Some(paths) = picked {
-            self.queue_picker_paths_open(paths, ctx);
+    /// Shows the selected multi-view cell alone in the single view, or returns
+    /// to the grid it came from.
+    fn toggle_promoted_cell(&mut self, ctx: &egui::Context) {
+        if self.promoted_cell.is_some() {
+            self.return_promoted_cell(ctx);
+        } else if self.can_toggle_promoted_cell() {
+            self.promote_selected_cell();
         }
     }
 
-    fn show_file_drop_overlay(&self, ctx: &egui::Context, hovered_files: &[egui::HoveredFile]) {
-        if hovered_files.is_empty() {
+    fn promote_selected_cell(&mut self) {
+        let selected_index = self
+            .mammo_selected_index
+            .min(self.mammo_group.len().saturating_sub(1));
+        let mut group = std::mem::take(&mut self.mammo_group);
+        let Some(viewport) = group.get_mut(selected_index).and_then(Option::take) else {
+            self.mammo_group = group;
             return;
-        }
-
-        let overlay_rect = ctx.content_rect();
-        let painter = ctx.layer_painter(egui::LayerId::new(
-            egui::Order::Foreground,
-            egui::Id::new("file-drop-backdrop"),
-        ));
-        painter.rect_filled(overlay_rect, 0.0, egui::Color32::from_black_alpha(168));
-
-        let heading = Self::file_drop_overlay_heading(hovered_files);
-        egui::Area::new(egui::Id::new("file-drop-overlay"))
-            .order(egui::Order::Foreground)
-            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
-            .show(ctx, |ui| {
-                egui::Frame::NONE
-                    .fill(egui::Color32::from_black_alpha(228))
-                    .stroke(egui::Stroke::NONE)
-                    .corner_radius(12)
-                    .inner_margin(egui::Margin::symmetric(24, 20))
-                    .show(ui, |ui| {
-                        ui.set_min_width(FILE_DROP_OVERLAY_WIDTH);
-                        ui.set_max_width(FILE_DROP_OVERLAY_WIDTH);
-                        ui.vertical_centered(|ui| {
-                            ui.label(egui::RichText::new(heading).strong().size(24.0));
-                            ui.add_space(6.0);
-                            ui.label(
-                                egui::RichText::new("Drop DICOM files anywhere in the window.")
-                                    .color(egui::Color32::from_gray(196)),
-                            );
-                        });
-                    });
-            });
+        };
+        let overlay_visible = self.overlay_visible;
+        let gsps_saved_windows = std::mem::take(&mut self.gsps_saved_windows);
+        self.clear_single_viewer();
+        self.overlay_visible = overlay_visible;
+        self.gsps_saved_windows = gsps_saved_windows;
+        self.image = Some(viewport.image);
+        self.current_single_path = Some(viewport.path);
+        self.texture = Some(viewport.texture);
+        self.window_center = viewport.window_center;
+        self.window_width = viewport.window_width.max(1.0);
+        self.current_frame = viewport.current_frame;
+        self.promoted_cell = Some(PromotedCell {
+            group,
+            selected_index,
+            history_thumb: viewport.history_thumb,
+            label: viewport.label,
+            zoom: viewport.zoom,
+            pan: viewport.pan,
+            window_preset_index: viewport.window_preset_index,
+            overlay_visible,
+        });
     }
 
-    fn toggle_cine_mode(&mut self) {
-        if let Some(image) = self.image.as_ref() {
-            if image.frame_count() <= 1 {
-                self.cine_mode = false;
-                log::debug!("Cine mode requires a multi-frame DICOM.");
-                return;
-            }
-            self.cine_mode = !self.cine_mode;
-            self.clear_live_measurement();
-            self.last_cine_advance = Some(Instant::now());
+    /// Puts the promoted image, with any window/level and frame changes made
+    /// in the single view, back into its cell.
+    fn return_promoted_cell(&mut self, ctx: &egui::Context) {
+        if self.image.is_none() || self.current_single_path.is_none() || self.texture.is_none() {
             return;
         }
-
-        if self.loaded_mammo_count() == 0 {
-            self.cine_mode = false;
-            return;
+        if self.active_subtraction_mask().is_some() {
+            self.toggle_subtraction(ctx);
         }
-
-        if !self.mammo_group_complete() {
-            self.cine_mode = false;
-            log::debug!("Multi-view cine mode requires all views to be loaded.");
+        let Some(promoted) = self.promoted_cell.take() else {
             return;
-        }
-
-        let frame_count = self.mammo_group_common_frame_count();
-        if frame_count <= 1 {
-            self.cine_mode = false;
-            log::debug!("Multi-view cine mode requires all views to be multi-frame.");
+        };
+        let (Some(image), Some(path), Some(texture)) = (
+            self.image.take(),
+            self.current_single_path.take(),
+            self.texture.take(),
+        ) else {
             return;
+        };
+        let viewport = MammoViewport {
+            path,
+            image,
+            texture,
+            history_thumb: promoted.history_thumb,
+            label: promoted.label,
+            window_center: self.window_center,
+            window_width: self.window_width,
+            current_frame: self.current_frame,
+            zoom: promoted.zoom,
+            pan: promoted.pan,
+            frame_scroll_accum: 0.0,
+            window_preset_index: promoted.window_preset_index,
+        };
+        let overlay_visible = self.overlay_visible;
+        let gsps_saved_windows = std::mem::take(&mut self.gsps_saved_windows);
+        self.clear_single_viewer();
+        self.overlay_visible = overlay_visible;
+        self.gsps_saved_windows = gsps_saved_windows;
+        self.mammo_group = promoted.group;
+        if let Some(slot) = self.mammo_group.get_mut(promoted.selected_index) {
+            *slot = Some(viewport);
         }
-
-        let enabling = !self.cine_mode;
-        self.cine_mode = enabling;
-        self.clear_live_measurement();
-        self.last_cine_advance = Some(Instant::now());
-        if enabling {
-            let start_frame = self
-                .selected_mammo_frame_index()
-                .min(frame_count.saturating_sub(1));
-            let _ = self.set_mammo_group_frame(start_frame);
+        self.mammo_selected_index = promoted.selected_index;
+        // Overlays toggled in the single view only switched the promoted image's window.
+        self.apply_gsps_windows(overlay_visible);
+        if overlay_visible != promoted.overlay_visible {
+            self.refresh_active_textures(ctx);
+        }
+        self.sync_current_state_to_history();
+    }
+
+    /// Region of the single image to fit into the canvas. Without auto-crop, or
+    /// before the first frame is decoded, this is the whole texture.
+    fn single_view_content_uv(&mut self) -> egui::Rect {
+        let full = egui::Rect::from_min_max(egui::Pos2::ZERO, egui::pos2(1.0, 1.0));
+        if !self.preferences.auto_crop_borders {
+            return full;
+        }
+        if let Some(cached) = self.single_view_content_uv {
+            return cached.unwrap_or(full);
+        }
+        let Some(image) = self.image.as_ref() else {
+            return full;
+        };
+        let detected = if image.is_monochrome() {
+            let Some(pixels) = image.frame_mono_pixels(0) else {
+                return full;
+            };
+            image.detect_content_bounds(&pixels).map(|bounds| {
+                let (width, height) = (image.width as f32, image.height as f32);
+                egui::Rect::from_min_size(
+                    egui::pos2(bounds.x as f32 / width, bounds.y as f32 / height),
+                    egui::vec2(bounds.width as f32 / width, bounds.height as f32 / height),
+                )
+            })
+        } else {
+            None
+        };
+        self.single_view_content_uv = Some(detected);
+        detected.unwrap_or(full)
+    }
+
+    /// Screen rect of the whole image when `content_uv` is centered in the
+    /// canvas, so overlays keep mapping through the full image rect.
+    fn single_view_image_rect(
+        canvas_center: egui::Pos2,
+        pan: egui::Vec2,
+        draw_size: egui::Vec2,
+        content_uv: egui::Rect,
+    ) -> egui::Rect {
+        let content_offset = (egui::vec2(0.5, 0.5) - content_uv.center().to_vec2()) * draw_size;
+        egui::Rect::from_center_size(canvas_center + pan + content_offset, draw_size)
+    }
+
+    fn show_monitor_dpi_setting(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Monitor DPI");
+            let mut dpi = self.preferences.monitor_dpi.unwrap_or(0.0);
+            let response = ui
+                .add(
+                    egui::DragValue::new(&mut dpi)
+                        .range(0.0..=MAX_MONITOR_DPI)
+                        .speed(0.5)
+                        .max_decimals(1),
+                )
+                .on_hover_text(
+                    "Physical pixels per inch of this monitor, used by True Size. 0 disables it.",
+                );
+            if response.changed() {
+                self.preferences.monitor_dpi = (dpi > 0.0).then_some(dpi);
+            }
+            if response.drag_stopped() || (response.changed() && !response.dragged()) {
+                self.persist_settings();
+            }
+        });
+    }
+
+    fn show_view_fallback_tags_setting(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label("View Tags");
+            let response = ui
+                .add(
+                    egui::TextEdit::singleline(&mut self.view_fallback_tags_text)
+                        .hint_text("none")
+                        .desired_width(180.0),
+                )
+                .on_hover_text(
+                    "Comma-separated DICOM keywords, such as AcquisitionDeviceProcessingDescription, read in order for view and laterality when ViewPosition and ImageLaterality are empty. Applies to images opened afterwards.",
+                );
+            if !response.lost_focus() {
+                return;
+            }
+            match parse_view_fallback_tags(&self.view_fallback_tags_text) {
+                Ok(tags) => {
+                    self.view_fallback_tags_text = tags.join(", ");
+                    if tags != self.preferences.view_fallback_tags {
+                        self.preferences.view_fallback_tags = tags;
+                        self.persist_settings();
+                    }
+                }
+                Err(err) => {
+                    log::warn!("Ignoring view tags setting: {err}.");
+                    self.view_fallback_tags_text = self.preferences.view_fallback_tags.join(", ");
+                }
+            }
+        });
+    }
+
+    fn show_grid_style_settings(&mut self, ui: &mut egui::Ui) {
+        ui.menu_button("Multi-View Grid", |ui| {
+            let style = &mut self.preferences.grid_style;
+            let mut committed = false;
+            ui.horizontal(|ui| {
+                ui.label("Gap");
+                let response = ui
+                    .add(
+                        egui::DragValue::new(&mut style.gap)
+                            .range(0.0..=MAX_GRID_GAP)
+                            .speed(0.1)
+                            .max_decimals(1),
+                    )
+                    .on_hover_text("Points between multi-view cells. 0 makes the grid seamless.");
+                committed |= Self::value_edit_committed(&response);
+            });
+            ui.horizontal(|ui| {
+                ui.label("Border Width");
+                let response = ui
+                    .add(
+                        egui::DragValue::new(&mut style.border_width)
+                            .range(0.0..=MAX_GRID_BORDER_WIDTH)
+                            .speed(0.1)
+                            .max_decimals(1),
+                    )
+                    .on_hover_text(
+                        "Width of each cell's border. The selected cell stays outlined.",
+                    );
+                committed |= Self::value_edit_committed(&response);
+            });
+            ui.horizontal(|ui| {
+                ui.label("Border Color");
+                let mut color = style.border_color;
+                if ui.color_edit_button_srgba(&mut color).changed() {
+                    style.border_color = color.to_opaque();
+                    committed = true;
+                }
+            });
+            if ui.button("Default Grid").clicked() {
+                *style = MultiViewGridStyle::default();
+                committed = true;
+            }
+            if committed {
+                self.persist_settings();
+            }
+        });
+    }
+
+    /// With `keep_zoom_on_resize`, rescales a zoomed single view so a change
+    /// of fit scale leaves its drawn size alone. A view at fit keeps fitting
+    /// the canvas.
+    fn hold_single_view_zoom_across_resize(&mut self, content_size: egui::Vec2, fit_scale: f32) {
+        if let Some((last_content_size, last_fit_scale)) = self.single_view_last_fit {
+            if self.preferences.keep_zoom_on_resize
+                && self.single_view_zoomed
+                && last_content_size == content_size
+                && last_fit_scale != fit_scale
+            {
+                self.single_view_zoom =
+                    Self::zoom_for_fit_change(self.single_view_zoom, last_fit_scale, fit_scale);
+            }
+        }
+        self.single_view_last_fit = Some((content_size, fit_scale));
+    }
+
+    /// Zoom that keeps the drawn size (`fit_scale * zoom`) when the fit scale
+    /// changes from `old_fit` to `new_fit`.
+    fn zoom_for_fit_change(zoom: f32, old_fit: f32, new_fit: f32) -> f32 {
+        let rescaled = zoom * old_fit / new_fit.max(f32::EPSILON);
+        if rescaled.is_finite() {
+            rescaled.clamp(SINGLE_VIEW_MIN_TRUE_SIZE_ZOOM, SINGLE_VIEW_MAX_ZOOM)
+        } else {
+            zoom
+        }
+    }
+
+    /// Single-view zoom, relative to fit-to-canvas, at which one image column
+    /// spans its physical width on a display with the given DPI.
+    fn true_size_zoom(
+        column_spacing_mm: f32,
+        monitor_dpi: f32,
+        pixels_per_point: f32,
+        fit_scale: f32,
+    ) -> Option<f32> {
+        let points_per_image_pixel =
+            column_spacing_mm / 25.4 * monitor_dpi / pixels_per_point.max(f32::EPSILON);
+        let zoom = points_per_image_pixel / fit_scale;
+        (zoom.is_finite() && zoom > 0.0)
+            .then(|| zoom.clamp(SINGLE_VIEW_MIN_TRUE_SIZE_ZOOM, SINGLE_VIEW_MAX_ZOOM))
+    }
+
+    fn single_image_column_spacing_mm(&self) -> Option<f32> {
+        self.image
+            .as_ref()
+            .and_then(|image| image.pixel_spacing_mm)
+            .map(|spacing| spacing.col_mm)
+            .filter(|spacing| spacing.is_finite() && *spacing > 0.0)
+    }
+
+    fn clear_load_error(&mut self) {
+        self.load_error_message = None;
+        self.load_error_detail = None;
+    }
+
+    fn set_load_error(&mut self, message: impl Into<String>) {
+        self.load_error_message = Some(message.into());
+        self.load_error_detail = None;
+    }
+
+    fn set_load_error_with_detail(
+        &mut self,
+        message: impl Into<String>,
+        detail: impl Into<String>,
+    ) {
+        self.load_error_message = Some(message.into());
+        self.load_error_detail = Some(detail.into()).filter(|detail| !detail.trim().is_empty());
+    }
+
+    fn open_dicoms(&mut self, ctx: &egui::Context) {
+        let picked = rfd::FileDialog::new()
+            .add_filter("DICOM", &["dcm", "dicom"])
+            .add_filter("All files", &["*"])
+            .pick_files();
+
+        if let Some(paths) = picked {
+            self.queue_picker_paths_open(paths, ctx);
+        }
+    }
+
+    fn show_file_drop_overlay(&self, ctx: &egui::Context, hovered_files: &[egui::HoveredFile]) {
+        if hovered_files.is_empty() {
+            return;
+        }
+
+        let overlay_rect = ctx.content_rect();
+        let painter = ctx.layer_painter(egui::LayerId::new(
+            egui::Order::Foreground,
+            egui::Id::new("file-drop-backdrop"),
+        ));
+        painter.rect_filled(overlay_rect, 0.0, egui::Color32::from_black_alpha(168));
+
+        let heading = Self::file_drop_overlay_heading(hovered_files);
+        egui::Area::new(egui::Id::new("file-drop-overlay"))
+            .order(egui::Order::Foreground)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                egui::Frame::NONE
+                    .fill(egui::Color32::from_black_alpha(228))
+                    .stroke(egui::Stroke::NONE)
+                    .corner_radius(12)
+                    .inner_margin(egui::Margin::symmetric(24, 20))
+                    .show(ui, |ui| {
+                        ui.set_min_width(FILE_DROP_OVERLAY_WIDTH);
+                        ui.set_max_width(FILE_DROP_OVERLAY_WIDTH);
+                        ui.vertical_centered(|ui| {
+                            ui.label(egui::RichText::new(heading).strong().size(24.0));
+                            ui.add_space(6.0);
+                            ui.label(
+                                egui::RichText::new("Drop DICOM files anywhere in the window.")
+                                    .color(egui::Color32::from_gray(196)),
+                            );
+                        });
+                    });
+            });
+    }
+
+    /// Overrides whether cine autoplays for this session, as `cine=` does.
+    pub fn set_session_autoplay_cine(&mut self, autoplay: bool) {
+        self.session_autoplay_cine = autoplay;
+    }
+
+    /// Trims the image the launch opens to `frames` (0-based, inclusive), as
+    /// `frames=` does.
+    pub fn set_launch_frame_range(&mut self, frames: RangeInclusive<usize>) {
+        self.launch_frame_range = Some(frames);
+    }
+
+    /// Opens the first study of a `--worklist`; the rest are stepped through
+    /// with `[` and `]`.
+    pub fn set_worklist(&mut self, worklist: Vec<LaunchRequest>) {
+        self.pending_launch_request = worklist.first().cloned();
+        self.worklist = worklist;
+        self.worklist_index = 0;
+    }
+
+    /// Inclusive frame bounds of the single view: its trimmed range, or every
+    /// frame.
+    fn single_frame_bounds(&self, frame_count: usize) -> (usize, usize) {
+        let last = frame_count.saturating_sub(1);
+        match self.single_frame_range.as_ref() {
+            Some(range) => (*range.start(), *range.end()),
+            None => (0, last),
+        }
+    }
+
+    /// Starts cine for a just-opened multi-frame image or group when autoplay
+    /// is on for this session.
+    fn autoplay_cine_on_open(&mut self) {
+        if !self.session_autoplay_cine || self.cine_mode {
+            return;
+        }
+        self.toggle_cine_mode();
+        self.cine_autoplayed = self.cine_mode;
+    }
+
+    /// Cine toggle from the `C` key or the cine button. Stopping a cine that
+    /// autoplay started keeps later images from autoplaying this session.
+    fn toggle_cine_mode_from_user(&mut self) {
+        let stopping_autoplay = self.cine_mode && self.cine_autoplayed;
+        self.cine_autoplayed = false;
+        self.toggle_cine_mode();
+        if stopping_autoplay && !self.cine_mode {
+            self.session_autoplay_cine = false;
+            log::info!("Cine autoplay turned off for this session.");
+        }
+    }
+
+    fn toggle_cine_mode(&mut self) {
+        if let Some(image) = self.image.as_ref() {
+            if image.frame_count() <= 1 {
+                self.cine_mode = false;
+                log::debug!("Cine mode requires a multi-frame DICOM.");
+                return;
+            }
+            self.cine_mode = !self.cine_mode;
+            self.clear_live_measurement();
+            self.last_cine_advance = Some(Instant::now());
+            return;
+        }
+
+        if self.loaded_mammo_count() == 0 {
+            self.cine_mode = false;
+            return;
+        }
+
+        if !self.mammo_group_complete() {
+            self.cine_mode = false;
+            log::debug!("Multi-view cine mode requires all views to be loaded.");
+            return;
+        }
+
+        let frame_count = self.mammo_group_common_frame_count();
+        if frame_count <= 1 {
+            self.cine_mode = false;
+            log::debug!("Multi-view cine mode requires all views to be multi-frame.");
+            return;
+        }
+
+        let enabling = !self.cine_mode;
+        self.cine_mode = enabling;
+        self.clear_live_measurement();
+        self.last_cine_advance = Some(Instant::now());
+        if enabling {
+            let start_frame = self
+                .selected_mammo_frame_index()
+                .min(frame_count.saturating_sub(1));
+            let _ = self.set_mammo_group_frame(start_frame);
         }
     }
 
     fn advance_cine_if_needed(&mut self, ctx: &egui::Context) {
         if !self.cine_mode {
+            self.cine_rate_meter.clear();
             return;
         }
 
@@ -973,6 +2029,19 @@ impl DicomViewerApp {
             return;
         }
 
+        let window_hidden = ctx.input(|input| {
+            let viewport = input.viewport();
+            Self::cine_paused_for_window(viewport.minimized, viewport.focused)
+        });
+        if window_hidden {
+            // Hold playback without leaving cine mode; restarting the tick clock
+            // keeps the first frame after refocus from jumping ahead. egui
+            // repaints on its own when focus returns.
+            self.last_cine_advance = Some(Instant::now());
+            self.cine_rate_meter.clear();
+            return;
+        }
+
         let fps = self.cine_fps.clamp(1.0, 120.0);
         let frame_interval = Duration::from_secs_f32(1.0 / fps);
         let now = Instant::now();
@@ -980,23 +2049,81 @@ impl DicomViewerApp {
         let elapsed = now.duration_since(last);
 
         if elapsed >= frame_interval {
-            let frames_to_advance = ((elapsed.as_secs_f32() * fps).floor() as usize).max(1);
+            let frames_to_advance = if self.preferences.cine_every_frame {
+                1
+            } else {
+                ((elapsed.as_secs_f32() * fps).floor() as usize).max(1)
+            };
             if self.image.is_some() {
-                self.set_single_current_frame(
-                    (self.current_frame + frames_to_advance) % frame_count,
-                );
+                let (first, last) = self.single_frame_bounds(frame_count);
+                let clip_len = last - first + 1;
+                let offset = self.current_frame.saturating_sub(first);
+                self.set_single_current_frame(first + (offset + frames_to_advance) % clip_len);
             } else {
                 let next_frame =
                     (self.selected_mammo_frame_index() + frames_to_advance) % frame_count;
                 let _ = self.set_mammo_group_frame(next_frame);
             }
-            self.last_cine_advance = Some(now);
+            self.last_cine_advance = Some(if self.preferences.cine_every_frame {
+                Self::next_every_frame_cine_tick(last, now, frame_interval)
+            } else {
+                now
+            });
             if self.image.is_some() {
                 self.rebuild_texture(ctx);
             }
+            self.cine_rate_meter.record(now);
+        }
+
+        let next_last = self.last_cine_advance.unwrap_or(now);
+        ctx.request_repaint_after(Self::cine_repaint_delay(
+            next_last,
+            Instant::now(),
+            frame_interval,
+        ));
+    }
+
+    /// Cine FPS slider label. While playing it adds the rate frames are
+    /// actually shown at, highlighted once playback falls below 90% of the
+    /// requested rate.
+    fn cine_fps_slider_text(requested_fps: f32, achieved_fps: Option<f32>) -> egui::RichText {
+        let Some(achieved) = achieved_fps else {
+            return egui::RichText::new("Cine FPS");
+        };
+        let text = egui::RichText::new(format!("Cine FPS ({achieved:.1} shown)"));
+        if achieved < requested_fps * 0.9 {
+            text.color(egui::Color32::from_rgb(255, 170, 60))
+        } else {
+            text
         }
+    }
+
+    /// Cine holds while the window is minimized or in the background so hidden
+    /// playback does not keep decoding frames. Unknown state counts as visible.
+    fn cine_paused_for_window(minimized: Option<bool>, focused: Option<bool>) -> bool {
+        minimized == Some(true) || focused == Some(false)
+    }
 
-        ctx.request_repaint_after(Duration::from_millis(8));
+    /// Time left until the next cine tick is due, so playback wakes the UI
+    /// once per frame interval instead of polling at a fixed fast rate.
+    fn cine_repaint_delay(last: Instant, now: Instant, frame_interval: Duration) -> Duration {
+        frame_interval.saturating_sub(now.saturating_duration_since(last))
+    }
+
+    /// Schedules the next every-frame cine tick one interval after the last so
+    /// playback holds the requested rate, but restarts from `now` once the UI
+    /// has fallen a full interval behind rather than bursting to catch up.
+    fn next_every_frame_cine_tick(
+        last: Instant,
+        now: Instant,
+        frame_interval: Duration,
+    ) -> Instant {
+        let scheduled = last + frame_interval;
+        if now.saturating_duration_since(scheduled) >= frame_interval {
+            now
+        } else {
+            scheduled
+        }
     }
 
     fn render_image_frame(
@@ -1008,6 +2135,18 @@ impl DicomViewerApp {
     ) -> Option<ColorImage> {
         let mut color_image = if image.is_monochrome() {
             let frame_pixels = image.frame_mono_pixels(frame_index)?;
+            // egui panics when a texture's size disagrees with its pixel count,
+            // so a malformed frame is skipped instead of uploaded.
+            let expected_len = image.width.checked_mul(image.height);
+            if expected_len != Some(frame_pixels.len()) {
+                log::warn!(
+                    "Skipping frame {frame_index}: {} samples do not fill {}x{}.",
+                    frame_pixels.len(),
+                    image.width,
+                    image.height
+                );
+                return None;
+            }
             render_window_level(
                 image.width,
                 image.height,
@@ -1027,12 +2166,109 @@ impl DicomViewerApp {
         };
 
         if show_overlay {
-            Self::blend_parametric_map_overlay(&mut color_image, image, frame_index);
+            Self::apply_frame_overlays(&mut color_image, image, frame_index);
         }
 
         Some(color_image)
     }
 
+    /// Parametric map blend and GSPS shutter drawn into a rendered frame when
+    /// overlays are shown.
+    fn apply_frame_overlays(color_image: &mut ColorImage, image: &DicomImage, frame_index: usize) {
+        Self::blend_parametric_map_overlay(color_image, image, frame_index);
+        if let Some(shutter) = image
+            .gsps_overlay
+            .as_ref()
+            .and_then(|overlay| overlay.shutter.as_ref())
+        {
+            apply_gsps_shutter(color_image, shutter);
+        }
+    }
+
+    /// `render_image_frame` for upload as a view texture: shrunk to the GPU's
+    /// texture size limit when larger. The `DicomImage` keeps its full
+    /// resolution, and views lay out by image size rather than texture size,
+    /// so probing and measurement still address original pixels.
+    fn render_display_frame(
+        image: &DicomImage,
+        frame_index: usize,
+        window_center: f32,
+        window_width: f32,
+        show_overlay: bool,
+    ) -> Option<ColorImage> {
+        let color_image = Self::render_image_frame(
+            image,
+            frame_index,
+            window_center,
+            window_width,
+            show_overlay,
+        )?;
+        Some(fit_to_max_texture_side(
+            color_image,
+            MAX_TEXTURE_SIDE.load(Ordering::Relaxed),
+        ))
+    }
+
+    /// The active single image or selected viewport frame as currently
+    /// displayed, with its window/level and any visible overlay.
+    fn active_frame_color_image(&self) -> Option<ColorImage> {
+        if let Some(image) = self.image.as_ref() {
+            if let Some(mask_frame) = self.active_subtraction_mask() {
+                return Self::render_subtracted_frame(
+                    image,
+                    self.current_frame,
+                    mask_frame,
+                    self.window_center,
+                    self.window_width,
+                    self.overlay_visible,
+                );
+            }
+            return Self::render_image_frame(
+                image,
+                self.current_frame,
+                self.window_center,
+                self.window_width,
+                self.overlay_visible,
+            );
+        }
+        let viewport = self.selected_mammo_viewport()?;
+        Self::render_image_frame(
+            &viewport.image,
+            viewport.current_frame,
+            viewport.window_center,
+            viewport.window_width,
+            self.overlay_visible,
+        )
+    }
+
+    fn copy_active_frame_to_clipboard(&mut self) -> anyhow::Result<()> {
+        let color_image = self
+            .active_frame_color_image()
+            .context("No decoded frame to copy")?;
+        let [width, height] = color_image.size;
+        let bytes = color_image
+            .pixels
+            .iter()
+            .flat_map(|pixel| pixel.to_array())
+            .collect::<Vec<u8>>();
+
+        let clipboard = match self.frame_clipboard.as_mut() {
+            Some(clipboard) => clipboard,
+            None => self
+                .frame_clipboard
+                .insert(arboard::Clipboard::new().context("Could not open the clipboard")?),
+        };
+        clipboard
+            .set_image(arboard::ImageData {
+                width,
+                height,
+                bytes: bytes.into(),
+            })
+            .context("Could not place the frame on the clipboard")?;
+        log::info!("Copied {width}x{height} frame to clipboard.");
+        Ok(())
+    }
+
     fn blend_parametric_map_overlay(
         color_image: &mut ColorImage,
         image: &DicomImage,
@@ -1065,20 +2301,30 @@ impl DicomViewerApp {
             }
 
             let frame_index = self.current_frame.min(frame_count.saturating_sub(1));
-            let color_image = Self::render_image_frame(
-                image,
-                frame_index,
-                self.window_center,
-                self.window_width,
-                self.overlay_visible,
-            )?;
+            let color_image = match self.active_subtraction_mask() {
+                Some(mask_frame) => Self::render_subtracted_frame(
+                    image,
+                    frame_index,
+                    mask_frame,
+                    self.window_center,
+                    self.window_width,
+                    self.overlay_visible,
+                )?,
+                None => Self::render_image_frame(
+                    image,
+                    frame_index,
+                    self.window_center,
+                    self.window_width,
+                    self.overlay_visible,
+                )?,
+            };
             Some((color_image, frame_index))
         });
 
         let Some((color_image, frame_index)) = prepared else {
             if had_renderable_image {
                 self.frame_wait_pending = true;
-                ctx.request_repaint_after(Duration::from_millis(16));
+                ctx.request_repaint_after(ACTIVE_REPAINT_INTERVAL);
             } else {
                 self.texture = None;
                 self.frame_wait_pending = false;
@@ -1088,11 +2334,26 @@ impl DicomViewerApp {
 
         self.frame_wait_pending = false;
         self.current_frame = frame_index;
-        if let Some(texture) = self.texture.as_mut() {
-            texture.set(color_image, TextureOptions::LINEAR);
-        } else {
-            self.texture =
-                Some(ctx.load_texture("dicom-image", color_image, TextureOptions::LINEAR));
+        self.upload_single_texture(ctx, color_image);
+    }
+
+    /// Uploads a single-view frame through `prepare_texture_upload`, showing
+    /// an error instead of a stale or blank texture when it is refused.
+    fn upload_single_texture(&mut self, ctx: &egui::Context, color_image: ColorImage) {
+        match prepare_texture_upload(color_image, MAX_TEXTURE_SIDE.load(Ordering::Relaxed)) {
+            Ok(color_image) => {
+                if let Some(texture) = self.texture.as_mut() {
+                    texture.set(color_image, TextureOptions::LINEAR);
+                } else {
+                    self.texture =
+                        Some(ctx.load_texture("dicom-image", color_image, TextureOptions::LINEAR));
+                }
+            }
+            Err(message) => {
+                log::warn!("{message}");
+                self.texture = None;
+                self.set_load_error(message);
+            }
         }
     }
 
@@ -1127,12 +2388,34 @@ impl DicomViewerApp {
         }
     }
 
-    fn active_metadata(&self) -> Option<&[(String, String)]> {
-        if let Some(image) = self.active_image() {
-            Some(image.metadata.as_slice())
-        } else {
-            self.report
-                .as_ref()
+    /// Flips grayscale polarity of the active image for this session, for
+    /// images whose photometric interpretation is wrong or missing.
+    fn dicom_load_options(&self) -> DicomLoadOptions {
+        DicomLoadOptions {
+            view_fallback_tags: self.preferences.view_fallback_tags.clone(),
+            assume_monochrome1_when_missing: self.preferences.assume_monochrome1_when_missing,
+        }
+    }
+
+    fn toggle_active_invert(&mut self) -> bool {
+        let Some(image) = self.active_image_mut() else {
+            return false;
+        };
+        if !image.is_monochrome() {
+            return false;
+        }
+        image.invert = !image.invert;
+        // Auto-crop scans for content darker than the background, which flips with invert.
+        self.single_view_content_uv = None;
+        true
+    }
+
+    fn active_metadata(&self) -> Option<&[(String, String)]> {
+        if let Some(image) = self.active_image() {
+            Some(image.metadata.as_slice())
+        } else {
+            self.report
+                .as_ref()
                 .map(|report| report.metadata.as_slice())
         }
     }
@@ -1147,10 +2430,15 @@ impl DicomViewerApp {
             .map(Into::into)
             .collect::<Vec<_>>();
         match image_paths.as_slice() {
-            [ref path] => self
-                .current_single_path
-                .as_ref()
-                .is_some_and(|current| current == path),
+            [ref path] => {
+                self.current_single_path
+                    .as_ref()
+                    .is_some_and(|current| current == path)
+                    || (self.mammo_group_complete()
+                        && self
+                            .loaded_mammo_viewports()
+                            .all(|viewport| &viewport.path == path))
+            }
             paths if Self::is_supported_multi_view_group_size(paths.len()) => {
                 if !self.mammo_group_complete() {
                     return false;
@@ -1179,6 +2467,7 @@ impl DicomViewerApp {
                 default_width: image.window_width,
                 window_center: self.window_center,
                 window_width: self.window_width,
+                window_presets: image.window_presets.clone(),
                 current_frame: self.current_frame,
             })
         } else {
@@ -1201,6 +2490,7 @@ impl DicomViewerApp {
                     default_width: viewport.image.window_width,
                     window_center: viewport.window_center,
                     window_width: viewport.window_width,
+                    window_presets: viewport.image.window_presets.clone(),
                     current_frame,
                 }
             })
@@ -1239,12 +2529,100 @@ impl DicomViewerApp {
                 viewport.window_width = state.window_width.max(1.0);
                 viewport.current_frame = next_frame;
                 if self.rebuild_selected_mammo_texture() {
-                    ctx.request_repaint_after(Duration::from_millis(16));
+                    ctx.request_repaint_after(ACTIVE_REPAINT_INTERVAL);
                 }
             }
         }
     }
 
+    /// Embedded window preset last applied to the single image or selected
+    /// cell.
+    fn active_window_preset_index(&self) -> Option<usize> {
+        if self.image.is_some() {
+            self.single_window_preset_index
+        } else {
+            self.selected_mammo_viewport()
+                .and_then(|viewport| viewport.window_preset_index)
+        }
+    }
+
+    /// Name of the embedded window preset the active image is shown with, or
+    /// `None` once the window has been adjusted away from every preset.
+    fn active_window_preset_label(&self) -> Option<String> {
+        let state = self.active_viewport_state()?;
+        if !state.is_monochrome {
+            return None;
+        }
+        let index = shown_window_preset_index(
+            &state.window_presets,
+            self.active_window_preset_index(),
+            state.window_center,
+            state.window_width,
+        )?;
+        state
+            .window_presets
+            .get(index)
+            .map(|preset| preset.label.clone())
+    }
+
+    /// Applies the next embedded window preset of the active image, wrapping
+    /// after the last one.
+    fn cycle_window_preset(&mut self, ctx: &egui::Context) {
+        let Some(state) = self.active_viewport_state() else {
+            return;
+        };
+        if !state.is_monochrome {
+            return;
+        }
+        let stored_index = self.active_window_preset_index();
+        let Some(index) = next_window_preset_index(
+            &state.window_presets,
+            stored_index,
+            state.window_center,
+            state.window_width,
+        ) else {
+            return;
+        };
+        let Some(preset) = state.window_presets.get(index) else {
+            return;
+        };
+        if self.window_level_locked {
+            log::info!("Window/level is locked; press K to unlock before changing the window.");
+            return;
+        }
+        log::info!("Window preset: {}", preset.label);
+        self.apply_active_window(preset.center, preset.width, Some(index), ctx);
+    }
+
+    /// Sets the window of the single image or selected cell, remembering which
+    /// embedded preset it came from so `W` continues from there. Does nothing
+    /// while window/level is locked.
+    fn apply_active_window(
+        &mut self,
+        center: f32,
+        width: f32,
+        preset_index: Option<usize>,
+        ctx: &egui::Context,
+    ) {
+        if self.window_level_locked {
+            log::info!("Window/level is locked; press K to unlock before changing the window.");
+            return;
+        }
+        if self.image.is_some() {
+            self.single_window_preset_index = preset_index;
+            self.window_center = center;
+            self.window_width = width.max(1.0);
+            self.rebuild_texture(ctx);
+        } else if let Some(viewport) = self.selected_mammo_viewport_mut() {
+            viewport.window_preset_index = preset_index;
+            viewport.window_center = center;
+            viewport.window_width = width.max(1.0);
+            if self.rebuild_selected_mammo_texture() {
+                ctx.request_repaint_after(ACTIVE_REPAINT_INTERVAL);
+            }
+        }
+    }
+
     fn selected_mammo_viewport_mut(&mut self) -> Option<&mut MammoViewport> {
         if self.loaded_mammo_count() == 0 {
             return None;
@@ -1269,7 +2647,7 @@ impl DicomViewerApp {
         }
 
         viewport.current_frame = viewport.current_frame.min(frame_count.saturating_sub(1));
-        let Some(color_image) = Self::render_image_frame(
+        let Some(color_image) = Self::render_display_frame(
             &viewport.image,
             viewport.current_frame,
             viewport.window_center,
@@ -1761,6 +3139,66 @@ impl DicomViewerApp {
             || (*window_width - old_width).abs() > f32::EPSILON
     }
 
+    /// Applies a window/level drag to the single image and re-renders it,
+    /// unless window/level is locked.
+    fn drag_single_window_level(&mut self, drag_delta: egui::Vec2, ctx: &egui::Context) {
+        if self.window_level_locked {
+            return;
+        }
+        let Some((min_value, max_value)) = self
+            .image
+            .as_ref()
+            .map(|image| (image.min_value, image.max_value))
+        else {
+            return;
+        };
+        if Self::apply_window_level_drag(
+            &mut self.window_center,
+            &mut self.window_width,
+            min_value,
+            max_value,
+            drag_delta,
+        ) {
+            self.rebuild_texture(ctx);
+        }
+    }
+
+    /// Applies a window/level drag to one multi-view cell and re-renders its
+    /// texture, unless window/level is locked.
+    fn drag_viewport_window_level(
+        viewport: &mut MammoViewport,
+        window_level_locked: bool,
+        drag_delta: egui::Vec2,
+        show_overlay: bool,
+    ) {
+        if window_level_locked
+            || !Self::apply_window_level_drag(
+                &mut viewport.window_center,
+                &mut viewport.window_width,
+                viewport.image.min_value,
+                viewport.image.max_value,
+                drag_delta,
+            )
+        {
+            return;
+        }
+        if let Some(color_image) = Self::render_display_frame(
+            &viewport.image,
+            viewport.current_frame,
+            viewport.window_center,
+            viewport.window_width,
+            show_overlay,
+        ) {
+            viewport.texture.set(color_image, TextureOptions::LINEAR);
+        }
+    }
+
+    /// A drag finished, or the value changed without a drag (typed or
+    /// clicked), so settings are written once per edit rather than per frame.
+    fn value_edit_committed(response: &egui::Response) -> bool {
+        response.drag_stopped() || (response.changed() && !response.dragged())
+    }
+
     fn add_value_control_no_border<'a>(
         ui: &mut egui::Ui,
         size: [f32; 2],
@@ -1861,27 +3299,50 @@ impl DicomViewerApp {
             });
     }
 
+    /// Frames to move for one tick of scroll input: scrolling down (negative
+    /// delta) advances, scrolling up goes back. Each `PIXELS_PER_FRAME_STEP`
+    /// of travel in one direction is exactly one frame.
     fn frame_step_from_scroll(scroll_accum: &mut f32, scroll: f32) -> i32 {
         const DEAD_ZONE: f32 = 0.5;
         const PIXELS_PER_FRAME_STEP: f32 = 30.0;
-
-        if scroll.abs() <= DEAD_ZONE {
+        // Absorbs float drift so deltas that sum to exactly one step in
+        // decimal (12.1 + 8.7 + 9.2) still produce it.
+        const STEP_EPSILON: f32 = 1e-3;
+        // A single large trackpad delta must not skip past frames a reader
+        // would want to see, so whole steps beyond this per-tick cap are dropped.
+        const MAX_FRAME_STEPS_PER_TICK: i32 = 3;
+
+        let reversed = *scroll_accum != 0.0 && scroll.signum() != scroll_accum.signum();
+        // The dead zone only filters jitter from rest. Once a gesture is under
+        // way, the small tail of a smoothed wheel notch must still count, or a
+        // notch comes up short of a full step.
+        if scroll.abs() <= DEAD_ZONE && (*scroll_accum == 0.0 || reversed) {
             return 0;
         }
 
         // Reset stale residuals when the user reverses scroll direction.
-        if *scroll_accum != 0.0 && scroll.signum() != scroll_accum.signum() {
+        if reversed {
             *scroll_accum = 0.0;
         }
         *scroll_accum += scroll;
 
-        let raw_steps = (*scroll_accum / PIXELS_PER_FRAME_STEP).trunc() as i32;
-        if raw_steps == 0 {
+        let whole_steps =
+            ((scroll_accum.abs() + STEP_EPSILON) / PIXELS_PER_FRAME_STEP).trunc() as i32;
+        if whole_steps == 0 {
             return 0;
         }
 
-        *scroll_accum -= raw_steps as f32 * PIXELS_PER_FRAME_STEP;
-        -raw_steps
+        let accum_sign = scroll_accum.signum();
+        *scroll_accum -= accum_sign * whole_steps as f32 * PIXELS_PER_FRAME_STEP;
+        if scroll_accum.abs() < STEP_EPSILON {
+            *scroll_accum = 0.0;
+        }
+        let steps = whole_steps.min(MAX_FRAME_STEPS_PER_TICK);
+        if accum_sign > 0.0 {
+            -steps
+        } else {
+            steps
+        }
     }
 
     fn dominant_scroll_axis(scroll: egui::Vec2) -> f32 {
@@ -1896,13 +3357,100 @@ impl DicomViewerApp {
         modifiers.shift
     }
 
+    fn show_series_tab_strip(&mut self, root_ui: &mut egui::Ui, ctx: &egui::Context) {
+        if self.series_tabs.is_empty() {
+            return;
+        }
+
+        let active = self.active_series_tab();
+        let mut clicked = None;
+        egui::Panel::top("series-tabs").show(root_ui, |ui| {
+            egui::ScrollArea::horizontal().show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    for (index, tab) in self.series_tabs.iter().enumerate() {
+                        if ui
+                            .selectable_label(active == Some(index), tab.label.as_str())
+                            .clicked()
+                        {
+                            clicked = Some(index);
+                        }
+                    }
+                });
+            });
+        });
+
+        if let Some(index) = clicked.filter(|index| Some(*index) != active) {
+            self.open_series_tab(index, ctx);
+        }
+    }
+
+    /// Page strip for a multi-group launch, shown while one of its groups is
+    /// displayed. Page Up/Page Down flip pages from the keyboard.
+    fn show_group_page_strip(&mut self, root_ui: &mut egui::Ui, ctx: &egui::Context) {
+        if self.group_pages.len() < 2 {
+            return;
+        }
+        let Some(active) = self.active_group_page() else {
+            return;
+        };
+
+        let mut flip_direction = None;
+        let mut clicked_page = None;
+        egui::Panel::top("group-pages").show(root_ui, |ui| {
+            ui.horizontal(|ui| {
+                if ui
+                    .button("<")
+                    .on_hover_text("Previous page (Page Up)")
+                    .clicked()
+                {
+                    flip_direction = Some(-1);
+                }
+                for page in 0..self.group_pages.len() {
+                    let loaded = self.group_page_history_index(page).is_some();
+                    let response = ui
+                        .add_enabled(
+                            loaded,
+                            egui::Button::selectable(active == page, format!("Page {}", page + 1)),
+                        )
+                        .on_disabled_hover_text("Still loading");
+                    if response.clicked() {
+                        clicked_page = Some(page);
+                    }
+                }
+                if ui
+                    .button(">")
+                    .on_hover_text("Next page (Page Down)")
+                    .clicked()
+                {
+                    flip_direction = Some(1);
+                }
+            });
+        });
+
+        if let Some(direction) = flip_direction {
+            self.flip_group_page(direction, ctx);
+        } else if let Some(index) = clicked_page
+            .filter(|page| *page != active)
+            .and_then(|page| self.group_page_history_index(page))
+        {
+            self.switch_to_history_entry(index, ctx);
+        }
+    }
+
     fn show_mammo_grid(&mut self, ui: &mut egui::Ui) {
-        const MAMMO_GRID_GAP: f32 = 2.0;
         const MAMMO_VIEW_INNER_MARGIN: i8 = 3;
+        const RETRY_BUTTON_OFFSET: f32 = 36.0;
+        const RETRY_BUTTON_SIZE: egui::Vec2 = egui::vec2(96.0, 24.0);
+        let grid_style = self.preferences.grid_style;
+        let grid_gap = grid_style.gap;
         let show_overlay = self.overlay_visible;
+        let show_labels = self.view_labels_visible();
+        let show_cine_ring = self.cine_mode && self.preferences.cine_progress_ring;
+        let window_level_gesture = self.preferences.window_level_gesture;
+        let window_level_locked = self.window_level_locked;
 
         ui.scope(|ui| {
-            ui.spacing_mut().item_spacing = egui::vec2(MAMMO_GRID_GAP, MAMMO_GRID_GAP);
+            ui.spacing_mut().item_spacing = egui::vec2(grid_gap, grid_gap);
 
             let slot_count = if self.mammo_group.is_empty() {
                 self.dicomweb_active_group_expected
@@ -1913,14 +3461,17 @@ impl DicomViewerApp {
             };
             let (rows, columns) = Self::multi_view_grid_dimensions(slot_count).unwrap_or((2, 2));
             let available = ui.available_size();
-            let total_gap_x = MAMMO_GRID_GAP * columns.saturating_sub(1) as f32;
-            let total_gap_y = MAMMO_GRID_GAP * rows.saturating_sub(1) as f32;
+            let total_gap_x = grid_gap * columns.saturating_sub(1) as f32;
+            let total_gap_y = grid_gap * rows.saturating_sub(1) as f32;
             let cell_width = ((available.x - total_gap_x).max(2.0)) / columns as f32;
             let cell_height = ((available.y - total_gap_y).max(2.0)) / rows as f32;
             let cell_size = egui::vec2(cell_width, cell_height);
             let common_frame_count = self.mammo_group_common_frame_count();
             let mut clicked_index = None;
             let mut pending_frame_target: Option<(usize, usize)> = None;
+            let mut menu_index = None;
+            let mut retry_index = None;
+            let mut cell_responses = Vec::with_capacity(slot_count);
 
             for row in 0..rows {
                 ui.horizontal(|ui| {
@@ -1935,14 +3486,20 @@ impl DicomViewerApp {
                                     .get(index)
                                     .and_then(Option::as_ref)
                                     .is_some();
-                                let stroke_color =
+                                let stroke =
                                     if index == self.mammo_selected_index && has_loaded_image {
-                                        PERSPECTA_BRAND_BLUE
+                                        egui::Stroke::new(
+                                            grid_style.border_width.max(1.0),
+                                            PERSPECTA_BRAND_BLUE,
+                                        )
                                     } else {
-                                        egui::Color32::BLACK
+                                        egui::Stroke::new(
+                                            grid_style.border_width,
+                                            grid_style.border_color,
+                                        )
                                     };
                                 let frame = egui::Frame::NONE
-                                    .stroke(egui::Stroke::new(1.0, stroke_color))
+                                    .stroke(stroke)
                                     .inner_margin(egui::Margin::same(MAMMO_VIEW_INNER_MARGIN));
                                 frame.show(ui, |ui| {
                                     let remaining = ui.available_size();
@@ -1953,11 +3510,44 @@ impl DicomViewerApp {
                                     if !primary_interaction_blocked && response.clicked() {
                                         clicked_index = Some(index);
                                     }
+                                    if has_loaded_image && view_context_menu_requested(&response) {
+                                        menu_index = Some(index);
+                                    }
+                                    cell_responses.push((index, response.clone()));
                                     let painter = ui.painter().with_clip_rect(viewport_rect);
                                     if let Some(viewport) =
                                         self.mammo_group.get_mut(index).and_then(Option::as_mut)
                                     {
-                                        let texture_size = viewport.texture.size_vec2();
+                                        if viewport.image.undecodable_reason().is_some() {
+                                            Self::draw_undecodable_placeholder(
+                                                &painter,
+                                                viewport_rect,
+                                                &viewport.label,
+                                            );
+                                            if let Some(failed) = self
+                                                .mammo_failed_views
+                                                .get(viewport.path.identity_key())
+                                            {
+                                                let retrying = failed.retry.is_some();
+                                                let button_rect = egui::Rect::from_center_size(
+                                                    viewport_rect.center()
+                                                        + egui::vec2(0.0, RETRY_BUTTON_OFFSET),
+                                                    RETRY_BUTTON_SIZE,
+                                                );
+                                                let button = egui::Button::new(if retrying {
+                                                    "Retrying..."
+                                                } else {
+                                                    "Retry"
+                                                });
+                                                if ui.put(button_rect, button).clicked()
+                                                    && !retrying
+                                                {
+                                                    retry_index = Some(index);
+                                                }
+                                            }
+                                            return;
+                                        }
+                                        let texture_size = image_display_size(&viewport.image);
                                         if texture_size.x > 0.0
                                             && texture_size.y > 0.0
                                             && viewport_rect.is_positive()
@@ -1979,9 +3569,12 @@ impl DicomViewerApp {
                                                 viewport.zoom = 1.0;
                                                 viewport.pan = egui::Vec2::ZERO;
                                             }
-                                            if !primary_interaction_blocked
-                                                && response.dragged_by(egui::PointerButton::Primary)
-                                            {
+                                            let primary_dragged = !primary_interaction_blocked
+                                                && response
+                                                    .dragged_by(egui::PointerButton::Primary);
+                                            let secondary_dragged =
+                                                response.dragged_by(egui::PointerButton::Secondary);
+                                            if primary_dragged || secondary_dragged {
                                                 let (frame_drag_delta, shift_held) =
                                                     ui.input(|input| {
                                                         (
@@ -1989,30 +3582,19 @@ impl DicomViewerApp {
                                                             input.modifiers.shift,
                                                         )
                                                     });
-                                                if shift_held && viewport.image.is_monochrome() {
-                                                    if Self::apply_window_level_drag(
-                                                        &mut viewport.window_center,
-                                                        &mut viewport.window_width,
-                                                        viewport.image.min_value,
-                                                        viewport.image.max_value,
+                                                if window_level_gesture.adjusts_window_level(
+                                                    primary_dragged,
+                                                    secondary_dragged,
+                                                    shift_held,
+                                                ) && viewport.image.is_monochrome()
+                                                {
+                                                    Self::drag_viewport_window_level(
+                                                        viewport,
+                                                        window_level_locked,
                                                         frame_drag_delta,
-                                                    ) {
-                                                        if let Some(color_image) =
-                                                            Self::render_image_frame(
-                                                                &viewport.image,
-                                                                viewport.current_frame,
-                                                                viewport.window_center,
-                                                                viewport.window_width,
-                                                                self.overlay_visible,
-                                                            )
-                                                        {
-                                                            viewport.texture.set(
-                                                                color_image,
-                                                                TextureOptions::LINEAR,
-                                                            );
-                                                        }
-                                                    }
-                                                } else if viewport.zoom > 1.0 {
+                                                        self.overlay_visible,
+                                                    );
+                                                } else if primary_dragged && viewport.zoom > 1.0 {
                                                     viewport.pan += frame_drag_delta;
                                                 }
                                             }
@@ -2066,14 +3648,19 @@ impl DicomViewerApp {
                                                         if let Some(pointer_pos) =
                                                             response.hover_pos()
                                                         {
-                                                            let old_center =
-                                                                base_center_before + viewport.pan;
-                                                            let pointer_offset =
-                                                                pointer_pos - old_center;
-                                                            let zoom_ratio =
-                                                                viewport.zoom / old_zoom;
+                                                            let old_image_rect =
+                                                                egui::Rect::from_center_size(
+                                                                    base_center_before
+                                                                        + viewport.pan,
+                                                                    draw_size_before,
+                                                                );
                                                             viewport.pan +=
-                                                                pointer_offset * (1.0 - zoom_ratio);
+                                                                Self::zoom_anchor_pan_delta(
+                                                                    old_image_rect,
+                                                                    pointer_pos,
+                                                                    old_zoom,
+                                                                    viewport.zoom,
+                                                                );
                                                         }
                                                     }
                                                 }
@@ -2107,13 +3694,9 @@ impl DicomViewerApp {
                                             let geometry =
                                                 MeasurementGeometry::from_image(&viewport.image);
                                             let pointer_pos = ui.ctx().pointer_latest_pos();
-                                            let secondary_pointer_pos = if response
-                                                .contains_pointer()
-                                                && ui.input(|input| {
-                                                    input.pointer.button_pressed(
-                                                        egui::PointerButton::Secondary,
-                                                    )
-                                                }) {
+                                            let secondary_pointer_pos = if window_level_gesture
+                                                .measurement_anchor_requested(&response, ui)
+                                            {
                                                 pointer_pos
                                             } else {
                                                 None
@@ -2170,6 +3753,33 @@ impl DicomViewerApp {
                                                         viewport.current_frame,
                                                     );
                                                 }
+                                                if show_labels {
+                                                    Self::draw_mammo_view_badge(
+                                                        &painter,
+                                                        viewport_rect,
+                                                        &mammo_view_code(&viewport.image),
+                                                        index,
+                                                    );
+                                                    if let Some(orientation) =
+                                                        viewport.image.patient_orientation.as_ref()
+                                                    {
+                                                        Self::draw_orientation_labels(
+                                                            &painter,
+                                                            viewport_rect.intersect(image_rect),
+                                                            orientation,
+                                                        );
+                                                    }
+                                                }
+                                                if show_cine_ring {
+                                                    Self::draw_cine_progress_ring(
+                                                        &painter,
+                                                        viewport_rect,
+                                                        mammo_image_align(index),
+                                                        0.0,
+                                                        viewport.current_frame,
+                                                        common_frame_count,
+                                                    );
+                                                }
                                             }
                                             self.draw_live_measurement(
                                                 &painter, target, geometry, image_rect,
@@ -2191,23 +3801,316 @@ impl DicomViewerApp {
                 });
             }
 
-            if let Some(index) = clicked_index {
+            if let Some(index) = clicked_index.or(menu_index) {
                 self.mammo_selected_index = index;
             }
+            if let Some(index) = retry_index {
+                self.retry_mammo_view(index, ui.ctx());
+            }
+            if let Some((_, response)) = cell_responses
+                .iter()
+                .find(|(index, _)| *index == self.mammo_selected_index)
+            {
+                self.show_view_context_menu(response, menu_index.is_some());
+            }
             if let Some((index, frame_target)) = pending_frame_target {
                 self.mammo_selected_index = index;
                 if self.set_mammo_group_frame(frame_target) {
-                    ui.ctx().request_repaint_after(Duration::from_millis(16));
+                    ui.ctx().request_repaint_after(ACTIVE_REPAINT_INTERVAL);
                 }
                 self.last_cine_advance = Some(Instant::now());
             }
         });
     }
 
+    /// Hover text for the frame slider. The loaded count is only mentioned
+    /// while some frames are still undecoded, since that is when cine or
+    /// scrubbing may stutter.
+    fn frame_slider_hover_text(
+        frame_index: usize,
+        time_offset_ms: Option<f32>,
+        loaded_frames: Option<(usize, usize)>,
+    ) -> String {
+        let mut text = match time_offset_ms {
+            Some(offset_ms) => format!("Frame {frame_index} ({offset_ms:.1} ms)"),
+            None => format!("Frame {frame_index}"),
+        };
+        if let Some((loaded, total)) = loaded_frames.filter(|(loaded, total)| loaded < total) {
+            text.push_str(&format!("\n{loaded}/{total} loaded"));
+        }
+        text
+    }
+
+    /// Contiguous runs of decoded frames, for marking the frame slider track.
+    fn loaded_frame_runs(loaded: &[bool]) -> Vec<std::ops::Range<usize>> {
+        let mut runs = Vec::new();
+        let mut run_start = None;
+        for (index, is_loaded) in loaded.iter().copied().chain([false]).enumerate() {
+            match (is_loaded, run_start) {
+                (true, None) => run_start = Some(index),
+                (false, Some(start)) => {
+                    runs.push(start..index);
+                    run_start = None;
+                }
+                _ => {}
+            }
+        }
+        runs
+    }
+
+    /// Paints which frames are decoded as a thin strip under the frame slider
+    /// track while some are still missing, so the smooth-scrubbing parts show.
+    /// `slider_rect` is the slider plus its label, laid out right to left.
+    fn paint_loaded_frame_strip(
+        ui: &egui::Ui,
+        slider_rect: egui::Rect,
+        slider_width: f32,
+        loaded: &[bool],
+    ) {
+        if loaded.is_empty() || loaded.iter().all(|is_loaded| *is_loaded) {
+            return;
+        }
+        let handle_inset = slider_rect.height() / 2.5;
+        let track = egui::Rect::from_min_max(
+            egui::pos2(
+                slider_rect.right() - slider_width + handle_inset,
+                slider_rect.bottom() - 3.0,
+            ),
+            egui::pos2(
+                slider_rect.right() - handle_inset,
+                slider_rect.bottom() - 1.0,
+            ),
+        );
+        if track.width() <= 0.0 {
+            return;
+        }
+        let painter = ui.painter();
+        painter.rect_filled(track, 0.0, egui::Color32::from_gray(50));
+        let frame_width = track.width() / loaded.len() as f32;
+        for run in Self::loaded_frame_runs(loaded) {
+            let rect = egui::Rect::from_min_max(
+                egui::pos2(track.left() + run.start as f32 * frame_width, track.top()),
+                egui::pos2(track.left() + run.end as f32 * frame_width, track.bottom()),
+            );
+            painter.rect_filled(rect, 0.0, PERSPECTA_BRAND_BLUE);
+        }
+    }
+
+    /// Pan change that keeps the zoom anchor fixed on screen. An anchor in the
+    /// letterbox outside the drawn image zooms about the image center instead,
+    /// so wheel zoom from the margins does not swing the image toward the cursor.
+    fn zoom_anchor_pan_delta(
+        image_rect: egui::Rect,
+        anchor: egui::Pos2,
+        old_zoom: f32,
+        new_zoom: f32,
+    ) -> egui::Vec2 {
+        if !image_rect.contains(anchor) || old_zoom <= 0.0 {
+            return egui::Vec2::ZERO;
+        }
+        (anchor - image_rect.center()) * (1.0 - new_zoom / old_zoom)
+    }
+
+    /// Whether multi-view cells draw their view code badges and orientation
+    /// labels: hidden by `L`, and by the clean presentation of `Shift+L`.
+    fn view_labels_visible(&self) -> bool {
+        !self.preferences.hide_view_labels && !self.preferences.presentation_mode
+    }
+
+    /// Paints the view code in the cell corner away from the chest wall, where
+    /// the justified image leaves empty space.
+    fn draw_mammo_view_badge(
+        painter: &egui::Painter,
+        viewport_rect: egui::Rect,
+        code: &str,
+        index: usize,
+    ) {
+        const BADGE_TEXT_SIZE: f32 = 13.0;
+        const BADGE_MARGIN: f32 = 6.0;
+        const BADGE_PADDING: egui::Vec2 = egui::vec2(5.0, 2.0);
+
+        if code.is_empty() {
+            return;
+        }
+        let galley = painter.layout_no_wrap(
+            code.to_string(),
+            egui::FontId::proportional(BADGE_TEXT_SIZE),
+            egui::Color32::from_gray(230),
+        );
+        let badge_size = galley.size() + BADGE_PADDING * 2.0;
+        let top = viewport_rect.top() + BADGE_MARGIN;
+        let left = match mammo_image_align(index) {
+            egui::Align::Min => viewport_rect.right() - BADGE_MARGIN - badge_size.x,
+            egui::Align::Center | egui::Align::Max => viewport_rect.left() + BADGE_MARGIN,
+        };
+        let badge_rect = egui::Rect::from_min_size(egui::pos2(left, top), badge_size);
+        painter.rect_filled(badge_rect, 3.0, egui::Color32::from_black_alpha(170));
+        painter.galley(
+            badge_rect.min + BADGE_PADDING,
+            galley,
+            egui::Color32::from_gray(230),
+        );
+    }
+
+    /// Paints `PatientOrientation` edge labels at the middle of each edge of
+    /// the visible image region.
+    fn draw_orientation_labels(
+        painter: &egui::Painter,
+        visible_rect: egui::Rect,
+        orientation: &PatientOrientation,
+    ) {
+        const LABEL_TEXT_SIZE: f32 = 14.0;
+        const LABEL_MARGIN: f32 = 6.0;
+
+        if !visible_rect.is_positive() {
+            return;
+        }
+        let [left, right, top, bottom] = orientation.edge_labels();
+        let font = egui::FontId::proportional(LABEL_TEXT_SIZE);
+        let placements = [
+            (
+                left,
+                egui::pos2(visible_rect.left() + LABEL_MARGIN, visible_rect.center().y),
+                egui::Align2::LEFT_CENTER,
+            ),
+            (
+                right,
+                egui::pos2(visible_rect.right() - LABEL_MARGIN, visible_rect.center().y),
+                egui::Align2::RIGHT_CENTER,
+            ),
+            (
+                top,
+                egui::pos2(visible_rect.center().x, visible_rect.top() + LABEL_MARGIN),
+                egui::Align2::CENTER_TOP,
+            ),
+            (
+                bottom,
+                egui::pos2(
+                    visible_rect.center().x,
+                    visible_rect.bottom() - LABEL_MARGIN,
+                ),
+                egui::Align2::CENTER_BOTTOM,
+            ),
+        ];
+        for (label, pos, align) in placements {
+            // A dark offset copy keeps the label readable on bright anatomy.
+            painter.text(
+                pos + egui::vec2(1.0, 1.0),
+                align,
+                &label,
+                font.clone(),
+                egui::Color32::from_black_alpha(200),
+            );
+            painter.text(
+                pos,
+                align,
+                label,
+                font.clone(),
+                egui::Color32::from_rgb(255, 210, 80),
+            );
+        }
+    }
+
+    /// Paints cine loop progress as an arc in the bottom corner on the side
+    /// `image_align` leaves empty, raised by `bottom_inset` to clear anything
+    /// already drawn along the bottom edge.
+    fn draw_cine_progress_ring(
+        painter: &egui::Painter,
+        viewport_rect: egui::Rect,
+        image_align: egui::Align,
+        bottom_inset: f32,
+        frame_index: usize,
+        frame_count: usize,
+    ) {
+        const RING_RADIUS: f32 = 9.0;
+        const RING_MARGIN: f32 = 8.0;
+        const RING_STROKE_WIDTH: f32 = 2.5;
+
+        if frame_count <= 1 {
+            return;
+        }
+        let offset = RING_MARGIN + RING_RADIUS;
+        let center_x = match image_align {
+            egui::Align::Min => viewport_rect.right() - offset,
+            egui::Align::Center | egui::Align::Max => viewport_rect.left() + offset,
+        };
+        let center = egui::pos2(center_x, viewport_rect.bottom() - bottom_inset - offset);
+        painter.circle_stroke(
+            center,
+            RING_RADIUS,
+            egui::Stroke::new(RING_STROKE_WIDTH, egui::Color32::from_white_alpha(40)),
+        );
+        let points =
+            Self::cine_progress_arc_points(center, RING_RADIUS, (frame_index + 1, frame_count));
+        if points.len() >= 2 {
+            painter.add(egui::Shape::line(
+                points,
+                egui::Stroke::new(RING_STROKE_WIDTH, PERSPECTA_BRAND_BLUE.gamma_multiply(0.85)),
+            ));
+        }
+    }
+
+    /// Points along a clockwise arc from twelve o'clock covering
+    /// `shown / total` of the circle.
+    fn cine_progress_arc_points(
+        center: egui::Pos2,
+        radius: f32,
+        (shown, total): (usize, usize),
+    ) -> Vec<egui::Pos2> {
+        const FULL_CIRCLE_SEGMENTS: usize = 48;
+
+        if total == 0 || shown == 0 {
+            return Vec::new();
+        }
+        let fraction = (shown.min(total) as f32) / total as f32;
+        let segments = ((FULL_CIRCLE_SEGMENTS as f32 * fraction).ceil() as usize).max(1);
+        (0..=segments)
+            .map(|step| {
+                let angle = std::f32::consts::TAU * fraction * step as f32 / segments as f32;
+                center + radius * egui::vec2(angle.sin(), -angle.cos())
+            })
+            .collect()
+    }
+
+    fn draw_undecodable_placeholder(painter: &egui::Painter, rect: egui::Rect, label: &str) {
+        const PLACEHOLDER_TEXT_SIZE: f32 = 14.0;
+
+        painter.text(
+            rect.center(),
+            egui::Align2::CENTER_CENTER,
+            format!("No decodable pixel data\n{label}"),
+            egui::FontId::proportional(PLACEHOLDER_TEXT_SIZE),
+            egui::Color32::from_gray(160),
+        );
+    }
+
     fn show_resize_grip(&self, ctx: &egui::Context) {
         const GRIP_SIZE: f32 = 18.0;
         const MARGIN: f32 = 1.0;
 
+        let is_maximized = ctx.input(|input| input.viewport().maximized.unwrap_or(false));
+        if !is_maximized {
+            for (index, (direction, rect)) in
+                resize_edge_rects(ctx.content_rect(), RESIZE_EDGE_WIDTH, GRIP_SIZE)
+                    .into_iter()
+                    .enumerate()
+            {
+                egui::Area::new(egui::Id::new(("window-resize-edge", index)))
+                    .order(egui::Order::Foreground)
+                    .fixed_pos(rect.min)
+                    .show(ctx, |ui| {
+                        let (_, response) = ui.allocate_exact_size(rect.size(), Sense::drag());
+                        if response.drag_started() {
+                            ui.ctx()
+                                .send_viewport_cmd(ViewportCommand::BeginResize(direction));
+                        }
+                        if response.hovered() && !response.dragged() {
+                            ui.ctx().set_cursor_icon(resize_cursor_icon(direction));
+                        }
+                    });
+            }
+        }
+
         egui::Area::new(egui::Id::new("window-resize-grip"))
             .order(egui::Order::Foreground)
             .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-MARGIN, -MARGIN))
@@ -2243,7 +4146,13 @@ impl eframe::App for DicomViewerApp {
     fn ui(&mut self, root_ui: &mut egui::Ui, _frame: &mut eframe::Frame) {
         let ctx = root_ui.ctx().clone();
         let ctx = &ctx;
-        Self::apply_black_background(ctx);
+        // Covers the titlebar buttons, Cmd+Shift+W and the OS close alike, all
+        // of which end in a close request.
+        if ctx.input(|input| input.viewport().close_requested()) {
+            request_shutdown();
+        }
+        update_max_texture_side(ctx.input(|input| input.max_texture_side));
+        self.ensure_ui_theme_applied(ctx);
         if self.is_loading() || self.frame_wait_pending {
             ctx.set_cursor_icon(egui::CursorIcon::Progress);
         } else {
@@ -2265,6 +4174,7 @@ impl eframe::App for DicomViewerApp {
         self.poll_full_metadata_load(ctx);
         self.poll_single_load(ctx);
         self.poll_mammo_group_load(ctx);
+        self.poll_mammo_view_retries(ctx);
         if self.frame_wait_pending && !self.cine_mode {
             if self.image.is_some() {
                 self.rebuild_texture(ctx);
@@ -2272,7 +4182,7 @@ impl eframe::App for DicomViewerApp {
                 let pending = self.set_mammo_group_frame(self.selected_mammo_frame_index());
                 self.frame_wait_pending = pending;
                 if pending {
-                    ctx.request_repaint_after(Duration::from_millis(16));
+                    ctx.request_repaint_after(ACTIVE_REPAINT_INTERVAL);
                 }
             } else {
                 self.frame_wait_pending = false;
@@ -2282,14 +4192,59 @@ impl eframe::App for DicomViewerApp {
         self.sync_measurement_primary_interaction_block(ctx);
 
         let mut history_cycle_direction = None;
+        let mut history_toggle_requested = false;
+        let mut group_page_direction = None;
+        let mut worklist_direction = None;
         let mut close_app_requested = false;
         let mut close_group_requested = false;
         let mut c_pressed = false;
+        let mut f_pressed = false;
         let mut g_pressed = false;
+        // Arrow keys drive the history list while it is hovered or was last
+        // clicked, unless a widget (for example a value being typed) holds
+        // keyboard focus.
+        let (hover_pos, press_pos) = ctx.input(|input| {
+            let pointer = &input.pointer;
+            (
+                pointer.hover_pos(),
+                pointer
+                    .primary_pressed()
+                    .then(|| pointer.interact_pos())
+                    .flatten(),
+            )
+        });
+        self.track_history_panel_focus(press_pos);
+        let history_keys_enabled = !self.history_entries.is_empty()
+            && ctx.memory(|memory| memory.focused().is_none())
+            && self.history_keys_active(hover_pos);
+        let mut history_focus_direction = None;
+        let mut enter_pressed = false;
+        let mut i_pressed = false;
+        let mut invert_pressed = false;
+        let mut center_pressed = false;
+        let mut k_pressed = false;
+        let mut l_pressed = false;
+        let mut shift_l_pressed = false;
+        let mut m_pressed = false;
         let mut n_pressed = false;
+        let mut s_pressed = false;
+        let mut set_mask_pressed = false;
         let mut v_pressed = false;
+        let mut w_pressed = false;
+        let mut z_pressed = false;
         let mut escape_pressed = false;
+        // Ctrl+C arrives as a copy event rather than a key press; leave it to
+        // focused widgets, selected label text and open popups or modals.
+        let frame_copy_enabled = frame_copy_enabled(ctx);
+        let mut copy_frame_requested = false;
         ctx.input_mut(|input| {
+            if frame_copy_enabled {
+                let event_count = input.events.len();
+                input
+                    .events
+                    .retain(|event| !matches!(event, egui::Event::Copy));
+                copy_frame_requested = input.events.len() != event_count;
+            }
             if input.consume_key(
                 egui::Modifiers::COMMAND | egui::Modifiers::SHIFT,
                 egui::Key::W,
@@ -2301,10 +4256,42 @@ impl eframe::App for DicomViewerApp {
                 history_cycle_direction = Some(-1);
             } else if input.consume_key(egui::Modifiers::NONE, egui::Key::Tab) {
                 history_cycle_direction = Some(1);
+            } else if input.consume_key(egui::Modifiers::NONE, egui::Key::Backtick) {
+                history_toggle_requested = true;
+            } else if input.consume_key(egui::Modifiers::NONE, egui::Key::PageUp) {
+                group_page_direction = Some(-1);
+            } else if input.consume_key(egui::Modifiers::NONE, egui::Key::PageDown) {
+                group_page_direction = Some(1);
+            } else if input.consume_key(egui::Modifiers::NONE, egui::Key::OpenBracket) {
+                worklist_direction = Some(-1);
+            } else if input.consume_key(egui::Modifiers::NONE, egui::Key::CloseBracket) {
+                worklist_direction = Some(1);
             }
             c_pressed = input.consume_key(egui::Modifiers::NONE, egui::Key::C);
+            f_pressed = input.consume_key(egui::Modifiers::NONE, egui::Key::F);
             g_pressed = input.consume_key(egui::Modifiers::NONE, egui::Key::G);
+            invert_pressed = input.consume_key(egui::Modifiers::SHIFT, egui::Key::I);
+            i_pressed = input.consume_key(egui::Modifiers::NONE, egui::Key::I);
+            if history_keys_enabled {
+                if input.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp) {
+                    history_focus_direction = Some(-1);
+                } else if input.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown) {
+                    history_focus_direction = Some(1);
+                }
+                if self.history_focus_id.is_some() {
+                    enter_pressed = input.consume_key(egui::Modifiers::NONE, egui::Key::Enter);
+                }
+            }
+            k_pressed = input.consume_key(egui::Modifiers::NONE, egui::Key::K);
+            shift_l_pressed = input.consume_key(egui::Modifiers::SHIFT, egui::Key::L);
+            l_pressed = input.consume_key(egui::Modifiers::NONE, egui::Key::L);
+            m_pressed = input.consume_key(egui::Modifiers::NONE, egui::Key::M);
+            center_pressed = input.consume_key(egui::Modifiers::NONE, egui::Key::Num0);
             n_pressed = input.consume_key(egui::Modifiers::NONE, egui::Key::N);
+            set_mask_pressed = input.consume_key(egui::Modifiers::SHIFT, egui::Key::S);
+            s_pressed = input.consume_key(egui::Modifiers::NONE, egui::Key::S);
+            w_pressed = input.consume_key(egui::Modifiers::NONE, egui::Key::W);
+            z_pressed = input.consume_key(egui::Modifiers::NONE, egui::Key::Z);
             if self.can_toggle_full_metadata_popup() {
                 v_pressed = input.consume_key(egui::Modifiers::NONE, egui::Key::V);
             }
@@ -2317,7 +4304,13 @@ impl eframe::App for DicomViewerApp {
             return;
         }
         if let Some(direction) = history_cycle_direction {
-            self.cycle_history_entry(direction);
+            self.cycle_history_entry(direction, ctx);
+        } else if history_toggle_requested {
+            self.toggle_previous_history_entry(ctx);
+        } else if let Some(direction) = group_page_direction {
+            self.flip_group_page(direction, ctx);
+        } else if let Some(direction) = worklist_direction {
+            self.step_worklist(direction);
         }
         let history_transition_pending = self.pending_history_open_id.is_some();
         if close_group_requested
@@ -2328,27 +4321,84 @@ impl eframe::App for DicomViewerApp {
             return;
         }
         if c_pressed && !history_transition_pending {
-            self.toggle_cine_mode();
+            self.toggle_cine_mode_from_user();
+        }
+        if f_pressed && !history_transition_pending {
+            self.toggle_promoted_cell(ctx);
         }
         if g_pressed && !history_transition_pending && self.toggle_overlay() {
             self.refresh_active_textures(ctx);
         }
-        if n_pressed && !history_transition_pending {
-            self.jump_to_next_overlay(ctx);
+        if w_pressed && !history_transition_pending {
+            self.cycle_window_preset(ctx);
         }
-        if v_pressed {
-            self.toggle_full_metadata_popup();
+        if z_pressed && !history_transition_pending {
+            self.request_measurement_zoom();
         }
-        if escape_pressed {
-            self.handle_escape_action();
+        if let Some(direction) = history_focus_direction {
+            self.move_history_focus(direction);
         }
-
-        let mut open_dicoms_clicked = false;
-        let hovered_files = ctx.input(|input| input.raw.hovered_files.clone());
-
-        let is_maximized = ctx.input(|input| input.viewport().maximized.unwrap_or(false));
-        let title_text = format!("{APP_TITLE} v{APP_VERSION}");
-        let bar_fill = ctx.global_style().visuals.panel_fill;
+        if enter_pressed {
+            self.open_focused_history_entry();
+        }
+        if i_pressed {
+            self.preferences.compact_info_hud = !self.preferences.compact_info_hud;
+            self.persist_settings();
+        }
+        if invert_pressed && !history_transition_pending && self.toggle_active_invert() {
+            self.refresh_active_textures(ctx);
+        }
+        if center_pressed {
+            self.center_active_view();
+        }
+        if copy_frame_requested {
+            if let Err(err) = self.copy_active_frame_to_clipboard() {
+                log::warn!("Could not copy frame: {err:#}");
+            }
+        }
+        if k_pressed {
+            self.window_level_locked = !self.window_level_locked;
+        }
+        if l_pressed {
+            self.preferences.hide_view_labels = !self.preferences.hide_view_labels;
+            self.persist_settings();
+        }
+        if shift_l_pressed {
+            self.preferences.presentation_mode = !self.preferences.presentation_mode;
+            self.persist_settings();
+        }
+        if m_pressed && !history_transition_pending {
+            self.toggle_montage();
+        }
+        if n_pressed && !history_transition_pending {
+            self.jump_to_next_overlay(ctx);
+        }
+        if set_mask_pressed && !history_transition_pending {
+            self.set_subtraction_mask(ctx);
+        }
+        if s_pressed && !history_transition_pending {
+            self.toggle_subtraction(ctx);
+        }
+        if v_pressed {
+            self.toggle_full_metadata_popup();
+        }
+        if escape_pressed {
+            self.handle_escape_action();
+        }
+
+        let mut open_dicoms_clicked = false;
+        let hovered_files = ctx.input(|input| input.raw.hovered_files.clone());
+
+        let is_maximized = ctx.input(|input| input.viewport().maximized.unwrap_or(false));
+        let outer_rect = ctx.input(|input| input.viewport().outer_rect);
+        if !is_maximized {
+            if let Some(rect) = outer_rect.filter(|rect| rect.is_positive()) {
+                self.restored_window_size = Some(rect.size());
+            }
+        }
+        let restored_window_size = self.restored_window_size;
+        let title_text = format!("{APP_TITLE} v{APP_VERSION}");
+        let bar_fill = ctx.global_style().visuals.panel_fill;
         egui::Panel::top("titlebar")
             .show_separator_line(false)
             .frame(egui::Frame::NONE.fill(bar_fill))
@@ -2385,9 +4435,220 @@ impl eframe::App for DicomViewerApp {
                                             open_dicoms_clicked = true;
                                             ui.close();
                                         }
+                                        let study_link = self.study_link();
+                                        if ui
+                                            .add_enabled(
+                                                study_link.is_some(),
+                                                egui::Button::new("Copy Study Link"),
+                                            )
+                                            .clicked()
+                                        {
+                                            if let Some(link) = study_link {
+                                                ui.ctx().copy_text(link);
+                                                log::info!("Copied study link to clipboard.");
+                                            }
+                                            ui.close();
+                                        }
+                                        if let Some(path) = self.revealable_file_path() {
+                                            if ui.button(REVEAL_IN_FILE_MANAGER_LABEL).clicked() {
+                                                if let Err(err) = reveal_in_file_manager(&path) {
+                                                    log::warn!(
+                                                        "Could not open the file manager: {err:#}"
+                                                    );
+                                                }
+                                                ui.close();
+                                            }
+                                        }
                                         ui.menu_button("Select Metadata Fields", |ui| {
                                             self.show_metadata_field_options_menu(ui);
                                         });
+                                        if ui
+                                            .checkbox(
+                                                &mut self.preferences.cine_every_frame,
+                                                "Cine Plays Every Frame",
+                                            )
+                                            .on_hover_text(
+                                                "Never skip frames during cine, even if playback falls behind the requested FPS.",
+                                            )
+                                            .changed()
+                                        {
+                                            self.last_cine_advance = Some(Instant::now());
+                                            self.persist_settings();
+                                        }
+                                        if ui
+                                            .checkbox(
+                                                &mut self.preferences.autoplay_cine,
+                                                "Auto-Play Cine on Open",
+                                            )
+                                            .on_hover_text(
+                                                "Start cine when a multi-frame image or group opens. Stopping an auto-started cine turns this off until the next launch.",
+                                            )
+                                            .changed()
+                                        {
+                                            self.session_autoplay_cine =
+                                                self.preferences.autoplay_cine;
+                                            self.persist_settings();
+                                        }
+                                        if ui
+                                            .checkbox(
+                                                &mut self.preferences.cine_progress_ring,
+                                                "Cine Progress Ring",
+                                            )
+                                            .on_hover_text(
+                                                "While cine plays, show the position within the loop as a ring in the corner of each view.",
+                                            )
+                                            .changed()
+                                        {
+                                            self.persist_settings();
+                                        }
+                                        if ui
+                                            .checkbox(
+                                                &mut self.preferences.instant_history_cycling,
+                                                "Instant History Cycling",
+                                            )
+                                            .on_hover_text(
+                                                "Tab/Shift+Tab switch immediately to history entries that are already decoded, so the title and overlays update without a one-frame delay.",
+                                            )
+                                            .changed()
+                                        {
+                                            self.persist_settings();
+                                        }
+                                        if ui
+                                            .checkbox(
+                                                &mut self.preferences.instant_local_open,
+                                                "Instant Local Open",
+                                            )
+                                            .on_hover_text(
+                                                "Open local files right away instead of waiting one frame to show the busy cursor. DICOMweb opens keep the delay.",
+                                            )
+                                            .changed()
+                                        {
+                                            self.persist_settings();
+                                        }
+                                        if ui
+                                            .checkbox(
+                                                &mut self.preferences.auto_crop_borders,
+                                                "Auto-Crop Black Borders",
+                                            )
+                                            .on_hover_text(
+                                                "Fit the single view to the image content, hiding black film borders.",
+                                            )
+                                            .changed()
+                                        {
+                                            self.single_view_content_uv = None;
+                                            self.reset_single_view_transform();
+                                            self.persist_settings();
+                                        }
+                                        if ui
+                                            .checkbox(
+                                                &mut self.preferences.sticky_window,
+                                                "Sticky Window",
+                                            )
+                                            .on_hover_text(
+                                                "Open images with the last window you set on an image of the same modality instead of the image's default window.",
+                                            )
+                                            .changed()
+                                        {
+                                            self.persist_settings();
+                                        }
+                                        if ui
+                                            .checkbox(
+                                                &mut self.preferences.keep_zoom_on_resize,
+                                                "Keep Zoom on Resize",
+                                            )
+                                            .on_hover_text(
+                                                "Keep a zoomed single image at the same on-screen size when the window is resized, instead of zooming along with the fitted image.",
+                                            )
+                                            .changed()
+                                        {
+                                            self.persist_settings();
+                                        }
+                                        if ui
+                                            .checkbox(
+                                                &mut self.preferences.compact_info_hud,
+                                                "Compact Info Line (I)",
+                                            )
+                                            .on_hover_text(
+                                                "Show a one-line image summary at the bottom instead of the metadata overlay.",
+                                            )
+                                            .changed()
+                                        {
+                                            self.persist_settings();
+                                        }
+                                        if ui
+                                            .checkbox(
+                                                &mut self.preferences.assume_monochrome1_when_missing,
+                                                "Missing Photometric Means MONOCHROME1",
+                                            )
+                                            .on_hover_text(
+                                                "Show images without a PhotometricInterpretation inverted. Applies to images opened afterwards; press Shift+I to invert the current image.",
+                                            )
+                                            .changed()
+                                        {
+                                            self.persist_settings();
+                                        }
+                                        if ui
+                                            .checkbox(
+                                                &mut self.preferences.frame_filmstrip,
+                                                "Frame Filmstrip",
+                                            )
+                                            .on_hover_text(
+                                                "Show frame thumbnails below multi-frame images; click one to jump to that frame.",
+                                            )
+                                            .changed()
+                                        {
+                                            self.persist_settings();
+                                        }
+                                        if ui
+                                            .checkbox(
+                                                &mut self.preferences.sort_history_by_date,
+                                                "Sort History by Study Date",
+                                            )
+                                            .on_hover_text(
+                                                "List history newest study first instead of most recently opened first. Tab cycles in the same order.",
+                                            )
+                                            .changed()
+                                        {
+                                            self.history_focus_id = None;
+                                            self.persist_settings();
+                                        }
+                                        if ui
+                                            .checkbox(
+                                                &mut self.preferences.history_view_thumbnails,
+                                                "History Thumbnail per View",
+                                            )
+                                            .on_hover_text(
+                                                "Show groups of up to four views in history as one small thumbnail per view instead of a combined grid.",
+                                            )
+                                            .changed()
+                                        {
+                                            self.rebuild_group_history_thumbs(ctx);
+                                            self.persist_settings();
+                                        }
+                                        ui.menu_button("Window/Level Drag", |ui| {
+                                            let mut gesture =
+                                                self.preferences.window_level_gesture;
+                                            for option in WindowLevelGesture::ALL {
+                                                ui.radio_value(&mut gesture, option, option.label());
+                                            }
+                                            if gesture != self.preferences.window_level_gesture {
+                                                self.preferences.window_level_gesture = gesture;
+                                                self.persist_settings();
+                                            }
+                                        });
+                                        self.show_monitor_dpi_setting(ui);
+                                        self.show_grid_style_settings(ui);
+                                        self.show_view_fallback_tags_setting(ui);
+                                        self.show_export_settings(ui);
+                                        ui.separator();
+                                        if ui.button("Reset All Settings...").clicked() {
+                                            self.reset_settings_confirm_open = true;
+                                            ui.close();
+                                        }
+                                        if ui.button("About / Diagnostics...").clicked() {
+                                            self.about_panel_open = true;
+                                            ui.close();
+                                        }
                                     },
                                 );
                             Self::register_icon_button_accessibility(
@@ -2426,7 +4687,20 @@ impl eframe::App for DicomViewerApp {
                         ui.visuals().text_color(),
                     );
 
-                    if drag_response.is_pointer_button_down_on() {
+                    if is_maximized {
+                        // Wait for real movement so a double-click can still
+                        // restore; then drop out of maximized like a native
+                        // titlebar, keeping the window under the cursor.
+                        if drag_response.drag_started() {
+                            for command in unmaximize_drag_commands(
+                                drag_response.interact_pointer_pos(),
+                                outer_rect,
+                                restored_window_size,
+                            ) {
+                                ctx.send_viewport_cmd(command);
+                            }
+                        }
+                    } else if drag_response.is_pointer_button_down_on() {
                         ctx.send_viewport_cmd(ViewportCommand::StartDrag);
                     }
                     if drag_response.double_clicked() {
@@ -2503,6 +4777,7 @@ impl eframe::App for DicomViewerApp {
         let has_mammo_group = self.has_mammo_group();
 
         let has_history = !self.history_entries.is_empty();
+        self.track_displayed_history_entry();
         let current_history_id = self.current_history_id();
         let mut open_history_index = None;
 
@@ -2513,11 +4788,14 @@ impl eframe::App for DicomViewerApp {
         let mut request_rebuild = false;
         let has_active_overlay = self.has_available_overlay();
         let has_overlay_navigation_target = self.next_overlay_navigation_target().is_some();
+        let has_true_size_action = self.single_image_column_spacing_mm().is_some();
+        let mut true_size_clicked = false;
 
         if let Some(state) = active_state.as_mut() {
             let spacing = ctx.global_style().spacing.clone();
             let has_slider_rows = state.is_monochrome || state.frame_count > 1;
-            let has_action_rows = state.frame_count > 1 || has_active_overlay;
+            let has_action_rows =
+                state.frame_count > 1 || has_active_overlay || has_true_size_action;
             let wl_layout = Self::wl_overlay_layout(
                 ctx.content_rect().width(),
                 spacing.interact_size.y,
@@ -2531,8 +4809,12 @@ impl eframe::App for DicomViewerApp {
                 let row_spacing_y = spacing.item_spacing.y + 4.0;
                 let mut overlay_rows = Vec::new();
                 if state.is_monochrome {
+                    if !state.window_presets.is_empty() {
+                        overlay_rows.push(WlOverlayRow::Preset);
+                    }
                     overlay_rows.push(WlOverlayRow::Center);
                     overlay_rows.push(WlOverlayRow::Width);
+                    overlay_rows.push(WlOverlayRow::LockWindowLevel);
                 }
                 if state.frame_count > 1 {
                     overlay_rows.push(WlOverlayRow::Frame);
@@ -2545,12 +4827,20 @@ impl eframe::App for DicomViewerApp {
                         overlay_rows.push(WlOverlayRow::NextOverlay);
                     }
                 }
+                if has_true_size_action {
+                    overlay_rows.push(WlOverlayRow::TrueSize);
+                }
 
-                let mut bottom_offset_y = 10.0;
+                let preset_label = self.active_window_preset_label();
+                let mut bottom_offset_y = 10.0 + self.filmstrip_bottom_inset();
                 for row in overlay_rows.into_iter().rev() {
                     let (row_id, row_width) = match row {
+                        WlOverlayRow::Preset => ("wl-overlay-preset", wl_layout.slider_row_width),
                         WlOverlayRow::Center => ("wl-overlay-center", wl_layout.slider_row_width),
                         WlOverlayRow::Width => ("wl-overlay-width", wl_layout.slider_row_width),
+                        WlOverlayRow::LockWindowLevel => {
+                            ("wl-overlay-lock", wl_layout.action_row_width)
+                        }
                         WlOverlayRow::Frame => ("wl-overlay-frame", wl_layout.slider_row_width),
                         WlOverlayRow::CineFps => {
                             ("wl-overlay-cine-fps", wl_layout.slider_row_width)
@@ -2564,16 +4854,50 @@ impl eframe::App for DicomViewerApp {
                         WlOverlayRow::NextOverlay => {
                             ("wl-overlay-next-overlay", wl_layout.action_row_width)
                         }
+                        WlOverlayRow::TrueSize => {
+                            ("wl-overlay-true-size", wl_layout.action_row_width)
+                        }
                     };
 
+                    let row_locked = self.window_level_locked && row.adjusts_window_level();
                     Self::show_wl_overlay_row(
                         ctx,
                         row_id,
                         row_width,
                         row_height,
                         bottom_offset_y,
-                        !history_transition_pending,
+                        !history_transition_pending && !row_locked,
                         |ui| match row {
+                            WlOverlayRow::Preset => {
+                                let selected =
+                                    preset_label.clone().unwrap_or_else(|| "Custom".to_string());
+                                ui.with_layout(
+                                    egui::Layout::right_to_left(egui::Align::Center),
+                                    |ui| {
+                                        egui::ComboBox::from_id_salt("wl-overlay-preset-combo")
+                                            .width(wl_layout.slider_widget_width)
+                                            .selected_text(selected)
+                                            .show_ui(ui, |ui| {
+                                                for preset in &state.window_presets {
+                                                    if ui
+                                                        .selectable_label(
+                                                            preset.center == state.window_center
+                                                                && preset.width
+                                                                    == state.window_width,
+                                                            preset.label.as_str(),
+                                                        )
+                                                        .clicked()
+                                                    {
+                                                        state.window_center = preset.center;
+                                                        state.window_width = preset.width;
+                                                        request_rebuild = true;
+                                                    }
+                                                }
+                                            });
+                                        ui.label("Preset");
+                                    },
+                                );
+                            }
                             WlOverlayRow::Center => {
                                 let center_range = (state.min_value as f32 - 2000.0)
                                     ..=(state.max_value as f32 + 2000.0);
@@ -2680,7 +5004,23 @@ impl eframe::App for DicomViewerApp {
                             }
                             WlOverlayRow::Frame => {
                                 let mut frame_index = state.current_frame as u32;
-                                let max_frame = state.frame_count.saturating_sub(1) as u32;
+                                let (min_frame, max_frame) = if state.is_single {
+                                    self.single_frame_bounds(state.frame_count)
+                                } else {
+                                    (0, state.frame_count.saturating_sub(1))
+                                };
+                                let (min_frame, max_frame) = (min_frame as u32, max_frame as u32);
+                                let active_image = self.active_image();
+                                let loaded_frames = active_image.map(DicomImage::loaded_frames);
+                                let frame_hover_text = Self::frame_slider_hover_text(
+                                    state.current_frame,
+                                    active_image.and_then(|image| {
+                                        image.frame_time_offset_ms(state.current_frame)
+                                    }),
+                                    active_image.map(|image| {
+                                        (image.loaded_frame_count(), image.frame_count())
+                                    }),
+                                );
                                 let refresh_button_size = ui.spacing().interact_size.y;
                                 ui.with_layout(
                                     egui::Layout::right_to_left(egui::Align::Center),
@@ -2697,8 +5037,8 @@ impl eframe::App for DicomViewerApp {
                                             .on_hover_text("Reset Frame")
                                             .clicked()
                                         {
-                                            frame_index = 0;
-                                            state.current_frame = 0;
+                                            frame_index = min_frame;
+                                            state.current_frame = min_frame as usize;
                                             self.last_cine_advance = Some(Instant::now());
                                             request_rebuild = true;
                                         }
@@ -2707,7 +5047,7 @@ impl eframe::App for DicomViewerApp {
                                             ui,
                                             [CONTROL_VALUE_WIDTH, row_height],
                                             egui::DragValue::new(&mut frame_index)
-                                                .range(0..=max_frame)
+                                                .range(min_frame..=max_frame)
                                                 .speed(1.0),
                                         )
                                         .changed()
@@ -2717,22 +5057,30 @@ impl eframe::App for DicomViewerApp {
                                             request_rebuild = true;
                                         }
 
-                                        if ui
+                                        let slider_response = ui
                                             .scope(|ui| {
                                                 ui.spacing_mut().slider_width =
                                                     wl_layout.slider_widget_width;
                                                 ui.add(
                                                     egui::Slider::new(
                                                         &mut frame_index,
-                                                        0..=max_frame,
+                                                        min_frame..=max_frame,
                                                     )
                                                     .show_value(false)
                                                     .text("Frame"),
                                                 )
+                                                .on_hover_text(frame_hover_text)
                                             })
-                                            .inner
-                                            .changed()
-                                        {
+                                            .inner;
+                                        if let Some(loaded) = loaded_frames.as_deref() {
+                                            Self::paint_loaded_frame_strip(
+                                                ui,
+                                                slider_response.rect,
+                                                wl_layout.slider_widget_width,
+                                                loaded,
+                                            );
+                                        }
+                                        if slider_response.changed() {
                                             state.current_frame = frame_index as usize;
                                             self.last_cine_advance = Some(Instant::now());
                                             request_rebuild = true;
@@ -2740,6 +5088,32 @@ impl eframe::App for DicomViewerApp {
                                     },
                                 );
                             }
+                            WlOverlayRow::LockWindowLevel => {
+                                ui.with_layout(
+                                    egui::Layout::right_to_left(egui::Align::Center),
+                                    |ui| {
+                                        if Self::add_action_control_button_no_border(
+                                            ui,
+                                            [
+                                                CONTROL_ACTION_BUTTON_WIDTH,
+                                                ui.spacing().interact_size.y,
+                                            ],
+                                            if self.window_level_locked {
+                                                "Unlock W/L (K)"
+                                            } else {
+                                                "Lock W/L (K)"
+                                            },
+                                        )
+                                        .on_hover_text(
+                                            "While locked, drags and the window sliders leave window/level unchanged.",
+                                        )
+                                        .clicked()
+                                        {
+                                            self.window_level_locked = !self.window_level_locked;
+                                        }
+                                    },
+                                );
+                            }
                             WlOverlayRow::CineFps => {
                                 let refresh_button_size = ui.spacing().interact_size.y;
                                 ui.with_layout(
@@ -2763,23 +5137,32 @@ impl eframe::App for DicomViewerApp {
                                             self.cine_fps =
                                                 self.default_cine_fps_for_active_image();
                                             self.last_cine_advance = Some(Instant::now());
+                                            self.forget_cine_fps_for_active_modality();
                                         }
 
-                                        if Self::add_value_control_no_border(
+                                        let fps_response = Self::add_value_control_no_border(
                                             ui,
                                             [CONTROL_VALUE_WIDTH, row_height],
                                             egui::DragValue::new(&mut self.cine_fps)
                                                 .range(1.0..=120.0)
                                                 .speed(0.5)
                                                 .max_decimals(1),
-                                        )
-                                        .changed()
-                                        {
+                                        );
+                                        if fps_response.changed() {
                                             self.cine_fps = self.cine_fps.clamp(1.0, 120.0);
                                             self.last_cine_advance = Some(Instant::now());
                                         }
+                                        if Self::value_edit_committed(&fps_response) {
+                                            self.remember_cine_fps_for_active_modality();
+                                        }
 
-                                        if ui
+                                        let slider_text = Self::cine_fps_slider_text(
+                                            self.cine_fps,
+                                            self.cine_rate_meter
+                                                .fps(Instant::now())
+                                                .filter(|_| self.cine_mode),
+                                        );
+                                        let slider_response = ui
                                             .scope(|ui| {
                                                 ui.spacing_mut().slider_width =
                                                     wl_layout.slider_widget_width;
@@ -2789,15 +5172,17 @@ impl eframe::App for DicomViewerApp {
                                                         1.0..=120.0,
                                                     )
                                                     .show_value(false)
-                                                    .text("Cine FPS"),
+                                                    .text(slider_text),
                                                 )
                                             })
-                                            .inner
-                                            .changed()
-                                        {
+                                            .inner;
+                                        if slider_response.changed() {
                                             self.cine_fps = self.cine_fps.clamp(1.0, 120.0);
                                             self.last_cine_advance = Some(Instant::now());
                                         }
+                                        if Self::value_edit_committed(&slider_response) {
+                                            self.remember_cine_fps_for_active_modality();
+                                        }
                                     },
                                 );
                             }
@@ -2869,6 +5254,36 @@ impl eframe::App for DicomViewerApp {
                                     },
                                 );
                             }
+                            WlOverlayRow::TrueSize => {
+                                ui.with_layout(
+                                    egui::Layout::right_to_left(egui::Align::Center),
+                                    |ui| {
+                                        let dpi_set = self.preferences.monitor_dpi.is_some();
+                                        if ui
+                                            .add_enabled_ui(dpi_set, |ui| {
+                                                Self::add_action_control_button_no_border(
+                                                    ui,
+                                                    [
+                                                        CONTROL_ACTION_BUTTON_WIDTH,
+                                                        ui.spacing().interact_size.y,
+                                                    ],
+                                                    "True Size",
+                                                )
+                                            })
+                                            .inner
+                                            .on_hover_text(
+                                                "Zoom so 1 cm in the image is 1 cm on screen.",
+                                            )
+                                            .on_disabled_hover_text(
+                                                "Set Monitor DPI in the titlebar menu to enable True Size.",
+                                            )
+                                            .clicked()
+                                        {
+                                            true_size_clicked = true;
+                                        }
+                                    },
+                                );
+                            }
                         },
                     );
                     bottom_offset_y += row_height + row_spacing_y;
@@ -2877,7 +5292,7 @@ impl eframe::App for DicomViewerApp {
         }
 
         if toggle_cine_clicked {
-            self.toggle_cine_mode();
+            self.toggle_cine_mode_from_user();
         }
         if toggle_overlay_clicked && self.toggle_overlay() {
             self.refresh_active_textures(ctx);
@@ -2885,6 +5300,9 @@ impl eframe::App for DicomViewerApp {
         if next_overlay_clicked {
             self.jump_to_next_overlay(ctx);
         }
+        if true_size_clicked {
+            self.true_size_requested = true;
+        }
 
         // Avoid applying stale W/L UI state while cycling history quickly with Tab.
         if request_rebuild && !next_overlay_clicked {
@@ -2893,46 +5311,91 @@ impl eframe::App for DicomViewerApp {
             }
         }
 
+        if let Some(frame_index) = self.show_frame_filmstrip(root_ui, ctx) {
+            self.jump_to_frame(frame_index, ctx);
+        }
+        self.show_series_tab_strip(root_ui, ctx);
+        self.show_group_page_strip(root_ui, ctx);
+
         egui::CentralPanel::default().show(root_ui, |ui| {
             if has_mammo_group {
                 self.show_mammo_grid(ui);
+            } else if self.montage_active() {
+                self.show_frame_montage(ui);
             } else if let Some(texture) = self.texture.clone() {
                 let available = ui.available_size();
                 let (canvas_rect, response) =
                     ui.allocate_exact_size(available, Sense::click_and_drag());
-                let image_size = texture.size_vec2();
+                let image_size = self
+                    .image
+                    .as_ref()
+                    .map_or_else(|| texture.size_vec2(), image_display_size);
                 let primary_interaction_blocked =
                     self.maybe_clear_live_measurement_with_primary(&response);
+                self.show_view_context_menu(&response, view_context_menu_requested(&response));
                 if image_size.x > 0.0 && image_size.y > 0.0 && canvas_rect.is_positive() {
                     if !primary_interaction_blocked && response.double_clicked() {
                         self.reset_single_view_transform();
                     }
+                    let content_uv = self.single_view_content_uv();
+                    let content_size = image_size * content_uv.size();
+                    let fit_scale = (canvas_rect.width() / content_size.x)
+                        .min(canvas_rect.height() / content_size.y)
+                        .max(0.01);
+                    self.hold_single_view_zoom_across_resize(content_size, fit_scale);
+                    if std::mem::take(&mut self.true_size_requested) {
+                        if let Some(zoom) = self
+                            .single_image_column_spacing_mm()
+                            .zip(self.preferences.monitor_dpi)
+                            .and_then(|(spacing, dpi)| {
+                                Self::true_size_zoom(
+                                    spacing,
+                                    dpi,
+                                    ui.ctx().pixels_per_point(),
+                                    fit_scale,
+                                )
+                            })
+                        {
+                            self.single_view_zoom = zoom;
+                            self.single_view_zoomed = true;
+                            self.single_view_pan = egui::Vec2::ZERO;
+                        }
+                    }
 
-                    if !primary_interaction_blocked
-                        && response.dragged_by(egui::PointerButton::Primary)
-                    {
+                    if std::mem::take(&mut self.measurement_zoom_requested) {
+                        if let Some(region) = self.single_measurement_bounds() {
+                            (self.single_view_zoom, self.single_view_pan) = zoom_to_region(
+                                region,
+                                image_size,
+                                content_uv,
+                                canvas_rect.size(),
+                                fit_scale,
+                                SINGLE_VIEW_MAX_ZOOM,
+                            );
+                            self.single_view_zoomed = true;
+                        }
+                    }
+
+                    let primary_dragged = !primary_interaction_blocked
+                        && response.dragged_by(egui::PointerButton::Primary);
+                    let secondary_dragged = response.dragged_by(egui::PointerButton::Secondary);
+                    if primary_dragged || secondary_dragged {
                         let (frame_drag_delta, shift_held) =
                             ui.input(|input| (input.pointer.delta(), input.modifiers.shift));
-                        let wl_meta = self
-                            .image
-                            .as_ref()
-                            .map(|image| (image.is_monochrome(), image.min_value, image.max_value));
+                        let single_is_monochrome =
+                            self.image.as_ref().is_some_and(DicomImage::is_monochrome);
                         let mut handled_wl_drag = false;
-                        if shift_held {
-                            if let Some((true, min_value, max_value)) = wl_meta {
-                                handled_wl_drag = true;
-                                if Self::apply_window_level_drag(
-                                    &mut self.window_center,
-                                    &mut self.window_width,
-                                    min_value,
-                                    max_value,
-                                    frame_drag_delta,
-                                ) {
-                                    self.rebuild_texture(ctx);
-                                }
-                            }
+                        if single_is_monochrome
+                            && self.preferences.window_level_gesture.adjusts_window_level(
+                                primary_dragged,
+                                secondary_dragged,
+                                shift_held,
+                            )
+                        {
+                            handled_wl_drag = true;
+                            self.drag_single_window_level(frame_drag_delta, ctx);
                         }
-                        if !handled_wl_drag && self.single_view_zoom > 1.0 {
+                        if !handled_wl_drag && primary_dragged && self.single_view_zoom > 1.0 {
                             self.single_view_pan += frame_drag_delta;
                         }
                     }
@@ -2975,45 +5438,63 @@ impl eframe::App for DicomViewerApp {
                             } else if (wheel_zoom - 1.0_f32).abs() > f32::EPSILON {
                                 next_zoom *= wheel_zoom;
                             }
-                            next_zoom = next_zoom.clamp(1.0, 12.0);
+                            // True Size may sit below fit; keep it from snapping on the next wheel tick.
+                            next_zoom = next_zoom
+                                .clamp(self.single_view_zoom.min(1.0), SINGLE_VIEW_MAX_ZOOM);
 
                             if (next_zoom - self.single_view_zoom).abs() > f32::EPSILON {
                                 let old_zoom = self.single_view_zoom;
                                 self.single_view_zoom = next_zoom;
+                                self.single_view_zoomed = next_zoom != 1.0;
                                 if let Some(pointer_pos) = response.hover_pos() {
-                                    let old_center = canvas_rect.center() + self.single_view_pan;
-                                    let pointer_offset = pointer_pos - old_center;
-                                    let zoom_ratio = self.single_view_zoom / old_zoom;
-                                    self.single_view_pan += pointer_offset * (1.0 - zoom_ratio);
+                                    let old_image_rect = Self::single_view_image_rect(
+                                        canvas_rect.center(),
+                                        self.single_view_pan,
+                                        image_size * fit_scale * old_zoom,
+                                        content_uv,
+                                    );
+                                    self.single_view_pan += Self::zoom_anchor_pan_delta(
+                                        old_image_rect,
+                                        pointer_pos,
+                                        old_zoom,
+                                        self.single_view_zoom,
+                                    );
                                 }
                             }
                         }
                     }
 
-                    let fit_scale = (canvas_rect.width() / image_size.x)
-                        .min(canvas_rect.height() / image_size.y)
-                        .max(0.01);
                     let draw_size = image_size * fit_scale * self.single_view_zoom;
-                    let max_pan_x = ((draw_size.x - canvas_rect.width()) * 0.5).max(0.0);
-                    let max_pan_y = ((draw_size.y - canvas_rect.height()) * 0.5).max(0.0);
+                    let content_draw_size = draw_size * content_uv.size();
+                    let max_pan_x = ((content_draw_size.x - canvas_rect.width()) * 0.5).max(0.0);
+                    let max_pan_y = ((content_draw_size.y - canvas_rect.height()) * 0.5).max(0.0);
                     self.single_view_pan.x = self.single_view_pan.x.clamp(-max_pan_x, max_pan_x);
                     self.single_view_pan.y = self.single_view_pan.y.clamp(-max_pan_y, max_pan_y);
                     if self.single_view_zoom <= 1.0 {
                         self.single_view_pan = egui::Vec2::ZERO;
                     }
 
-                    let image_rect = egui::Rect::from_center_size(
-                        canvas_rect.center() + self.single_view_pan,
+                    let image_rect = Self::single_view_image_rect(
+                        canvas_rect.center(),
+                        self.single_view_pan,
                         draw_size,
+                        content_uv,
+                    );
+                    let content_rect = egui::Rect::from_center_size(
+                        canvas_rect.center() + self.single_view_pan,
+                        content_draw_size,
                     );
-                    let painter = ui.painter().with_clip_rect(canvas_rect);
+                    let painter = ui
+                        .painter()
+                        .with_clip_rect(canvas_rect.intersect(content_rect));
                     if let Some(geometry) = self.image.as_ref().map(MeasurementGeometry::from_image)
                     {
                         let pointer_pos = ui.ctx().pointer_latest_pos();
-                        let secondary_pointer_pos = if response.contains_pointer()
-                            && ui.input(|input| {
-                                input.pointer.button_pressed(egui::PointerButton::Secondary)
-                            }) {
+                        let secondary_pointer_pos = if self
+                            .preferences
+                            .window_level_gesture
+                            .measurement_anchor_requested(&response, ui)
+                        {
                             pointer_pos
                         } else {
                             None
@@ -3061,6 +5542,37 @@ impl eframe::App for DicomViewerApp {
                                 );
                             }
                         }
+                        if let Some(orientation) = self
+                            .image
+                            .as_ref()
+                            .filter(|_| !self.preferences.presentation_mode)
+                            .and_then(|image| image.patient_orientation.as_ref())
+                        {
+                            Self::draw_orientation_labels(
+                                &painter,
+                                canvas_rect.intersect(content_rect),
+                                orientation,
+                            );
+                        }
+                        if self.cine_mode && self.preferences.cine_progress_ring {
+                            if let Some(image) = self.image.as_ref() {
+                                // Bottom left: the window/level controls own the
+                                // bottom right corner.
+                                let bottom_inset = if self.preferences.compact_info_hud {
+                                    COMPACT_HUD_RING_INSET
+                                } else {
+                                    0.0
+                                };
+                                Self::draw_cine_progress_ring(
+                                    &painter,
+                                    canvas_rect,
+                                    egui::Align::Max,
+                                    bottom_inset,
+                                    self.current_frame,
+                                    image.frame_count(),
+                                );
+                            }
+                        }
                         self.draw_live_measurement(
                             &painter,
                             MeasurementTarget::Single,
@@ -3088,10 +5600,14 @@ impl eframe::App for DicomViewerApp {
         });
 
         self.show_metadata_ui(ctx);
+        self.show_reset_settings_confirmation(ctx);
+        self.show_about_panel(ctx);
+        self.show_export_dialog(ctx);
 
+        self.history_panel_rect = None;
         if has_history {
             let overlay_height = (ctx.content_rect().height() * 0.62).max(160.0);
-            egui::Area::new(egui::Id::new("history-overlay-right"))
+            let history_area = egui::Area::new(egui::Id::new("history-overlay-right"))
                 .order(egui::Order::Foreground)
                 .anchor(egui::Align2::RIGHT_TOP, egui::vec2(-10.0, 36.0))
                 .show(ctx, |ui| {
@@ -3102,9 +5618,11 @@ impl eframe::App for DicomViewerApp {
                         open_history_index = Some(index);
                     }
                 });
+            self.history_panel_rect = Some(history_area.response.rect);
         }
 
         if let Some(message) = self.load_error_message.clone() {
+            let detail = self.load_error_detail.clone();
             let mut dismiss_error = false;
             egui::Area::new(egui::Id::new("load-error-overlay"))
                 .order(egui::Order::Foreground)
@@ -3143,6 +5661,26 @@ impl eframe::App for DicomViewerApp {
                                     "Dismiss",
                                 );
                             });
+                            if let Some(detail) = detail {
+                                egui::CollapsingHeader::new("Details")
+                                    .id_salt("load-error-detail")
+                                    .show(ui, |ui| {
+                                        ui.set_max_width(520.0);
+                                        egui::ScrollArea::vertical().max_height(180.0).show(
+                                            ui,
+                                            |ui| {
+                                                ui.add(
+                                                    egui::Label::new(
+                                                        egui::RichText::new(detail)
+                                                            .monospace()
+                                                            .small(),
+                                                    )
+                                                    .wrap(),
+                                                );
+                                            },
+                                        );
+                                    });
+                            }
                         });
                 });
             if dismiss_error {
@@ -3164,32 +5702,348 @@ impl eframe::App for DicomViewerApp {
     }
 }
 
-fn default_visible_metadata_fields() -> HashSet<String> {
-    METADATA_FIELD_NAMES
-        .iter()
-        .map(|field| (*field).to_string())
-        .collect()
+/// Largest texture side uploads may use; 0 until the first frame reports the
+/// GPU limit. Shared with background threads that render load previews.
+static MAX_TEXTURE_SIDE: AtomicUsize = AtomicUsize::new(0);
+
+/// Records the GPU's texture size limit, unless `PERSPECTA_MAX_TEXTURE_SIDE`
+/// overrides it for drivers that misreport their limit.
+fn update_max_texture_side(reported: usize) {
+    let max_side = configured_max_texture_side().unwrap_or(reported);
+    MAX_TEXTURE_SIDE.store(max_side, Ordering::Relaxed);
 }
 
-fn ordered_visible_metadata_fields(visible: &HashSet<String>) -> Vec<String> {
-    METADATA_FIELD_NAMES
-        .iter()
-        .filter(|field| visible.contains(**field))
-        .map(|field| (*field).to_string())
-        .collect()
+fn configured_max_texture_side() -> Option<usize> {
+    static CONFIG: OnceLock<Option<usize>> = OnceLock::new();
+
+    *CONFIG.get_or_init(|| {
+        parse_max_texture_side(env::var("PERSPECTA_MAX_TEXTURE_SIDE").ok().as_deref())
+    })
 }
 
-fn metadata_settings_file_path() -> Option<PathBuf> {
-    #[cfg(target_os = "windows")]
-    {
-        return env::var_os("APPDATA")
-            .map(PathBuf::from)
-            .map(|base| base.join("perspecta").join("settings.toml"));
+/// Texture size limit for the About panel: the effective cap in pixels, or
+/// "unknown" before the first frame reports one.
+fn max_texture_side_summary() -> String {
+    match MAX_TEXTURE_SIDE.load(Ordering::Relaxed) {
+        0 => "unknown".to_string(),
+        side => format!("{side} px"),
     }
+}
 
-    #[cfg(target_os = "macos")]
-    {
-        env::var_os("HOME").map(PathBuf::from).map(|home| {
+fn parse_max_texture_side(raw: Option<&str>) -> Option<usize> {
+    raw?.trim().parse::<usize>().ok().filter(|side| *side > 0)
+}
+
+fn fit_to_max_texture_side(color_image: ColorImage, max_side: usize) -> ColorImage {
+    if max_side == 0 || color_image.size[0].max(color_image.size[1]) <= max_side {
+        return color_image;
+    }
+    downsample_color_image(&color_image, max_side)
+}
+
+/// Checks a load preview before it becomes a texture. egui cannot report a
+/// failed GPU upload, so frames the backend would reject are caught here:
+/// oversized ones are shrunk to the texture size limit (previews may be
+/// rendered before the first frame reports it) and empty or short ones are
+/// refused with an error for the status message.
+fn prepare_texture_upload(color_image: ColorImage, max_side: usize) -> Result<ColorImage, String> {
+    let [width, height] = color_image.size;
+    if width == 0 || height == 0 || width.checked_mul(height) != Some(color_image.pixels.len()) {
+        return Err(format!(
+            "Could not display the image: its {width}x{height} frame has {} pixels and cannot be uploaded as a texture.",
+            color_image.pixels.len()
+        ));
+    }
+    if max_side > 0 && width.max(height) > max_side {
+        log::warn!(
+            "Shrinking {width}x{height} frame to the {max_side} px texture size limit before upload."
+        );
+    }
+    Ok(fit_to_max_texture_side(color_image, max_side))
+}
+
+/// Size views lay an image out at: its pixel dimensions, which the texture
+/// may be smaller than after `fit_to_max_texture_side`.
+fn image_display_size(image: &DicomImage) -> egui::Vec2 {
+    egui::vec2(image.width as f32, image.height as f32)
+}
+
+fn apply_gsps_shutter(color_image: &mut ColorImage, shutter: &GspsShutter) {
+    let [width, _] = color_image.size;
+    if width == 0 {
+        return;
+    }
+    let shutter_color = egui::Color32::from_gray(shutter.gray);
+    for (index, pixel) in color_image.pixels.iter_mut().enumerate() {
+        if shutter.hides(index % width, index / width) {
+            *pixel = shutter_color;
+        }
+    }
+}
+
+/// Where a window restored from maximized goes so the cursor keeps the same
+/// relative spot along the titlebar and the same height within it.
+fn unmaximized_window_origin(
+    pointer_on_screen: egui::Pos2,
+    pointer_in_window: egui::Pos2,
+    maximized_width: f32,
+    restored_width: f32,
+) -> egui::Pos2 {
+    let fraction = if maximized_width > 0.0 {
+        (pointer_in_window.x / maximized_width).clamp(0.0, 1.0)
+    } else {
+        0.5
+    };
+    egui::pos2(
+        pointer_on_screen.x - fraction * restored_width,
+        pointer_on_screen.y - pointer_in_window.y,
+    )
+}
+
+/// Viewport commands for dragging a maximized window by its titlebar. The
+/// window is only repositioned under the cursor when its pre-maximize size is
+/// known; a window maximized since launch is left where the OS restores it.
+fn unmaximize_drag_commands(
+    pointer_in_window: Option<egui::Pos2>,
+    outer_rect: Option<egui::Rect>,
+    restored_window_size: Option<egui::Vec2>,
+) -> Vec<ViewportCommand> {
+    let mut commands = vec![ViewportCommand::Maximized(false)];
+    if let (Some(pointer), Some(outer), Some(restored)) =
+        (pointer_in_window, outer_rect, restored_window_size)
+    {
+        commands.push(ViewportCommand::OuterPosition(unmaximized_window_origin(
+            outer.min + pointer.to_vec2(),
+            pointer,
+            outer.width(),
+            restored.x,
+        )));
+    }
+    commands.push(ViewportCommand::StartDrag);
+    commands
+}
+
+/// Invisible hit regions along the window border that start an OS resize:
+/// four edges of `edge` thickness and, at each corner, two `edge`-thick
+/// strips `corner` long. The strips leave the inside of a corner free for
+/// the titlebar buttons, and the south-east corner is left to the visible
+/// resize grip.
+fn resize_edge_rects(
+    screen: egui::Rect,
+    edge: f32,
+    corner: f32,
+) -> Vec<(ResizeDirection, egui::Rect)> {
+    let corner = corner.min(screen.width() / 2.0).min(screen.height() / 2.0);
+    let (left, right, top, bottom) = (screen.left(), screen.right(), screen.top(), screen.bottom());
+    let rect = |x0: f32, y0: f32, x1: f32, y1: f32| {
+        egui::Rect::from_min_max(egui::pos2(x0, y0), egui::pos2(x1, y1))
+    };
+    vec![
+        (
+            ResizeDirection::North,
+            rect(left + corner, top, right - corner, top + edge),
+        ),
+        (
+            ResizeDirection::South,
+            rect(left + corner, bottom - edge, right - corner, bottom),
+        ),
+        (
+            ResizeDirection::West,
+            rect(left, top + corner, left + edge, bottom - corner),
+        ),
+        (
+            ResizeDirection::East,
+            rect(right - edge, top + corner, right, bottom - corner),
+        ),
+        (
+            ResizeDirection::NorthWest,
+            rect(left, top, left + corner, top + edge),
+        ),
+        (
+            ResizeDirection::NorthWest,
+            rect(left, top, left + edge, top + corner),
+        ),
+        (
+            ResizeDirection::NorthEast,
+            rect(right - corner, top, right, top + edge),
+        ),
+        (
+            ResizeDirection::NorthEast,
+            rect(right - edge, top, right, top + corner),
+        ),
+        (
+            ResizeDirection::SouthWest,
+            rect(left, bottom - edge, left + corner, bottom),
+        ),
+        (
+            ResizeDirection::SouthWest,
+            rect(left, bottom - corner, left + edge, bottom),
+        ),
+    ]
+}
+
+fn resize_cursor_icon(direction: ResizeDirection) -> egui::CursorIcon {
+    match direction {
+        ResizeDirection::North => egui::CursorIcon::ResizeNorth,
+        ResizeDirection::South => egui::CursorIcon::ResizeSouth,
+        ResizeDirection::East => egui::CursorIcon::ResizeEast,
+        ResizeDirection::West => egui::CursorIcon::ResizeWest,
+        ResizeDirection::NorthEast => egui::CursorIcon::ResizeNorthEast,
+        ResizeDirection::SouthEast => egui::CursorIcon::ResizeSouthEast,
+        ResizeDirection::NorthWest => egui::CursorIcon::ResizeNorthWest,
+        ResizeDirection::SouthWest => egui::CursorIcon::ResizeSouthWest,
+    }
+}
+
+/// Whether a copy event should copy the active frame rather than text: no
+/// widget holds keyboard focus, no label text is selected and no popup or
+/// modal is open.
+fn frame_copy_enabled(ctx: &egui::Context) -> bool {
+    let label_text_selected = ctx
+        .with_plugin::<egui::text_selection::LabelSelectionState, _>(|state| state.has_selection())
+        .unwrap_or(false);
+    !label_text_selected
+        && !egui::Popup::is_any_open(ctx)
+        && ctx.memory(|memory| memory.focused().is_none() && memory.top_modal_layer().is_none())
+}
+
+/// The preset `W` moves to: the one after the preset currently shown, or
+/// after the last one applied when the window has since been adjusted.
+fn next_window_preset_index(
+    presets: &[WindowPreset],
+    stored_index: Option<usize>,
+    window_center: f32,
+    window_width: f32,
+) -> Option<usize> {
+    if presets.is_empty() {
+        return None;
+    }
+    let current = shown_window_preset_index(presets, stored_index, window_center, window_width)
+        .or(stored_index);
+    Some(current.map_or(0, |index| (index + 1) % presets.len()))
+}
+
+/// The preset the window currently shows: the last one applied when it still
+/// matches, otherwise the first preset with the same center and width.
+fn shown_window_preset_index(
+    presets: &[WindowPreset],
+    stored_index: Option<usize>,
+    window_center: f32,
+    window_width: f32,
+) -> Option<usize> {
+    let shows = |index: usize| {
+        presets
+            .get(index)
+            .is_some_and(|preset| preset.center == window_center && preset.width == window_width)
+    };
+    stored_index
+        .filter(|index| shows(*index))
+        .or_else(|| (0..presets.len()).find(|index| shows(*index)))
+}
+
+/// Clamps a requested frame range to the frames an image has, so a link
+/// naming frames past the end still opens the frames that exist.
+fn clamp_frame_range(
+    frames: RangeInclusive<usize>,
+    frame_count: usize,
+) -> Option<RangeInclusive<usize>> {
+    let last = frame_count.checked_sub(1)?;
+    Some((*frames.start()).min(last)..=(*frames.end()).min(last))
+}
+
+/// Fields shown before the user picks any: identity, date, modality, view and
+/// image size. The picker's `All` button shows the rest.
+const DEFAULT_VISIBLE_METADATA_FIELDS: &[&str] = &[
+    "PatientName",
+    "PatientID",
+    "StudyDate",
+    "Modality",
+    "ViewPosition",
+    "ImageLaterality",
+    "Rows",
+    "Columns",
+];
+
+fn default_visible_metadata_fields() -> Vec<String> {
+    DEFAULT_VISIBLE_METADATA_FIELDS
+        .iter()
+        .map(|field| (*field).to_string())
+        .collect()
+}
+
+fn move_metadata_field(fields: &mut Vec<String>, from: usize, to: usize) -> bool {
+    if from == to || from >= fields.len() || to >= fields.len() {
+        return false;
+    }
+    let field = fields.remove(from);
+    fields.insert(to, field);
+    true
+}
+
+#[cfg(target_os = "macos")]
+const REVEAL_IN_FILE_MANAGER_LABEL: &str = "Reveal in Finder";
+#[cfg(target_os = "windows")]
+const REVEAL_IN_FILE_MANAGER_LABEL: &str = "Show in Explorer";
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const REVEAL_IN_FILE_MANAGER_LABEL: &str = "Open Containing Folder";
+
+/// Opens the platform file manager on `path`: Finder and Explorer select the
+/// file itself, while other desktops open its parent directory through
+/// `xdg-open`, which has no way to select a file.
+fn reveal_in_file_manager(path: &Path) -> anyhow::Result<()> {
+    #[cfg(target_os = "macos")]
+    let mut command = {
+        let mut command = std::process::Command::new("open");
+        command.arg("-R").arg(path);
+        command
+    };
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut select_arg = std::ffi::OsString::from("/select,");
+        select_arg.push(path);
+        let mut command = std::process::Command::new("explorer");
+        command.arg(select_arg);
+        command
+    };
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let mut command = {
+        let folder = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+        let mut command = std::process::Command::new("xdg-open");
+        command.arg(folder);
+        command
+    };
+
+    spawn_reaped(&mut command)
+}
+
+/// Launches `command` without waiting for it, reaping it on a detached
+/// thread when it exits so it does not linger as a zombie process.
+fn spawn_reaped(command: &mut std::process::Command) -> anyhow::Result<()> {
+    let mut child = command
+        .spawn()
+        .with_context(|| format!("Could not launch {:?}", command.get_program()))?;
+    thread::spawn(move || {
+        if let Err(err) = child.wait() {
+            log::debug!("Could not wait for launched process: {err}");
+        }
+    });
+    Ok(())
+}
+
+fn metadata_settings_file_path() -> Option<PathBuf> {
+    #[cfg(target_os = "windows")]
+    {
+        return env::var_os("APPDATA")
+            .map(PathBuf::from)
+            .map(|base| base.join("perspecta").join("settings.toml"));
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        env::var_os("HOME").map(PathBuf::from).map(|home| {
             home.join("Library")
                 .join("Application Support")
                 .join("perspecta")
@@ -3208,14 +6062,15 @@ fn metadata_settings_file_path() -> Option<PathBuf> {
     }
 }
 
-fn load_visible_metadata_fields(path: &Path) -> Option<HashSet<String>> {
+fn load_visible_metadata_fields(path: &Path) -> Option<Vec<String>> {
     let text = fs::read_to_string(path).ok()?;
     let parsed = parse_visible_metadata_fields_from_toml(&text)?;
-    let filtered = parsed
-        .iter()
-        .filter(|field| METADATA_FIELD_NAMES.contains(&field.as_str()))
-        .cloned()
-        .collect::<HashSet<_>>();
+    let mut filtered = Vec::with_capacity(parsed.len());
+    for field in &parsed {
+        if METADATA_FIELD_NAMES.contains(&field.as_str()) && !filtered.contains(field) {
+            filtered.push(field.clone());
+        }
+    }
 
     if parsed.is_empty() {
         return Some(filtered);
@@ -3226,7 +6081,142 @@ fn load_visible_metadata_fields(path: &Path) -> Option<HashSet<String>> {
     Some(filtered)
 }
 
-fn render_settings_toml(fields: &[String]) -> String {
+/// View fallback tags from the saved settings, for prefetch runs that select
+/// DICOMweb instances without opening the viewer.
+pub fn saved_view_fallback_tags() -> Vec<String> {
+    metadata_settings_file_path()
+        .map(|path| load_viewer_preferences(&path).view_fallback_tags)
+        .unwrap_or_default()
+}
+
+fn load_viewer_preferences(path: &Path) -> ViewerPreferences {
+    let Ok(text) = fs::read_to_string(path) else {
+        return ViewerPreferences::default();
+    };
+    let defaults = ViewerPreferences::default();
+    ViewerPreferences {
+        cine_every_frame: parse_bool_setting_from_toml(&text, "cine_every_frame")
+            .unwrap_or(defaults.cine_every_frame),
+        autoplay_cine: parse_bool_setting_from_toml(&text, "autoplay_cine")
+            .unwrap_or(defaults.autoplay_cine),
+        cine_progress_ring: parse_bool_setting_from_toml(&text, "cine_progress_ring")
+            .unwrap_or(defaults.cine_progress_ring),
+        instant_history_cycling: parse_bool_setting_from_toml(&text, "instant_history_cycling")
+            .unwrap_or(defaults.instant_history_cycling),
+        instant_local_open: parse_bool_setting_from_toml(&text, "instant_local_open")
+            .unwrap_or(defaults.instant_local_open),
+        monitor_dpi: parse_f32_setting_from_toml(&text, "monitor_dpi")
+            .filter(|dpi| *dpi > 0.0 && *dpi <= MAX_MONITOR_DPI)
+            .or(defaults.monitor_dpi),
+        auto_crop_borders: parse_bool_setting_from_toml(&text, "auto_crop_borders")
+            .unwrap_or(defaults.auto_crop_borders),
+        sticky_window: parse_bool_setting_from_toml(&text, "sticky_window")
+            .unwrap_or(defaults.sticky_window),
+        keep_zoom_on_resize: parse_bool_setting_from_toml(&text, "keep_zoom_on_resize")
+            .unwrap_or(defaults.keep_zoom_on_resize),
+        compact_info_hud: parse_bool_setting_from_toml(&text, "compact_info_hud")
+            .unwrap_or(defaults.compact_info_hud),
+        hide_view_labels: parse_bool_setting_from_toml(&text, "hide_view_labels")
+            .unwrap_or(defaults.hide_view_labels),
+        presentation_mode: parse_bool_setting_from_toml(&text, "presentation_mode")
+            .unwrap_or(defaults.presentation_mode),
+        assume_monochrome1_when_missing: parse_bool_setting_from_toml(
+            &text,
+            "assume_monochrome1_when_missing",
+        )
+        .unwrap_or(defaults.assume_monochrome1_when_missing),
+        frame_filmstrip: parse_bool_setting_from_toml(&text, "frame_filmstrip")
+            .unwrap_or(defaults.frame_filmstrip),
+        sort_history_by_date: parse_bool_setting_from_toml(&text, "sort_history_by_date")
+            .unwrap_or(defaults.sort_history_by_date),
+        history_view_thumbnails: parse_bool_setting_from_toml(&text, "history_view_thumbnails")
+            .unwrap_or(defaults.history_view_thumbnails),
+        window_level_gesture: parse_string_setting_from_toml(&text, "window_level_drag")
+            .and_then(|value| WindowLevelGesture::parse(&value))
+            .unwrap_or(defaults.window_level_gesture),
+        export_format: parse_string_setting_from_toml(&text, "export_format")
+            .and_then(|value| ExportFormat::parse(&value))
+            .unwrap_or(defaults.export_format),
+        export_jpeg_quality: parse_f32_setting_from_toml(&text, "export_jpeg_quality")
+            .and_then(JpegQuality::parse)
+            .unwrap_or(defaults.export_jpeg_quality),
+        grid_style: MultiViewGridStyle {
+            gap: parse_f32_setting_from_toml(&text, "grid_gap")
+                .and_then(MultiViewGridStyle::parse_gap)
+                .unwrap_or(defaults.grid_style.gap),
+            border_width: parse_f32_setting_from_toml(&text, "grid_border_width")
+                .and_then(MultiViewGridStyle::parse_border_width)
+                .unwrap_or(defaults.grid_style.border_width),
+            border_color: parse_string_setting_from_toml(&text, "grid_border_color")
+                .and_then(|value| MultiViewGridStyle::parse_border_color(&value))
+                .unwrap_or(defaults.grid_style.border_color),
+        },
+        view_fallback_tags: parse_string_setting_from_toml(&text, "view_fallback_tags")
+            .and_then(|value| parse_view_fallback_tags(&value).ok())
+            .unwrap_or(defaults.view_fallback_tags),
+    }
+}
+
+fn load_cine_fps_by_modality(path: &Path) -> BTreeMap<String, f32> {
+    fs::read_to_string(path)
+        .map(|text| parse_cine_fps_by_modality_from_toml(&text))
+        .unwrap_or_default()
+}
+
+/// Reads the `[cine_fps_by_modality]` table, which `render_settings_toml`
+/// writes last so its keys cannot shadow top-level settings.
+fn parse_cine_fps_by_modality_from_toml(text: &str) -> BTreeMap<String, f32> {
+    let mut in_table = false;
+    let mut fps_by_modality = BTreeMap::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.starts_with('[') && line.ends_with(']') {
+            in_table = line == "[cine_fps_by_modality]";
+            continue;
+        }
+        if !in_table {
+            continue;
+        }
+        let Some((name, value)) = line.split_once('=') else {
+            continue;
+        };
+        let Some(modality) = normalize_modality(name) else {
+            continue;
+        };
+        if let Some(fps) = value
+            .trim()
+            .parse::<f32>()
+            .ok()
+            .filter(|fps| fps.is_finite())
+        {
+            fps_by_modality.insert(modality, fps.clamp(1.0, 120.0));
+        }
+    }
+    fps_by_modality
+}
+
+fn normalize_modality(value: &str) -> Option<String> {
+    let modality = value.trim().to_ascii_uppercase();
+    (!modality.is_empty()
+        && modality
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_'))
+    .then_some(modality)
+}
+
+fn image_modality(image: &DicomImage) -> Option<String> {
+    image
+        .metadata
+        .iter()
+        .find(|(key, _)| key == "Modality")
+        .and_then(|(_, value)| normalize_modality(value))
+}
+
+fn render_settings_toml(
+    fields: &[String],
+    preferences: &ViewerPreferences,
+    cine_fps_by_modality: &BTreeMap<String, f32>,
+) -> String {
     let mut text = String::from("visible_metadata_fields = [\n");
     for field in fields {
         text.push_str("  \"");
@@ -3234,9 +6224,133 @@ fn render_settings_toml(fields: &[String]) -> String {
         text.push_str("\",\n");
     }
     text.push_str("]\n");
+    text.push_str(&format!(
+        "cine_every_frame = {}\n",
+        preferences.cine_every_frame
+    ));
+    text.push_str(&format!("autoplay_cine = {}\n", preferences.autoplay_cine));
+    text.push_str(&format!(
+        "cine_progress_ring = {}\n",
+        preferences.cine_progress_ring
+    ));
+    text.push_str(&format!(
+        "instant_history_cycling = {}\n",
+        preferences.instant_history_cycling
+    ));
+    text.push_str(&format!(
+        "instant_local_open = {}\n",
+        preferences.instant_local_open
+    ));
+    if let Some(dpi) = preferences.monitor_dpi {
+        text.push_str(&format!("monitor_dpi = {dpi}\n"));
+    }
+    text.push_str(&format!(
+        "auto_crop_borders = {}\n",
+        preferences.auto_crop_borders
+    ));
+    text.push_str(&format!("sticky_window = {}\n", preferences.sticky_window));
+    text.push_str(&format!(
+        "keep_zoom_on_resize = {}\n",
+        preferences.keep_zoom_on_resize
+    ));
+    text.push_str(&format!(
+        "compact_info_hud = {}\n",
+        preferences.compact_info_hud
+    ));
+    text.push_str(&format!(
+        "hide_view_labels = {}\n",
+        preferences.hide_view_labels
+    ));
+    text.push_str(&format!(
+        "presentation_mode = {}\n",
+        preferences.presentation_mode
+    ));
+    text.push_str(&format!(
+        "assume_monochrome1_when_missing = {}\n",
+        preferences.assume_monochrome1_when_missing
+    ));
+    text.push_str(&format!(
+        "frame_filmstrip = {}\n",
+        preferences.frame_filmstrip
+    ));
+    text.push_str(&format!(
+        "sort_history_by_date = {}\n",
+        preferences.sort_history_by_date
+    ));
+    text.push_str(&format!(
+        "history_view_thumbnails = {}\n",
+        preferences.history_view_thumbnails
+    ));
+    text.push_str(&format!(
+        "window_level_drag = \"{}\"\n",
+        preferences.window_level_gesture.setting_value()
+    ));
+    text.push_str(&format!(
+        "export_format = \"{}\"\n",
+        preferences.export_format.setting_value()
+    ));
+    text.push_str(&format!(
+        "export_jpeg_quality = {}\n",
+        preferences.export_jpeg_quality.0
+    ));
+    text.push_str(&format!("grid_gap = {}\n", preferences.grid_style.gap));
+    text.push_str(&format!(
+        "grid_border_width = {}\n",
+        preferences.grid_style.border_width
+    ));
+    text.push_str(&format!(
+        "grid_border_color = \"{}\"\n",
+        preferences.grid_style.border_color_setting_value()
+    ));
+    text.push_str(&format!(
+        "view_fallback_tags = \"{}\"\n",
+        preferences.view_fallback_tags.join(", ")
+    ));
+    if !cine_fps_by_modality.is_empty() {
+        text.push_str("\n[cine_fps_by_modality]\n");
+        for (modality, fps) in cine_fps_by_modality {
+            text.push_str(&format!("{modality} = {fps}\n"));
+        }
+    }
     text
 }
 
+fn parse_bool_setting_from_toml(text: &str, key: &str) -> Option<bool> {
+    text.lines().find_map(|line| {
+        let (name, value) = line.split_once('=')?;
+        if name.trim() != key {
+            return None;
+        }
+        value.trim().parse::<bool>().ok()
+    })
+}
+
+fn parse_f32_setting_from_toml(text: &str, key: &str) -> Option<f32> {
+    text.lines().find_map(|line| {
+        let (name, value) = line.split_once('=')?;
+        if name.trim() != key {
+            return None;
+        }
+        value
+            .trim()
+            .parse::<f32>()
+            .ok()
+            .filter(|value| value.is_finite())
+    })
+}
+
+fn parse_string_setting_from_toml(text: &str, key: &str) -> Option<String> {
+    text.lines().find_map(|line| {
+        let (name, value) = line.split_once('=')?;
+        if name.trim() != key {
+            return None;
+        }
+        let value = value.trim();
+        let inner = value.strip_prefix('"')?.strip_suffix('"')?;
+        Some(unescape_toml_string(inner))
+    })
+}
+
 fn parse_visible_metadata_fields_from_toml(text: &str) -> Option<Vec<String>> {
     let key_pos = text.find("visible_metadata_fields")?;
     let after_key = &text[key_pos..];
@@ -3293,6 +6407,7 @@ mod tests {
     use dicom_core::value::DataSetSequence;
     use dicom_core::{DataElement, PrimitiveValue, Tag, VR};
     use dicom_object::{FileMetaTableBuilder, InMemDicomObject};
+    use dicom_transfer_syntax_registry::entries::IMPLICIT_VR_LITTLE_ENDIAN;
 
     use crate::dicom::{
         load_parametric_map_overlays, SrOverlay, SrOverlayGraphic, SrOverlayLabel,
@@ -3474,6 +6589,40 @@ mod tests {
         DicomSource::from_memory(preferred_name, bytes)
     }
 
+    fn test_four_frame_source(modality: &str, sop_class_uid: &str) -> DicomSource {
+        let object = InMemDicomObject::from_element_iter([
+            DataElement::new(Tag(0x0008, 0x0016), VR::UI, sop_class_uid),
+            DataElement::new(Tag(0x0008, 0x0018), VR::UI, "4.3.2.40"),
+            DataElement::new(Tag(0x0008, 0x0060), VR::CS, modality),
+            DataElement::new(Tag(0x0028, 0x0002), VR::US, PrimitiveValue::from(1u16)),
+            DataElement::new(Tag(0x0028, 0x0004), VR::CS, "MONOCHROME2"),
+            DataElement::new(Tag(0x0028, 0x0008), VR::IS, "4"),
+            DataElement::new(Tag(0x0028, 0x0010), VR::US, PrimitiveValue::from(1u16)),
+            DataElement::new(Tag(0x0028, 0x0011), VR::US, PrimitiveValue::from(1u16)),
+            DataElement::new(Tag(0x0028, 0x0100), VR::US, PrimitiveValue::from(8u16)),
+            DataElement::new(Tag(0x0028, 0x0101), VR::US, PrimitiveValue::from(8u16)),
+            DataElement::new(Tag(0x0028, 0x0102), VR::US, PrimitiveValue::from(7u16)),
+            DataElement::new(Tag(0x0028, 0x0103), VR::US, PrimitiveValue::from(0u16)),
+            DataElement::new(
+                Tag(0x7FE0, 0x0010),
+                VR::OB,
+                PrimitiveValue::from(vec![10u8, 20, 30, 40]),
+            ),
+        ])
+        .with_meta(
+            FileMetaTableBuilder::new()
+                .transfer_syntax(EXPLICIT_VR_LITTLE_ENDIAN_UID)
+                .media_storage_sop_class_uid(sop_class_uid)
+                .media_storage_sop_instance_uid("4.3.2.40"),
+        )
+        .expect("four-frame test object should build file meta");
+        let mut bytes = Vec::new();
+        object
+            .write_all(&mut bytes)
+            .expect("four-frame test object should serialize");
+        DicomSource::from_memory(&format!("{modality}-four-frame.dcm"), bytes)
+    }
+
     fn test_memory_gsps_source(
         preferred_name: &str,
         study_uid: &str,
@@ -3520,165 +6669,1308 @@ mod tests {
             ),
         ]);
 
-        let gsps_obj = gsps_dataset
-            .with_meta(
-                FileMetaTableBuilder::new()
-                    .transfer_syntax(EXPLICIT_VR_LITTLE_ENDIAN_UID)
-                    .media_storage_sop_class_uid(GSPS_SOP_CLASS_UID)
-                    .media_storage_sop_instance_uid(instance_uid),
-            )
-            .expect("memory GSPS test object should build file meta");
+        let gsps_obj = gsps_dataset
+            .with_meta(
+                FileMetaTableBuilder::new()
+                    .transfer_syntax(EXPLICIT_VR_LITTLE_ENDIAN_UID)
+                    .media_storage_sop_class_uid(GSPS_SOP_CLASS_UID)
+                    .media_storage_sop_instance_uid(instance_uid),
+            )
+            .expect("memory GSPS test object should build file meta");
+
+        let path = unique_test_file_path("memory-gsps-source");
+        gsps_obj
+            .write_to_file(&path)
+            .expect("memory GSPS test object should write to disk");
+        let bytes = fs::read(&path).expect("memory GSPS test bytes should read from disk");
+        let _ = fs::remove_file(&path);
+        DicomSource::from_memory(preferred_name, bytes)
+    }
+
+    fn test_memory_parametric_map_source(
+        preferred_name: &str,
+        study_uid: &str,
+        series_uid: &str,
+        instance_uid: &str,
+        referenced_instance_uid: &str,
+    ) -> DicomSource {
+        let referenced_image = InMemDicomObject::from_element_iter([
+            DataElement::new(
+                Tag(0x0008, 0x1150),
+                VR::UI,
+                DIGITAL_MAMMOGRAPHY_XRAY_IMAGE_PRESENTATION_SOP_CLASS_UID,
+            ),
+            DataElement::new(Tag(0x0008, 0x1155), VR::UI, referenced_instance_uid),
+        ]);
+        let pm_dataset = InMemDicomObject::from_element_iter([
+            DataElement::new(Tag(0x0008, 0x0016), VR::UI, PARAMETRIC_MAP_SOP_CLASS_UID),
+            DataElement::new(Tag(0x0008, 0x0018), VR::UI, instance_uid),
+            DataElement::new(Tag(0x0008, 0x0060), VR::CS, "MG"),
+            DataElement::new(Tag(0x0020, 0x000D), VR::UI, study_uid),
+            DataElement::new(Tag(0x0020, 0x000E), VR::UI, series_uid),
+            DataElement::new(Tag(0x0028, 0x0002), VR::US, PrimitiveValue::from(1u16)),
+            DataElement::new(Tag(0x0028, 0x0004), VR::CS, "MONOCHROME2"),
+            DataElement::new(Tag(0x0028, 0x0010), VR::US, PrimitiveValue::from(1u16)),
+            DataElement::new(Tag(0x0028, 0x0011), VR::US, PrimitiveValue::from(1u16)),
+            DataElement::new(Tag(0x0028, 0x0008), VR::IS, "1"),
+            DataElement::new(
+                Tag(0x0008, 0x2112),
+                VR::SQ,
+                DataSetSequence::from(vec![referenced_image]),
+            ),
+            DataElement::new(
+                Tag(0x7FE0, 0x0008),
+                VR::OF,
+                PrimitiveValue::F32(vec![1.0f32].into()),
+            ),
+        ]);
+
+        let pm_obj = pm_dataset
+            .with_meta(
+                FileMetaTableBuilder::new()
+                    .transfer_syntax(EXPLICIT_VR_LITTLE_ENDIAN_UID)
+                    .media_storage_sop_class_uid(PARAMETRIC_MAP_SOP_CLASS_UID)
+                    .media_storage_sop_instance_uid(instance_uid),
+            )
+            .expect("memory Parametric Map test object should build file meta");
+
+        let path = unique_test_file_path("memory-parametric-map-source");
+        pm_obj
+            .write_to_file(&path)
+            .expect("memory Parametric Map test object should write to disk");
+        let bytes = fs::read(&path).expect("memory Parametric Map test bytes should read");
+        let _ = fs::remove_file(&path);
+        DicomSource::from_memory(preferred_name, bytes)
+    }
+
+    fn single_history_entry(ctx: &egui::Context, path: &str, texture_name: &str) -> HistoryEntry {
+        let path_buf = PathBuf::from(path);
+        HistoryEntry {
+            id: history_id_from_paths(std::slice::from_ref(&path_buf)),
+            kind: HistoryKind::Single(Box::new(HistorySingleData {
+                path: path_buf.clone().into(),
+                image: DicomImage::test_stub(None),
+                texture: test_texture(ctx, texture_name),
+                window_center: 0.0,
+                window_width: 1.0,
+                current_frame: 0,
+                cine_fps: DEFAULT_CINE_FPS,
+            })),
+            thumbs: Vec::new(),
+            sort_key: None,
+        }
+    }
+
+    fn report_history_entry(ctx: &egui::Context, path: &str, texture_name: &str) -> HistoryEntry {
+        let path_buf = PathBuf::from(path);
+        HistoryEntry {
+            id: history_id_from_paths(std::slice::from_ref(&path_buf)),
+            kind: HistoryKind::Report(Box::new(HistoryReportData {
+                path: path_buf.into(),
+                report: StructuredReportDocument::test_stub(),
+            })),
+            thumbs: vec![HistoryThumb {
+                texture: test_texture(ctx, texture_name),
+            }],
+            sort_key: None,
+        }
+    }
+
+    #[test]
+    fn memory_sources_use_semantic_identity_for_history_and_display_matching() {
+        let reopened = test_memory_source(
+            "reopened-report",
+            "1.2.840.10008.1",
+            "1.2.840.10008.1.1",
+            "1.2.840.10008.1.1.1",
+        );
+        let reopened_again = test_memory_source(
+            "same-report-different-handle",
+            "1.2.840.10008.1",
+            "1.2.840.10008.1.1",
+            "1.2.840.10008.1.1.1",
+        );
+        let different = test_memory_source(
+            "different-report",
+            "1.2.840.10008.1",
+            "1.2.840.10008.1.1",
+            "1.2.840.10008.1.1.2",
+        );
+
+        let reopened_id = history_id_from_paths(std::slice::from_ref(&reopened));
+        let reopened_again_id = history_id_from_paths(std::slice::from_ref(&reopened_again));
+        let different_id = history_id_from_paths(std::slice::from_ref(&different));
+
+        assert_eq!(reopened_id, reopened_again_id);
+        assert_ne!(reopened_id, different_id);
+
+        let app = DicomViewerApp {
+            current_single_path: Some((&reopened).into()),
+            ..Default::default()
+        };
+        assert!(app.displayed_study_matches_paths(&[reopened_again]));
+        assert!(!app.displayed_study_matches_paths(&[different]));
+    }
+
+    #[test]
+    fn study_link_uses_local_path_for_displayed_file() {
+        let app = DicomViewerApp {
+            current_single_path: Some(test_meta("example-data/a.dcm")),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            app.study_link().as_deref(),
+            Some("perspecta://open?path=example-data%2Fa.dcm")
+        );
+    }
+
+    #[test]
+    fn study_link_uses_originating_dicomweb_request_for_memory_sources() {
+        let dicomweb_request = |study_uid: &str| {
+            LaunchRequest::DicomWeb(DicomWebLaunchRequest {
+                base_url: "http://localhost:8042/dicom-web".to_string(),
+                study_uid: study_uid.to_string(),
+                series_uid: None,
+                instance_uid: None,
+                username: None,
+                password: None,
+                wado_uri: false,
+                series_tabs: Vec::new(),
+            })
+        };
+        let first = DicomSource::from_memory_with_identity("first.dcm", "dicom:first", vec![]);
+        let second = DicomSource::from_memory_with_identity("second.dcm", "dicom:second", vec![]);
+        let mut app = DicomViewerApp {
+            current_single_path: Some((&first).into()),
+            ..Default::default()
+        };
+        assert_eq!(app.study_link(), None);
+
+        app.remote_launch_request = Some(dicomweb_request("study_uid_alpha"));
+        app.record_remote_launch_request(&DicomWebDownloadResult::Single(vec![first.clone()]));
+        app.remote_launch_request = Some(dicomweb_request("study_uid_beta"));
+        app.record_remote_launch_request(&DicomWebDownloadResult::Single(vec![second.clone()]));
+
+        // The earlier study keeps its own link after a later launch completes.
+        assert_eq!(
+            app.study_link_request(),
+            Some(dicomweb_request("study_uid_alpha"))
+        );
+        app.current_single_path = Some((&second).into());
+        assert_eq!(
+            app.study_link_request(),
+            Some(dicomweb_request("study_uid_beta"))
+        );
+
+        // A failed launch records nothing.
+        let (tx, rx) = mpsc::channel();
+        tx.send(Err("server unavailable".to_string()))
+            .expect("test receiver should be alive");
+        app.dicomweb_receiver = Some(rx);
+        app.remote_launch_request = Some(dicomweb_request("study_uid_gamma"));
+        app.poll_dicomweb_download(&egui::Context::default());
+        assert_eq!(app.remote_launch_request, None);
+        assert_eq!(
+            app.study_link_request(),
+            Some(dicomweb_request("study_uid_beta"))
+        );
+        assert!(!app
+            .remote_launch_requests
+            .values()
+            .any(|request| *request == dicomweb_request("study_uid_gamma")));
+    }
+
+    #[test]
+    fn revealable_file_path_skips_in_memory_sources() {
+        let mut app = DicomViewerApp {
+            current_single_path: Some(test_meta("example-data/a.dcm")),
+            ..Default::default()
+        };
+        assert_eq!(
+            app.revealable_file_path(),
+            Some(PathBuf::from("example-data/a.dcm"))
+        );
+
+        let source = DicomSource::from_memory_with_identity("remote.dcm", "dicom:remote", vec![]);
+        app.current_single_path = Some((&source).into());
+        assert_eq!(app.revealable_file_path(), None);
+    }
+
+    #[test]
+    fn unmaximized_window_origin_keeps_cursor_at_same_titlebar_fraction() {
+        let origin = unmaximized_window_origin(
+            egui::pos2(1500.0, 12.0),
+            egui::pos2(1500.0, 12.0),
+            2000.0,
+            800.0,
+        );
+        assert_eq!(origin, egui::pos2(900.0, 0.0));
+
+        let origin =
+            unmaximized_window_origin(egui::pos2(110.0, 40.0), egui::pos2(10.0, 15.0), 0.0, 800.0);
+        assert_eq!(origin, egui::pos2(-290.0, 25.0));
+    }
+
+    #[test]
+    fn unmaximize_drag_starts_even_without_a_restored_size() {
+        let pointer = egui::pos2(1500.0, 12.0);
+        let outer = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(2000.0, 1000.0));
+        assert_eq!(
+            unmaximize_drag_commands(Some(pointer), Some(outer), None),
+            vec![
+                ViewportCommand::Maximized(false),
+                ViewportCommand::StartDrag
+            ]
+        );
+        assert_eq!(
+            unmaximize_drag_commands(Some(pointer), Some(outer), Some(egui::vec2(800.0, 600.0))),
+            vec![
+                ViewportCommand::Maximized(false),
+                ViewportCommand::OuterPosition(egui::pos2(900.0, 0.0)),
+                ViewportCommand::StartDrag
+            ]
+        );
+    }
+
+    #[test]
+    fn resize_edge_rects_cover_the_border_except_the_grip_corner() {
+        let screen = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(800.0, 600.0));
+        let rects = resize_edge_rects(screen, 4.0, 18.0);
+        let direction_at = |x: f32, y: f32| {
+            rects
+                .iter()
+                .find(|(_, rect)| rect.contains(egui::pos2(x, y)))
+                .map(|(direction, _)| *direction)
+        };
+
+        assert_eq!(direction_at(400.0, 1.0), Some(ResizeDirection::North));
+        assert_eq!(direction_at(400.0, 599.0), Some(ResizeDirection::South));
+        assert_eq!(direction_at(1.0, 300.0), Some(ResizeDirection::West));
+        assert_eq!(direction_at(799.0, 300.0), Some(ResizeDirection::East));
+        assert_eq!(direction_at(1.0, 10.0), Some(ResizeDirection::NorthWest));
+        assert_eq!(direction_at(790.0, 1.0), Some(ResizeDirection::NorthEast));
+        assert_eq!(direction_at(10.0, 599.0), Some(ResizeDirection::SouthWest));
+        assert_eq!(direction_at(795.0, 595.0), None);
+        assert_eq!(direction_at(400.0, 300.0), None);
+        // The inside of a corner square stays free for the titlebar buttons.
+        assert_eq!(direction_at(9.0, 9.0), None);
+        assert_eq!(direction_at(791.0, 9.0), None);
+        assert_eq!(direction_at(9.0, 591.0), None);
+    }
+
+    #[test]
+    fn next_window_preset_index_advances_and_wraps() {
+        let presets = [(40.0, 80.0), (300.0, 1500.0), (50.0, 350.0)]
+            .into_iter()
+            .map(|(center, width)| WindowPreset {
+                center,
+                width,
+                label: format!("{center}/{width}"),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(next_window_preset_index(&[], None, 40.0, 80.0), None);
+        assert_eq!(
+            next_window_preset_index(&presets, None, 40.0, 80.0),
+            Some(1)
+        );
+        assert_eq!(next_window_preset_index(&presets, None, 0.0, 1.0), Some(0));
+        assert_eq!(
+            next_window_preset_index(&presets, Some(2), 50.0, 350.0),
+            Some(0)
+        );
+        assert_eq!(
+            next_window_preset_index(&presets, Some(1), 10.0, 20.0),
+            Some(2)
+        );
+        assert_eq!(
+            next_window_preset_index(&presets, Some(7), 10.0, 20.0),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn history_id_from_paths_uses_collision_free_length_prefix_encoding() {
+        let left = vec![PathBuf::from("a|b"), PathBuf::from("c")];
+        let right = vec![PathBuf::from("a"), PathBuf::from("b|c")];
+
+        assert_ne!(history_id_from_paths(&left), history_id_from_paths(&right));
+    }
+
+    #[test]
+    fn metadata_settings_toml_roundtrip() {
+        let selected = vec![
+            "PatientName".to_string(),
+            "StudyDescription".to_string(),
+            "Modality".to_string(),
+        ];
+        let toml = render_settings_toml(&selected, &ViewerPreferences::default(), &BTreeMap::new());
+        let parsed = parse_visible_metadata_fields_from_toml(&toml).expect("TOML should parse");
+        assert_eq!(parsed, selected);
+    }
+
+    #[test]
+    fn view_labels_hide_with_either_label_toggle_or_presentation_mode() {
+        let mut app = DicomViewerApp::default();
+        assert!(app.view_labels_visible());
+
+        app.preferences.hide_view_labels = true;
+        assert!(!app.view_labels_visible());
+
+        app.preferences.hide_view_labels = false;
+        app.preferences.presentation_mode = true;
+        assert!(!app.view_labels_visible());
+    }
+
+    #[test]
+    fn viewer_preferences_toml_roundtrip() {
+        let preferences = ViewerPreferences {
+            cine_every_frame: true,
+            autoplay_cine: true,
+            cine_progress_ring: true,
+            instant_history_cycling: true,
+            instant_local_open: true,
+            monitor_dpi: Some(109.5),
+            auto_crop_borders: true,
+            sticky_window: true,
+            keep_zoom_on_resize: true,
+            compact_info_hud: true,
+            hide_view_labels: true,
+            presentation_mode: true,
+            assume_monochrome1_when_missing: true,
+            frame_filmstrip: true,
+            sort_history_by_date: true,
+            history_view_thumbnails: true,
+            window_level_gesture: WindowLevelGesture::SecondaryDrag,
+            export_format: ExportFormat::Jpeg,
+            export_jpeg_quality: JpegQuality(75),
+            grid_style: MultiViewGridStyle {
+                gap: 0.0,
+                border_width: 2.5,
+                border_color: egui::Color32::from_rgb(0x40, 0x80, 0xff),
+            },
+            view_fallback_tags: vec![
+                "AcquisitionDeviceProcessingDescription".to_string(),
+                "SeriesDescription".to_string(),
+            ],
+        };
+        let toml = render_settings_toml(
+            &["PatientName".to_string()],
+            &preferences,
+            &BTreeMap::from([("US".to_string(), 24.0)]),
+        );
+
+        assert_eq!(
+            parse_bool_setting_from_toml(&toml, "cine_every_frame"),
+            Some(true)
+        );
+        assert_eq!(
+            parse_bool_setting_from_toml(&toml, "autoplay_cine"),
+            Some(true)
+        );
+        assert_eq!(
+            parse_bool_setting_from_toml(&toml, "cine_progress_ring"),
+            Some(true)
+        );
+        assert_eq!(
+            parse_bool_setting_from_toml(&toml, "instant_history_cycling"),
+            Some(true)
+        );
+        assert_eq!(
+            parse_bool_setting_from_toml(&toml, "instant_local_open"),
+            Some(true)
+        );
+        assert_eq!(
+            parse_f32_setting_from_toml(&toml, "monitor_dpi"),
+            Some(109.5)
+        );
+        assert_eq!(
+            parse_bool_setting_from_toml(&toml, "auto_crop_borders"),
+            Some(true)
+        );
+        assert_eq!(
+            parse_bool_setting_from_toml(&toml, "sticky_window"),
+            Some(true)
+        );
+        assert_eq!(
+            parse_bool_setting_from_toml(&toml, "keep_zoom_on_resize"),
+            Some(true)
+        );
+        assert_eq!(
+            parse_bool_setting_from_toml(&toml, "compact_info_hud"),
+            Some(true)
+        );
+        assert_eq!(
+            parse_bool_setting_from_toml(&toml, "hide_view_labels"),
+            Some(true)
+        );
+        assert_eq!(
+            parse_bool_setting_from_toml(&toml, "presentation_mode"),
+            Some(true)
+        );
+        assert_eq!(
+            parse_bool_setting_from_toml(&toml, "assume_monochrome1_when_missing"),
+            Some(true)
+        );
+        assert_eq!(
+            parse_bool_setting_from_toml(&toml, "frame_filmstrip"),
+            Some(true)
+        );
+        assert_eq!(
+            parse_bool_setting_from_toml(&toml, "sort_history_by_date"),
+            Some(true)
+        );
+        assert_eq!(
+            parse_bool_setting_from_toml(&toml, "history_view_thumbnails"),
+            Some(true)
+        );
+        assert_eq!(
+            parse_string_setting_from_toml(&toml, "window_level_drag")
+                .and_then(|value| WindowLevelGesture::parse(&value)),
+            Some(WindowLevelGesture::SecondaryDrag)
+        );
+        assert_eq!(
+            parse_string_setting_from_toml(&toml, "export_format")
+                .and_then(|value| ExportFormat::parse(&value)),
+            Some(ExportFormat::Jpeg)
+        );
+        assert_eq!(
+            parse_f32_setting_from_toml(&toml, "export_jpeg_quality").and_then(JpegQuality::parse),
+            Some(JpegQuality(75))
+        );
+        assert_eq!(parse_f32_setting_from_toml(&toml, "grid_gap"), Some(0.0));
+        assert_eq!(
+            parse_f32_setting_from_toml(&toml, "grid_border_width"),
+            Some(2.5)
+        );
+        assert_eq!(
+            parse_string_setting_from_toml(&toml, "grid_border_color").as_deref(),
+            Some("#4080ff")
+        );
+        assert_eq!(
+            parse_string_setting_from_toml(&toml, "view_fallback_tags").as_deref(),
+            Some("AcquisitionDeviceProcessingDescription, SeriesDescription")
+        );
+        assert_eq!(
+            parse_cine_fps_by_modality_from_toml(&toml),
+            BTreeMap::from([("US".to_string(), 24.0)])
+        );
+        assert!(
+            !render_settings_toml(&[], &ViewerPreferences::default(), &BTreeMap::new())
+                .contains("monitor_dpi")
+        );
+        assert_eq!(
+            parse_visible_metadata_fields_from_toml(&toml),
+            Some(vec!["PatientName".to_string()])
+        );
+    }
+
+    #[test]
+    fn grid_style_settings_reject_out_of_range_values() {
+        assert_eq!(MultiViewGridStyle::parse_gap(0.0), Some(0.0));
+        assert_eq!(MultiViewGridStyle::parse_gap(MAX_GRID_GAP + 1.0), None);
+        assert_eq!(MultiViewGridStyle::parse_border_width(-1.0), None);
+        assert_eq!(
+            MultiViewGridStyle::parse_border_color(" #FF8000 "),
+            Some(egui::Color32::from_rgb(255, 128, 0))
+        );
+        assert_eq!(MultiViewGridStyle::parse_border_color("ff8000"), None);
+        assert_eq!(MultiViewGridStyle::parse_border_color("#ff80"), None);
+        assert_eq!(MultiViewGridStyle::parse_border_color("#gg8000"), None);
+    }
+
+    #[test]
+    fn cine_rate_meter_averages_recent_frames() {
+        let start = Instant::now();
+        let mut meter = CineRateMeter::default();
+        meter.record(start);
+        meter.record(start + Duration::from_millis(100));
+        assert_eq!(meter.fps(start + Duration::from_millis(100)), None);
+
+        for tick in 2..=20 {
+            meter.record(start + Duration::from_millis(100 * tick));
+        }
+        let fps = meter
+            .fps(start + Duration::from_millis(2000))
+            .expect("enough frames for a rate");
+        assert!((fps - 10.0).abs() < 0.01, "{fps}");
+
+        // Frames older than the window stop counting, so a slowdown shows.
+        meter.record(start + Duration::from_millis(3000));
+        meter.record(start + Duration::from_millis(3500));
+        let fps = meter
+            .fps(start + Duration::from_millis(3500))
+            .expect("rate after slowdown");
+        assert!(fps < 10.0, "{fps}");
+
+        meter.clear();
+        assert_eq!(meter.fps(start + Duration::from_millis(3500)), None);
+    }
+
+    #[test]
+    fn cine_rate_meter_drops_toward_zero_while_frames_stall() {
+        let start = Instant::now();
+        let mut meter = CineRateMeter::default();
+        for tick in 0..=20 {
+            meter.record(start + Duration::from_millis(100 * tick));
+        }
+
+        // No frame arrives for a second: only half the window still has any.
+        let fps = meter
+            .fps(start + Duration::from_millis(3000))
+            .expect("rate during stall");
+        assert!((fps - 5.0).abs() < 0.01, "{fps}");
+
+        // A full window without frames reads as a stopped cine.
+        assert_eq!(meter.fps(start + Duration::from_millis(4500)), Some(0.0));
+    }
+
+    #[test]
+    fn window_level_lock_leaves_single_view_window_unchanged_on_drag() {
+        let ctx = egui::Context::default();
+        let mut app = DicomViewerApp {
+            image: Some(DicomImage::test_stub_with_mono_frames(None, 1)),
+            window_center: 40.0,
+            window_width: 400.0,
+            window_level_locked: true,
+            ..Default::default()
+        };
+        let drag = egui::vec2(10.0, -10.0);
+
+        app.drag_single_window_level(drag, &ctx);
+        assert_eq!((app.window_center, app.window_width), (40.0, 400.0));
+
+        app.window_level_locked = false;
+        app.drag_single_window_level(drag, &ctx);
+        assert_ne!((app.window_center, app.window_width), (40.0, 400.0));
+    }
+
+    #[test]
+    fn window_level_lock_leaves_single_view_window_unchanged_on_preset() {
+        let ctx = egui::Context::default();
+        let mut image = DicomImage::test_stub_with_mono_frames(None, 1);
+        image.window_presets = vec![WindowPreset {
+            center: 300.0,
+            width: 1500.0,
+            label: "Bone".to_string(),
+        }];
+        let mut app = DicomViewerApp {
+            image: Some(image),
+            window_center: 40.0,
+            window_width: 400.0,
+            window_level_locked: true,
+            ..Default::default()
+        };
+
+        app.cycle_window_preset(&ctx);
+        app.apply_active_window(300.0, 1500.0, Some(0), &ctx);
+        assert_eq!((app.window_center, app.window_width), (40.0, 400.0));
+        assert_eq!(app.single_window_preset_index, None);
+
+        app.window_level_locked = false;
+        app.cycle_window_preset(&ctx);
+        assert_eq!((app.window_center, app.window_width), (300.0, 1500.0));
+        assert_eq!(app.single_window_preset_index, Some(0));
+    }
+
+    #[test]
+    fn window_level_lock_leaves_multi_view_cell_window_unchanged_on_drag() {
+        let ctx = egui::Context::default();
+        let mut viewport = MammoViewport {
+            path: test_meta("locked-cell.dcm"),
+            image: DicomImage::test_stub_with_mono_frames(None, 1),
+            texture: ctx.load_texture("test-locked-cell", test_preview(), TextureOptions::LINEAR),
+            history_thumb: test_preview(),
+            label: "A".to_string(),
+            window_center: 40.0,
+            window_width: 400.0,
+            current_frame: 0,
+            zoom: 1.0,
+            pan: egui::Vec2::ZERO,
+            frame_scroll_accum: 0.0,
+            window_preset_index: None,
+        };
+        let drag = egui::vec2(10.0, -10.0);
+
+        DicomViewerApp::drag_viewport_window_level(&mut viewport, true, drag, false);
+        assert_eq!(
+            (viewport.window_center, viewport.window_width),
+            (40.0, 400.0)
+        );
+
+        DicomViewerApp::drag_viewport_window_level(&mut viewport, false, drag, false);
+        assert_ne!(
+            (viewport.window_center, viewport.window_width),
+            (40.0, 400.0)
+        );
+    }
+
+    #[test]
+    fn window_level_gesture_keeps_shift_left_as_default() {
+        let default = WindowLevelGesture::default();
+        assert_eq!(default, WindowLevelGesture::ShiftPrimaryDrag);
+        assert!(default.adjusts_window_level(true, false, true));
+        assert!(!default.adjusts_window_level(true, false, false));
+        assert!(!default.adjusts_window_level(false, true, false));
+
+        let right = WindowLevelGesture::SecondaryDrag;
+        assert!(right.adjusts_window_level(false, true, false));
+        assert!(!right.adjusts_window_level(true, false, true));
+        assert_eq!(WindowLevelGesture::parse(" RIGHT "), Some(right));
+        assert_eq!(WindowLevelGesture::parse("middle"), None);
+    }
+
+    #[test]
+    fn diagnostics_rows_report_versions_and_decoded_frames() {
+        let mut app = DicomViewerApp {
+            image: Some(DicomImage::test_stub_with_mono_frames(None, 3)),
+            ..Default::default()
+        };
+        app.set_renderer_name("glow");
+
+        let rows = app.diagnostics_rows(2);
+        let value = |label: &str| {
+            rows.iter()
+                .find(|(name, _)| *name == label)
+                .map(|(_, value)| value.as_str())
+        };
+        assert_eq!(value("Perspecta"), Some(APP_VERSION));
+        assert_ne!(value("egui"), Some("unknown"));
+        assert_eq!(value("Renderer"), Some("glow"));
+        assert_eq!(value("Open images"), Some("1"));
+        assert_eq!(value("Decoded frames"), Some("3 of 3"));
+        assert_eq!(value("Textures"), Some("2"));
+    }
+
+    #[test]
+    fn frame_copy_is_left_to_selected_label_text() {
+        let ctx = egui::Context::default();
+        let label_rect = std::cell::Cell::new(egui::Rect::NOTHING);
+        let run = |events: Vec<egui::Event>| {
+            let input = egui::RawInput {
+                screen_rect: Some(egui::Rect::from_min_size(
+                    egui::Pos2::ZERO,
+                    egui::vec2(400.0, 200.0),
+                )),
+                events,
+                ..Default::default()
+            };
+            let _ = ctx.run_ui(input, |ui| {
+                label_rect.set(ui.label("Patient name").rect);
+            });
+        };
+        run(Vec::new());
+        assert!(frame_copy_enabled(&ctx));
+
+        let rect = label_rect.get();
+        let button = |pos, pressed| egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: egui::Modifiers::NONE,
+        };
+        run(vec![
+            egui::Event::PointerMoved(rect.left_center()),
+            button(rect.left_center(), true),
+        ]);
+        run(vec![egui::Event::PointerMoved(rect.right_center())]);
+        run(vec![button(rect.right_center(), false)]);
+        run(Vec::new());
+
+        assert!(!frame_copy_enabled(&ctx));
+    }
+
+    #[test]
+    fn spawn_reaped_reports_programs_that_cannot_launch() {
+        let error = spawn_reaped(&mut std::process::Command::new(
+            "perspecta-test-missing-program",
+        ))
+        .expect_err("a missing program should not launch");
+        assert!(error.to_string().starts_with("Could not launch"));
+
+        #[cfg(unix)]
+        spawn_reaped(&mut std::process::Command::new("true")).expect("`true` should launch");
+    }
+
+    #[test]
+    fn diagnostics_environment_redacts_values_outside_the_allow_list() {
+        let vars = [
+            ("PERSPECTA_PRELOAD_WORKERS", "4"),
+            ("PERSPECTA_DICOMWEB_TOKEN", "secret"),
+            ("HOME", "/home/user"),
+        ]
+        .map(|(name, value)| (name.to_string(), value.to_string()));
+
+        assert_eq!(
+            diagnostics::diagnostics_environment(vars),
+            vec![
+                "PERSPECTA_DICOMWEB_TOKEN=<redacted>".to_string(),
+                "PERSPECTA_PRELOAD_WORKERS=4".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn stopping_autoplayed_cine_turns_autoplay_off_for_the_session() {
+        let mut app = DicomViewerApp {
+            image: Some(DicomImage::test_stub_with_mono_frames(None, 3)),
+            ..Default::default()
+        };
+        app.set_session_autoplay_cine(true);
+
+        app.autoplay_cine_on_open();
+        assert!(app.cine_mode);
+        app.toggle_cine_mode_from_user();
+        assert!(!app.cine_mode);
+        assert!(!app.session_autoplay_cine);
+
+        app.autoplay_cine_on_open();
+        assert!(!app.cine_mode);
+
+        app.set_session_autoplay_cine(true);
+        app.image = Some(DicomImage::test_stub_with_mono_frames(None, 1));
+        app.autoplay_cine_on_open();
+        assert!(!app.cine_mode);
+    }
+
+    #[test]
+    fn cine_progress_arc_runs_clockwise_from_twelve_o_clock() {
+        let center = egui::pos2(10.0, 10.0);
+        let approx = |a: egui::Pos2, b: egui::Pos2| (a - b).length() < 1e-3;
+
+        let quarter = DicomViewerApp::cine_progress_arc_points(center, 5.0, (1, 4));
+        assert!(approx(quarter[0], egui::pos2(10.0, 5.0)));
+        assert!(approx(
+            *quarter.last().expect("arc should have points"),
+            egui::pos2(15.0, 10.0)
+        ));
+
+        let full = DicomViewerApp::cine_progress_arc_points(center, 5.0, (4, 4));
+        assert!(approx(full[0], full[full.len() - 1]));
+        assert!(DicomViewerApp::cine_progress_arc_points(center, 5.0, (0, 4)).is_empty());
+        assert!(DicomViewerApp::cine_progress_arc_points(center, 5.0, (1, 0)).is_empty());
+    }
+
+    #[test]
+    fn render_image_frame_skips_frames_that_do_not_match_the_image_size() {
+        let mut image = DicomImage::test_stub_with_mono_frames(None, 1);
+        assert!(DicomViewerApp::render_image_frame(&image, 0, 0.0, 1.0, false).is_some());
+
+        image.width = 2;
+        assert!(DicomViewerApp::render_image_frame(&image, 0, 0.0, 1.0, false).is_none());
+    }
+
+    #[test]
+    fn launch_frame_range_clamps_to_the_image_and_bounds_cine() {
+        assert_eq!(clamp_frame_range(19..=44, 60), Some(19..=44));
+        assert_eq!(clamp_frame_range(19..=44, 30), Some(19..=29));
+        assert_eq!(clamp_frame_range(40..=44, 30), Some(29..=29));
+        assert_eq!(clamp_frame_range(0..=4, 0), None);
+
+        let ctx = egui::Context::default();
+        let mut app = DicomViewerApp {
+            image: Some(DicomImage::test_stub_with_mono_frames(None, 6)),
+            single_frame_range: Some(2..=4),
+            current_frame: 4,
+            cine_mode: true,
+            cine_fps: 120.0,
+            last_cine_advance: Some(Instant::now() - Duration::from_millis(20)),
+            ..Default::default()
+        };
+        app.preferences.cine_every_frame = true;
+        app.advance_cine_if_needed(&ctx);
+        assert_eq!(app.current_frame, 2);
+
+        app.set_single_current_frame(0);
+        assert_eq!(app.current_frame, 2);
+        app.set_single_current_frame(5);
+        assert_eq!(app.current_frame, 4);
+    }
+
+    #[test]
+    fn prepare_texture_upload_shrinks_oversized_and_refuses_broken_frames() {
+        let color_image = ColorImage::new([800, 400], vec![egui::Color32::WHITE; 800 * 400]);
+        let prepared =
+            prepare_texture_upload(color_image, 200).expect("oversized frame should shrink");
+        assert_eq!(prepared.size, [200, 100]);
+
+        let empty = ColorImage::new([0, 0], Vec::new());
+        assert!(prepare_texture_upload(empty, 200).is_err());
+        let mut short = ColorImage::new([4, 4], vec![egui::Color32::WHITE; 16]);
+        short.pixels.truncate(10);
+        assert!(prepare_texture_upload(short, 200).is_err());
+    }
+
+    #[test]
+    fn refused_single_texture_upload_shows_an_error_instead_of_a_stale_frame() {
+        let ctx = egui::Context::default();
+        let mut app = DicomViewerApp::default();
+        app.upload_single_texture(&ctx, ColorImage::new([2, 2], vec![egui::Color32::WHITE; 4]));
+        assert!(app.texture.is_some());
+        assert!(app.load_error_message.is_none());
+
+        app.upload_single_texture(&ctx, ColorImage::new([0, 0], Vec::new()));
+        assert!(app.texture.is_none());
+        assert!(app.load_error_message.is_some());
+    }
+
+    #[test]
+    fn oversized_frames_shrink_to_the_texture_side_limit() {
+        let color_image = ColorImage::new([500, 700], vec![egui::Color32::WHITE; 500 * 700]);
+
+        assert_eq!(
+            fit_to_max_texture_side(color_image.clone(), 0).size,
+            [500, 700]
+        );
+        assert_eq!(
+            fit_to_max_texture_side(color_image.clone(), 700).size,
+            [500, 700]
+        );
+        assert_eq!(fit_to_max_texture_side(color_image, 350).size, [250, 350]);
+
+        assert_eq!(parse_max_texture_side(Some(" 4096 ")), Some(4096));
+        assert_eq!(parse_max_texture_side(Some("0")), None);
+        assert_eq!(parse_max_texture_side(Some("large")), None);
+        assert_eq!(parse_max_texture_side(None), None);
+    }
+
+    #[test]
+    fn active_frame_color_image_renders_displayed_frame() {
+        let mut app = DicomViewerApp {
+            image: Some(DicomImage::test_stub_with_mono_frames(None, 3)),
+            current_frame: 2,
+            window_center: 0.0,
+            window_width: 1.0,
+            ..Default::default()
+        };
+        let copied = app
+            .active_frame_color_image()
+            .expect("current frame should render");
+        assert_eq!(copied.size, [1, 1]);
+        assert_eq!(copied.pixels[0], egui::Color32::WHITE);
+
+        app.image = None;
+        assert!(app.active_frame_color_image().is_none());
+    }
+
+    #[test]
+    fn center_active_view_keeps_zoom() {
+        let mut app = DicomViewerApp {
+            image: Some(DicomImage::test_stub(None)),
+            single_view_zoom: 4.0,
+            single_view_pan: egui::vec2(120.0, -80.0),
+            ..Default::default()
+        };
+
+        app.center_active_view();
+
+        assert_eq!(app.single_view_pan, egui::Vec2::ZERO);
+        assert_eq!(app.single_view_zoom, 4.0);
+    }
+
+    #[test]
+    fn toggle_active_invert_flips_only_monochrome_images() {
+        let mut app = DicomViewerApp {
+            image: Some(DicomImage::test_stub_with_mono_frames(None, 1)),
+            ..Default::default()
+        };
+
+        app.single_view_content_uv = Some(None);
+        assert!(app.toggle_active_invert());
+        assert!(app.image.as_ref().is_some_and(|image| image.invert));
+        // The cached auto-crop was detected for the old polarity.
+        assert_eq!(app.single_view_content_uv, None);
+        assert!(app.toggle_active_invert());
+        assert!(app.image.as_ref().is_some_and(|image| !image.invert));
+
+        if let Some(image) = app.image.as_mut() {
+            image.color_mode = crate::dicom::ImageColorMode::Rgb;
+        }
+        assert!(!app.toggle_active_invert());
+        assert!(!DicomViewerApp::default().toggle_active_invert());
+    }
+
+    #[test]
+    fn reset_all_settings_restores_defaults_and_rewrites_file() {
+        let path = std::env::temp_dir().join(format!(
+            "perspecta-settings-reset-test-{}-{}.toml",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos()
+        ));
+        let mut app = DicomViewerApp {
+            visible_metadata_fields: vec!["Modality".to_string()],
+            preferences: ViewerPreferences {
+                cine_every_frame: true,
+                autoplay_cine: true,
+                cine_progress_ring: true,
+                instant_history_cycling: true,
+                instant_local_open: true,
+                monitor_dpi: Some(96.0),
+                auto_crop_borders: true,
+                sticky_window: true,
+                keep_zoom_on_resize: true,
+                compact_info_hud: true,
+                hide_view_labels: true,
+                presentation_mode: true,
+                assume_monochrome1_when_missing: true,
+                frame_filmstrip: true,
+                sort_history_by_date: true,
+                history_view_thumbnails: true,
+                window_level_gesture: WindowLevelGesture::SecondaryDrag,
+                export_format: ExportFormat::Jpeg,
+                export_jpeg_quality: JpegQuality(60),
+                grid_style: MultiViewGridStyle {
+                    gap: 6.0,
+                    border_width: 0.0,
+                    border_color: egui::Color32::WHITE,
+                },
+                view_fallback_tags: vec!["SeriesDescription".to_string()],
+            },
+            cine_fps_by_modality: BTreeMap::from([("XA".to_string(), 15.0)]),
+            settings_path: Some(path.clone()),
+            ..Default::default()
+        };
+
+        app.reset_all_settings();
+
+        assert_eq!(
+            app.visible_metadata_fields,
+            default_visible_metadata_fields()
+        );
+        assert!(app.cine_fps_by_modality.is_empty());
+        assert_eq!(app.preferences, ViewerPreferences::default());
+        assert_eq!(
+            load_visible_metadata_fields(&path),
+            Some(default_visible_metadata_fields())
+        );
+        assert_eq!(load_viewer_preferences(&path), ViewerPreferences::default());
+
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn ui_theme_is_applied_once_and_reapplied_on_change() {
+        let ctx = egui::Context::default();
+        let mut app = DicomViewerApp::default();
+
+        assert!(app.ensure_ui_theme_applied(&ctx));
+        assert!(!app.ensure_ui_theme_applied(&ctx));
+        assert_eq!(ctx.theme(), egui::Theme::Dark);
+        assert_eq!(ctx.global_style().visuals.panel_fill, egui::Color32::BLACK);
+
+        app.ui_theme = UiTheme::Light;
+        assert!(app.ensure_ui_theme_applied(&ctx));
+        assert_eq!(ctx.theme(), egui::Theme::Light);
+        assert!(!app.ensure_ui_theme_applied(&ctx));
+    }
+
+    #[test]
+    fn frame_slider_hover_text_prefers_acquisition_time() {
+        assert_eq!(
+            DicomViewerApp::frame_slider_hover_text(3, Some(99.9), None),
+            "Frame 3 (99.9 ms)"
+        );
+        assert_eq!(
+            DicomViewerApp::frame_slider_hover_text(3, None, None),
+            "Frame 3"
+        );
+    }
+
+    #[test]
+    fn loaded_frame_runs_group_contiguous_decoded_frames() {
+        assert_eq!(
+            DicomViewerApp::loaded_frame_runs(&[true, true, false, true, false, true]),
+            vec![0..2, 3..4, 5..6]
+        );
+        assert!(DicomViewerApp::loaded_frame_runs(&[false, false]).is_empty());
+    }
+
+    #[test]
+    fn frame_slider_hover_text_reports_loaded_frames_until_complete() {
+        assert_eq!(
+            DicomViewerApp::frame_slider_hover_text(3, None, Some((48, 120))),
+            "Frame 3\n48/120 loaded"
+        );
+        assert_eq!(
+            DicomViewerApp::frame_slider_hover_text(3, None, Some((120, 120))),
+            "Frame 3"
+        );
+    }
+
+    #[test]
+    fn zoom_anchor_pan_delta_ignores_anchor_at_letterboxed_canvas_corner() {
+        let canvas_rect = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(200.0, 100.0));
+        let image_rect =
+            egui::Rect::from_center_size(canvas_rect.center(), egui::vec2(100.0, 100.0));
+
+        assert_eq!(
+            DicomViewerApp::zoom_anchor_pan_delta(image_rect, canvas_rect.min, 1.0, 2.0),
+            egui::Vec2::ZERO
+        );
+        assert_eq!(
+            DicomViewerApp::zoom_anchor_pan_delta(image_rect, egui::pos2(60.0, 10.0), 1.0, 2.0),
+            egui::vec2(40.0, 40.0)
+        );
+    }
+
+    #[test]
+    fn every_frame_cine_tick_keeps_cadence_but_does_not_burst_after_stall() {
+        let interval = Duration::from_millis(10);
+        let last = Instant::now();
 
-        let path = unique_test_file_path("memory-gsps-source");
-        gsps_obj
-            .write_to_file(&path)
-            .expect("memory GSPS test object should write to disk");
-        let bytes = fs::read(&path).expect("memory GSPS test bytes should read from disk");
-        let _ = fs::remove_file(&path);
-        DicomSource::from_memory(preferred_name, bytes)
+        let on_time = last + Duration::from_millis(12);
+        assert_eq!(
+            DicomViewerApp::next_every_frame_cine_tick(last, on_time, interval),
+            last + interval
+        );
+
+        let stalled = last + Duration::from_millis(45);
+        assert_eq!(
+            DicomViewerApp::next_every_frame_cine_tick(last, stalled, interval),
+            stalled
+        );
     }
 
-    fn test_memory_parametric_map_source(
-        preferred_name: &str,
-        study_uid: &str,
-        series_uid: &str,
-        instance_uid: &str,
-        referenced_instance_uid: &str,
-    ) -> DicomSource {
-        let referenced_image = InMemDicomObject::from_element_iter([
-            DataElement::new(
-                Tag(0x0008, 0x1150),
-                VR::UI,
-                DIGITAL_MAMMOGRAPHY_XRAY_IMAGE_PRESENTATION_SOP_CLASS_UID,
-            ),
-            DataElement::new(Tag(0x0008, 0x1155), VR::UI, referenced_instance_uid),
-        ]);
-        let pm_dataset = InMemDicomObject::from_element_iter([
-            DataElement::new(Tag(0x0008, 0x0016), VR::UI, PARAMETRIC_MAP_SOP_CLASS_UID),
-            DataElement::new(Tag(0x0008, 0x0018), VR::UI, instance_uid),
-            DataElement::new(Tag(0x0008, 0x0060), VR::CS, "MG"),
-            DataElement::new(Tag(0x0020, 0x000D), VR::UI, study_uid),
-            DataElement::new(Tag(0x0020, 0x000E), VR::UI, series_uid),
-            DataElement::new(Tag(0x0028, 0x0002), VR::US, PrimitiveValue::from(1u16)),
-            DataElement::new(Tag(0x0028, 0x0004), VR::CS, "MONOCHROME2"),
-            DataElement::new(Tag(0x0028, 0x0010), VR::US, PrimitiveValue::from(1u16)),
-            DataElement::new(Tag(0x0028, 0x0011), VR::US, PrimitiveValue::from(1u16)),
-            DataElement::new(Tag(0x0028, 0x0008), VR::IS, "1"),
-            DataElement::new(
-                Tag(0x0008, 0x2112),
-                VR::SQ,
-                DataSetSequence::from(vec![referenced_image]),
-            ),
-            DataElement::new(
-                Tag(0x7FE0, 0x0008),
-                VR::OF,
-                PrimitiveValue::F32(vec![1.0f32].into()),
-            ),
-        ]);
+    #[test]
+    fn zoom_for_fit_change_keeps_the_drawn_size() {
+        assert_approx_eq(DicomViewerApp::zoom_for_fit_change(2.0, 0.5, 0.25), 4.0);
+        assert_approx_eq(DicomViewerApp::zoom_for_fit_change(2.0, 0.5, 1.0), 1.0);
+        assert_approx_eq(
+            DicomViewerApp::zoom_for_fit_change(8.0, 1.0, 0.01),
+            SINGLE_VIEW_MAX_ZOOM,
+        );
+    }
 
-        let pm_obj = pm_dataset
-            .with_meta(
-                FileMetaTableBuilder::new()
-                    .transfer_syntax(EXPLICIT_VR_LITTLE_ENDIAN_UID)
-                    .media_storage_sop_class_uid(PARAMETRIC_MAP_SOP_CLASS_UID)
-                    .media_storage_sop_instance_uid(instance_uid),
-            )
-            .expect("memory Parametric Map test object should build file meta");
+    #[test]
+    fn keep_zoom_on_resize_holds_a_zoom_that_passes_through_fit() {
+        let content_size = egui::vec2(512.0, 512.0);
+        let mut app = DicomViewerApp {
+            preferences: ViewerPreferences {
+                keep_zoom_on_resize: true,
+                ..ViewerPreferences::default()
+            },
+            single_view_zoom: 2.0,
+            single_view_zoomed: true,
+            ..Default::default()
+        };
+        app.hold_single_view_zoom_across_resize(content_size, 0.5);
 
-        let path = unique_test_file_path("memory-parametric-map-source");
-        pm_obj
-            .write_to_file(&path)
-            .expect("memory Parametric Map test object should write to disk");
-        let bytes = fs::read(&path).expect("memory Parametric Map test bytes should read");
-        let _ = fs::remove_file(&path);
-        DicomSource::from_memory(preferred_name, bytes)
+        // Growing the window lands the zoom on exactly 1 ...
+        app.hold_single_view_zoom_across_resize(content_size, 1.0);
+        assert_approx_eq(app.single_view_zoom, 1.0);
+
+        // ... and shrinking it again must still restore the drawn size.
+        app.hold_single_view_zoom_across_resize(content_size, 0.5);
+        assert_approx_eq(app.single_view_zoom, 2.0);
+
+        // A view reset to fit follows the window again.
+        app.reset_single_view_transform();
+        app.hold_single_view_zoom_across_resize(content_size, 0.25);
+        assert_approx_eq(app.single_view_zoom, 1.0);
     }
 
-    fn single_history_entry(ctx: &egui::Context, path: &str, texture_name: &str) -> HistoryEntry {
-        let path_buf = PathBuf::from(path);
-        HistoryEntry {
-            id: history_id_from_paths(std::slice::from_ref(&path_buf)),
-            kind: HistoryKind::Single(Box::new(HistorySingleData {
-                path: path_buf.clone().into(),
-                image: DicomImage::test_stub(None),
-                texture: test_texture(ctx, texture_name),
-                window_center: 0.0,
-                window_width: 1.0,
-                current_frame: 0,
-                cine_fps: DEFAULT_CINE_FPS,
-            })),
-            thumbs: Vec::new(),
-        }
+    #[test]
+    fn true_size_zoom_matches_physical_pixel_spacing() {
+        // 0.254 mm pixels on a 100 DPI, 1x display: each image pixel is one point.
+        let zoom = DicomViewerApp::true_size_zoom(0.254, 100.0, 1.0, 0.5)
+            .expect("valid spacing and DPI should give a zoom");
+        assert_approx_eq(zoom, 2.0);
+
+        let hidpi_zoom = DicomViewerApp::true_size_zoom(0.254, 200.0, 2.0, 0.5)
+            .expect("valid spacing and DPI should give a zoom");
+        assert_approx_eq(hidpi_zoom, 2.0);
+
+        assert_eq!(DicomViewerApp::true_size_zoom(0.0, 100.0, 1.0, 0.5), None);
     }
 
-    fn report_history_entry(ctx: &egui::Context, path: &str, texture_name: &str) -> HistoryEntry {
-        let path_buf = PathBuf::from(path);
-        HistoryEntry {
-            id: history_id_from_paths(std::slice::from_ref(&path_buf)),
-            kind: HistoryKind::Report(Box::new(HistoryReportData {
-                path: path_buf.into(),
-                report: StructuredReportDocument::test_stub(),
-            })),
-            thumbs: vec![HistoryThumb {
-                texture: test_texture(ctx, texture_name),
-            }],
-        }
+    #[test]
+    fn cine_pauses_only_when_window_is_known_hidden() {
+        assert!(!DicomViewerApp::cine_paused_for_window(None, None));
+        assert!(!DicomViewerApp::cine_paused_for_window(
+            Some(false),
+            Some(true)
+        ));
+        assert!(DicomViewerApp::cine_paused_for_window(Some(true), None));
+        assert!(DicomViewerApp::cine_paused_for_window(
+            Some(false),
+            Some(false)
+        ));
     }
 
     #[test]
-    fn memory_sources_use_semantic_identity_for_history_and_display_matching() {
-        let reopened = test_memory_source(
-            "reopened-report",
-            "1.2.840.10008.1",
-            "1.2.840.10008.1.1",
-            "1.2.840.10008.1.1.1",
+    fn cine_repaint_delay_waits_for_next_tick_only() {
+        let interval = Duration::from_millis(100);
+        let last = Instant::now();
+
+        assert_eq!(
+            DicomViewerApp::cine_repaint_delay(last, last + Duration::from_millis(30), interval),
+            Duration::from_millis(70)
         );
-        let reopened_again = test_memory_source(
-            "same-report-different-handle",
-            "1.2.840.10008.1",
-            "1.2.840.10008.1.1",
-            "1.2.840.10008.1.1.1",
+        assert_eq!(
+            DicomViewerApp::cine_repaint_delay(last, last + Duration::from_millis(150), interval),
+            Duration::ZERO
         );
-        let different = test_memory_source(
-            "different-report",
-            "1.2.840.10008.1",
-            "1.2.840.10008.1.1",
-            "1.2.840.10008.1.1.2",
+    }
+
+    #[test]
+    fn auto_crop_centers_detected_content_in_the_canvas() {
+        let mut image = DicomImage::test_stub_with_mono_frames(None, 1);
+        image.width = 4;
+        image.height = 2;
+        image.max_value = 100;
+        let mut app = DicomViewerApp {
+            image: Some(image),
+            ..Default::default()
+        };
+        let full = egui::Rect::from_min_max(egui::Pos2::ZERO, egui::pos2(1.0, 1.0));
+        assert_eq!(app.single_view_content_uv(), full);
+
+        app.preferences.auto_crop_borders = true;
+        // The stub's single frame holds one pixel, too few to scan, so no crop is cached.
+        assert_eq!(app.single_view_content_uv(), full);
+        app.single_view_content_uv = Some(Some(egui::Rect::from_min_max(
+            egui::pos2(0.5, 0.0),
+            egui::pos2(1.0, 1.0),
+        )));
+        let content_uv = app.single_view_content_uv();
+
+        let image_rect = DicomViewerApp::single_view_image_rect(
+            egui::pos2(100.0, 100.0),
+            egui::Vec2::ZERO,
+            egui::vec2(80.0, 40.0),
+            content_uv,
+        );
+        assert_eq!(
+            image_rect,
+            egui::Rect::from_center_size(egui::pos2(80.0, 100.0), egui::vec2(80.0, 40.0))
         );
+    }
 
-        let reopened_id = history_id_from_paths(std::slice::from_ref(&reopened));
-        let reopened_again_id = history_id_from_paths(std::slice::from_ref(&reopened_again));
-        let different_id = history_id_from_paths(std::slice::from_ref(&different));
+    #[test]
+    fn initial_cine_fps_prefers_recommended_then_remembered_modality_rate() {
+        let app = DicomViewerApp {
+            cine_fps_by_modality: BTreeMap::from([("US".to_string(), 24.0)]),
+            ..Default::default()
+        };
+        let mut image = DicomImage::test_stub(None);
+        image.metadata = vec![("Modality".to_string(), "us".to_string())];
+        assert_eq!(app.initial_cine_fps_for_image(&image), 24.0);
 
-        assert_eq!(reopened_id, reopened_again_id);
-        assert_ne!(reopened_id, different_id);
+        image.recommended_cine_fps = Some(12.0);
+        assert_eq!(app.initial_cine_fps_for_image(&image), 12.0);
 
-        let app = DicomViewerApp {
-            current_single_path: Some((&reopened).into()),
+        image.recommended_cine_fps = None;
+        image.metadata = vec![("Modality".to_string(), "XA".to_string())];
+        assert_eq!(app.initial_cine_fps_for_image(&image), DEFAULT_CINE_FPS);
+    }
+
+    #[test]
+    fn sticky_window_carries_a_manual_window_to_the_same_modality() {
+        let ct_image = || {
+            let mut image = DicomImage::test_stub_with_mono_frames(None, 1);
+            image.metadata = vec![("Modality".to_string(), "CT".to_string())];
+            image
+        };
+        let mut app = DicomViewerApp {
+            image: Some(ct_image()),
+            window_center: 40.0,
+            window_width: 400.0,
             ..Default::default()
         };
-        assert!(app.displayed_study_matches_paths(&[reopened_again]));
-        assert!(!app.displayed_study_matches_paths(&[different]));
+        app.preferences.sticky_window = true;
+
+        app.clear_single_viewer();
+        assert_eq!(
+            app.sticky_window_for_image(&ct_image()),
+            Some((40.0, 400.0))
+        );
+        let mut mr_image = ct_image();
+        mr_image.metadata = vec![("Modality".to_string(), "MR".to_string())];
+        assert_eq!(app.sticky_window_for_image(&mr_image), None);
+
+        app.preferences.sticky_window = false;
+        assert_eq!(app.sticky_window_for_image(&ct_image()), None);
+
+        // Leaving an image at its default window forgets the manual one.
+        app.preferences.sticky_window = true;
+        let image = ct_image();
+        (app.window_center, app.window_width) = (image.window_center, image.window_width);
+        app.image = Some(image);
+        app.clear_single_viewer();
+        assert_eq!(app.sticky_window_for_image(&ct_image()), None);
     }
 
     #[test]
-    fn history_id_from_paths_uses_collision_free_length_prefix_encoding() {
-        let left = vec![PathBuf::from("a|b"), PathBuf::from("c")];
-        let right = vec![PathBuf::from("a"), PathBuf::from("b|c")];
+    fn frame_step_from_scroll_caps_large_deltas_and_keeps_residual() {
+        let mut accum = 0.0;
+        assert_eq!(
+            DicomViewerApp::frame_step_from_scroll(&mut accum, 1000.0),
+            -3
+        );
+        assert!((accum - 10.0).abs() < 1e-3);
 
-        assert_ne!(history_id_from_paths(&left), history_id_from_paths(&right));
+        assert_eq!(DicomViewerApp::frame_step_from_scroll(&mut accum, 20.0), -1);
+        assert!(accum.abs() < 1e-3);
+
+        assert_eq!(
+            DicomViewerApp::frame_step_from_scroll(&mut accum, -1000.0),
+            3
+        );
     }
 
     #[test]
-    fn metadata_settings_toml_roundtrip() {
-        let selected = vec![
-            "PatientName".to_string(),
-            "StudyDescription".to_string(),
-            "Modality".to_string(),
-        ];
-        let toml = render_settings_toml(&selected);
-        let parsed = parse_visible_metadata_fields_from_toml(&toml).expect("TOML should parse");
-        assert_eq!(parsed, selected);
+    fn frame_step_from_scroll_steps_once_at_exactly_one_step_of_travel() {
+        let mut accum = 0.0;
+        assert_eq!(DicomViewerApp::frame_step_from_scroll(&mut accum, -29.9), 0);
+        assert_eq!(DicomViewerApp::frame_step_from_scroll(&mut accum, -0.1), 1);
+        assert_eq!(accum, 0.0);
+
+        for delta in [12.1, 8.7] {
+            assert_eq!(DicomViewerApp::frame_step_from_scroll(&mut accum, delta), 0);
+        }
+        assert_eq!(DicomViewerApp::frame_step_from_scroll(&mut accum, 9.2), -1);
+        assert_eq!(accum, 0.0);
+    }
+
+    #[test]
+    fn frame_step_from_scroll_carries_residual_and_resets_on_reversal() {
+        let mut accum = 0.0;
+        assert_eq!(DicomViewerApp::frame_step_from_scroll(&mut accum, -45.0), 1);
+        assert_eq!(DicomViewerApp::frame_step_from_scroll(&mut accum, -15.0), 1);
+
+        assert_eq!(DicomViewerApp::frame_step_from_scroll(&mut accum, -20.0), 0);
+        assert_eq!(DicomViewerApp::frame_step_from_scroll(&mut accum, 20.0), 0);
+        assert_eq!(accum, 20.0);
+        assert_eq!(DicomViewerApp::frame_step_from_scroll(&mut accum, 10.0), -1);
+    }
+
+    #[test]
+    fn frame_step_from_scroll_ignores_jitter_only_from_rest() {
+        let mut accum = 0.0;
+        assert_eq!(DicomViewerApp::frame_step_from_scroll(&mut accum, 0.3), 0);
+        assert_eq!(accum, 0.0);
+
+        // A smoothed notch: a large head followed by sub-dead-zone tail deltas.
+        assert_eq!(DicomViewerApp::frame_step_from_scroll(&mut accum, -29.6), 0);
+        assert_eq!(DicomViewerApp::frame_step_from_scroll(&mut accum, -0.4), 1);
+
+        assert_eq!(DicomViewerApp::frame_step_from_scroll(&mut accum, -10.0), 0);
+        assert_eq!(DicomViewerApp::frame_step_from_scroll(&mut accum, 0.4), 0);
+        assert_eq!(accum, -10.0);
+    }
+
+    #[test]
+    fn default_visible_metadata_fields_are_a_known_subset() {
+        let defaults = default_visible_metadata_fields();
+        assert!(defaults.len() < METADATA_FIELD_NAMES.len());
+        assert!(defaults
+            .iter()
+            .all(|field| METADATA_FIELD_NAMES.contains(&field.as_str())));
+        assert_eq!(defaults.first().map(String::as_str), Some("PatientName"));
     }
 
     #[test]
@@ -3695,12 +7987,48 @@ mod tests {
         fs::write(&path, toml).expect("should write temp settings");
 
         let loaded = load_visible_metadata_fields(&path).expect("settings should load");
-        assert!(loaded.contains("PatientName"));
-        assert!(!loaded.contains("UnknownField"));
+        assert_eq!(loaded, vec!["PatientName".to_string()]);
+
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn load_visible_metadata_fields_preserves_saved_order_and_drops_duplicates() {
+        let path = std::env::temp_dir().join(format!(
+            "perspecta-settings-order-test-{}-{}.toml",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos()
+        ));
+        let toml = "visible_metadata_fields = [\"Modality\", \"PatientName\", \"Modality\"]\n";
+        fs::write(&path, toml).expect("should write temp settings");
+
+        let loaded = load_visible_metadata_fields(&path).expect("settings should load");
+        assert_eq!(
+            loaded,
+            vec!["Modality".to_string(), "PatientName".to_string()]
+        );
 
         let _ = fs::remove_file(path);
     }
 
+    #[test]
+    fn move_metadata_field_reorders_within_bounds() {
+        let mut fields = vec![
+            "PatientName".to_string(),
+            "StudyDate".to_string(),
+            "Modality".to_string(),
+        ];
+
+        assert!(move_metadata_field(&mut fields, 2, 0));
+        assert_eq!(fields, vec!["Modality", "PatientName", "StudyDate"]);
+        assert!(!move_metadata_field(&mut fields, 1, 1));
+        assert!(!move_metadata_field(&mut fields, 0, 3));
+        assert_eq!(fields, vec!["Modality", "PatientName", "StudyDate"]);
+    }
+
     #[test]
     fn wl_overlay_layout_uses_minimum_width_clamp_for_small_screens() {
         let layout = DicomViewerApp::wl_overlay_layout(320.0, 20.0, 8.0, true, false);
@@ -3792,6 +8120,29 @@ mod tests {
         assert_eq!(candidates, vec![path]);
     }
 
+    #[test]
+    fn picker_dicom_candidates_accept_extensionless_raw_data_sets() {
+        let path = unique_test_file_path_with_suffix("extensionless-raw", "");
+        // A first element longer than the prefix the picker reads.
+        let long_image_type = vec!["DERIVED"; 40].join("\\");
+        let dataset = InMemDicomObject::from_element_iter([
+            DataElement::new(Tag(0x0008, 0x0008), VR::CS, long_image_type.as_str()),
+            DataElement::new(Tag(0x0008, 0x0016), VR::UI, "1.2.840.10008.5.1.4.1.1.7"),
+            DataElement::new(Tag(0x0008, 0x0018), VR::UI, "1.2.3.4"),
+        ]);
+        let mut bytes = Vec::new();
+        dataset
+            .write_dataset_with_ts(&mut bytes, &IMPLICIT_VR_LITTLE_ENDIAN.erased())
+            .expect("raw data set should serialize");
+        assert!(bytes.len() > PICKER_DICOM_PREFIX_READ_BYTES);
+        fs::write(&path, bytes).expect("raw data set test file should be written");
+
+        let candidates = DicomViewerApp::picker_dicom_candidates(vec![path.clone()]);
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(candidates, vec![path]);
+    }
+
     #[test]
     fn picker_dicom_candidates_reject_extensionless_non_dicom_files() {
         let path = unique_test_file_path_with_suffix("extensionless-non-dicom", "");
@@ -3910,7 +8261,7 @@ mod tests {
     #[test]
     fn queue_local_paths_open_clears_active_load_receivers() {
         let (_single_tx, single_rx) = mpsc::channel::<Result<PendingSingleLoad, String>>();
-        let (mammo_tx, mammo_rx) = mpsc::channel::<Result<PendingLoad, String>>();
+        let (mammo_tx, mammo_rx) = mpsc::channel::<PendingLoad>();
         let mut app = DicomViewerApp {
             single_load_receiver: Some(single_rx),
             mammo_load_receiver: Some(mammo_rx),
@@ -3946,22 +8297,66 @@ mod tests {
     }
 
     #[test]
-    fn process_pending_local_open_starts_prepare_worker_after_arming() {
+    fn process_pending_local_open_starts_prepare_worker_after_arming() {
+        let ctx = egui::Context::default();
+        let mut app = DicomViewerApp::default();
+
+        app.queue_local_paths_open(vec![PathBuf::from("large-local.dcm")]);
+        app.process_pending_local_open(&ctx);
+
+        assert!(app.pending_local_open_armed);
+        assert!(app.local_prepare_receiver.is_none());
+
+        app.process_pending_local_open(&ctx);
+
+        assert!(app.pending_local_open_paths.is_none());
+        assert!(!app.pending_local_open_armed);
+        assert!(app.local_prepare_receiver.is_some());
+        assert!(app.is_loading());
+    }
+
+    #[test]
+    fn instant_local_open_starts_prepare_worker_without_arming() {
         let ctx = egui::Context::default();
         let mut app = DicomViewerApp::default();
+        app.preferences.instant_local_open = true;
 
-        app.queue_local_paths_open(vec![PathBuf::from("large-local.dcm")]);
-        app.process_pending_local_open(&ctx);
-
-        assert!(app.pending_local_open_armed);
-        assert!(app.local_prepare_receiver.is_none());
-
+        app.queue_local_paths_open(vec![PathBuf::from("fast-local.dcm")]);
         app.process_pending_local_open(&ctx);
 
         assert!(app.pending_local_open_paths.is_none());
         assert!(!app.pending_local_open_armed);
         assert!(app.local_prepare_receiver.is_some());
-        assert!(app.is_loading());
+    }
+
+    #[test]
+    fn dicomweb_failure_keeps_full_error_chain_as_detail() {
+        let ctx = egui::Context::default();
+        let (download_tx, download_rx) = mpsc::channel::<Result<DicomWebDownloadResult, String>>();
+        let mut app = DicomViewerApp {
+            dicomweb_receiver: Some(download_rx),
+            ..Default::default()
+        };
+        download_tx
+            .send(Err(
+                "instance download failed: GET https://pacs.test/instances/1 (Accept: application/dicom): 406"
+                    .to_string(),
+            ))
+            .expect("download result should send");
+
+        app.poll_dicomweb_download(&ctx);
+
+        assert_eq!(
+            app.load_error_message.as_deref(),
+            Some("DICOMweb request failed.")
+        );
+        assert!(app
+            .load_error_detail
+            .as_deref()
+            .is_some_and(|detail| detail.contains("Accept: application/dicom")));
+
+        app.clear_load_error();
+        assert!(app.load_error_detail.is_none());
     }
 
     #[test]
@@ -4180,7 +8575,7 @@ mod tests {
         let mut app = DicomViewerApp::default();
         assert!(!app.has_mammo_group());
 
-        let (_tx, rx) = mpsc::channel::<Result<PendingLoad, String>>();
+        let (_tx, rx) = mpsc::channel::<PendingLoad>();
         app.mammo_load_receiver = Some(rx);
         assert!(app.has_mammo_group());
 
@@ -4616,6 +9011,7 @@ mod tests {
                     cine_fps: DEFAULT_CINE_FPS,
                 })),
                 thumbs: Vec::new(),
+                sort_key: None,
             }],
             ..Default::default()
         };
@@ -4688,6 +9084,7 @@ mod tests {
                         cine_fps: DEFAULT_CINE_FPS,
                     })),
                     thumbs: Vec::new(),
+                    sort_key: None,
                 },
                 HistoryEntry {
                     id: history_id_from_paths(&[
@@ -4726,6 +9123,7 @@ mod tests {
                         selected_index: 0,
                     }),
                     thumbs: Vec::new(),
+                    sort_key: None,
                 },
             ],
             ..Default::default()
@@ -4787,6 +9185,7 @@ mod tests {
                     cine_fps: DEFAULT_CINE_FPS,
                 })),
                 thumbs: Vec::new(),
+                sort_key: None,
             }],
             ..Default::default()
         };
@@ -4864,6 +9263,7 @@ mod tests {
                         cine_fps: DEFAULT_CINE_FPS,
                     })),
                     thumbs: Vec::new(),
+                    sort_key: None,
                 },
                 HistoryEntry {
                     id: history_id_from_paths(&[
@@ -4902,6 +9302,7 @@ mod tests {
                         selected_index: 0,
                     }),
                     thumbs: Vec::new(),
+                    sort_key: None,
                 },
             ],
             ..Default::default()
@@ -4930,6 +9331,130 @@ mod tests {
         );
     }
 
+    #[test]
+    fn toggle_overlay_applies_and_restores_gsps_window() {
+        let mut image = DicomImage::test_stub_with_mono_frames(
+            Some(GspsOverlay {
+                voi: Some(crate::dicom::GspsVoi {
+                    center: 40.0,
+                    width: 400.0,
+                }),
+                ..Default::default()
+            }),
+            1,
+        );
+        image.window_center = 100.0;
+        image.window_width = 200.0;
+        let mut app = DicomViewerApp {
+            image: Some(image),
+            current_single_path: Some(test_meta("gsps-image.dcm")),
+            window_center: 100.0,
+            window_width: 200.0,
+            ..Default::default()
+        };
+
+        assert!(app.toggle_overlay());
+        assert_eq!((app.window_center, app.window_width), (40.0, 400.0));
+        assert!(app.toggle_overlay());
+        assert_eq!((app.window_center, app.window_width), (100.0, 200.0));
+
+        assert!(app.toggle_overlay());
+        app.window_center = 55.0;
+        assert!(app.toggle_overlay());
+        assert_eq!((app.window_center, app.window_width), (55.0, 400.0));
+    }
+
+    #[test]
+    fn toggle_overlay_off_restores_window_shown_before_gsps() {
+        let gsps = GspsOverlay {
+            voi: Some(crate::dicom::GspsVoi {
+                center: 40.0,
+                width: 400.0,
+            }),
+            ..Default::default()
+        };
+        let mut image = DicomImage::test_stub_with_mono_frames(Some(gsps.clone()), 1);
+        image.window_center = 100.0;
+        image.window_width = 200.0;
+        let mut app = DicomViewerApp {
+            image: Some(image.clone()),
+            current_single_path: Some(test_meta("single.dcm")),
+            window_center: 70.0,
+            window_width: 300.0,
+            ..Default::default()
+        };
+
+        assert!(app.toggle_overlay());
+        assert_eq!((app.window_center, app.window_width), (40.0, 400.0));
+        assert!(app.toggle_overlay());
+        // The manual window, not the image's default, comes back.
+        assert_eq!((app.window_center, app.window_width), (70.0, 300.0));
+
+        // Each viewport gets its own window back.
+        let ctx = egui::Context::default();
+        let viewport = |name: &str, window_center: f32, window_width: f32| MammoViewport {
+            path: test_meta(name),
+            image: image.clone(),
+            texture: ctx.load_texture(name, test_preview(), TextureOptions::LINEAR),
+            history_thumb: test_preview(),
+            label: name.to_string(),
+            window_center,
+            window_width,
+            current_frame: 0,
+            zoom: 1.0,
+            pan: egui::Vec2::ZERO,
+            frame_scroll_accum: 0.0,
+            window_preset_index: None,
+        };
+        let mut app = DicomViewerApp {
+            mammo_group: vec![
+                Some(viewport("left.dcm", 70.0, 300.0)),
+                Some(viewport("right.dcm", 90.0, 500.0)),
+            ],
+            ..Default::default()
+        };
+        assert!(app.toggle_overlay());
+        assert!(app.toggle_overlay());
+        let windows = app
+            .mammo_group
+            .iter()
+            .flatten()
+            .map(|viewport| (viewport.window_center, viewport.window_width))
+            .collect::<Vec<_>>();
+        assert_eq!(windows, vec![(70.0, 300.0), (90.0, 500.0)]);
+    }
+
+    #[test]
+    fn gsps_window_applies_when_overlay_attaches_while_visible() {
+        let mut image = DicomImage::test_stub_with_mono_frames(None, 1);
+        image.sop_instance_uid = Some("1.2.3.4".to_string());
+        let mut app = DicomViewerApp {
+            image: Some(image),
+            current_single_path: Some(test_meta("backfill.dcm")),
+            window_center: 70.0,
+            window_width: 300.0,
+            overlay_visible: true,
+            ..Default::default()
+        };
+
+        app.pending_gsps_overlays.insert(
+            "1.2.3.4".to_string(),
+            GspsOverlay {
+                voi: Some(crate::dicom::GspsVoi {
+                    center: 40.0,
+                    width: 400.0,
+                }),
+                ..Default::default()
+            },
+        );
+        app.attach_pending_overlays_to_current_study();
+        assert_eq!((app.window_center, app.window_width), (40.0, 400.0));
+
+        app.overlay_visible = true;
+        assert!(app.toggle_overlay());
+        assert_eq!((app.window_center, app.window_width), (70.0, 300.0));
+    }
+
     #[test]
     fn toggle_overlay_without_active_overlay_resets_to_off() {
         let mut app = DicomViewerApp {
@@ -4993,6 +9518,7 @@ mod tests {
                 },
                 referenced_frames: Some(vec![9]),
             }],
+            ..Default::default()
         };
         let app = DicomViewerApp {
             image: Some(DicomImage::test_stub_with_mono_frames(Some(overlay), 4)),
@@ -5015,6 +9541,7 @@ mod tests {
                 },
                 referenced_frames: Some(vec![4]),
             }],
+            ..Default::default()
         };
         let texture_a = ctx.load_texture(
             "test-non-renderable-group-a",
@@ -5040,6 +9567,7 @@ mod tests {
                     zoom: 1.0,
                     pan: egui::Vec2::ZERO,
                     frame_scroll_accum: 0.0,
+                    window_preset_index: None,
                 }),
                 Some(MammoViewport {
                     path: test_meta("non-renderable-b.dcm"),
@@ -5053,6 +9581,7 @@ mod tests {
                     zoom: 1.0,
                     pan: egui::Vec2::ZERO,
                     frame_scroll_accum: 0.0,
+                    window_preset_index: None,
                 }),
             ],
             ..Default::default()
@@ -5061,6 +9590,93 @@ mod tests {
         assert!(!app.has_available_overlay());
     }
 
+    #[test]
+    fn promoted_cell_returns_to_its_group_slot() {
+        let ctx = egui::Context::default();
+        let viewport = |name: &str, label: &str| MammoViewport {
+            path: test_meta(name),
+            image: DicomImage::test_stub_with_mono_frames(None, 3),
+            texture: test_texture(&ctx, name),
+            history_thumb: test_preview(),
+            label: label.to_string(),
+            window_center: 40.0,
+            window_width: 400.0,
+            current_frame: 0,
+            zoom: 2.0,
+            pan: egui::Vec2::ZERO,
+            frame_scroll_accum: 0.0,
+            window_preset_index: None,
+        };
+        let mut app = DicomViewerApp {
+            mammo_group: vec![Some(viewport("a.dcm", "A")), Some(viewport("b.dcm", "B"))],
+            mammo_selected_index: 1,
+            ..Default::default()
+        };
+
+        app.toggle_promoted_cell(&ctx);
+        assert!(app.mammo_group.is_empty());
+        assert_eq!(app.current_single_path, Some(test_meta("b.dcm")));
+        assert_eq!(app.window_center, 40.0);
+
+        app.window_center = 90.0;
+        app.current_frame = 2;
+        app.toggle_promoted_cell(&ctx);
+        assert!(app.image.is_none());
+        assert!(app.promoted_cell.is_none());
+        assert_eq!(app.mammo_selected_index, 1);
+        let restored = app.mammo_group[1]
+            .as_ref()
+            .expect("promoted cell should return");
+        assert_eq!(restored.label, "B");
+        assert_eq!(
+            (
+                restored.window_center,
+                restored.current_frame,
+                restored.zoom
+            ),
+            (90.0, 2, 2.0)
+        );
+        let other = app.mammo_group[0]
+            .as_ref()
+            .expect("other cell should be kept");
+        assert_eq!(other.window_center, 40.0);
+    }
+
+    #[test]
+    fn history_view_thumbnails_setting_splits_group_entries_per_view() {
+        let ctx = egui::Context::default();
+        let viewport = |name: &str| MammoViewport {
+            path: test_meta(name),
+            image: DicomImage::test_stub_with_mono_frames(None, 1),
+            texture: test_texture(&ctx, name),
+            history_thumb: test_preview(),
+            label: name.to_string(),
+            window_center: 40.0,
+            window_width: 400.0,
+            current_frame: 0,
+            zoom: 1.0,
+            pan: egui::Vec2::ZERO,
+            frame_scroll_accum: 0.0,
+            window_preset_index: None,
+        };
+        let mut app = DicomViewerApp::default();
+
+        app.push_group_history_entry(&[viewport("a.dcm"), viewport("b.dcm")], 0, &ctx);
+        assert_eq!(app.history_entries[0].thumbs.len(), 1);
+
+        app.preferences.history_view_thumbnails = true;
+        app.rebuild_group_history_thumbs(&ctx);
+        assert_eq!(app.history_entries[0].thumbs.len(), 2);
+        assert!(app.history_entries[0]
+            .thumbs
+            .iter()
+            .all(|thumb| thumb.texture.size()[0] <= HISTORY_VIEW_THUMB_MAX_DIM));
+
+        app.preferences.history_view_thumbnails = false;
+        app.rebuild_group_history_thumbs(&ctx);
+        assert_eq!(app.history_entries[0].thumbs.len(), 1);
+    }
+
     #[test]
     fn toggle_overlay_allows_group_overlay_when_other_viewport_is_selected() {
         let overlay = GspsOverlay::from_graphics(vec![GspsGraphic::Point {
@@ -5092,6 +9708,7 @@ mod tests {
                     zoom: 1.0,
                     pan: egui::Vec2::ZERO,
                     frame_scroll_accum: 0.0,
+                    window_preset_index: None,
                 }),
                 Some(MammoViewport {
                     path: test_meta("b.dcm"),
@@ -5105,6 +9722,7 @@ mod tests {
                     zoom: 1.0,
                     pan: egui::Vec2::ZERO,
                     frame_scroll_accum: 0.0,
+                    window_preset_index: None,
                 }),
             ],
             mammo_selected_index: 0,
@@ -5126,6 +9744,7 @@ mod tests {
                 },
                 referenced_frames: Some(vec![2, 4]),
             }],
+            ..Default::default()
         };
         let mut app = DicomViewerApp {
             image: Some(DicomImage::test_stub_with_mono_frames(Some(overlay), 4)),
@@ -5155,6 +9774,7 @@ mod tests {
                 },
                 referenced_frames: Some(vec![2, 4]),
             }],
+            ..Default::default()
         };
         let mut app = DicomViewerApp {
             image: Some(DicomImage::test_stub_with_mono_frames(Some(overlay), 4)),
@@ -5180,6 +9800,7 @@ mod tests {
                 },
                 referenced_frames: Some(vec![1, 4]),
             }],
+            ..Default::default()
         };
         let image = DicomImage::test_stub_with_mono_frames_and_reverse(Some(overlay), 4, true);
 
@@ -5214,6 +9835,7 @@ mod tests {
                 },
                 referenced_frames: Some(vec![1]),
             }],
+            ..Default::default()
         };
         let image = DicomImage::test_stub_with_mono_frames_and_reverse(Some(overlay), 4, true);
         let stored_frame_index = image
@@ -5241,6 +9863,7 @@ mod tests {
                 },
                 referenced_frames: Some(vec![2]),
             }],
+            ..Default::default()
         };
         let overlay_b = GspsOverlay {
             graphics: vec![crate::dicom::GspsOverlayGraphic {
@@ -5251,6 +9874,7 @@ mod tests {
                 },
                 referenced_frames: Some(vec![1]),
             }],
+            ..Default::default()
         };
         let texture_image = ColorImage::new([1, 1], vec![egui::Color32::BLACK]);
         let ctx = egui::Context::default();
@@ -5274,6 +9898,7 @@ mod tests {
                     zoom: 1.0,
                     pan: egui::Vec2::ZERO,
                     frame_scroll_accum: 0.0,
+                    window_preset_index: None,
                 }),
                 Some(MammoViewport {
                     path: test_meta("b.dcm"),
@@ -5287,6 +9912,7 @@ mod tests {
                     zoom: 1.0,
                     pan: egui::Vec2::ZERO,
                     frame_scroll_accum: 0.0,
+                    window_preset_index: None,
                 }),
             ],
             mammo_selected_index: 0,
@@ -5329,6 +9955,7 @@ mod tests {
                     cine_fps: DEFAULT_CINE_FPS,
                 })),
                 thumbs: Vec::new(),
+                sort_key: None,
             }],
             ..Default::default()
         };
@@ -5338,12 +9965,138 @@ mod tests {
         assert!(app.load_error_message.is_none());
     }
 
+    #[test]
+    fn toggle_previous_history_entry_returns_to_the_last_shown_entry() {
+        let ctx = egui::Context::default();
+        let mut app = DicomViewerApp {
+            history_entries: vec![
+                single_history_entry(&ctx, "a.dcm", "history-toggle-a"),
+                single_history_entry(&ctx, "b.dcm", "history-toggle-b"),
+                single_history_entry(&ctx, "c.dcm", "history-toggle-c"),
+            ],
+            ..Default::default()
+        };
+        app.preferences.instant_history_cycling = true;
+        let displayed = |app: &DicomViewerApp| {
+            app.current_single_path
+                .as_ref()
+                .map(|path| path.display_label().to_string())
+        };
+
+        app.open_history_entry(2, &ctx);
+        app.track_displayed_history_entry();
+        app.toggle_previous_history_entry(&ctx);
+        assert_eq!(displayed(&app).as_deref(), Some("c.dcm"));
+
+        app.open_history_entry(0, &ctx);
+        app.track_displayed_history_entry();
+        app.toggle_previous_history_entry(&ctx);
+        app.track_displayed_history_entry();
+        assert_eq!(displayed(&app).as_deref(), Some("c.dcm"));
+        app.toggle_previous_history_entry(&ctx);
+        app.track_displayed_history_entry();
+        assert_eq!(displayed(&app).as_deref(), Some("a.dcm"));
+
+        app.preferences.instant_history_cycling = false;
+        app.toggle_previous_history_entry(&ctx);
+        let pending = app.pending_history_open_id.clone();
+        assert_eq!(pending, Some(app.history_entries[2].id.clone()));
+        app.toggle_previous_history_entry(&ctx);
+        assert_eq!(
+            app.pending_history_open_id,
+            Some(app.history_entries[0].id.clone())
+        );
+    }
+
+    #[test]
+    fn step_worklist_queues_the_neighbouring_study_and_stops_at_the_ends() {
+        let study = |name: &str| LaunchRequest::LocalPaths(vec![PathBuf::from(name)]);
+        let mut app = DicomViewerApp::default();
+        app.set_worklist(vec![study("a.dcm"), study("b.dcm")]);
+        assert_eq!(app.pending_launch_request, Some(study("a.dcm")));
+
+        app.pending_launch_request = None;
+        app.step_worklist(-1);
+        assert_eq!(app.pending_launch_request, None);
+
+        app.step_worklist(1);
+        assert_eq!(app.worklist_index, 1);
+        assert_eq!(app.pending_launch_request, Some(study("b.dcm")));
+
+        app.pending_launch_request = None;
+        app.step_worklist(1);
+        assert_eq!(app.worklist_index, 1);
+        assert_eq!(app.pending_launch_request, None);
+    }
+
+    #[test]
+    fn flip_group_page_wraps_and_skips_pages_still_loading() {
+        let ctx = egui::Context::default();
+        let entries = vec![
+            single_history_entry(&ctx, "current.dcm", "group-page-current"),
+            single_history_entry(&ctx, "prior.dcm", "group-page-prior"),
+        ];
+        let loading_id = history_id_from_paths(&[PathBuf::from("older.dcm")]);
+        let mut app = DicomViewerApp {
+            group_pages: vec![entries[0].id.clone(), entries[1].id.clone(), loading_id],
+            history_entries: entries,
+            ..Default::default()
+        };
+        app.preferences.instant_history_cycling = true;
+        app.open_history_entry(0, &ctx);
+        assert_eq!(app.active_group_page(), Some(0));
+
+        app.flip_group_page(1, &ctx);
+        assert_eq!(app.active_group_page(), Some(1));
+        app.flip_group_page(1, &ctx);
+        assert_eq!(app.active_group_page(), Some(0));
+        app.flip_group_page(-1, &ctx);
+        assert_eq!(app.active_group_page(), Some(1));
+    }
+
+    #[test]
+    fn instant_history_cycling_opens_decoded_entries_without_deferral() {
+        let ctx = egui::Context::default();
+        let mut undecoded = single_history_entry(&ctx, "a.dcm", "history-instant-a");
+        if let HistoryKind::Single(single) = &mut undecoded.kind {
+            single.image = DicomImage::test_stub_with_lazy_mono_cache(&[(0, 1), (2, 3)]);
+            single.current_frame = 1;
+        }
+        let mut app = DicomViewerApp {
+            history_entries: vec![
+                undecoded,
+                single_history_entry(&ctx, "b.dcm", "history-instant-b"),
+            ],
+            ..Default::default()
+        };
+        app.preferences.instant_history_cycling = true;
+        app.open_history_entry(0, &ctx);
+
+        app.cycle_history_entry(1, &ctx);
+        assert!(app.pending_history_open_id.is_none());
+        assert_eq!(
+            app.current_single_path
+                .as_ref()
+                .map(DicomSourceMeta::display_label),
+            Some("b.dcm")
+        );
+
+        app.cycle_history_entry(1, &ctx);
+        assert!(app.pending_history_open_id.is_some());
+        assert_eq!(
+            app.current_single_path
+                .as_ref()
+                .map(DicomSourceMeta::display_label),
+            Some("b.dcm")
+        );
+    }
+
     #[test]
     fn open_history_entry_single_hides_streaming_group_placeholders() {
         let ctx = egui::Context::default();
         let (_tx, rx) = mpsc::channel::<DicomWebGroupStreamUpdate>();
         let (_single_tx, single_rx) = mpsc::channel::<Result<PendingSingleLoad, String>>();
-        let (_mammo_tx, mammo_rx) = mpsc::channel::<Result<PendingLoad, String>>();
+        let (_mammo_tx, mammo_rx) = mpsc::channel::<PendingLoad>();
         let mut app = DicomViewerApp {
             dicomweb_active_group_expected: Some(2),
             dicomweb_active_path_receiver: Some(rx),
@@ -5398,6 +10151,7 @@ mod tests {
                     cine_fps: DEFAULT_CINE_FPS,
                 })),
                 thumbs: Vec::new(),
+                sort_key: None,
             }],
             ..Default::default()
         };
@@ -5413,6 +10167,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sync_current_state_to_history_keeps_the_window_subtraction_replaced() {
+        let ctx = egui::Context::default();
+        let mut app = DicomViewerApp {
+            image: Some(DicomImage::test_stub_with_mono_frames(None, 3)),
+            current_single_path: Some(test_meta("subtracted.dcm")),
+            window_center: 40.0,
+            window_width: 400.0,
+            history_entries: vec![single_history_entry(&ctx, "subtracted.dcm", "subtracted")],
+            ..Default::default()
+        };
+
+        app.toggle_subtraction(&ctx);
+        assert_eq!(app.window_center, 0.0);
+        app.sync_current_state_to_history();
+
+        let HistoryKind::Single(single) = &app.history_entries[0].kind else {
+            panic!("expected single history entry");
+        };
+        assert_eq!((single.window_center, single.window_width), (40.0, 400.0));
+        // The live view stays subtracted.
+        assert_eq!(app.active_subtraction_mask(), Some(0));
+    }
+
     #[test]
     fn sync_current_state_to_history_persists_group_view_gsps_removal() {
         let ctx = egui::Context::default();
@@ -5449,6 +10227,7 @@ mod tests {
                     zoom: 1.0,
                     pan: egui::Vec2::ZERO,
                     frame_scroll_accum: 0.0,
+                    window_preset_index: None,
                 }),
                 Some(MammoViewport {
                     path: path_b.clone(),
@@ -5462,6 +10241,7 @@ mod tests {
                     zoom: 1.0,
                     pan: egui::Vec2::ZERO,
                     frame_scroll_accum: 0.0,
+                    window_preset_index: None,
                 }),
             ],
             history_entries: vec![HistoryEntry {
@@ -5492,6 +10272,7 @@ mod tests {
                     selected_index: 0,
                 }),
                 thumbs: Vec::new(),
+                sort_key: None,
             }],
             ..Default::default()
         };
@@ -5556,6 +10337,7 @@ mod tests {
                     selected_index: 0,
                 }),
                 thumbs: Vec::new(),
+                sort_key: None,
             }],
             ..Default::default()
         };
@@ -5616,6 +10398,7 @@ mod tests {
                     selected_index: 0,
                 }),
                 thumbs: Vec::new(),
+                sort_key: None,
             }],
             ..Default::default()
         };
@@ -5676,6 +10459,7 @@ mod tests {
                     zoom: 1.0,
                     pan: egui::Vec2::ZERO,
                     frame_scroll_accum: 0.0,
+                    window_preset_index: None,
                 }),
                 Some(MammoViewport {
                     path: test_meta("group-b.dcm"),
@@ -5689,6 +10473,7 @@ mod tests {
                     zoom: 1.0,
                     pan: egui::Vec2::ZERO,
                     frame_scroll_accum: 0.0,
+                    window_preset_index: None,
                 }),
             ],
             history_entries: vec![report_history_entry(
@@ -5760,6 +10545,13 @@ mod tests {
         local_app.preload_non_active_groups_into_history(&groups, 0, None, &ctx);
 
         assert_eq!(local_app.history_preload_queue.len(), 2);
+        assert_eq!(
+            local_app.group_pages,
+            groups
+                .iter()
+                .map(|group| history_id_from_paths(&group.image_paths))
+                .collect::<Vec<_>>()
+        );
 
         let (_tx, rx) = mpsc::channel::<Result<HistoryPreloadResult, String>>();
         let mut dicomweb_app = DicomViewerApp {
@@ -5840,6 +10632,83 @@ mod tests {
         let _ = std::fs::remove_file(&path);
     }
 
+    #[test]
+    fn incomplete_mammo_group_message_lists_missing_views() {
+        let loaded = vec![
+            ("RCC".to_string(), "RCC (a.dcm)".to_string()),
+            (
+                "LCC For Processing".to_string(),
+                "LCC For Processing (b.dcm)".to_string(),
+            ),
+            (String::new(), "c.dcm".to_string()),
+        ];
+        let (summary, detail) = DicomViewerApp::incomplete_mammo_group_message(4, &loaded);
+        assert_eq!(
+            summary,
+            "Multi-view group load incomplete: 3 of 4 images loaded."
+        );
+        assert_eq!(
+            detail,
+            "Loaded: RCC (a.dcm), LCC For Processing (b.dcm), c.dcm. Missing: RMLO, LMLO. \
+             1 loaded image(s) have no usable laterality/view tags."
+        );
+
+        let (_, empty_detail) = DicomViewerApp::incomplete_mammo_group_message(2, &[]);
+        assert_eq!(empty_detail, "No image was received.");
+    }
+
+    #[test]
+    fn downloading_background_group_can_be_opened_and_keeps_streaming() {
+        let (tx, rx) = mpsc::channel::<DicomWebGroupStreamUpdate>();
+        tx.send(DicomWebGroupStreamUpdate::BackgroundGroupInstanceCount {
+            group_index: 1,
+            count: 4,
+        })
+        .expect("background count should send");
+        tx.send(DicomWebGroupStreamUpdate::BackgroundGroupPath {
+            group_index: 1,
+            path: test_source("background-1.dcm"),
+        })
+        .expect("background path should send");
+
+        let ctx = egui::Context::default();
+        let mut app = DicomViewerApp {
+            dicomweb_active_path_receiver: Some(rx),
+            ..Default::default()
+        };
+        app.poll_dicomweb_active_paths(&ctx);
+
+        let download = app
+            .dicomweb_background_group_downloads
+            .get(&1)
+            .expect("background group progress should be tracked");
+        assert_eq!(download.expected, 4);
+        assert_eq!(download.paths, vec![test_source("background-1.dcm")]);
+        assert!(app.dicomweb_active_group_paths.is_empty());
+
+        app.open_downloading_background_group(1, &ctx);
+        assert!(app.dicomweb_background_group_downloads.is_empty());
+        assert_eq!(app.dicomweb_open_group_override, Some(1));
+        assert!(app.dicomweb_completed_background_groups.contains(&1));
+        assert_eq!(app.dicomweb_active_group_expected, Some(4));
+        assert_eq!(app.mammo_group.len(), 4);
+        assert_eq!(
+            app.dicomweb_active_pending_paths,
+            VecDeque::from([test_source("background-1.dcm")])
+        );
+
+        tx.send(DicomWebGroupStreamUpdate::BackgroundGroupPath {
+            group_index: 1,
+            path: test_source("background-2.dcm"),
+        })
+        .expect("later background path should send");
+        app.poll_dicomweb_active_paths(&ctx);
+        assert_eq!(
+            app.dicomweb_active_group_paths,
+            vec![test_meta("background-1.dcm"), test_meta("background-2.dcm")]
+        );
+    }
+
     #[test]
     fn poll_dicomweb_active_paths_stages_streamed_structured_report_in_history() {
         let path = write_test_structured_report_file("streamed-active-report");
@@ -6036,7 +10905,7 @@ mod tests {
     }
 
     #[test]
-    fn poll_history_preload_drops_group_when_any_viewport_render_fails() {
+    fn poll_history_preload_keeps_group_with_placeholder_when_one_viewport_render_fails() {
         let (tx, rx) = mpsc::channel::<Result<HistoryPreloadResult, String>>();
         tx.send(Ok(HistoryPreloadResult::Group {
             viewports: vec![
@@ -6049,12 +10918,53 @@ mod tests {
                     DicomImage::test_stub_with_mono_frames(None, 1),
                 ),
                 (
-                    test_source("history-group-c.dcm"),
+                    test_source("history-group-c.dcm"),
+                    DicomImage::test_stub(None),
+                ),
+                (
+                    test_source("history-group-d.dcm"),
+                    DicomImage::test_stub_with_mono_frames(None, 1),
+                ),
+            ],
+        }))
+        .expect("group preload should send");
+        drop(tx);
+
+        let ctx = egui::Context::default();
+        let mut app = DicomViewerApp {
+            history_preload_receiver: Some(rx),
+            ..Default::default()
+        };
+
+        app.poll_history_preload(&ctx);
+
+        assert_eq!(app.history_entries.len(), 1);
+        let HistoryKind::Group(group) = &app.history_entries[0].kind else {
+            panic!("expected a group history entry");
+        };
+        assert_eq!(group.viewports.len(), 4);
+        assert_eq!(
+            group
+                .viewports
+                .iter()
+                .filter(|viewport| viewport.image.undecodable_reason().is_some())
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn poll_history_preload_drops_group_when_every_viewport_render_fails() {
+        let (tx, rx) = mpsc::channel::<Result<HistoryPreloadResult, String>>();
+        tx.send(Ok(HistoryPreloadResult::Group {
+            viewports: vec![
+                (
+                    test_source("history-group-a.dcm"),
                     DicomImage::test_stub(None),
                 ),
                 (
-                    test_source("history-group-d.dcm"),
-                    DicomImage::test_stub_with_mono_frames(None, 1),
+                    test_source("history-group-b.dcm"),
+                    DicomImage::test_stub(None),
                 ),
             ],
         }))
@@ -6089,6 +10999,7 @@ mod tests {
                     zoom: 1.0,
                     pan: egui::Vec2::ZERO,
                     frame_scroll_accum: 0.0,
+                    window_preset_index: None,
                 }),
                 Some(MammoViewport {
                     path: test_meta("history-b.dcm"),
@@ -6102,6 +11013,7 @@ mod tests {
                     zoom: 1.0,
                     pan: egui::Vec2::ZERO,
                     frame_scroll_accum: 0.0,
+                    window_preset_index: None,
                 }),
             ],
             dicomweb_active_group_expected: Some(2),
@@ -6148,6 +11060,110 @@ mod tests {
         assert!(app.load_error_message.is_none());
     }
 
+    #[test]
+    fn grouped_dicomweb_launch_with_single_image_open_group_loads_single_view() {
+        let study_uid = "9.999.104.1";
+        let series_uid = "9.999.104.2";
+        let single_source =
+            test_memory_image_source("open-single", study_uid, series_uid, "9.999.104.10");
+        let pair_sources = vec![
+            test_memory_image_source("pair-a", study_uid, "9.999.104.3", "9.999.104.11"),
+            test_memory_image_source("pair-b", study_uid, "9.999.104.3", "9.999.104.12"),
+        ];
+
+        let (stream_tx, stream_rx) = mpsc::channel::<DicomWebGroupStreamUpdate>();
+        stream_tx
+            .send(DicomWebGroupStreamUpdate::ActiveGroupInstanceCount(1))
+            .expect("streamed group count should send");
+        stream_tx
+            .send(DicomWebGroupStreamUpdate::ActivePath(single_source.clone()))
+            .expect("streamed single path should send");
+        drop(stream_tx);
+        let (download_tx, download_rx) = mpsc::channel::<Result<DicomWebDownloadResult, String>>();
+
+        let ctx = egui::Context::default();
+        let mut app = DicomViewerApp {
+            dicomweb_receiver: Some(download_rx),
+            dicomweb_active_path_receiver: Some(stream_rx),
+            // Left over from a previous quartet launch; the new count must replace it.
+            dicomweb_active_group_expected: Some(4),
+            ..Default::default()
+        };
+
+        app.poll_dicomweb_active_paths(&ctx);
+
+        assert_eq!(app.dicomweb_active_group_expected, Some(1));
+        assert!(app.mammo_load_receiver.is_none());
+        assert!(app.single_load_receiver.is_some());
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while app.single_load_receiver.is_some() && Instant::now() < deadline {
+            app.poll_single_load(&ctx);
+            thread::sleep(Duration::from_millis(5));
+        }
+
+        assert!(app.image.is_some());
+        assert!(app.mammo_group.is_empty());
+        assert_eq!(app.current_single_path, Some((&single_source).into()));
+
+        download_tx
+            .send(Ok(DicomWebDownloadResult::Grouped {
+                groups: vec![vec![single_source.clone()], pair_sources],
+                open_group: 0,
+            }))
+            .expect("grouped result should send");
+        app.poll_dicomweb_download(&ctx);
+
+        assert!(app.load_error_message.is_none());
+        assert!(app.image.is_some());
+        assert!(app.mammo_group.is_empty());
+        assert_eq!(app.current_single_path, Some((&single_source).into()));
+        assert_eq!(app.dicomweb_active_group_expected, None);
+        assert!(app
+            .history_entries
+            .iter()
+            .any(|entry| matches!(entry.kind, HistoryKind::Single(_))));
+    }
+
+    #[test]
+    fn grouped_dicomweb_launch_with_multi_view_open_group_replaces_stale_single_expectation() {
+        let study_uid = "9.999.105.1";
+        let series_uid = "9.999.105.2";
+        let (tx, rx) = mpsc::channel::<DicomWebGroupStreamUpdate>();
+        tx.send(DicomWebGroupStreamUpdate::ActiveGroupInstanceCount(2))
+            .expect("streamed group count should send");
+        for (name, instance_uid) in [("open-a", "9.999.105.10"), ("open-b", "9.999.105.11")] {
+            tx.send(DicomWebGroupStreamUpdate::ActivePath(
+                test_memory_image_source(name, study_uid, series_uid, instance_uid),
+            ))
+            .expect("streamed path should send");
+        }
+
+        let ctx = egui::Context::default();
+        let mut app = DicomViewerApp {
+            dicomweb_active_path_receiver: Some(rx),
+            dicomweb_active_group_expected: Some(1),
+            ..Default::default()
+        };
+
+        app.poll_dicomweb_active_paths(&ctx);
+
+        assert_eq!(app.dicomweb_active_group_expected, Some(2));
+        assert!(app.single_load_receiver.is_none());
+        assert_eq!(app.mammo_group.len(), 2);
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !app.mammo_group_complete() && Instant::now() < deadline {
+            app.poll_mammo_group_load(&ctx);
+            thread::sleep(Duration::from_millis(5));
+        }
+
+        assert!(app.mammo_group_complete());
+        assert!(app.image.is_none());
+        assert!(app.load_error_message.is_none());
+        drop(tx);
+    }
+
     #[test]
     fn poll_dicomweb_grouped_preloads_active_group_when_viewing_other_group() {
         let (tx, rx) = mpsc::channel::<Result<DicomWebDownloadResult, String>>();
@@ -6178,6 +11194,7 @@ mod tests {
                     zoom: 1.0,
                     pan: egui::Vec2::ZERO,
                     frame_scroll_accum: 0.0,
+                    window_preset_index: None,
                 }),
                 Some(MammoViewport {
                     path: test_meta("history-b.dcm"),
@@ -6191,6 +11208,7 @@ mod tests {
                     zoom: 1.0,
                     pan: egui::Vec2::ZERO,
                     frame_scroll_accum: 0.0,
+                    window_preset_index: None,
                 }),
             ],
             ..Default::default()
@@ -6264,6 +11282,7 @@ mod tests {
                     zoom: 1.0,
                     pan: egui::Vec2::ZERO,
                     frame_scroll_accum: 0.0,
+                    window_preset_index: None,
                 }),
                 Some(MammoViewport {
                     path: (&image_b_source).into(),
@@ -6277,6 +11296,7 @@ mod tests {
                     zoom: 1.0,
                     pan: egui::Vec2::ZERO,
                     frame_scroll_accum: 0.0,
+                    window_preset_index: None,
                 }),
             ],
             ..Default::default()
@@ -6415,6 +11435,7 @@ mod tests {
                     selected_index: 0,
                 }),
                 thumbs: Vec::new(),
+                sort_key: None,
             }],
             ..Default::default()
         };
@@ -6505,6 +11526,7 @@ mod tests {
                     cine_fps: DEFAULT_CINE_FPS,
                 })),
                 thumbs: Vec::new(),
+                sort_key: None,
             }],
             ..Default::default()
         };
@@ -6584,6 +11606,149 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mismatched_thumb_members_flags_small_localizer_among_mammograms() {
+        let sizes = [[3328, 4096], [256, 256], [3328, 4096], [2560, 3328]];
+        assert_eq!(
+            mismatched_thumb_members(&sizes),
+            vec![false, true, false, false]
+        );
+        assert_eq!(
+            mismatched_thumb_members(&[[2048, 2048], [1024, 1024]]),
+            vec![false, false]
+        );
+        assert!(mismatched_thumb_members(&[]).is_empty());
+    }
+
+    #[test]
+    fn history_sort_key_pads_times_and_appends_series() {
+        let metadata = |fields: &[(&str, &str)]| {
+            fields
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            history_sort_key(&metadata(&[
+                ("StudyDate", "20240102"),
+                ("StudyTime", "0930")
+            ])),
+            Some("20240102093000".to_string())
+        );
+        assert_eq!(
+            history_sort_key(&metadata(&[
+                ("StudyDate", "2024.01.02"),
+                ("StudyTime", "09:30:15.25"),
+                ("SeriesDate", "20240102"),
+                ("SeriesTime", "094500"),
+            ])),
+            Some("2024010209301520240102094500".to_string())
+        );
+        assert_eq!(
+            history_sort_key(&metadata(&[("SeriesDate", "20231231")])),
+            Some("20231231000000".to_string())
+        );
+        assert_eq!(history_sort_key(&metadata(&[("StudyDate", "2024")])), None);
+        assert_eq!(history_sort_key(&[]), None);
+    }
+
+    #[test]
+    fn history_date_order_sorts_newest_study_first_and_cycles_in_that_order() {
+        let ctx = egui::Context::default();
+        let dated = |path: &str, sort_key: Option<&str>| HistoryEntry {
+            sort_key: sort_key.map(str::to_string),
+            ..single_history_entry(&ctx, path, path)
+        };
+        let mut app = DicomViewerApp {
+            image: Some(DicomImage::test_stub(None)),
+            current_single_path: Some(test_meta("a.dcm")),
+            history_entries: vec![
+                dated("a.dcm", Some("20200101000000")),
+                dated("b.dcm", None),
+                dated("c.dcm", Some("20240101000000")),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(app.history_display_order(), vec![0, 1, 2]);
+
+        app.preferences.sort_history_by_date = true;
+        assert_eq!(app.history_display_order(), vec![2, 0, 1]);
+
+        let id = |path: &str| Some(history_id_from_paths(&[PathBuf::from(path)]));
+        app.move_history_focus(1);
+        assert_eq!(app.history_focus_id, id("b.dcm"));
+        app.move_history_focus(-1);
+        app.move_history_focus(-1);
+        assert_eq!(app.history_focus_id, id("c.dcm"));
+
+        app.cycle_history_entry(1, &ctx);
+        assert_eq!(
+            app.pending_history_open_id,
+            Some(history_id_from_paths(&[PathBuf::from("b.dcm")]))
+        );
+    }
+
+    #[test]
+    fn history_keys_need_the_history_list_hovered_or_clicked() {
+        let mut app = DicomViewerApp {
+            history_panel_rect: Some(egui::Rect::from_min_size(
+                egui::pos2(600.0, 36.0),
+                egui::vec2(170.0, 300.0),
+            )),
+            history_focus_id: Some("entry".to_string()),
+            ..Default::default()
+        };
+        let inside = egui::pos2(650.0, 100.0);
+        let outside = egui::pos2(100.0, 100.0);
+
+        assert!(app.history_keys_active(Some(inside)));
+        assert!(!app.history_keys_active(Some(outside)));
+        assert!(!app.history_keys_active(None));
+
+        app.track_history_panel_focus(Some(inside));
+        assert!(app.history_keys_active(Some(outside)));
+        app.track_history_panel_focus(None);
+        assert!(app.history_keys_active(None));
+
+        app.track_history_panel_focus(Some(outside));
+        assert!(!app.history_keys_active(Some(outside)));
+        assert_eq!(app.history_focus_id, None);
+    }
+
+    #[test]
+    fn history_focus_moves_from_current_entry_and_enter_queues_open() {
+        let ctx = egui::Context::default();
+        let mut app = DicomViewerApp {
+            image: Some(DicomImage::test_stub(None)),
+            current_single_path: Some(test_meta("b.dcm")),
+            history_entries: vec![
+                single_history_entry(&ctx, "a.dcm", "history-a"),
+                single_history_entry(&ctx, "b.dcm", "history-b"),
+                single_history_entry(&ctx, "c.dcm", "history-c"),
+            ],
+            ..Default::default()
+        };
+
+        let id = |path: &str| Some(history_id_from_paths(&[PathBuf::from(path)]));
+        app.move_history_focus(1);
+        assert_eq!(app.history_focus_id, id("c.dcm"));
+        app.move_history_focus(1);
+        assert_eq!(app.history_focus_id, id("c.dcm"));
+        app.move_history_focus(-1);
+        app.move_history_focus(-1);
+        assert_eq!(app.history_focus_id, id("a.dcm"));
+
+        // The highlight follows its entry when the list reorders.
+        app.history_entries.rotate_left(1);
+        app.open_focused_history_entry();
+        assert!(app.history_focus_id.is_none());
+        assert_eq!(
+            app.pending_history_open_id,
+            Some(history_id_from_paths(&[PathBuf::from("a.dcm")]))
+        );
+    }
+
     #[test]
     fn close_current_group_without_history_clears_viewer_state() {
         let ctx = egui::Context::default();
@@ -6673,7 +11838,7 @@ mod tests {
         }))
         .expect("grouped result should send");
 
-        let (mammo_tx, mammo_rx) = mpsc::channel::<Result<PendingLoad, String>>();
+        let (mammo_tx, mammo_rx) = mpsc::channel::<PendingLoad>();
         let mut app = DicomViewerApp {
             dicomweb_receiver: Some(rx),
             dicomweb_active_group_expected: Some(2),
@@ -6710,6 +11875,8 @@ mod tests {
             instance_uid: Some("1.2.3".to_string()),
             username: None,
             password: None,
+            wado_uri: false,
+            series_tabs: Vec::new(),
         });
 
         assert!(app.single_load_receiver.is_none());
@@ -6781,6 +11948,67 @@ mod tests {
         assert!(app.texture.is_some());
     }
 
+    #[test]
+    fn load_path_splits_only_four_frame_mammograms_into_packed_views() {
+        let ctx = egui::Context::default();
+        for (modality, sop_class_uid, expect_packed) in [
+            (
+                "MG",
+                DIGITAL_MAMMOGRAPHY_XRAY_IMAGE_PRESENTATION_SOP_CLASS_UID,
+                true,
+            ),
+            ("US", "1.2.840.10008.5.1.4.1.1.3.1", false),
+        ] {
+            let mut app = DicomViewerApp::default();
+            app.load_path(test_four_frame_source(modality, sop_class_uid), true, &ctx);
+            let result = app
+                .single_load_receiver
+                .as_ref()
+                .expect("load should start a worker")
+                .recv_timeout(Duration::from_secs(10))
+                .expect("load worker should finish");
+
+            assert_eq!(
+                matches!(result, Ok(PendingSingleLoad::PackedViews(_))),
+                expect_packed,
+                "{modality}"
+            );
+        }
+    }
+
+    #[test]
+    fn poll_single_load_shows_packed_views_as_multi_view_group() {
+        let path = test_source("packed.dcm");
+        let views = DicomViewerApp::pending_packed_views(
+            path.clone(),
+            DicomImage::test_stub_with_mono_frames(None, PACKED_MAMMO_VIEW_COUNT),
+        )
+        .expect("eager frames should split into views");
+        let (tx, rx) = mpsc::channel::<Result<PendingSingleLoad, String>>();
+        tx.send(Ok(PendingSingleLoad::PackedViews(views)))
+            .expect("success should send");
+
+        let mut app = DicomViewerApp {
+            single_load_receiver: Some(rx),
+            ..Default::default()
+        };
+
+        let ctx = egui::Context::default();
+        app.poll_single_load(&ctx);
+
+        assert!(app.image.is_none());
+        assert!(app.mammo_group_complete());
+        assert_eq!(app.mammo_group.len(), PACKED_MAMMO_VIEW_COUNT);
+        let labels = app
+            .loaded_mammo_viewports()
+            .map(|viewport| viewport.label.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(labels[0], "Frame 1 (packed.dcm)");
+        assert_eq!(labels[3], "Frame 4 (packed.dcm)");
+        assert!(app.displayed_study_matches_paths(&[path]));
+        assert!(app.history_pushed_for_active_group);
+    }
+
     #[test]
     fn pending_load_uses_first_renderable_frame() {
         let image = DicomImage::test_stub_with_lazy_mono_cache(&[(1, 7)]);
@@ -6815,12 +12043,12 @@ mod tests {
 
     #[test]
     fn poll_mammo_group_load_drains_all_available_images_in_one_repaint() {
-        let (tx, rx) = mpsc::channel::<Result<PendingLoad, String>>();
+        let (tx, rx) = mpsc::channel::<PendingLoad>();
         for path in ["one.dcm", "two.dcm", "three.dcm"] {
-            tx.send(Ok(test_pending_load(
+            tx.send(test_pending_load(
                 path,
                 DicomImage::test_stub_with_mono_frames(None, 1),
-            )))
+            ))
             .expect("pending mammo image should send");
         }
         drop(tx);
@@ -6842,14 +12070,13 @@ mod tests {
 
     #[test]
     fn poll_mammo_group_load_uses_pending_initial_frame() {
-        let (tx, rx) = mpsc::channel::<Result<PendingLoad, String>>();
+        let (tx, rx) = mpsc::channel::<PendingLoad>();
         let mut pending = test_pending_load(
             "initial-frame.dcm",
             DicomImage::test_stub_with_mono_frames(None, 3),
         );
         pending.initial_frame = 2;
-        tx.send(Ok(pending))
-            .expect("pending mammo image should send");
+        tx.send(pending).expect("pending mammo image should send");
         drop(tx);
 
         let mut app = DicomViewerApp {
@@ -6871,15 +12098,205 @@ mod tests {
     }
 
     #[test]
-    fn poll_mammo_group_load_keeps_error_when_batch_contains_failure() {
-        let (tx, rx) = mpsc::channel::<Result<PendingLoad, String>>();
-        tx.send(Ok(test_pending_load(
+    fn group_member_that_cannot_be_opened_keeps_the_group_and_is_retryable() {
+        let (tx, rx) = mpsc::channel::<PendingLoad>();
+        tx.send(test_pending_load(
             "one.dcm",
             DicomImage::test_stub_with_mono_frames(None, 1),
-        )))
+        ))
         .expect("pending mammo image should send");
-        tx.send(Err("decode failed".to_string()))
-            .expect("pending mammo failure should send");
+        tx.send(DicomViewerApp::pending_group_member_load(
+            test_source("missing.dcm"),
+            &DicomLoadOptions::default(),
+        ))
+        .expect("pending mammo placeholder should send");
+        drop(tx);
+
+        let mut app = DicomViewerApp {
+            mammo_group: vec![None, None],
+            mammo_load_receiver: Some(rx),
+            ..Default::default()
+        };
+
+        let ctx = egui::Context::default();
+        app.poll_mammo_group_load(&ctx);
+
+        assert!(app.mammo_group_complete());
+        assert_eq!(app.undecodable_mammo_count(), 1);
+        assert!(app.load_error_message.is_none());
+        assert!(app
+            .mammo_failed_views
+            .contains_key(test_source("missing.dcm").identity_key().as_ref()));
+    }
+
+    #[test]
+    fn streamed_download_failure_becomes_a_cell_that_retries_the_download() {
+        let stand_in =
+            DicomSource::from_memory_with_identity("inst_2", "inst-2-identity", Vec::new());
+        let retry = Box::new(InstanceRedownload::test_stub("inst_2"));
+        let (mammo_tx, mammo_rx) = mpsc::channel::<PendingLoad>();
+        mammo_tx
+            .send(test_pending_load(
+                "one.dcm",
+                DicomImage::test_stub_with_mono_frames(None, 1),
+            ))
+            .expect("pending mammo image should send");
+        let mut app = DicomViewerApp {
+            mammo_group: vec![None, None],
+            mammo_load_receiver: Some(mammo_rx),
+            mammo_load_sender: Some(mammo_tx),
+            dicomweb_active_group_expected: Some(2),
+            ..Default::default()
+        };
+        let (tx, rx) = mpsc::channel::<DicomWebGroupStreamUpdate>();
+        tx.send(DicomWebGroupStreamUpdate::ActiveDownloadFailed {
+            path: stand_in.clone(),
+            error: "connection reset".to_string(),
+            retry,
+        })
+        .expect("failed download should send");
+        app.dicomweb_active_path_receiver = Some(rx);
+
+        let ctx = egui::Context::default();
+        app.poll_dicomweb_active_paths(&ctx);
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !app.mammo_group_complete() && Instant::now() < deadline {
+            app.poll_mammo_group_load(&ctx);
+            thread::sleep(Duration::from_millis(5));
+        }
+
+        assert!(app.mammo_group_complete());
+        assert_eq!(app.undecodable_mammo_count(), 1);
+        let failed = app
+            .mammo_failed_views
+            .get("inst-2-identity")
+            .expect("the failed download should be retryable");
+        assert!(failed.redownload.is_some());
+        assert!(app.dicomweb_failed_downloads.is_empty());
+        let reason = app
+            .mammo_group
+            .iter()
+            .flatten()
+            .find_map(|viewport| viewport.image.undecodable_reason())
+            .expect("the placeholder should carry its reason");
+        assert!(reason.contains("connection reset"));
+    }
+
+    #[test]
+    fn clear_active_study_drops_failed_views() {
+        let mut app = DicomViewerApp::default();
+        app.mammo_failed_views.insert(
+            "two".to_string(),
+            FailedMammoView {
+                source: test_source("two.dcm"),
+                redownload: None,
+                retry: None,
+            },
+        );
+        app.dicomweb_failed_downloads.insert(
+            "three".to_string(),
+            FailedInstanceDownload {
+                error: "connection reset".to_string(),
+                retry: Box::new(InstanceRedownload::test_stub("inst_3")),
+            },
+        );
+
+        app.clear_active_study();
+
+        assert!(app.mammo_failed_views.is_empty());
+        assert!(app.dicomweb_failed_downloads.is_empty());
+    }
+
+    #[test]
+    fn poll_mammo_group_load_keeps_group_with_undecodable_placeholder() {
+        let (tx, rx) = mpsc::channel::<PendingLoad>();
+        tx.send(test_pending_load(
+            "one.dcm",
+            DicomImage::test_stub_with_mono_frames(None, 3),
+        ))
+        .expect("pending mammo image should send");
+        tx.send(test_pending_load(
+            "two.dcm",
+            DicomImage::test_stub_with_mono_frames(None, 3)
+                .into_undecodable_placeholder("decode failed"),
+        ))
+        .expect("pending mammo placeholder should send");
+        drop(tx);
+
+        let mut app = DicomViewerApp {
+            mammo_group: vec![None, None],
+            mammo_load_receiver: Some(rx),
+            ..Default::default()
+        };
+
+        let ctx = egui::Context::default();
+        app.poll_mammo_group_load(&ctx);
+
+        assert!(app.mammo_group_complete());
+        assert_eq!(app.undecodable_mammo_count(), 1);
+        assert_eq!(app.mammo_group_common_frame_count(), 3);
+        assert!(app.load_error_message.is_none());
+    }
+
+    #[test]
+    fn retried_placeholder_view_replaces_only_its_own_cell() {
+        let (tx, rx) = mpsc::channel::<PendingLoad>();
+        tx.send(test_pending_load(
+            "one.dcm",
+            DicomImage::test_stub_with_mono_frames(None, 3),
+        ))
+        .expect("pending mammo image should send");
+        tx.send(test_pending_load(
+            "two.dcm",
+            DicomImage::test_stub_with_mono_frames(None, 3)
+                .into_undecodable_placeholder("decode failed"),
+        ))
+        .expect("pending mammo placeholder should send");
+        drop(tx);
+
+        let mut app = DicomViewerApp {
+            mammo_group: vec![None, None],
+            mammo_load_receiver: Some(rx),
+            ..Default::default()
+        };
+        let ctx = egui::Context::default();
+        app.poll_mammo_group_load(&ctx);
+        let key = test_source("two.dcm").identity_key().into_owned();
+        assert!(app.mammo_failed_views.contains_key(&key));
+
+        let (retry_tx, retry_rx) = mpsc::channel::<Result<PendingLoad, String>>();
+        retry_tx
+            .send(Ok(test_pending_load(
+                "two.dcm",
+                DicomImage::test_stub_with_mono_frames(None, 3),
+            )))
+            .expect("retried mammo image should send");
+        if let Some(failed) = app.mammo_failed_views.get_mut(&key) {
+            failed.retry = Some(retry_rx);
+        }
+        app.poll_mammo_view_retries(&ctx);
+
+        assert!(app.mammo_group_complete());
+        assert_eq!(app.undecodable_mammo_count(), 0);
+        assert!(app.mammo_failed_views.is_empty());
+        assert!(app
+            .mammo_group
+            .iter()
+            .flatten()
+            .any(|viewport| viewport.path.display_label() == "one.dcm"));
+    }
+
+    #[test]
+    fn poll_mammo_group_load_fails_when_every_image_is_a_placeholder() {
+        let (tx, rx) = mpsc::channel::<PendingLoad>();
+        for path in ["one.dcm", "two.dcm"] {
+            tx.send(test_pending_load(
+                path,
+                DicomImage::test_stub_with_mono_frames(None, 1)
+                    .into_undecodable_placeholder("decode failed"),
+            ))
+            .expect("pending mammo placeholder should send");
+        }
         drop(tx);
 
         let mut app = DicomViewerApp {
@@ -6891,10 +12308,7 @@ mod tests {
         let ctx = egui::Context::default();
         app.poll_mammo_group_load(&ctx);
 
-        assert!(app.mammo_load_receiver.is_none());
-        assert!(app.mammo_load_sender.is_none());
         assert!(app.mammo_group.is_empty());
-        assert_eq!(app.loaded_mammo_count(), 0);
         assert_eq!(
             app.load_error_message.as_deref(),
             Some("Failed to load multi-view DICOM group.")
@@ -6986,6 +12400,7 @@ mod tests {
                     cine_fps: DEFAULT_CINE_FPS,
                 })),
                 thumbs: Vec::new(),
+                sort_key: None,
             }],
             ..Default::default()
         };
@@ -0,0 +1,157 @@
+use super::*;
+
+const VIEW_CONTEXT_MENU_ID: &str = "view-context-menu";
+
+/// Ctrl+right click (Cmd+right click on macOS) opens the view menu. A plain
+/// right click stays with measurement and the right-drag window/level gesture.
+pub(super) fn view_context_menu_requested(response: &egui::Response) -> bool {
+    response.secondary_clicked() && response.ctx.input(|input| input.modifiers.command)
+}
+
+impl DicomViewerApp {
+    /// Shows the view context menu anchored where it was opened. `response`
+    /// is the single canvas or the selected multi-view cell.
+    pub(super) fn show_view_context_menu(&mut self, response: &egui::Response, open: bool) {
+        let ctx = response.ctx.clone();
+        let open_command = if open {
+            Some(egui::SetOpenCommand::Bool(true))
+        } else if response.clicked() {
+            Some(egui::SetOpenCommand::Bool(false))
+        } else {
+            None
+        };
+        egui::Popup::menu(response)
+            .id(egui::Id::new(VIEW_CONTEXT_MENU_ID))
+            .open_memory(open_command)
+            .at_pointer_fixed()
+            .show(|ui| self.view_context_menu_contents(ui, &ctx));
+    }
+
+    fn view_context_menu_contents(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
+        let Some(state) = self.active_viewport_state() else {
+            ui.close();
+            return;
+        };
+
+        if ui
+            .add(egui::Button::new("Reset View").shortcut_text("Double-click"))
+            .clicked()
+        {
+            self.reset_active_view();
+        }
+        if ui
+            .add(egui::Button::new("Center View").shortcut_text("0"))
+            .clicked()
+        {
+            self.center_active_view();
+        }
+        if ui
+            .add_enabled(
+                state.is_monochrome,
+                egui::Button::new("Invert").shortcut_text("Shift+I"),
+            )
+            .clicked()
+            && self.toggle_active_invert()
+        {
+            self.refresh_active_textures(ctx);
+        }
+        if ui
+            .add_enabled(
+                self.can_toggle_montage(),
+                egui::Button::new("Frame Montage").shortcut_text("M"),
+            )
+            .clicked()
+        {
+            self.toggle_montage();
+        }
+        let promote_label = if self.promoted_cell.is_some() {
+            "Back to Multi-View"
+        } else {
+            "Show Cell Alone"
+        };
+        if ui
+            .add_enabled(
+                self.can_toggle_promoted_cell(),
+                egui::Button::new(promote_label).shortcut_text("F"),
+            )
+            .clicked()
+        {
+            self.toggle_promoted_cell(ctx);
+        }
+
+        ui.separator();
+        let can_subtract = state.is_single && self.can_subtract_frames();
+        if ui
+            .add_enabled(
+                can_subtract,
+                egui::Button::new("Set Mask Frame").shortcut_text("Shift+S"),
+            )
+            .clicked()
+        {
+            self.set_subtraction_mask(ctx);
+        }
+        if ui
+            .add_enabled(
+                can_subtract,
+                egui::Button::new("Subtract Mask")
+                    .selected(self.active_subtraction_mask().is_some())
+                    .shortcut_text("S"),
+            )
+            .clicked()
+        {
+            self.toggle_subtraction(ctx);
+        }
+
+        ui.separator();
+        ui.add_enabled_ui(state.is_monochrome && !self.window_level_locked, |ui| {
+            ui.menu_button("Window Preset", |ui| {
+                if ui.button("Default").clicked() {
+                    self.apply_active_window(state.default_center, state.default_width, None, ctx);
+                }
+                for (index, preset) in state.window_presets.iter().enumerate() {
+                    if ui.button(&preset.label).clicked() {
+                        self.apply_active_window(preset.center, preset.width, Some(index), ctx);
+                    }
+                }
+            });
+        });
+
+        ui.separator();
+        if ui
+            .add(
+                egui::Button::new("Copy Frame").shortcut_text(ctx.format_shortcut(
+                    &egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::C),
+                )),
+            )
+            .clicked()
+        {
+            if let Err(err) = self.copy_active_frame_to_clipboard() {
+                log::warn!("Could not copy frame: {err:#}");
+            }
+        }
+        if ui.button("Export Frame...").clicked() {
+            ui.close();
+            self.open_export_dialog();
+        }
+
+        ui.separator();
+        if ui
+            .add_enabled(
+                self.has_live_measurement(),
+                egui::Button::new("Clear Measurement").shortcut_text("Esc"),
+            )
+            .clicked()
+        {
+            self.clear_live_measurement();
+        }
+        if ui
+            .add_enabled(
+                self.single_measurement_bounds().is_some(),
+                egui::Button::new("Zoom to Measurement").shortcut_text("Z"),
+            )
+            .clicked()
+        {
+            self.request_measurement_zoom();
+        }
+    }
+}
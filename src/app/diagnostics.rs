@@ -0,0 +1,143 @@
+use super::*;
+use crate::dicom::preload_configuration_summary;
+
+const EGUI_VERSION: &str = env!("PERSPECTA_EGUI_VERSION");
+const EFRAME_VERSION: &str = env!("PERSPECTA_EFRAME_VERSION");
+const ENV_PREFIX: &str = "PERSPECTA_";
+// Tuning variables whose values are safe to show; any other `PERSPECTA_*`
+// value could hold a credential or PACS URL and is redacted.
+const DIAGNOSTIC_ENV_ALLOW_LIST: &[&str] = &[
+    "PERSPECTA_HTTP_CONNECT_TIMEOUT",
+    "PERSPECTA_HTTP_TIMEOUT",
+    "PERSPECTA_MAX_TEXTURE_SIDE",
+    "PERSPECTA_OPEN_RAW_DATASETS",
+    "PERSPECTA_PRELOAD_WORKERS",
+    "PERSPECTA_VERIFY_DICOMWEB_VIEWS",
+];
+
+impl DicomViewerApp {
+    /// Records which rendering backend eframe was started with, for the About
+    /// panel. eframe does not report the backend back to the app.
+    pub fn set_renderer_name(&mut self, renderer: impl Into<String>) {
+        self.renderer_name = Some(renderer.into());
+    }
+
+    /// Label/value rows for the About panel, in display order.
+    pub(super) fn diagnostics_rows(&self, texture_count: usize) -> Vec<(&'static str, String)> {
+        let mut rows = vec![
+            ("Perspecta", APP_VERSION.to_string()),
+            ("egui", EGUI_VERSION.to_string()),
+            ("eframe", EFRAME_VERSION.to_string()),
+            (
+                "Renderer",
+                self.renderer_name
+                    .clone()
+                    .unwrap_or_else(|| "unknown".to_string()),
+            ),
+            (
+                "Settings file",
+                self.settings_path.as_ref().map_or_else(
+                    || "unavailable".to_string(),
+                    |path| path.display().to_string(),
+                ),
+            ),
+            ("Max texture side", max_texture_side_summary()),
+            ("Frame preload", preload_configuration_summary()),
+        ];
+
+        let env_values = diagnostics_environment(std::env::vars());
+        rows.push((
+            "Environment",
+            if env_values.is_empty() {
+                format!("no {ENV_PREFIX}* variables set")
+            } else {
+                env_values.join("\n")
+            },
+        ));
+
+        let (image_count, decoded_frames, total_frames) = self
+            .image
+            .iter()
+            .chain(
+                self.loaded_mammo_viewports()
+                    .map(|viewport| &viewport.image),
+            )
+            .fold((0, 0, 0), |(images, decoded, total), image| {
+                (
+                    images + 1,
+                    decoded + image.loaded_frame_count(),
+                    total + image.frame_count(),
+                )
+            });
+        rows.push(("Open images", image_count.to_string()));
+        rows.push((
+            "Decoded frames",
+            format!("{decoded_frames} of {total_frames}"),
+        ));
+        rows.push(("Textures", texture_count.to_string()));
+        rows.push(("History entries", self.history_entries.len().to_string()));
+        rows
+    }
+
+    pub(super) fn show_about_panel(&mut self, ctx: &egui::Context) {
+        if !self.about_panel_open {
+            return;
+        }
+
+        let texture_count = ctx.tex_manager().read().num_allocated();
+        let rows = self.diagnostics_rows(texture_count);
+        let mut close_clicked = false;
+        let modal = egui::Modal::new(egui::Id::new("about-diagnostics")).show(ctx, |ui| {
+            ui.label(egui::RichText::new("About Perspecta").size(TITLE_TEXT_SIZE));
+            ui.add_space(4.0);
+            egui::Grid::new("about-diagnostics-grid")
+                .num_columns(2)
+                .striped(true)
+                .show(ui, |ui| {
+                    for (label, value) in &rows {
+                        ui.label(*label);
+                        ui.monospace(value);
+                        ui.end_row();
+                    }
+                });
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if ui.button("Copy to Clipboard").clicked() {
+                    let text = rows
+                        .iter()
+                        .map(|(label, value)| format!("{label}: {value}"))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    ui.ctx().copy_text(text);
+                }
+                if ui.button("Close").clicked() {
+                    close_clicked = true;
+                }
+            });
+        });
+
+        if close_clicked || modal.should_close() {
+            self.about_panel_open = false;
+        }
+    }
+}
+
+/// `NAME=value` lines for the `PERSPECTA_*` variables in `vars`, sorted, with
+/// values outside the allow-list redacted.
+pub(super) fn diagnostics_environment(
+    vars: impl IntoIterator<Item = (String, String)>,
+) -> Vec<String> {
+    let mut env_values = vars
+        .into_iter()
+        .filter(|(name, _)| name.starts_with(ENV_PREFIX))
+        .map(|(name, value)| {
+            if DIAGNOSTIC_ENV_ALLOW_LIST.contains(&name.as_str()) {
+                format!("{name}={value}")
+            } else {
+                format!("{name}=<redacted>")
+            }
+        })
+        .collect::<Vec<_>>();
+    env_values.sort();
+    env_values
+}
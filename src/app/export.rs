@@ -0,0 +1,288 @@
+use super::*;
+
+const DEFAULT_JPEG_QUALITY: u8 = 90;
+
+/// File format for exported frames. PNG is lossless and the default; JPEG
+/// trades fidelity for smaller files when sharing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(super) enum ExportFormat {
+    #[default]
+    Png,
+    Jpeg,
+}
+
+impl ExportFormat {
+    pub(super) const ALL: [Self; 2] = [Self::Png, Self::Jpeg];
+
+    pub(super) fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "png" => Some(Self::Png),
+            "jpeg" | "jpg" => Some(Self::Jpeg),
+            _ => None,
+        }
+    }
+
+    pub(super) fn setting_value(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpeg",
+        }
+    }
+
+    pub(super) fn label(self) -> &'static str {
+        match self {
+            Self::Png => "PNG (lossless)",
+            Self::Jpeg => "JPEG",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+        }
+    }
+}
+
+/// JPEG quality from 1 to 100, defaulting to 90.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct JpegQuality(pub(super) u8);
+
+impl Default for JpegQuality {
+    fn default() -> Self {
+        Self(DEFAULT_JPEG_QUALITY)
+    }
+}
+
+impl JpegQuality {
+    pub(super) fn parse(value: f32) -> Option<Self> {
+        (1.0..=100.0)
+            .contains(&value)
+            .then(|| Self(value.round() as u8))
+    }
+}
+
+/// Format and quality picked for one export, seeded from the settings.
+pub(super) struct FrameExportDialog {
+    format: ExportFormat,
+    jpeg_quality: JpegQuality,
+}
+
+impl DicomViewerApp {
+    pub(super) fn open_export_dialog(&mut self) {
+        self.export_dialog = Some(FrameExportDialog {
+            format: self.preferences.export_format,
+            jpeg_quality: self.preferences.export_jpeg_quality,
+        });
+    }
+
+    pub(super) fn show_export_dialog(&mut self, ctx: &egui::Context) {
+        let Some(dialog) = self.export_dialog.as_mut() else {
+            return;
+        };
+
+        let mut save = false;
+        let mut cancelled = false;
+        let modal = egui::Modal::new(egui::Id::new("export-frame")).show(ctx, |ui| {
+            ui.label(egui::RichText::new("Export Frame").size(TITLE_TEXT_SIZE));
+            ui.add_space(4.0);
+            for format in ExportFormat::ALL {
+                ui.radio_value(&mut dialog.format, format, format.label());
+            }
+            ui.add_enabled_ui(dialog.format == ExportFormat::Jpeg, |ui| {
+                ui.add(egui::Slider::new(&mut dialog.jpeg_quality.0, 1..=100).text("Quality"));
+            });
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if ui.button("Save...").clicked() {
+                    save = true;
+                }
+                if ui.button("Cancel").clicked() {
+                    cancelled = true;
+                }
+            });
+        });
+
+        let (format, jpeg_quality) = (dialog.format, dialog.jpeg_quality);
+        if save || cancelled || modal.should_close() {
+            self.export_dialog = None;
+        }
+        if save {
+            self.export_active_frame_with_file_dialog(format, jpeg_quality);
+        }
+    }
+
+    /// Saves the displayed frame, with its window/level and visible overlay,
+    /// at full image resolution.
+    fn export_active_frame(
+        &self,
+        path: &Path,
+        format: ExportFormat,
+        jpeg_quality: JpegQuality,
+    ) -> anyhow::Result<()> {
+        let color_image = self
+            .active_frame_color_image()
+            .context("No decoded frame to export")?;
+        let file = fs::File::create(path)
+            .with_context(|| format!("Could not create {}", path.display()))?;
+        let writer = std::io::BufWriter::new(file);
+        match format {
+            ExportFormat::Png => write_png(writer, &color_image)?,
+            ExportFormat::Jpeg => write_jpeg(writer, &color_image, jpeg_quality)?,
+        }
+        let [width, height] = color_image.size;
+        log::info!("Exported {width}x{height} frame to {}.", path.display());
+        Ok(())
+    }
+
+    fn export_active_frame_with_file_dialog(
+        &self,
+        format: ExportFormat,
+        jpeg_quality: JpegQuality,
+    ) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter(format.label(), &[format.extension()])
+            .set_file_name(format!("frame.{}", format.extension()))
+            .save_file()
+        else {
+            return;
+        };
+        if let Err(err) = self.export_active_frame(&path, format, jpeg_quality) {
+            log::warn!("Could not export frame: {err:#}");
+        }
+    }
+
+    pub(super) fn show_export_settings(&mut self, ui: &mut egui::Ui) {
+        ui.menu_button("Export Format", |ui| {
+            let mut format = self.preferences.export_format;
+            for option in ExportFormat::ALL {
+                ui.radio_value(&mut format, option, option.label());
+            }
+            if format != self.preferences.export_format {
+                self.preferences.export_format = format;
+                self.persist_settings();
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label("JPEG Quality");
+            let response = ui
+                .add(
+                    egui::DragValue::new(&mut self.preferences.export_jpeg_quality.0)
+                        .range(1..=100),
+                )
+                .on_hover_text("Default quality for JPEG frame exports, from 1 to 100.");
+            if Self::value_edit_committed(&response) {
+                self.persist_settings();
+            }
+        });
+    }
+}
+
+fn write_png(writer: impl std::io::Write, color_image: &ColorImage) -> anyhow::Result<()> {
+    let [width, height] = color_image.size;
+    let mut encoder = png::Encoder::new(
+        writer,
+        u32::try_from(width).context("Frame is too wide for PNG")?,
+        u32::try_from(height).context("Frame is too tall for PNG")?,
+    );
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let bytes = color_image
+        .pixels
+        .iter()
+        .flat_map(|pixel| pixel.to_array())
+        .collect::<Vec<u8>>();
+    encoder
+        .write_header()
+        .context("Could not write PNG header")?
+        .write_image_data(&bytes)
+        .context("Could not write PNG image data")?;
+    Ok(())
+}
+
+/// Rendered frames are opaque, so alpha is dropped.
+fn write_jpeg(
+    writer: impl std::io::Write,
+    color_image: &ColorImage,
+    quality: JpegQuality,
+) -> anyhow::Result<()> {
+    let [width, height] = color_image.size;
+    let width = u16::try_from(width).context("Frame is too wide for JPEG")?;
+    let height = u16::try_from(height).context("Frame is too tall for JPEG")?;
+    let bytes = color_image
+        .pixels
+        .iter()
+        .flat_map(|pixel| [pixel.r(), pixel.g(), pixel.b()])
+        .collect::<Vec<u8>>();
+    jpeg_encoder::Encoder::new(writer, quality.0.clamp(1, 100))
+        .encode(&bytes, width, height, jpeg_encoder::ColorType::Rgb)
+        .context("Could not write JPEG image data")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_frame() -> ColorImage {
+        ColorImage::new(
+            [3, 2],
+            vec![
+                egui::Color32::BLACK,
+                egui::Color32::WHITE,
+                egui::Color32::from_gray(128),
+                egui::Color32::RED,
+                egui::Color32::GREEN,
+                egui::Color32::BLUE,
+            ],
+        )
+    }
+
+    #[test]
+    fn write_png_encodes_the_frame_size_and_pixels() {
+        let mut bytes = Vec::new();
+        write_png(&mut bytes, &test_frame()).expect("PNG should encode");
+
+        let mut reader = png::Decoder::new(std::io::Cursor::new(bytes))
+            .read_info()
+            .expect("PNG should decode");
+        let mut decoded = vec![0; reader.output_buffer_size()];
+        let info = reader
+            .next_frame(&mut decoded)
+            .expect("PNG frame should read");
+        assert_eq!((info.width, info.height), (3, 2));
+        assert_eq!(&decoded[4..8], &[255, 255, 255, 255]);
+        assert_eq!(&decoded[20..24], &[0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn write_jpeg_honors_quality() {
+        let frame = ColorImage::new(
+            [64, 64],
+            (0..64 * 64)
+                .map(|index| egui::Color32::from_gray((index * 37 % 251) as u8))
+                .collect(),
+        );
+        let encode = |quality| {
+            let mut bytes = Vec::new();
+            write_jpeg(&mut bytes, &frame, JpegQuality(quality)).expect("JPEG should encode");
+            bytes
+        };
+        let high = encode(100);
+        let low = encode(10);
+        assert_eq!(&high[..2], &[0xFF, 0xD8]);
+        assert_eq!(&high[high.len() - 2..], &[0xFF, 0xD9]);
+        assert!(low.len() < high.len());
+    }
+
+    #[test]
+    fn export_settings_parse_their_setting_values() {
+        for format in ExportFormat::ALL {
+            assert_eq!(ExportFormat::parse(format.setting_value()), Some(format));
+        }
+        assert_eq!(ExportFormat::parse("JPG"), Some(ExportFormat::Jpeg));
+        assert_eq!(ExportFormat::parse("tiff"), None);
+        assert_eq!(JpegQuality::parse(75.0), Some(JpegQuality(75)));
+        assert_eq!(JpegQuality::parse(0.0), None);
+        assert_eq!(JpegQuality::parse(101.0), None);
+    }
+}
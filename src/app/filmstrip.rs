@@ -0,0 +1,192 @@
+use super::*;
+use crate::dicom::frame_preload_enabled;
+
+/// Height of the bottom filmstrip panel, which corner overlays clear.
+pub(super) const FILMSTRIP_HEIGHT: f32 = 84.0;
+const FILMSTRIP_MAX_THUMBS: usize = 60;
+const FILMSTRIP_THUMB_MAX_DIM: usize = 64;
+/// Thumbnails rendered per UI frame, so opening a long cine stays responsive.
+const FILMSTRIP_THUMBS_PER_UPDATE: usize = 2;
+
+/// Lazily rendered frame thumbnails for the active multi-frame image.
+pub(super) struct FrameFilmstrip {
+    /// Identity of the image the thumbnails were rendered from.
+    key: String,
+    thumbs: FrameThumbnails,
+    /// Frame the strip last scrolled to, so it only follows the current frame
+    /// when that changes and otherwise leaves manual scrolling alone.
+    scrolled_to: Option<usize>,
+}
+
+/// Frames that get a thumbnail: every frame for short cines, otherwise an
+/// even sampling of at most `FILMSTRIP_MAX_THUMBS`.
+pub(super) fn filmstrip_frames(frame_count: usize) -> Vec<usize> {
+    let stride = frame_count.div_ceil(FILMSTRIP_MAX_THUMBS).max(1);
+    (0..frame_count).step_by(stride).collect()
+}
+
+/// Frames the filmstrip may render thumbnails for. With preload off, frames
+/// decode one at a time as they are shown, so the strip only uses frames that
+/// are already decoded rather than queueing its own decodes ahead of them.
+fn filmstrip_thumbnail_frames(frames: &[usize], loaded: &[bool], preload: bool) -> Vec<usize> {
+    frames
+        .iter()
+        .copied()
+        .filter(|frame| preload || loaded.get(*frame).copied().unwrap_or(false))
+        .collect()
+}
+
+impl DicomViewerApp {
+    /// Identity, image, current frame and frame count the filmstrip follows:
+    /// the single image or the selected multi-view cell.
+    fn filmstrip_source(&self) -> Option<(String, &DicomImage, usize, usize)> {
+        let (key, image, current_frame, frame_count) = if let Some(image) = self.image.as_ref() {
+            let key = self
+                .current_single_path
+                .as_ref()
+                .map_or_else(String::new, |path| path.identity_key().to_string());
+            (key, image, self.current_frame, image.frame_count())
+        } else {
+            let viewport = self.selected_mammo_viewport()?;
+            (
+                viewport.path.identity_key().to_string(),
+                &viewport.image,
+                self.selected_mammo_frame_index(),
+                self.mammo_group_common_frame_count(),
+            )
+        };
+        (frame_count > 1).then_some((key, image, current_frame, frame_count))
+    }
+
+    /// Space the filmstrip takes from the bottom of the window, for overlays
+    /// anchored there.
+    pub(super) fn filmstrip_bottom_inset(&self) -> f32 {
+        if self.preferences.frame_filmstrip && self.filmstrip_source().is_some() {
+            FILMSTRIP_HEIGHT
+        } else {
+            0.0
+        }
+    }
+
+    /// Draws the filmstrip panel and returns the frame the user clicked.
+    pub(super) fn show_frame_filmstrip(
+        &mut self,
+        root_ui: &mut egui::Ui,
+        ctx: &egui::Context,
+    ) -> Option<usize> {
+        // Taken out while the source image is borrowed and put back below.
+        let cached = self.frame_filmstrip.take();
+        if !self.preferences.frame_filmstrip {
+            return None;
+        }
+        let (key, image, current_frame, frame_count) = self.filmstrip_source()?;
+
+        let frames = filmstrip_frames(frame_count);
+        let mut filmstrip = cached
+            .filter(|filmstrip| filmstrip.key == key)
+            .unwrap_or_else(|| FrameFilmstrip {
+                key,
+                thumbs: FrameThumbnails::default(),
+                scrolled_to: None,
+            });
+        let thumbnail_frames =
+            filmstrip_thumbnail_frames(&frames, &image.loaded_frames(), frame_preload_enabled());
+        // Thumbnails use the image's own window so they stay stable while the
+        // user adjusts window/level.
+        let repaint_after = filmstrip.thumbs.update(
+            ctx,
+            image,
+            &thumbnail_frames,
+            FILMSTRIP_THUMBS_PER_UPDATE,
+            "frame-filmstrip",
+            |frame| {
+                Self::render_image_frame(
+                    image,
+                    frame,
+                    image.window_center,
+                    image.window_width,
+                    false,
+                )
+                .map(|color_image| downsample_color_image(&color_image, FILMSTRIP_THUMB_MAX_DIM))
+            },
+        );
+        if let Some(delay) = repaint_after {
+            ctx.request_repaint_after(delay);
+        }
+
+        let thumb_size = egui::Vec2::splat(FILMSTRIP_THUMB_MAX_DIM as f32);
+        let mut clicked = None;
+        egui::Panel::bottom("frame-filmstrip")
+            .exact_size(FILMSTRIP_HEIGHT)
+            .show(root_ui, |ui| {
+                egui::ScrollArea::horizontal().show(ui, |ui| {
+                    ui.horizontal_centered(|ui| {
+                        for (position, frame) in frames.iter().copied().enumerate() {
+                            let next = frames.get(position + 1).copied().unwrap_or(frame_count);
+                            let selected = (frame..next).contains(&current_frame);
+                            let button = match filmstrip.thumbs.get(frame) {
+                                Some(texture) => egui::Button::image(
+                                    egui::Image::new(texture).max_size(thumb_size),
+                                ),
+                                None => {
+                                    egui::Button::new((frame + 1).to_string()).min_size(thumb_size)
+                                }
+                            };
+                            let response = ui
+                                .add(button.selected(selected))
+                                .on_hover_text(format!("Frame {}", frame + 1));
+                            if selected && filmstrip.scrolled_to != Some(current_frame) {
+                                response.scroll_to_me(None);
+                            }
+                            if response.clicked() {
+                                clicked = Some(frame);
+                            }
+                        }
+                    });
+                });
+            });
+        filmstrip.scrolled_to = Some(current_frame);
+        self.frame_filmstrip = Some(filmstrip);
+        clicked
+    }
+
+    /// Shows `frame_index` in the active image or viewport group.
+    pub(super) fn jump_to_frame(&mut self, frame_index: usize, ctx: &egui::Context) {
+        let Some(mut state) = self.active_viewport_state() else {
+            return;
+        };
+        state.current_frame = frame_index;
+        self.apply_active_viewport_state(&state, ctx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filmstrip_frames_samples_long_cines_evenly() {
+        assert_eq!(filmstrip_frames(0), Vec::<usize>::new());
+        assert_eq!(filmstrip_frames(5), vec![0, 1, 2, 3, 4]);
+        assert_eq!(filmstrip_frames(60).len(), 60);
+
+        let frames = filmstrip_frames(250);
+        assert!(frames.len() <= FILMSTRIP_MAX_THUMBS);
+        assert_eq!(frames.first(), Some(&0));
+        assert_eq!(frames[1], 5);
+    }
+
+    #[test]
+    fn filmstrip_thumbnails_wait_for_shown_frames_when_preload_is_off() {
+        let frames = [0, 2, 4];
+        let loaded = [true, false, false, false, true];
+        assert_eq!(
+            filmstrip_thumbnail_frames(&frames, &loaded, true),
+            vec![0, 2, 4]
+        );
+        assert_eq!(
+            filmstrip_thumbnail_frames(&frames, &loaded, false),
+            vec![0, 4]
+        );
+    }
+}
@@ -93,6 +93,24 @@ pub(super) struct HistoryEntry {
     pub(super) id: String,
     pub(super) kind: HistoryKind,
     pub(super) thumbs: Vec<HistoryThumb>,
+    /// Study date and time as `YYYYMMDDHHMMSS`, followed by the series date
+    /// and time when present, for the chronological history order.
+    pub(super) sort_key: Option<String>,
+}
+
+impl HistoryEntry {
+    /// Whether every image of the entry was read from a local file rather
+    /// than downloaded.
+    pub(super) fn is_local(&self) -> bool {
+        match &self.kind {
+            HistoryKind::Single(single) => single.path.file_path().is_some(),
+            HistoryKind::Group(group) => group
+                .viewports
+                .iter()
+                .all(|viewport| viewport.path.file_path().is_some()),
+            HistoryKind::Report(report) => report.path.file_path().is_some(),
+        }
+    }
 }
 
 impl DicomViewerApp {
@@ -126,21 +144,51 @@ impl DicomViewerApp {
         Some(ctx.load_texture(texture_name, thumb, TextureOptions::LINEAR))
     }
 
-    fn build_group_history_thumb(
+    /// History thumbnails for a group: one composite grid, or with the
+    /// `history_view_thumbnails` setting one small thumbnail per view for
+    /// groups of up to `HISTORY_VIEW_THUMBS_MAX` views.
+    fn build_group_history_thumbs(
         &mut self,
-        group: &[MammoViewport],
-        texture_key_prefix: &str,
+        views: &[(&DicomImage, &ColorImage)],
         ctx: &egui::Context,
-    ) -> Option<TextureHandle> {
-        let ordered_indices = order_mammo_indices(group, |viewport| &viewport.image);
+    ) -> Vec<HistoryThumb> {
+        let ordered_indices = order_mammo_indices(views, |view| view.0);
+        if self.preferences.history_view_thumbnails && views.len() <= HISTORY_VIEW_THUMBS_MAX {
+            return ordered_indices
+                .into_iter()
+                .map(|index| {
+                    let thumb = downsample_color_image(views[index].1, HISTORY_VIEW_THUMB_MAX_DIM);
+                    let texture_name = self.next_history_texture_name("group-view");
+                    HistoryThumb {
+                        texture: ctx.load_texture(texture_name, thumb, TextureOptions::LINEAR),
+                    }
+                })
+                .collect();
+        }
+
+        let sizes = ordered_indices
+            .iter()
+            .map(|index| [views[*index].0.width, views[*index].0.height])
+            .collect::<Vec<_>>();
+        let outliers = mismatched_thumb_members(&sizes);
+        let outlier_count = outliers.iter().filter(|outlier| **outlier).count();
+        if outlier_count > 0 {
+            log::warn!(
+                "Leaving {outlier_count} view(s) with mismatched dimensions out of the group history thumbnail."
+            );
+        }
         let mut rendered_views = Vec::new();
-        for index in ordered_indices {
-            let viewport = &group[index];
-            rendered_views.push(viewport.history_thumb.clone());
+        for (index, outlier) in ordered_indices.into_iter().zip(outliers) {
+            // The grid still shows the outlier; only its thumbnail cell stays blank.
+            rendered_views.push(if outlier {
+                ColorImage::new([1, 1], vec![egui::Color32::BLACK])
+            } else {
+                views[index].1.clone()
+            });
         }
 
         if rendered_views.is_empty() {
-            return None;
+            return Vec::new();
         }
 
         let thumb = if rendered_views.len() == 1 {
@@ -149,8 +197,31 @@ impl DicomViewerApp {
             compose_grid_thumb(&rendered_views, HISTORY_THUMB_MAX_DIM)
         };
 
-        let texture_name = self.next_history_texture_name(texture_key_prefix);
-        Some(ctx.load_texture(texture_name, thumb, TextureOptions::LINEAR))
+        let texture_name = self.next_history_texture_name("group");
+        vec![HistoryThumb {
+            texture: ctx.load_texture(texture_name, thumb, TextureOptions::LINEAR),
+        }]
+    }
+
+    /// Rebuilds every group entry's thumbnails after the
+    /// `history_view_thumbnails` setting changes.
+    pub(super) fn rebuild_group_history_thumbs(&mut self, ctx: &egui::Context) {
+        let mut entries = std::mem::take(&mut self.history_entries);
+        for entry in &mut entries {
+            let HistoryKind::Group(group) = &entry.kind else {
+                continue;
+            };
+            let views = group
+                .viewports
+                .iter()
+                .map(|viewport| (&viewport.image, &viewport.history_thumb))
+                .collect::<Vec<_>>();
+            let thumbs = self.build_group_history_thumbs(&views, ctx);
+            if !thumbs.is_empty() {
+                entry.thumbs = thumbs;
+            }
+        }
+        self.history_entries = entries;
     }
 
     fn build_report_history_thumb(
@@ -272,12 +343,14 @@ impl DicomViewerApp {
         };
 
         let history_paths = vec![single.path.clone()];
+        let sort_key = history_sort_key(&single.image.metadata);
         self.upsert_history_entry(HistoryEntry {
             id: history_id_from_paths(&history_paths),
             kind: HistoryKind::Single(Box::new(single)),
             thumbs: vec![HistoryThumb {
                 texture: thumb_texture,
             }],
+            sort_key,
         });
     }
 
@@ -290,12 +363,14 @@ impl DicomViewerApp {
         let texture_name = self.next_history_texture_name("single");
         let thumb_texture = ctx.load_texture(texture_name, thumb, TextureOptions::LINEAR);
         let history_paths = vec![single.path.clone()];
+        let sort_key = history_sort_key(&single.image.metadata);
         self.upsert_history_entry(HistoryEntry {
             id: history_id_from_paths(&history_paths),
             kind: HistoryKind::Single(Box::new(single)),
             thumbs: vec![HistoryThumb {
                 texture: thumb_texture,
             }],
+            sort_key,
         });
     }
 
@@ -324,19 +399,26 @@ impl DicomViewerApp {
                 current_frame: viewport.current_frame,
             });
         }
-        let Some(group_thumb) = self.build_group_history_thumb(group, "group", ctx) else {
+        let views = group
+            .iter()
+            .map(|viewport| (&viewport.image, &viewport.history_thumb))
+            .collect::<Vec<_>>();
+        let thumbs = self.build_group_history_thumbs(&views, ctx);
+        if thumbs.is_empty() {
             return;
-        };
+        }
 
+        let sort_key = group
+            .iter()
+            .find_map(|viewport| history_sort_key(&viewport.image.metadata));
         self.upsert_history_entry(HistoryEntry {
             id: history_id_from_paths(&paths),
             kind: HistoryKind::Group(HistoryGroupData {
                 viewports: cached_viewports,
                 selected_index: selected_index.min(group.len().saturating_sub(1)),
             }),
-            thumbs: vec![HistoryThumb {
-                texture: group_thumb,
-            }],
+            thumbs,
+            sort_key,
         });
     }
 
@@ -354,6 +436,7 @@ impl DicomViewerApp {
             thumbs: vec![HistoryThumb {
                 texture: thumb_texture,
             }],
+            sort_key: None,
         });
     }
 
@@ -394,10 +477,13 @@ impl DicomViewerApp {
         self.pending_history_open_id = None;
         self.pending_history_open_armed = false;
         self.dicomweb_receiver = None;
+        self.remote_launch_request = None;
         self.dicomweb_active_path_receiver = None;
         self.dicomweb_active_group_expected = None;
         self.dicomweb_active_group_paths.clear();
         self.dicomweb_completed_background_groups.clear();
+        self.dicomweb_background_group_downloads.clear();
+        self.dicomweb_open_group_override = None;
         self.dicomweb_active_pending_paths.clear();
         self.single_load_receiver = None;
         self.mammo_load_receiver = None;
@@ -412,6 +498,8 @@ impl DicomViewerApp {
         self.authoritative_pm_overlay_keys.clear();
         self.clear_single_viewer();
         self.mammo_group.clear();
+        self.mammo_failed_views.clear();
+        self.dicomweb_failed_downloads.clear();
         self.mammo_selected_index = 0;
         self.clear_load_error();
     }
@@ -501,9 +589,10 @@ impl DicomViewerApp {
 
     fn preload_parametric_map_into_history(
         path: DicomSource,
+        load_options: &DicomLoadOptions,
         tx: &mpsc::Sender<Result<HistoryPreloadResult, String>>,
     ) {
-        let result = load_parametric_map(&path)
+        let result = load_parametric_map(&path, &load_options.view_fallback_tags)
             .map(|image| HistoryPreloadResult::Single {
                 path: path.clone(),
                 image: Box::new(image),
@@ -514,6 +603,7 @@ impl DicomViewerApp {
 
     fn preload_group_into_history(
         prepared: PreparedLoadPaths,
+        load_options: &DicomLoadOptions,
         tx: &mpsc::Sender<Result<HistoryPreloadResult, String>>,
     ) {
         let load_paths = prepared.image_paths;
@@ -532,7 +622,7 @@ impl DicomViewerApp {
                 Self::preload_report_into_history(path, tx);
             }
             for path in parametric_map_paths {
-                Self::preload_parametric_map_into_history(path, tx);
+                Self::preload_parametric_map_into_history(path, load_options, tx);
             }
             return;
         }
@@ -541,13 +631,13 @@ impl DicomViewerApp {
             Self::preload_report_into_history(path, tx);
         }
         for path in parametric_map_paths {
-            Self::preload_parametric_map_into_history(path, tx);
+            Self::preload_parametric_map_into_history(path, load_options, tx);
         }
 
         let result = match load_paths.len() {
             1 => {
                 let path = load_paths[0].clone();
-                load_dicom(&path)
+                load_dicom(&path, load_options)
                     .map(|mut image| {
                         Self::attach_matching_gsps_overlay(&mut image, &gsps_overlays);
                         Self::attach_matching_sr_overlay(&mut image, &sr_overlays);
@@ -563,14 +653,19 @@ impl DicomViewerApp {
             count if Self::is_supported_multi_view_group_size(count) => {
                 let mut viewports = Vec::with_capacity(load_paths.len());
                 for path in &load_paths {
-                    let image = match load_dicom(path).map_err(|err| format!("{err:#}")) {
+                    let mut image = match load_dicom(path, load_options) {
                         Ok(image) => image,
                         Err(err) => {
-                            let _ = tx.send(Err(err));
-                            return;
+                            log::warn!(
+                                "History preload is showing a placeholder for an undecodable group image: {err:#}"
+                            );
+                            load_dicom_placeholder(
+                                path,
+                                &load_options.view_fallback_tags,
+                                format!("{err:#}"),
+                            )
                         }
                     };
-                    let mut image = image;
                     Self::attach_matching_gsps_overlay(&mut image, &gsps_overlays);
                     Self::attach_matching_sr_overlay(&mut image, &sr_overlays);
                     Self::attach_matching_pm_overlay(&mut image, &pm_overlays);
@@ -593,11 +688,14 @@ impl DicomViewerApp {
         };
         let job_key = job.preload_key();
 
+        let load_options = self.dicom_load_options();
         let (tx, rx) = mpsc::channel::<Result<HistoryPreloadResult, String>>();
         thread::spawn(move || match job {
-            HistoryPreloadJob::Group(prepared) => Self::preload_group_into_history(prepared, &tx),
+            HistoryPreloadJob::Group(prepared) => {
+                Self::preload_group_into_history(prepared, &load_options, &tx);
+            }
             HistoryPreloadJob::ParametricMap(path) => {
-                Self::preload_parametric_map_into_history(path, &tx);
+                Self::preload_parametric_map_into_history(path, &load_options, &tx);
             }
             HistoryPreloadJob::StructuredReport(path) => {
                 Self::preload_report_into_history(path, &tx);
@@ -605,7 +703,7 @@ impl DicomViewerApp {
         });
         self.history_preload_receiver = Some(rx);
         self.history_preload_active_key = Some(job_key);
-        ctx.request_repaint_after(Duration::from_millis(16));
+        ctx.request_repaint_after(IDLE_POLL_REPAINT_INTERVAL);
     }
 
     pub(super) fn enqueue_history_preload_job(
@@ -627,6 +725,8 @@ impl DicomViewerApp {
         self.start_next_history_preload(ctx);
     }
 
+    /// Queues every group but the open one for history and records all of
+    /// them as group pages.
     pub(super) fn preload_non_active_groups_into_history(
         &mut self,
         groups: &[PreparedLoadPaths],
@@ -634,6 +734,10 @@ impl DicomViewerApp {
         completed_background_groups: Option<&HashSet<usize>>,
         ctx: &egui::Context,
     ) {
+        self.group_pages = groups
+            .iter()
+            .map(|group| history_id_from_paths(&group.image_paths))
+            .collect();
         let queued_groups = groups
             .iter()
             .enumerate()
@@ -654,6 +758,7 @@ impl DicomViewerApp {
 
     pub(super) fn sync_current_state_to_history(&mut self) {
         let loaded_mammo_count = self.loaded_mammo_count();
+        let single_window = self.window_without_subtraction();
         let selected_index = self
             .mammo_selected_index
             .min(self.mammo_group.len().saturating_sub(1));
@@ -681,8 +786,7 @@ impl DicomViewerApp {
                 if let Some(texture) = self.texture.as_ref() {
                     single.texture = texture.clone();
                 }
-                single.window_center = self.window_center;
-                single.window_width = self.window_width;
+                (single.window_center, single.window_width) = single_window;
                 single.current_frame = self.current_frame;
                 single.cine_fps = self.cine_fps;
             }
@@ -730,6 +834,7 @@ impl DicomViewerApp {
     }
 
     pub(super) fn open_history_entry(&mut self, index: usize, ctx: &egui::Context) {
+        self.discard_subtraction();
         self.sync_current_state_to_history();
         self.single_load_receiver = None;
         self.mammo_load_receiver = None;
@@ -751,9 +856,12 @@ impl DicomViewerApp {
                 self.current_single_path = Some(single.path);
                 self.texture = None;
                 self.overlay_visible = false;
+                self.gsps_saved_windows.clear();
                 self.window_center = single.window_center;
                 self.window_width = single.window_width.max(1.0);
                 self.current_frame = single.current_frame;
+                self.single_frame_range = None;
+                self.promoted_cell = None;
                 self.cine_mode = false;
                 self.last_cine_advance = None;
                 self.cine_fps = single.cine_fps.clamp(1.0, 120.0);
@@ -761,6 +869,7 @@ impl DicomViewerApp {
                 self.mammo_selected_index = 0;
                 self.reset_single_view_transform();
                 self.single_view_frame_scroll_accum = 0.0;
+                self.single_view_content_uv = None;
                 if let Some(image) = self.image.as_ref() {
                     let frame_count = image.frame_count();
                     if frame_count == 0 {
@@ -802,6 +911,7 @@ impl DicomViewerApp {
                             zoom: 1.0,
                             pan: egui::Vec2::ZERO,
                             frame_scroll_accum: 0.0,
+                            window_preset_index: None,
                         })
                     })
                     .collect::<Vec<_>>();
@@ -831,13 +941,90 @@ impl DicomViewerApp {
         }
     }
 
-    pub(super) fn cycle_history_entry(&mut self, direction: i32) {
-        let len = self.history_entries.len();
+    /// Entry indices in the order the history list shows them. Entries are
+    /// stored most recent first; the date order only changes presentation, so
+    /// the recency cap still evicts the least recently opened entry.
+    pub(super) fn history_display_order(&self) -> Vec<usize> {
+        let mut order = (0..self.history_entries.len()).collect::<Vec<_>>();
+        if self.preferences.sort_history_by_date {
+            order.sort_by(|a, b| {
+                compare_history_sort_keys(
+                    self.history_entries[*a].sort_key.as_deref(),
+                    self.history_entries[*b].sort_key.as_deref(),
+                )
+            });
+        }
+        order
+    }
+
+    /// Moves the keyboard highlight in the history list, starting next to the
+    /// displayed entry when nothing is highlighted yet.
+    pub(super) fn move_history_focus(&mut self, direction: i32) {
+        let order = self.history_display_order();
+        let len = order.len();
+        if len == 0 {
+            self.history_focus_id = None;
+            return;
+        }
+
+        let base = self
+            .history_focus_id
+            .clone()
+            .or_else(|| self.current_history_id())
+            .and_then(|id| self.history_entries.iter().position(|entry| entry.id == id))
+            .and_then(|index| order.iter().position(|entry| *entry == index));
+        let next = match base {
+            Some(position) if direction < 0 => position.saturating_sub(1),
+            Some(position) => (position + 1).min(len - 1),
+            None if direction < 0 => len - 1,
+            None => 0,
+        };
+        self.history_focus_id = Some(self.history_entries[order[next]].id.clone());
+        self.history_focus_scroll_pending = true;
+    }
+
+    pub(super) fn open_focused_history_entry(&mut self) {
+        let Some(index) = self
+            .history_focus_id
+            .take()
+            .and_then(|id| self.history_entries.iter().position(|entry| entry.id == id))
+        else {
+            return;
+        };
+        self.queue_history_open(index);
+    }
+
+    /// Whether the arrow keys belong to the history list: the pointer is
+    /// over it, or the last click landed in it.
+    pub(super) fn history_keys_active(&self, hover_pos: Option<egui::Pos2>) -> bool {
+        self.history_panel_focused
+            || hover_pos.is_some_and(|pos| {
+                self.history_panel_rect
+                    .is_some_and(|rect| rect.contains(pos))
+            })
+    }
+
+    /// Gives the history list keyboard focus on a click inside it and takes
+    /// it away on a click anywhere else.
+    pub(super) fn track_history_panel_focus(&mut self, press_pos: Option<egui::Pos2>) {
+        if let Some(pos) = press_pos {
+            self.history_panel_focused = self
+                .history_panel_rect
+                .is_some_and(|rect| rect.contains(pos));
+            if !self.history_panel_focused {
+                self.history_focus_id = None;
+            }
+        }
+    }
+
+    pub(super) fn cycle_history_entry(&mut self, direction: i32, ctx: &egui::Context) {
+        let order = self.history_display_order();
+        let len = order.len();
         if len <= 1 {
             return;
         }
 
-        let current_index = self
+        let current_position = self
             .pending_history_open_id
             .as_deref()
             .and_then(|id| self.history_entries.iter().position(|entry| entry.id == id))
@@ -846,20 +1033,78 @@ impl DicomViewerApp {
                     .as_deref()
                     .and_then(|id| self.history_entries.iter().position(|entry| entry.id == id))
             })
-            .map(|index| index % len)
+            .and_then(|index| order.iter().position(|entry| *entry == index))
             .unwrap_or(0);
 
-        let next_index = if direction < 0 {
-            if current_index == 0 {
+        let next_position = if direction < 0 {
+            if current_position == 0 {
                 len - 1
             } else {
-                current_index - 1
+                current_position - 1
             }
         } else {
-            (current_index + 1) % len
+            (current_position + 1) % len
+        };
+        self.switch_to_history_entry(order[next_position], ctx);
+    }
+
+    /// Records which entry is displayed, so the entry shown before it becomes
+    /// the toggle target. Runs every frame, which covers opens from history,
+    /// from files and from DICOMweb alike.
+    pub(super) fn track_displayed_history_entry(&mut self) {
+        let Some(id) = self.current_history_id() else {
+            return;
+        };
+        if self.displayed_history_id.as_deref() == Some(id.as_str()) {
+            return;
+        }
+        self.previous_history_id = self.displayed_history_id.replace(id);
+    }
+
+    /// Jumps to the most recently shown entry other than the displayed one.
+    /// Pressed again while that open is still pending, it goes back.
+    pub(super) fn toggle_previous_history_entry(&mut self, ctx: &egui::Context) {
+        let target = if self.pending_history_open_id.is_some()
+            && self.pending_history_open_id == self.previous_history_id
+        {
+            self.displayed_history_id.clone()
+        } else {
+            self.previous_history_id.clone()
         };
+        let Some(index) =
+            target.and_then(|id| self.history_entries.iter().position(|entry| entry.id == id))
+        else {
+            return;
+        };
+        self.switch_to_history_entry(index, ctx);
+    }
+
+    pub(super) fn switch_to_history_entry(&mut self, index: usize, ctx: &egui::Context) {
+        if self.preferences.instant_history_cycling && self.history_entry_displayable_now(index) {
+            self.pending_history_open_id = None;
+            self.pending_history_open_armed = false;
+            self.open_history_entry(index, ctx);
+            return;
+        }
+        self.queue_history_open(index);
+    }
 
-        self.queue_history_open(next_index);
+    /// Whether opening the entry only swaps in-memory state: every frame it
+    /// would show is already decoded, so no file read or decode runs on the UI
+    /// thread and the deferred open can be skipped.
+    pub(super) fn history_entry_displayable_now(&self, index: usize) -> bool {
+        let frame_decoded = |image: &DicomImage, frame: usize| {
+            image.frame_count() == 0 || image.loaded_frames().get(frame).copied() == Some(true)
+        };
+        match self.history_entries.get(index).map(|entry| &entry.kind) {
+            Some(HistoryKind::Single(single)) => frame_decoded(&single.image, single.current_frame),
+            Some(HistoryKind::Group(group)) => group
+                .viewports
+                .iter()
+                .all(|viewport| frame_decoded(&viewport.image, viewport.current_frame)),
+            Some(HistoryKind::Report(_)) => true,
+            None => false,
+        }
     }
 
     pub(super) fn poll_history_preload(&mut self, ctx: &egui::Context) {
@@ -878,7 +1123,7 @@ impl DicomViewerApp {
                         let center = image.window_center;
                         let width = image.window_width;
                         let Some(color_image) =
-                            Self::render_image_frame(&image, 0, center, width, false)
+                            Self::render_display_frame(&image, 0, center, width, false)
                         else {
                             break;
                         };
@@ -906,18 +1151,37 @@ impl DicomViewerApp {
                         let mut loaded = Vec::with_capacity(viewports.len());
                         let mut render_failed = false;
                         for (path, image) in viewports {
+                            let (image, color_image) = match Self::render_display_frame(
+                                &image,
+                                0,
+                                image.window_center,
+                                image.window_width,
+                                false,
+                            ) {
+                                Some(color_image) => (image, color_image),
+                                None => {
+                                    log::warn!(
+                                        "History preload is showing a placeholder for group viewport (instance {:?}).",
+                                        image.instance_number
+                                    );
+                                    let image = image.into_undecodable_placeholder(
+                                        "Could not render any frame of this image.",
+                                    );
+                                    let Some(color_image) = Self::render_display_frame(
+                                        &image,
+                                        0,
+                                        image.window_center,
+                                        image.window_width,
+                                        false,
+                                    ) else {
+                                        render_failed = true;
+                                        break;
+                                    };
+                                    (image, color_image)
+                                }
+                            };
                             let center = image.window_center;
                             let width = image.window_width;
-                            let Some(color_image) =
-                                Self::render_image_frame(&image, 0, center, width, false)
-                            else {
-                                log::warn!(
-                                    "History preload skipped group viewport (instance {:?}).",
-                                    image.instance_number
-                                );
-                                render_failed = true;
-                                break;
-                            };
                             let path_meta = DicomSourceMeta::from(&path);
                             let texture_name =
                                 Self::source_texture_name("history-preload-group", &path_meta);
@@ -938,9 +1202,15 @@ impl DicomViewerApp {
                                 zoom: 1.0,
                                 pan: egui::Vec2::ZERO,
                                 frame_scroll_accum: 0.0,
+                                window_preset_index: None,
                             });
                         }
-                        if !render_failed && Self::is_supported_multi_view_group_size(loaded.len())
+                        let has_decodable = loaded
+                            .iter()
+                            .any(|viewport| viewport.image.undecodable_reason().is_none());
+                        if !render_failed
+                            && has_decodable
+                            && Self::is_supported_multi_view_group_size(loaded.len())
                         {
                             let ordered_indices =
                                 order_mammo_indices(&loaded, |viewport| &viewport.image);
@@ -974,7 +1244,7 @@ impl DicomViewerApp {
 
         if keep_receiver {
             self.history_preload_receiver = Some(receiver);
-            ctx.request_repaint_after(Duration::from_millis(16));
+            ctx.request_repaint_after(IDLE_POLL_REPAINT_INTERVAL);
             return;
         }
 
@@ -983,32 +1253,39 @@ impl DicomViewerApp {
     }
 
     pub(super) fn show_history_list(
-        &self,
+        &mut self,
         ui: &mut egui::Ui,
         current_history_id: Option<&str>,
     ) -> Option<usize> {
-        if self.history_entries.is_empty() {
+        if self.history_entries.is_empty() && self.dicomweb_background_group_downloads.is_empty() {
             ui.label("No previous images.");
             return None;
         }
 
         let mut clicked_index = None;
+        let mut clicked_download = None;
+        let focus_id = self.history_focus_id.clone();
+        let scroll_to_focus = std::mem::take(&mut self.history_focus_scroll_pending);
         egui::ScrollArea::vertical()
             .id_salt("history-thumbnails")
             .show(ui, |ui| {
                 ui.with_layout(egui::Layout::top_down(egui::Align::Max), |ui| {
-                    for (index, entry) in self.history_entries.iter().enumerate() {
+                    for index in self.history_display_order() {
+                        let entry = &self.history_entries[index];
                         let is_current = current_history_id == Some(entry.id.as_str());
-                        let stroke_color = if is_current {
-                            PERSPECTA_BRAND_BLUE
+                        let is_focused = focus_id.as_deref() == Some(entry.id.as_str());
+                        let stroke = if is_focused {
+                            egui::Stroke::new(2.0, egui::Color32::from_gray(210))
+                        } else if is_current {
+                            egui::Stroke::new(1.0, PERSPECTA_BRAND_BLUE)
                         } else {
-                            egui::Color32::from_gray(35)
+                            egui::Stroke::new(1.0, egui::Color32::from_gray(35))
                         };
 
-                        egui::Frame::NONE
+                        let frame = egui::Frame::NONE
                             .fill(egui::Color32::TRANSPARENT)
-                            .stroke(egui::Stroke::new(1.0, stroke_color))
-                            .inner_margin(egui::Margin::same(6))
+                            .stroke(stroke)
+                            .inner_margin(egui::Margin::same(HISTORY_ENTRY_PADDING))
                             .show(ui, |ui| {
                                 ui.horizontal_wrapped(|ui| {
                                     for thumb in &entry.thumbs {
@@ -1017,25 +1294,110 @@ impl DicomViewerApp {
                                         let scale = (HISTORY_LIST_THUMB_MAX_DIM / max_side)
                                             .clamp(0.01, 1.0);
                                         let draw_size = texture_size * scale;
-                                        let response = ui.add(
-                                            egui::Image::new((thumb.texture.id(), draw_size))
-                                                .sense(Sense::click()),
-                                        );
-                                        if response.clicked() {
-                                            clicked_index = Some(index);
-                                        }
+                                        ui.add(egui::Image::new((thumb.texture.id(), draw_size)));
                                     }
                                 });
                             });
+                        // The whole framed entry, padding included, is the click target.
+                        let response = ui.interact(
+                            frame.response.rect,
+                            ui.id().with(("history-entry", index)),
+                            Sense::click(),
+                        );
+                        if response.clicked() {
+                            clicked_index = Some(index);
+                        }
+                        if is_focused && scroll_to_focus {
+                            response.scroll_to_me(None);
+                        }
+                        ui.add_space(4.0);
+                    }
+                    for (group_index, download) in &self.dicomweb_background_group_downloads {
+                        let label = format!(
+                            "Group {}: {}/{} downloaded",
+                            group_index + 1,
+                            download.paths.len(),
+                            download.expected
+                        );
+                        if ui
+                            .button(label)
+                            .on_hover_text("Open now; views fill in as they arrive.")
+                            .clicked()
+                        {
+                            clicked_download = Some(*group_index);
+                        }
                         ui.add_space(4.0);
                     }
                 });
             });
 
+        if let Some(group_index) = clicked_download {
+            let ctx = ui.ctx().clone();
+            self.open_downloading_background_group(group_index, &ctx);
+        }
+        if clicked_index.is_some() {
+            self.history_focus_id = None;
+        }
         clicked_index
     }
 }
 
+/// Chronological history key from the image's study date and time, with the
+/// series date and time as a tie-break between series of one study.
+pub(super) fn history_sort_key(metadata: &[(String, String)]) -> Option<String> {
+    let study = dicom_date_time_key(metadata, "StudyDate", "StudyTime");
+    let series = dicom_date_time_key(metadata, "SeriesDate", "SeriesTime");
+    match (study, series) {
+        (Some(study), Some(series)) => Some(study + &series),
+        (study, series) => study.or(series),
+    }
+}
+
+fn dicom_date_time_key(
+    metadata: &[(String, String)],
+    date_field: &str,
+    time_field: &str,
+) -> Option<String> {
+    let value = |field: &str| {
+        metadata
+            .iter()
+            .find(|(key, _)| key == field)
+            .map(|(_, value)| value.trim())
+    };
+    // Legacy ACR-NEMA dates and times may carry `.` or `:` separators.
+    let date = value(date_field)?
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>();
+    if date.len() != 8 {
+        return None;
+    }
+    // TM may be truncated to HH or HHMM and carry a fraction; keep HHMMSS.
+    let mut time = value(time_field)
+        .unwrap_or_default()
+        .split('.')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .filter(char::is_ascii_digit)
+        .take(6)
+        .collect::<String>();
+    while time.len() < 6 {
+        time.push('0');
+    }
+    Some(date + &time)
+}
+
+/// Newest first; entries without a date go last.
+fn compare_history_sort_keys(a: Option<&str>, b: Option<&str>) -> std::cmp::Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => b.cmp(a),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    }
+}
+
 fn history_preload_source_key(path: &DicomSource) -> String {
     DicomSourceMeta::from(path).identity_key().to_string()
 }
@@ -1081,6 +1443,28 @@ pub(super) fn downsample_color_image(source: &ColorImage, max_dim: usize) -> Col
     ColorImage::new([target_width, target_height], pixels)
 }
 
+/// Flags group members whose pixel area is a small fraction of the group's
+/// median, such as a localizer stored beside full-field mammograms. Scaling
+/// those up into a composite cell yields an unreadable thumbnail.
+pub(super) fn mismatched_thumb_members(sizes: &[[usize; 2]]) -> Vec<bool> {
+    const MAX_AREA_RATIO: usize = 16;
+
+    let mut areas = sizes
+        .iter()
+        .map(|[width, height]| width.saturating_mul(*height))
+        .collect::<Vec<_>>();
+    areas.sort_unstable();
+    let Some(median_area) = areas.get(areas.len() / 2).copied() else {
+        return Vec::new();
+    };
+    sizes
+        .iter()
+        .map(|[width, height]| {
+            width.saturating_mul(*height).saturating_mul(MAX_AREA_RATIO) < median_area
+        })
+        .collect()
+}
+
 pub(super) fn compose_grid_thumb(images: &[ColorImage], max_dim: usize) -> ColorImage {
     if images.is_empty() || max_dim == 0 {
         return ColorImage::new([1, 1], vec![egui::Color32::BLACK]);
@@ -4,6 +4,27 @@ const OPEN_STARTED_EVENT: &str = "open started";
 const OPEN_DICOM_LOADED_EVENT: &str = "open dicom-loaded";
 const OPEN_COMPLETED_EVENT: &str = "open completed";
 
+/// A multi-view cell shown as a placeholder because its image could not be
+/// displayed, kept so the load can be retried without reopening the group.
+pub(super) struct FailedMammoView {
+    pub(super) source: DicomSource,
+    /// Set when the image never downloaded, so a retry fetches it first.
+    pub(super) redownload: Option<InstanceRedownload>,
+    pub(super) retry: Option<Receiver<Result<PendingLoad, String>>>,
+}
+
+/// An image of the open DICOMweb group whose download failed.
+pub(super) struct FailedInstanceDownload {
+    pub(super) error: String,
+    pub(super) retry: Box<InstanceRedownload>,
+}
+
+/// Progress of a non-open DICOMweb group downloading in the background.
+pub(super) struct BackgroundGroupDownload {
+    pub(super) expected: usize,
+    pub(super) paths: Vec<DicomSource>,
+}
+
 pub(super) struct PendingLoad {
     pub(super) path: DicomSource,
     pub(super) image: DicomImage,
@@ -36,6 +57,7 @@ struct PendingOverlayState {
 
 pub(super) enum PendingSingleLoad {
     Image(Box<PendingLoad>),
+    PackedViews(Vec<PendingLoad>),
     StructuredReport {
         path: DicomSource,
         report: Box<StructuredReportDocument>,
@@ -197,6 +219,14 @@ impl DicomViewerApp {
     }
 
     pub(super) fn handle_launch_request(&mut self, request: LaunchRequest, ctx: &egui::Context) {
+        if matches!(
+            request,
+            LaunchRequest::DicomWeb(_)
+                | LaunchRequest::DicomWebGroups(_)
+                | LaunchRequest::RemoteFile(_)
+        ) {
+            self.remote_launch_request = Some(request.clone());
+        }
         match request {
             LaunchRequest::LocalPaths(paths) => self.queue_local_paths_open(paths),
             LaunchRequest::LocalGroups { groups, open_group } => {
@@ -204,9 +234,90 @@ impl DicomViewerApp {
             }
             LaunchRequest::DicomWebGroups(request) => self.start_dicomweb_group_download(request),
             LaunchRequest::DicomWeb(request) => self.start_dicomweb_download(request),
+            LaunchRequest::RemoteFile(request) => self.start_remote_file_download(request),
         }
     }
 
+    /// Opens the next (`direction > 0`) or previous worklist study. Stepping
+    /// past either end of the list stays put.
+    pub(super) fn step_worklist(&mut self, direction: i32) {
+        if self.worklist.is_empty() {
+            return;
+        }
+        let Some(index) = self
+            .worklist_index
+            .checked_add_signed(direction as isize)
+            .filter(|index| *index < self.worklist.len())
+        else {
+            log::info!(
+                "Already at the {} of the worklist.",
+                if direction > 0 { "end" } else { "start" }
+            );
+            return;
+        };
+        log::info!(
+            "Opening worklist study {} of {}.",
+            index + 1,
+            self.worklist.len()
+        );
+        self.worklist_index = index;
+        self.sync_current_state_to_history();
+        self.clear_active_study();
+        self.pending_launch_request = Some(self.worklist[index].clone());
+    }
+
+    /// Rebuilds a launch request for the displayed study: local files map to `path=`/`group=`,
+    /// while in-memory DICOMweb sources map back to the launch that downloaded them.
+    pub(super) fn study_link_request(&self) -> Option<LaunchRequest> {
+        let sources = if let Some(path) = self.current_single_path.as_ref() {
+            vec![path.clone()]
+        } else if self.mammo_group_complete() {
+            self.loaded_mammo_viewports()
+                .map(|viewport| viewport.path.clone())
+                .collect::<Vec<_>>()
+        } else {
+            Vec::new()
+        };
+        if sources.is_empty() {
+            return None;
+        }
+
+        let file_paths = sources
+            .iter()
+            .map(|source| source.file_path().map(Path::to_path_buf))
+            .collect::<Option<Vec<_>>>();
+        match file_paths {
+            Some(paths) if paths.len() == 1 => Some(LaunchRequest::LocalPaths(paths)),
+            Some(paths) => Some(LaunchRequest::LocalGroups {
+                groups: vec![paths],
+                open_group: 0,
+            }),
+            None => sources
+                .first()
+                .and_then(|source| self.remote_launch_requests.get(source.identity_key()))
+                .cloned(),
+        }
+    }
+
+    pub(super) fn study_link(&self) -> Option<String> {
+        self.study_link_request()
+            .map(|request| build_perspecta_uri(&request))
+    }
+
+    /// Local file behind the displayed single image or the selected
+    /// multi-view cell. DICOMweb and URL downloads live only in memory, so
+    /// they have no file to reveal.
+    pub(super) fn revealable_file_path(&self) -> Option<PathBuf> {
+        self.current_single_path
+            .as_ref()
+            .or_else(|| {
+                self.selected_mammo_viewport()
+                    .map(|viewport| &viewport.path)
+            })
+            .and_then(DicomSourceMeta::file_path)
+            .map(Path::to_path_buf)
+    }
+
     pub(super) fn cancel_local_prepare(&mut self) {
         if let Some(cancel) = self.local_prepare_cancel.take() {
             cancel.store(true, Ordering::Release);
@@ -229,11 +340,16 @@ impl DicomViewerApp {
     }
 
     fn clear_dicomweb_state_for_local_prepare(&mut self) {
+        self.series_tabs.clear();
+        self.group_pages.clear();
         self.dicomweb_receiver = None;
+        self.remote_launch_request = None;
         self.dicomweb_active_path_receiver = None;
         self.dicomweb_active_group_expected = None;
         self.dicomweb_active_group_paths.clear();
         self.dicomweb_completed_background_groups.clear();
+        self.dicomweb_background_group_downloads.clear();
+        self.dicomweb_open_group_override = None;
         self.dicomweb_active_pending_paths.clear();
     }
 
@@ -358,7 +474,7 @@ impl DicomViewerApp {
             }) => {
                 let _ = self.apply_prepared_open_group(prepared_group, open_group, ctx);
                 self.local_prepare_receiver = Some(receiver);
-                ctx.request_repaint_after(Duration::from_millis(16));
+                ctx.request_repaint_after(ACTIVE_REPAINT_INTERVAL);
             }
             Ok(LocalPrepareResult::Groups {
                 prepared_groups,
@@ -371,7 +487,7 @@ impl DicomViewerApp {
             }
             Err(TryRecvError::Empty) => {
                 self.local_prepare_receiver = Some(receiver);
-                ctx.request_repaint_after(Duration::from_millis(16));
+                ctx.request_repaint_after(ACTIVE_REPAINT_INTERVAL);
             }
             Err(TryRecvError::Disconnected) => {
                 self.local_prepare_cancel = None;
@@ -479,6 +595,24 @@ impl DicomViewerApp {
     }
 
     pub(super) fn start_dicomweb_download(&mut self, request: DicomWebLaunchRequest) {
+        log::info!("Loading study from DICOMweb...");
+        let view_fallback_tags = self.preferences.view_fallback_tags.clone();
+        self.start_single_remote_download(move || {
+            download_dicomweb_request(&request, &view_fallback_tags)
+        });
+    }
+
+    pub(super) fn start_remote_file_download(&mut self, request: RemoteFileLaunchRequest) {
+        log::info!("Loading DICOM file from URL...");
+        self.start_single_remote_download(move || download_remote_file(&request));
+    }
+
+    /// Runs a download that yields a single study on a worker thread, feeding
+    /// the result through the DICOMweb receiver.
+    fn start_single_remote_download(
+        &mut self,
+        download: impl FnOnce() -> anyhow::Result<DicomWebDownloadResult> + Send + 'static,
+    ) {
         if self.dicomweb_receiver.is_some() {
             log::warn!("DICOMweb download already in progress.");
             return;
@@ -487,6 +621,8 @@ impl DicomViewerApp {
         self.clear_load_error();
         self.sync_current_state_to_history();
         self.clear_history_preload();
+        self.series_tabs.clear();
+        self.group_pages.clear();
         self.single_load_receiver = None;
         self.mammo_load_receiver = None;
         self.mammo_load_sender = None;
@@ -498,15 +634,17 @@ impl DicomViewerApp {
         self.pending_pm_overlays.clear();
         self.authoritative_pm_overlay_keys.clear();
         self.overlay_visible = false;
+        self.gsps_saved_windows.clear();
         self.dicomweb_active_path_receiver = None;
         self.dicomweb_active_group_expected = None;
         self.dicomweb_active_group_paths.clear();
         self.dicomweb_completed_background_groups.clear();
+        self.dicomweb_background_group_downloads.clear();
+        self.dicomweb_open_group_override = None;
         self.dicomweb_active_pending_paths.clear();
-        log::info!("Loading study from DICOMweb...");
         let (tx, rx) = mpsc::channel::<Result<DicomWebDownloadResult, String>>();
         thread::spawn(move || {
-            let result = download_dicomweb_request(&request).map_err(|err| format!("{err:#}"));
+            let result = download().map_err(|err| format!("{err:#}"));
             let _ = tx.send(result);
         });
         self.dicomweb_receiver = Some(rx);
@@ -521,6 +659,8 @@ impl DicomViewerApp {
         self.clear_load_error();
         self.sync_current_state_to_history();
         self.clear_history_preload();
+        self.series_tabs.clear();
+        self.group_pages.clear();
         self.single_load_receiver = None;
         self.mammo_load_receiver = None;
         self.mammo_load_sender = None;
@@ -532,16 +672,20 @@ impl DicomViewerApp {
         self.pending_pm_overlays.clear();
         self.authoritative_pm_overlay_keys.clear();
         self.overlay_visible = false;
+        self.gsps_saved_windows.clear();
         log::info!("Loading grouped study from DICOMweb...");
         self.dicomweb_active_group_expected = None;
         self.dicomweb_active_group_paths.clear();
         self.dicomweb_completed_background_groups.clear();
+        self.dicomweb_background_group_downloads.clear();
+        self.dicomweb_open_group_override = None;
         self.dicomweb_active_pending_paths.clear();
 
         let (active_path_tx, active_path_rx) = mpsc::channel::<DicomWebGroupStreamUpdate>();
         let (tx, rx) = mpsc::channel::<Result<DicomWebDownloadResult, String>>();
+        let view_fallback_tags = self.preferences.view_fallback_tags.clone();
         thread::spawn(move || {
-            let result = download_dicomweb_group_request(&request, |update| {
+            let result = download_dicomweb_group_request(&request, &view_fallback_tags, |update| {
                 let _ = active_path_tx.send(update);
             })
             .map_err(|err| format!("{err:#}"));
@@ -553,7 +697,7 @@ impl DicomViewerApp {
 
     pub(super) fn insert_loaded_mammo(
         &mut self,
-        mut pending: PendingLoad,
+        pending: PendingLoad,
         ctx: &egui::Context,
     ) -> Result<(), String> {
         let slot = preferred_mammo_slot(&pending.image, self.mammo_group.len(), |index| {
@@ -572,49 +716,203 @@ impl DicomViewerApp {
             ));
         };
 
+        if pending.image.undecodable_reason().is_some() {
+            let key = pending.path.identity_key().into_owned();
+            let redownload = self
+                .dicomweb_failed_downloads
+                .remove(&key)
+                .map(|failed| *failed.retry);
+            self.mammo_failed_views.insert(
+                key,
+                FailedMammoView {
+                    source: pending.path.clone(),
+                    redownload,
+                    retry: None,
+                },
+            );
+        }
+        self.mammo_group[slot_index] = Some(self.mammo_viewport_from_pending(pending, ctx)?);
+        self.apply_gsps_windows(self.overlay_visible);
+
+        if self.loaded_mammo_count() == 1 {
+            if let Some(first_loaded_slot) = self.mammo_group.iter().position(Option::is_some) {
+                self.mammo_selected_index = first_loaded_slot;
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds the viewport of a loaded group member. A preview too broken to
+    /// upload becomes a placeholder, so one bad view does not discard the group.
+    fn mammo_viewport_from_pending(
+        &mut self,
+        mut pending: PendingLoad,
+        ctx: &egui::Context,
+    ) -> Result<MammoViewport, String> {
+        let max_side = MAX_TEXTURE_SIDE.load(Ordering::Relaxed);
+        let preview = match prepare_texture_upload(std::mem::take(&mut pending.preview), max_side) {
+            Ok(preview) => preview,
+            Err(err) => {
+                log::warn!("Showing placeholder for image that cannot be displayed: {err}");
+                self.mammo_failed_views.insert(
+                    pending.path.identity_key().into_owned(),
+                    FailedMammoView {
+                        source: pending.path.clone(),
+                        redownload: None,
+                        retry: None,
+                    },
+                );
+                let placeholder = pending.image.into_undecodable_placeholder(err);
+                pending = Self::pending_load(pending.path, placeholder)?;
+                std::mem::take(&mut pending.preview)
+            }
+        };
+
         Self::attach_matching_gsps_overlay(&mut pending.image, &self.pending_gsps_overlays);
         Self::attach_matching_sr_overlay(&mut pending.image, &self.pending_sr_overlays);
         Self::attach_matching_pm_overlay(&mut pending.image, &self.pending_pm_overlays);
 
-        let default_center = pending.image.window_center;
-        let default_width = pending.image.window_width;
-        let initial_frame = pending.initial_frame;
-
         let path_meta = DicomSourceMeta::from(&pending.path);
         let texture_name = Self::source_texture_name("mammo-group", &path_meta);
-        let texture = ctx.load_texture(texture_name, pending.preview, TextureOptions::LINEAR);
-        let history_thumb = pending.history_thumb;
+        let texture = ctx.load_texture(texture_name, preview, TextureOptions::LINEAR);
         let label = mammo_label(&pending.image, &path_meta);
-        self.mammo_group[slot_index] = Some(MammoViewport {
+        Ok(MammoViewport {
             path: path_meta,
+            window_center: pending.image.window_center,
+            window_width: pending.image.window_width,
             image: pending.image,
             texture,
-            history_thumb,
+            history_thumb: pending.history_thumb,
             label,
-            window_center: default_center,
-            window_width: default_width,
-            current_frame: initial_frame,
+            current_frame: pending.initial_frame,
             zoom: 1.0,
             pan: egui::Vec2::ZERO,
             frame_scroll_accum: 0.0,
+            window_preset_index: None,
+        })
+    }
+
+    /// Re-runs the load of a placeholder cell in the background, leaving the
+    /// other views of the group in place.
+    pub(super) fn retry_mammo_view(&mut self, index: usize, ctx: &egui::Context) {
+        let Some(viewport) = self.mammo_group.get(index).and_then(Option::as_ref) else {
+            return;
+        };
+        let load_options = self.dicom_load_options();
+        let Some(failed) = self
+            .mammo_failed_views
+            .get_mut(viewport.path.identity_key())
+        else {
+            return;
+        };
+        if failed.retry.is_some() {
+            return;
+        }
+
+        log::info!("Retrying load of {}.", viewport.path.display_label());
+        let source = failed.source.clone();
+        let redownload = failed.redownload.clone();
+        let (tx, rx) = mpsc::channel::<Result<PendingLoad, String>>();
+        thread::spawn(move || {
+            let source = match redownload {
+                Some(redownload) => redownload.download().map_err(|err| format!("{err:#}")),
+                None => Ok(source),
+            };
+            let _ = tx
+                .send(source.map(|source| Self::pending_group_member_load(source, &load_options)));
         });
+        failed.retry = Some(rx);
+        ctx.request_repaint();
+    }
 
-        if self.loaded_mammo_count() == 1 {
-            if let Some(first_loaded_slot) = self.mammo_group.iter().position(Option::is_some) {
-                self.mammo_selected_index = first_loaded_slot;
+    pub(super) fn poll_mammo_view_retries(&mut self, ctx: &egui::Context) {
+        let mut finished = Vec::new();
+        let mut still_running = false;
+        for (key, failed) in &mut self.mammo_failed_views {
+            let Some(receiver) = failed.retry.as_ref() else {
+                continue;
+            };
+            match receiver.try_recv() {
+                Ok(result) => {
+                    failed.retry = None;
+                    finished.push((key.clone(), result));
+                }
+                Err(TryRecvError::Empty) => still_running = true,
+                Err(TryRecvError::Disconnected) => {
+                    failed.retry = None;
+                    finished.push((key.clone(), Err("Retry worker disconnected.".to_string())));
+                }
             }
         }
-        Ok(())
+        if still_running {
+            ctx.request_repaint_after(ACTIVE_REPAINT_INTERVAL);
+        }
+
+        for (key, result) in finished {
+            self.apply_mammo_view_retry(&key, result, ctx);
+        }
+    }
+
+    fn apply_mammo_view_retry(
+        &mut self,
+        key: &str,
+        result: Result<PendingLoad, String>,
+        ctx: &egui::Context,
+    ) {
+        let pending = match result {
+            Ok(pending) => pending,
+            Err(err) => {
+                log::warn!("Retrying multi-view image failed: {err}");
+                return;
+            }
+        };
+        if let Some(reason) = pending.image.undecodable_reason() {
+            log::warn!("Retried multi-view image still cannot be shown: {reason}");
+            return;
+        }
+        let Some(index) = self.mammo_group.iter().position(|slot| {
+            slot.as_ref().is_some_and(|viewport| {
+                viewport.path.identity_key() == key && viewport.image.undecodable_reason().is_some()
+            })
+        }) else {
+            return;
+        };
+
+        self.mammo_failed_views.remove(key);
+        let viewport = match self.mammo_viewport_from_pending(pending, ctx) {
+            Ok(viewport) => viewport,
+            Err(err) => {
+                log::warn!("Retrying multi-view image failed: {err}");
+                return;
+            }
+        };
+        self.mammo_group[index] = Some(viewport);
+        self.apply_gsps_windows(self.overlay_visible);
+        self.reorder_complete_mammo_group();
+        if self.history_pushed_for_active_group {
+            let loaded = self
+                .mammo_group
+                .iter()
+                .filter_map(Option::as_ref)
+                .cloned()
+                .collect::<Vec<_>>();
+            self.push_group_history_entry(&loaded, self.mammo_selected_index, ctx);
+        }
+        ctx.request_repaint();
     }
 
     fn handle_mammo_load_failure(&mut self, err: &impl std::fmt::Display, clear_senders: bool) {
         self.set_load_error("Failed to load multi-view DICOM group.");
         log::error!("{err}");
         self.mammo_group.clear();
+        self.mammo_failed_views.clear();
+        self.dicomweb_failed_downloads.clear();
         self.history_pushed_for_active_group = false;
         self.dicomweb_active_group_expected = None;
         self.dicomweb_active_group_paths.clear();
         self.dicomweb_completed_background_groups.clear();
+        self.dicomweb_background_group_downloads.clear();
+        self.dicomweb_open_group_override = None;
         self.dicomweb_active_pending_paths.clear();
         self.dicomweb_active_path_receiver = None;
 
@@ -683,6 +981,66 @@ impl DicomViewerApp {
             .unwrap_or(0);
     }
 
+    fn begin_streamed_active_group(&mut self, count: usize) {
+        self.dicomweb_active_group_expected = Some(count);
+        if Self::is_supported_multi_view_group_size(count) {
+            self.mammo_load_receiver = None;
+            self.mammo_load_sender = None;
+            self.history_pushed_for_active_group = false;
+            self.clear_single_viewer();
+            self.mammo_failed_views.clear();
+            self.dicomweb_failed_downloads.clear();
+            self.mammo_group = (0..count).map(|_| None).collect();
+            self.mammo_selected_index = 0;
+            self.cine_mode = false;
+            self.last_cine_advance = None;
+            log::info!(
+                "Loading grouped study from DICOMweb (streaming active group {}, {} views)...",
+                Self::multi_view_layout_label(count),
+                count
+            );
+            let (tx, rx) = mpsc::channel::<PendingLoad>();
+            self.mammo_load_sender = Some(tx);
+            self.mammo_load_receiver = Some(rx);
+        }
+    }
+
+    /// Opens a background group that is still downloading. Its arrived
+    /// instances go through the same streaming path as the launch's open group
+    /// and the rest fill the grid as they land, instead of the group only
+    /// reaching history once complete.
+    pub(super) fn open_downloading_background_group(
+        &mut self,
+        group_index: usize,
+        ctx: &egui::Context,
+    ) {
+        let Some(download) = self
+            .dicomweb_background_group_downloads
+            .remove(&group_index)
+        else {
+            return;
+        };
+        log::info!(
+            "Opening DICOMweb group {} while downloading ({}/{} instances).",
+            group_index + 1,
+            download.paths.len(),
+            download.expected
+        );
+        self.sync_current_state_to_history();
+        self.clear_single_viewer();
+        self.mammo_group.clear();
+        self.history_pushed_for_active_group = false;
+        // Streaming delivers this group from here on, so its ready batch must
+        // not also be preloaded into history.
+        self.dicomweb_completed_background_groups
+            .insert(group_index);
+        self.dicomweb_open_group_override = Some(group_index);
+        self.dicomweb_active_group_paths.clear();
+        self.dicomweb_active_pending_paths = download.paths.into();
+        self.begin_streamed_active_group(download.expected);
+        ctx.request_repaint();
+    }
+
     pub(super) fn poll_dicomweb_active_paths(&mut self, ctx: &egui::Context) {
         let mut keep_receiver = false;
         if let Some(receiver) = self.dicomweb_active_path_receiver.take() {
@@ -690,30 +1048,48 @@ impl DicomViewerApp {
             loop {
                 match receiver.try_recv() {
                     Ok(DicomWebGroupStreamUpdate::ActiveGroupInstanceCount(count)) => {
-                        self.dicomweb_active_group_expected = Some(count);
-                        if Self::is_supported_multi_view_group_size(count) {
-                            self.mammo_load_receiver = None;
-                            self.mammo_load_sender = None;
-                            self.history_pushed_for_active_group = false;
-                            self.clear_single_viewer();
-                            self.mammo_group = (0..count).map(|_| None).collect();
-                            self.mammo_selected_index = 0;
-                            self.cine_mode = false;
-                            self.last_cine_advance = None;
-                            log::info!(
-                                "Loading grouped study from DICOMweb (streaming active group {}, {} views)...",
-                                Self::multi_view_layout_label(count),
-                                count
-                            );
-                            let (tx, rx) = mpsc::channel::<Result<PendingLoad, String>>();
-                            self.mammo_load_sender = Some(tx);
-                            self.mammo_load_receiver = Some(rx);
-                        }
+                        self.begin_streamed_active_group(count);
                     }
                     Ok(DicomWebGroupStreamUpdate::ActivePath(path)) => {
                         self.dicomweb_active_pending_paths.push_back(path);
                     }
+                    Ok(DicomWebGroupStreamUpdate::ActiveDownloadFailed { path, error, retry }) => {
+                        self.dicomweb_failed_downloads.insert(
+                            path.identity_key().into_owned(),
+                            FailedInstanceDownload { error, retry },
+                        );
+                        self.dicomweb_active_pending_paths.push_back(path);
+                    }
+                    Ok(DicomWebGroupStreamUpdate::BackgroundGroupInstanceCount {
+                        group_index,
+                        count,
+                    }) => {
+                        if !self
+                            .dicomweb_completed_background_groups
+                            .contains(&group_index)
+                        {
+                            self.dicomweb_background_group_downloads.insert(
+                                group_index,
+                                BackgroundGroupDownload {
+                                    expected: count,
+                                    paths: Vec::new(),
+                                },
+                            );
+                        }
+                    }
+                    Ok(DicomWebGroupStreamUpdate::BackgroundGroupPath { group_index, path }) => {
+                        if self.dicomweb_open_group_override == Some(group_index) {
+                            self.dicomweb_active_pending_paths.push_back(path);
+                        } else if let Some(download) = self
+                            .dicomweb_background_group_downloads
+                            .get_mut(&group_index)
+                        {
+                            download.paths.push(path);
+                        }
+                    }
                     Ok(DicomWebGroupStreamUpdate::BackgroundGroupReady { group_index, paths }) => {
+                        self.dicomweb_background_group_downloads
+                            .remove(&group_index);
                         if self
                             .dicomweb_completed_background_groups
                             .insert(group_index)
@@ -804,14 +1180,21 @@ impl DicomViewerApp {
                     count if Self::is_supported_multi_view_group_size(count) => {
                         self.dicomweb_active_group_paths.push((&path).into());
                         if let Some(sender) = self.mammo_load_sender.as_ref().cloned() {
+                            let load_options = self.dicom_load_options();
+                            let download_error = self
+                                .dicomweb_failed_downloads
+                                .get(path.identity_key().as_ref())
+                                .map(|failed| format!("Download failed: {}", failed.error));
                             thread::spawn(move || {
-                                let result = match load_dicom(&path) {
-                                    Ok(image) => Self::pending_load(path, image),
-                                    Err(err) => {
-                                        Err(format!("Error opening streamed DICOM: {err:#}"))
-                                    }
+                                let pending = match download_error {
+                                    Some(reason) => Self::pending_placeholder(
+                                        path,
+                                        &load_options.view_fallback_tags,
+                                        reason,
+                                    ),
+                                    None => Self::pending_group_member_load(path, &load_options),
                                 };
-                                let _ = sender.send(result);
+                                let _ = sender.send(pending);
                             });
                         } else {
                             let active_group_is_displayed = self.displayed_study_matches_paths(
@@ -836,6 +1219,8 @@ impl DicomViewerApp {
                                 self.cine_mode = false;
                                 self.dicomweb_active_group_paths.clear();
                                 self.dicomweb_completed_background_groups.clear();
+                                self.dicomweb_background_group_downloads.clear();
+                                self.dicomweb_open_group_override = None;
                                 self.dicomweb_active_pending_paths.clear();
                                 self.dicomweb_active_group_expected = None;
                                 self.dicomweb_active_path_receiver = None;
@@ -851,7 +1236,26 @@ impl DicomViewerApp {
         }
 
         if keep_receiver || !self.dicomweb_active_pending_paths.is_empty() {
-            ctx.request_repaint_after(Duration::from_millis(16));
+            ctx.request_repaint_after(ACTIVE_REPAINT_INTERVAL);
+        }
+    }
+
+    /// Remembers the in-flight launch for every source a finished download
+    /// produced; failed downloads never reach here, so they leave no link.
+    pub(super) fn record_remote_launch_request(&mut self, result: &DicomWebDownloadResult) {
+        let Some(request) = self.remote_launch_request.take() else {
+            return;
+        };
+        let sources: Vec<&DicomSource> = match result {
+            DicomWebDownloadResult::Single(paths) => paths.iter().collect(),
+            DicomWebDownloadResult::SeriesTabs(tabs) => {
+                tabs.iter().map(|tab| &tab.source).collect()
+            }
+            DicomWebDownloadResult::Grouped { groups, .. } => groups.iter().flatten().collect(),
+        };
+        for source in sources {
+            self.remote_launch_requests
+                .insert(source.identity_key().to_string(), request.clone());
         }
     }
 
@@ -862,120 +1266,142 @@ impl DicomViewerApp {
 
         match receiver.try_recv() {
             Ok(result) => match result {
-                Ok(download_result) => match download_result {
-                    DicomWebDownloadResult::Single(paths) => {
-                        let _ = self.load_selected_paths(paths, ctx);
-                    }
-                    DicomWebDownloadResult::Grouped { groups, open_group } => {
-                        let prepared_groups = groups
-                            .iter()
-                            .map(|group| Self::prepare_load_paths(group.clone()))
-                            .collect::<Vec<_>>();
-                        let grouped_gsps_overlays =
-                            Self::collect_grouped_gsps_overlays(&prepared_groups);
-                        let grouped_sr_overlays =
-                            Self::collect_grouped_sr_overlays(&prepared_groups);
-                        let grouped_pm_overlays =
-                            Self::collect_grouped_pm_overlays(&prepared_groups);
-                        let validated_open_group = if prepared_groups.is_empty() {
-                            0
-                        } else {
-                            open_group.min(prepared_groups.len().saturating_sub(1))
-                        };
-                        let active_group_len = prepared_groups
-                            .get(validated_open_group)
-                            .map(|group| group.image_paths.len())
-                            .unwrap_or(0);
-                        let active_group_paths = prepared_groups
-                            .get(validated_open_group)
-                            .map(|group| group.image_paths.clone())
-                            .unwrap_or_default();
-                        let streamed_count = self.dicomweb_active_group_paths.len();
-                        let streaming_started = streamed_count > 0;
-                        let active_group_is_multi_view =
-                            Self::is_supported_multi_view_group_size(active_group_len);
-                        let streamed_active_complete = streamed_count >= active_group_len
-                            && (active_group_len == 1 || active_group_is_multi_view)
-                            && self.dicomweb_active_pending_paths.is_empty()
-                            && (!active_group_is_multi_view || self.mammo_load_receiver.is_none());
-                        let active_group_is_displayed =
-                            self.displayed_study_matches_paths(active_group_paths.as_slice());
-                        let grouped_ready;
-
-                        if !streamed_active_complete && !streaming_started {
-                            self.load_local_groups(groups, validated_open_group, ctx);
-                            grouped_ready =
+                Ok(download_result) => {
+                    self.record_remote_launch_request(&download_result);
+                    match download_result {
+                        DicomWebDownloadResult::Single(paths) => {
+                            let _ = self.load_selected_paths(paths, ctx);
+                        }
+                        DicomWebDownloadResult::SeriesTabs(tabs) => {
+                            self.series_tabs = tabs;
+                            self.open_series_tab(0, ctx);
+                        }
+                        DicomWebDownloadResult::Grouped { groups, open_group } => {
+                            let prepared_groups = groups
+                                .iter()
+                                .map(|group| Self::prepare_load_paths(group.clone()))
+                                .collect::<Vec<_>>();
+                            let grouped_gsps_overlays =
+                                Self::collect_grouped_gsps_overlays(&prepared_groups);
+                            let grouped_sr_overlays =
+                                Self::collect_grouped_sr_overlays(&prepared_groups);
+                            let grouped_pm_overlays =
+                                Self::collect_grouped_pm_overlays(&prepared_groups);
+                            let open_group =
+                                self.dicomweb_open_group_override.unwrap_or(open_group);
+                            let validated_open_group = if prepared_groups.is_empty() {
+                                0
+                            } else {
+                                open_group.min(prepared_groups.len().saturating_sub(1))
+                            };
+                            let active_group_len = prepared_groups
+                                .get(validated_open_group)
+                                .map(|group| group.image_paths.len())
+                                .unwrap_or(0);
+                            let active_group_paths = prepared_groups
+                                .get(validated_open_group)
+                                .map(|group| group.image_paths.clone())
+                                .unwrap_or_default();
+                            let streamed_count = self.dicomweb_active_group_paths.len();
+                            let streaming_started = streamed_count > 0;
+                            let active_group_is_multi_view =
+                                Self::is_supported_multi_view_group_size(active_group_len);
+                            let streamed_active_complete = streamed_count >= active_group_len
+                                && (active_group_len == 1 || active_group_is_multi_view)
+                                && self.dicomweb_active_pending_paths.is_empty()
+                                && (!active_group_is_multi_view
+                                    || self.mammo_load_receiver.is_none());
+                            let active_group_is_displayed =
                                 self.displayed_study_matches_paths(active_group_paths.as_slice());
-                        } else {
-                            let completed_background_groups =
-                                self.dicomweb_completed_background_groups.clone();
-                            self.preload_non_active_groups_into_history(
-                                &prepared_groups,
-                                validated_open_group,
-                                Some(&completed_background_groups),
-                                ctx,
-                            );
-                            if !self.history_pushed_for_active_group {
-                                if Self::is_supported_multi_view_group_size(active_group_len)
-                                    && self.mammo_group_complete()
-                                    && active_group_is_displayed
-                                {
-                                    self.reorder_complete_mammo_group();
-                                    let loaded = self
-                                        .mammo_group
-                                        .iter()
-                                        .filter_map(Option::as_ref)
-                                        .cloned()
-                                        .collect::<Vec<_>>();
-                                    self.push_group_history_entry(
-                                        &loaded,
-                                        self.mammo_selected_index,
-                                        ctx,
-                                    );
-                                } else if !active_group_is_displayed {
-                                    if let Some(active_group) =
-                                        prepared_groups.get(validated_open_group).cloned()
+                            let grouped_ready;
+
+                            if !streamed_active_complete && !streaming_started {
+                                self.load_local_groups(groups, validated_open_group, ctx);
+                                grouped_ready = self
+                                    .displayed_study_matches_paths(active_group_paths.as_slice());
+                            } else {
+                                let completed_background_groups =
+                                    self.dicomweb_completed_background_groups.clone();
+                                self.preload_non_active_groups_into_history(
+                                    &prepared_groups,
+                                    validated_open_group,
+                                    Some(&completed_background_groups),
+                                    ctx,
+                                );
+                                if !self.history_pushed_for_active_group {
+                                    if Self::is_supported_multi_view_group_size(active_group_len)
+                                        && self.mammo_group_complete()
+                                        && active_group_is_displayed
                                     {
-                                        self.enqueue_history_preload_job(
-                                            HistoryPreloadJob::Group(active_group),
+                                        self.reorder_complete_mammo_group();
+                                        let loaded = self
+                                            .mammo_group
+                                            .iter()
+                                            .filter_map(Option::as_ref)
+                                            .cloned()
+                                            .collect::<Vec<_>>();
+                                        self.push_group_history_entry(
+                                            &loaded,
+                                            self.mammo_selected_index,
                                             ctx,
                                         );
+                                    } else if !active_group_is_displayed {
+                                        if let Some(active_group) =
+                                            prepared_groups.get(validated_open_group).cloned()
+                                        {
+                                            self.enqueue_history_preload_job(
+                                                HistoryPreloadJob::Group(active_group),
+                                                ctx,
+                                            );
+                                        }
                                     }
+                                    self.history_pushed_for_active_group = true;
                                 }
-                                self.history_pushed_for_active_group = true;
+                                self.move_current_history_to_front();
+                                grouped_ready = if active_group_is_multi_view {
+                                    active_group_is_displayed && self.mammo_group_complete()
+                                } else {
+                                    active_group_is_displayed
+                                };
+                            }
+                            self.set_authoritative_pending_gsps_overlays(grouped_gsps_overlays);
+                            self.set_authoritative_pending_sr_overlays(grouped_sr_overlays);
+                            self.set_authoritative_pending_pm_overlays(grouped_pm_overlays);
+
+                            if streamed_active_complete || !streaming_started {
+                                self.dicomweb_active_group_expected = None;
+                                self.dicomweb_active_group_paths.clear();
+                                self.dicomweb_completed_background_groups.clear();
+                                self.dicomweb_background_group_downloads.clear();
+                                self.dicomweb_open_group_override = None;
+                                self.dicomweb_active_pending_paths.clear();
+                                self.dicomweb_active_path_receiver = None;
+                                self.mammo_load_sender = None;
+                                self.history_pushed_for_active_group = false;
+                            }
+                            if grouped_ready {
+                                self.clear_load_error();
+                                log::info!("Loaded grouped study from DICOMweb.");
                             }
-                            self.move_current_history_to_front();
-                            grouped_ready = if active_group_is_multi_view {
-                                active_group_is_displayed && self.mammo_group_complete()
-                            } else {
-                                active_group_is_displayed
-                            };
-                        }
-                        self.set_authoritative_pending_gsps_overlays(grouped_gsps_overlays);
-                        self.set_authoritative_pending_sr_overlays(grouped_sr_overlays);
-                        self.set_authoritative_pending_pm_overlays(grouped_pm_overlays);
-
-                        if streamed_active_complete || !streaming_started {
-                            self.dicomweb_active_group_expected = None;
-                            self.dicomweb_active_group_paths.clear();
-                            self.dicomweb_completed_background_groups.clear();
-                            self.dicomweb_active_pending_paths.clear();
-                            self.dicomweb_active_path_receiver = None;
-                            self.mammo_load_sender = None;
-                            self.history_pushed_for_active_group = false;
-                        }
-                        if grouped_ready {
-                            self.clear_load_error();
-                            log::info!("Loaded grouped study from DICOMweb.");
                         }
                     }
-                },
+                }
                 Err(err) => {
-                    self.set_load_error("DICOMweb request failed.");
                     log::error!("DICOMweb error: {err}");
+                    let message = if matches!(
+                        self.remote_launch_request.take(),
+                        Some(LaunchRequest::RemoteFile(_))
+                    ) {
+                        "File download failed."
+                    } else {
+                        "DICOMweb request failed."
+                    };
+                    self.set_load_error_with_detail(message, err);
                     self.dicomweb_active_group_expected = None;
                     self.dicomweb_active_group_paths.clear();
                     self.dicomweb_completed_background_groups.clear();
+                    self.dicomweb_background_group_downloads.clear();
+                    self.dicomweb_open_group_override = None;
                     self.dicomweb_active_pending_paths.clear();
                     self.dicomweb_active_path_receiver = None;
                     self.mammo_load_sender = None;
@@ -984,7 +1410,7 @@ impl DicomViewerApp {
             },
             Err(TryRecvError::Empty) => {
                 self.dicomweb_receiver = Some(receiver);
-                ctx.request_repaint_after(Duration::from_millis(16));
+                ctx.request_repaint_after(ACTIVE_REPAINT_INTERVAL);
             }
             Err(TryRecvError::Disconnected) => {
                 self.set_load_error("DICOMweb download worker disconnected.");
@@ -992,6 +1418,8 @@ impl DicomViewerApp {
                 self.dicomweb_active_group_expected = None;
                 self.dicomweb_active_group_paths.clear();
                 self.dicomweb_completed_background_groups.clear();
+                self.dicomweb_background_group_downloads.clear();
+                self.dicomweb_open_group_override = None;
                 self.dicomweb_active_pending_paths.clear();
                 self.dicomweb_active_path_receiver = None;
                 self.mammo_load_sender = None;
@@ -1005,26 +1433,17 @@ impl DicomViewerApp {
             return;
         };
 
-        let mut had_error = false;
         let mut should_continue = true;
         let mut loaded_any = false;
         loop {
             match receiver.try_recv() {
-                Ok(result) => match result {
-                    Ok(pending) => {
-                        if let Err(err) = self.insert_loaded_mammo(pending, ctx) {
-                            self.handle_mammo_load_failure(&err, true);
-                            return;
-                        }
-                        loaded_any = true;
-                    }
-                    Err(err) => {
-                        self.handle_mammo_load_failure(&err, false);
-                        had_error = true;
-                        should_continue = false;
-                        break;
+                Ok(pending) => {
+                    if let Err(err) = self.insert_loaded_mammo(pending, ctx) {
+                        self.handle_mammo_load_failure(&err, true);
+                        return;
                     }
-                },
+                    loaded_any = true;
+                }
                 Err(TryRecvError::Empty) => break,
                 Err(TryRecvError::Disconnected) => {
                     should_continue = false;
@@ -1033,7 +1452,23 @@ impl DicomViewerApp {
             }
         }
 
-        if loaded_any && !had_error {
+        if loaded_any {
+            if self.mammo_group_complete() {
+                let undecodable = self.undecodable_mammo_count();
+                if undecodable == self.mammo_group.len() {
+                    self.handle_mammo_load_failure(
+                        &"No image in the multi-view group has decodable pixel data.",
+                        true,
+                    );
+                    return;
+                }
+                if undecodable > 0 {
+                    log::warn!(
+                        "Multi-view group loaded with {undecodable} of {} views shown as placeholders.",
+                        self.mammo_group.len()
+                    );
+                }
+            }
             self.reorder_complete_mammo_group();
             self.clear_load_error();
             if self.mammo_group_complete()
@@ -1052,20 +1487,14 @@ impl DicomViewerApp {
                 self.push_group_history_entry(&loaded, self.mammo_selected_index, ctx);
                 self.move_current_history_to_front();
                 self.history_pushed_for_active_group = true;
+                self.autoplay_cine_on_open();
             }
             ctx.request_repaint();
         }
 
-        if had_error {
-            self.mammo_load_receiver = None;
-            self.mammo_load_sender = None;
-            self.cine_mode = false;
-            return;
-        }
-
         if should_continue {
             self.mammo_load_receiver = Some(receiver);
-            ctx.request_repaint_after(Duration::from_millis(16));
+            ctx.request_repaint_after(ACTIVE_REPAINT_INTERVAL);
             return;
         }
 
@@ -1082,21 +1511,73 @@ impl DicomViewerApp {
                     .cloned()
                     .collect::<Vec<_>>();
                 self.push_group_history_entry(&loaded, self.mammo_selected_index, ctx);
+                self.autoplay_cine_on_open();
             }
             if self.mammo_group.len() == 8 {
                 log::info!(target: "perf", "{OPEN_COMPLETED_EVENT}");
             }
         } else {
-            self.set_load_error(
-                "Multi-view group load incomplete: worker exited before all images were received.",
-            );
-            log::warn!(
-                "Multi-view group load incomplete: worker exited before all images were received."
-            );
+            let loaded = self
+                .mammo_group
+                .iter()
+                .flatten()
+                .map(|viewport| (mammo_view_code(&viewport.image), viewport.label.clone()))
+                .collect::<Vec<_>>();
+            let (summary, detail) =
+                Self::incomplete_mammo_group_message(self.mammo_group.len(), &loaded);
+            log::warn!("{summary} {detail}");
+            self.set_load_error_with_detail(summary, detail);
         }
         ctx.request_repaint();
     }
 
+    /// Summary and detail for a multi-view group that ended short, built from
+    /// the view codes of what did load so an incomplete study can be told
+    /// apart from missing or unreadable laterality/view tags.
+    pub(super) fn incomplete_mammo_group_message(
+        expected: usize,
+        loaded: &[(String, String)],
+    ) -> (String, String) {
+        let summary = format!(
+            "Multi-view group load incomplete: {} of {expected} images loaded.",
+            loaded.len()
+        );
+
+        let mut detail = if loaded.is_empty() {
+            "No image was received.".to_string()
+        } else {
+            format!(
+                "Loaded: {}.",
+                loaded
+                    .iter()
+                    .map(|(_, label)| label.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        };
+        if expected == 4 {
+            let missing = ["RCC", "LCC", "RMLO", "LMLO"]
+                .into_iter()
+                .filter(|code| {
+                    // Codes may carry a "For Processing" suffix.
+                    !loaded
+                        .iter()
+                        .any(|(loaded_code, _)| loaded_code.split(' ').next() == Some(*code))
+                })
+                .collect::<Vec<_>>();
+            if !missing.is_empty() {
+                detail.push_str(&format!(" Missing: {}.", missing.join(", ")));
+            }
+        }
+        let unclassified = loaded.iter().filter(|(code, _)| code.is_empty()).count();
+        if unclassified > 0 {
+            detail.push_str(&format!(
+                " {unclassified} loaded image(s) have no usable laterality/view tags."
+            ));
+        }
+        (summary, detail)
+    }
+
     pub(super) fn poll_single_load(&mut self, ctx: &egui::Context) {
         let Some(receiver) = self.single_load_receiver.take() else {
             return;
@@ -1109,6 +1590,9 @@ impl DicomViewerApp {
                         self.apply_loaded_single(*pending, ctx);
                         self.clear_load_error();
                     }
+                    Ok(PendingSingleLoad::PackedViews(views)) => {
+                        self.apply_loaded_packed_views(views, ctx);
+                    }
                     Ok(PendingSingleLoad::StructuredReport { path, report }) => {
                         self.apply_loaded_structured_report(path, *report, ctx);
                         self.clear_load_error();
@@ -1123,7 +1607,7 @@ impl DicomViewerApp {
             }
             Err(TryRecvError::Empty) => {
                 self.single_load_receiver = Some(receiver);
-                ctx.request_repaint_after(Duration::from_millis(16));
+                ctx.request_repaint_after(ACTIVE_REPAINT_INTERVAL);
             }
             Err(TryRecvError::Disconnected) => {
                 self.single_load_receiver = None;
@@ -1255,7 +1739,10 @@ impl DicomViewerApp {
                 self.mammo_load_sender = None;
                 self.history_pushed_for_active_group = false;
                 if let Some(path) = paths.into_iter().next() {
-                    self.load_path(path, ctx);
+                    // A grouped DICOMweb launch whose active group is one instance may
+                    // have every view packed into that instance's frames; only a
+                    // mammogram is split, so a four-frame cine stays one image.
+                    self.load_path(path, preserve_history_preload, ctx);
                 }
                 Ok(())
             }
@@ -1285,6 +1772,67 @@ impl DicomViewerApp {
         }
     }
 
+    /// Index of the series tab whose image is on screen, if any.
+    pub(super) fn active_series_tab(&self) -> Option<usize> {
+        let current = self.current_single_path.as_ref()?;
+        self.series_tabs
+            .iter()
+            .position(|tab| tab.source.identity_key() == current.identity_key())
+    }
+
+    /// Shows a series tab, reusing its history entry when it was opened
+    /// before so switching back keeps window/level, zoom and frame.
+    pub(super) fn open_series_tab(&mut self, index: usize, ctx: &egui::Context) {
+        let Some(source) = self.series_tabs.get(index).map(|tab| tab.source.clone()) else {
+            return;
+        };
+        let entry_id = history_id_from_paths(std::slice::from_ref(&source));
+        if let Some(history_index) = self
+            .history_entries
+            .iter()
+            .position(|entry| entry.id == entry_id)
+        {
+            self.open_history_entry(history_index, ctx);
+        } else {
+            let _ = self.load_selected_paths(vec![source], ctx);
+        }
+    }
+
+    /// Index of the group page on screen, if the displayed study is one.
+    pub(super) fn active_group_page(&self) -> Option<usize> {
+        let current = self.current_history_id()?;
+        self.group_pages.iter().position(|id| *id == current)
+    }
+
+    /// History entry of a group page, once the group has loaded.
+    pub(super) fn group_page_history_index(&self, page: usize) -> Option<usize> {
+        let id = self.group_pages.get(page)?;
+        self.history_entries
+            .iter()
+            .position(|entry| entry.id == *id)
+    }
+
+    /// Flips to the previous or next group page, wrapping around. Pages
+    /// still loading in the background are skipped.
+    pub(super) fn flip_group_page(&mut self, direction: i32, ctx: &egui::Context) {
+        let Some(active) = self.active_group_page() else {
+            return;
+        };
+        let len = self.group_pages.len();
+        let target = (1..len)
+            .map(|offset| {
+                if direction < 0 {
+                    (active + len - offset) % len
+                } else {
+                    (active + offset) % len
+                }
+            })
+            .find_map(|page| self.group_page_history_index(page));
+        if let Some(index) = target {
+            self.switch_to_history_entry(index, ctx);
+        }
+    }
+
     pub(super) fn load_selected_paths<T>(
         &mut self,
         paths: Vec<T>,
@@ -1335,7 +1883,7 @@ impl DicomViewerApp {
         let mut preview = None;
         let mut initial_frame = 0;
         for frame_index in 0..image.frame_count() {
-            if let Some(rendered) = Self::render_image_frame(
+            if let Some(rendered) = Self::render_display_frame(
                 &image,
                 frame_index,
                 image.window_center,
@@ -1361,7 +1909,65 @@ impl DicomViewerApp {
         })
     }
 
-    pub(super) fn load_path(&mut self, path: DicomSource, ctx: &egui::Context) {
+    /// Prepares each frame of a multi-frame instance that packs a whole group
+    /// as its own multi-view cell.
+    pub(super) fn pending_packed_views(
+        path: DicomSource,
+        image: DicomImage,
+    ) -> Result<Vec<PendingLoad>, String> {
+        image
+            .decode_all_frames()
+            .map_err(|err| format!("Error decoding packed multi-view DICOM: {err:#}"))?;
+        image
+            .split_packed_view_frames()
+            .into_iter()
+            .map(|view| Self::pending_load(path.clone(), view))
+            .collect()
+    }
+
+    /// Loads one member of a multi-view group. An image that cannot be opened
+    /// or decoded becomes a labelled placeholder instead of an error, so the
+    /// rest of the group loads and the cell can be retried.
+    pub(super) fn pending_group_member_load(
+        path: DicomSource,
+        load_options: &DicomLoadOptions,
+    ) -> PendingLoad {
+        let decoded = load_dicom(&path, load_options)
+            .map_err(|err| format!("{err:#}"))
+            .and_then(|image| Self::pending_load(path.clone(), image));
+        match decoded {
+            Ok(pending) => pending,
+            Err(err) => {
+                log::warn!("Showing placeholder for undecodable image in group: {err}");
+                Self::pending_placeholder(path, &load_options.view_fallback_tags, err)
+            }
+        }
+    }
+
+    /// Prepares a placeholder cell for `path` without rendering it, so it
+    /// cannot fail the way a real preview can.
+    pub(super) fn pending_placeholder(
+        path: DicomSource,
+        view_fallback_tags: &[String],
+        reason: String,
+    ) -> PendingLoad {
+        let image = load_dicom_placeholder(&path, view_fallback_tags, reason);
+        let preview = ColorImage::new([1, 1], vec![egui::Color32::BLACK]);
+        PendingLoad {
+            path,
+            image,
+            history_thumb: preview.clone(),
+            preview,
+            initial_frame: 0,
+        }
+    }
+
+    pub(super) fn load_path(
+        &mut self,
+        path: DicomSource,
+        split_packed_views: bool,
+        ctx: &egui::Context,
+    ) {
         self.mammo_load_receiver = None;
         self.mammo_load_sender = None;
         self.single_load_receiver = None;
@@ -1369,9 +1975,17 @@ impl DicomViewerApp {
         self.clear_load_error();
         log::info!("Loading selected DICOM...");
         log::info!(target: "perf", "{OPEN_STARTED_EVENT}");
+        let load_options = self.dicom_load_options();
         let (tx, rx) = mpsc::channel::<Result<PendingSingleLoad, String>>();
         thread::spawn(move || {
-            let result = match load_dicom(&path) {
+            let result = match load_dicom(&path, &load_options) {
+                Ok(image)
+                    if split_packed_views
+                        && image.frame_count() == PACKED_MAMMO_VIEW_COUNT
+                        && image_modality(&image).as_deref() == Some("MG") =>
+                {
+                    Self::pending_packed_views(path, image).map(PendingSingleLoad::PackedViews)
+                }
                 Ok(image) => Self::pending_load(path, image).map(|pending| {
                     log::info!(target: "perf", "{OPEN_DICOM_LOADED_EVENT}");
                     PendingSingleLoad::Image(Box::new(pending))
@@ -1391,9 +2005,10 @@ impl DicomViewerApp {
         self.history_pushed_for_active_group = false;
         self.clear_load_error();
         log::info!("Loading selected Parametric Map...");
+        let load_options = self.dicom_load_options();
         let (tx, rx) = mpsc::channel::<Result<PendingSingleLoad, String>>();
         thread::spawn(move || {
-            let result = match load_parametric_map(&path) {
+            let result = match load_parametric_map(&path, &load_options.view_fallback_tags) {
                 Ok(image) => Self::pending_load(path, image)
                     .map(|pending| PendingSingleLoad::Image(Box::new(pending))),
                 Err(err) => Err(format!("Error opening selected Parametric Map: {err:#}")),
@@ -1438,18 +2053,19 @@ impl DicomViewerApp {
         Self::attach_matching_sr_overlay(&mut image, &self.pending_sr_overlays);
         Self::attach_matching_pm_overlay(&mut image, &self.pending_pm_overlays);
         self.overlay_visible = false;
+        self.gsps_saved_windows.clear();
         self.clear_load_error();
         self.reset_live_measurement();
 
-        self.window_center = image.window_center;
-        self.window_width = image.window_width;
+        self.discard_subtraction();
+        self.remember_manual_window();
+        let sticky_window = self.sticky_window_for_image(&image);
+        (self.window_center, self.window_width) =
+            sticky_window.unwrap_or((image.window_center, image.window_width));
         self.current_frame = initial_frame;
         self.cine_mode = false;
         self.last_cine_advance = None;
-        self.cine_fps = image
-            .recommended_cine_fps
-            .unwrap_or(DEFAULT_CINE_FPS)
-            .clamp(1.0, 120.0);
+        self.cine_fps = self.initial_cine_fps_for_image(&image);
 
         let history_image = image.clone();
         let path_meta = DicomSourceMeta::from(&path);
@@ -1457,14 +2073,26 @@ impl DicomViewerApp {
         self.image = Some(image);
         self.current_single_path = Some(path_meta.clone());
         self.mammo_group.clear();
+        self.promoted_cell = None;
         self.mammo_selected_index = 0;
         self.reset_single_view_transform();
         self.single_view_frame_scroll_accum = 0.0;
+        self.single_view_content_uv = None;
         self.frame_wait_pending = false;
-        if let Some(texture) = self.texture.as_mut() {
-            texture.set(preview, TextureOptions::LINEAR);
-        } else {
-            self.texture = Some(ctx.load_texture("dicom-image", preview, TextureOptions::LINEAR));
+        self.upload_single_texture(ctx, preview);
+        if sticky_window.is_some() {
+            // The preview was rendered with the image's own window.
+            self.rebuild_texture(ctx);
+        }
+        self.single_frame_range = self
+            .launch_frame_range
+            .take()
+            .and_then(|frames| clamp_frame_range(frames, history_image.frame_count()));
+        if let Some(frames) = self.single_frame_range.clone() {
+            if !frames.contains(&self.current_frame) {
+                self.set_single_current_frame(*frames.start());
+                self.rebuild_texture(ctx);
+            }
         }
         log::info!(target: "perf", "{OPEN_COMPLETED_EVENT}");
         let history_texture = self.texture.clone();
@@ -1483,9 +2111,51 @@ impl DicomViewerApp {
                 ctx,
             );
         }
+        self.autoplay_cine_on_open();
         log::info!("Loaded selected DICOM.");
     }
 
+    pub(super) fn apply_loaded_packed_views(
+        &mut self,
+        views: Vec<PendingLoad>,
+        ctx: &egui::Context,
+    ) {
+        let view_count = views.len();
+        self.clear_single_viewer();
+        self.mammo_group = (0..view_count).map(|_| None).collect();
+        self.mammo_selected_index = 0;
+        self.cine_mode = false;
+        self.last_cine_advance = None;
+        for pending in views {
+            if let Err(err) = self.insert_loaded_mammo(pending, ctx) {
+                self.handle_mammo_load_failure(&err, true);
+                return;
+            }
+        }
+        for viewport in self.mammo_group.iter_mut().filter_map(Option::as_mut) {
+            if let Some(stored_frame) = viewport.image.display_frame_index_to_stored(0) {
+                viewport.label = format!(
+                    "Frame {} ({})",
+                    stored_frame + 1,
+                    viewport.path.display_label()
+                );
+            }
+        }
+        self.reorder_complete_mammo_group();
+        self.clear_load_error();
+        let loaded = self
+            .mammo_group
+            .iter()
+            .filter_map(Option::as_ref)
+            .cloned()
+            .collect::<Vec<_>>();
+        self.push_group_history_entry(&loaded, self.mammo_selected_index, ctx);
+        self.move_current_history_to_front();
+        self.history_pushed_for_active_group = true;
+        log::info!(target: "perf", "{OPEN_COMPLETED_EVENT}");
+        log::info!("Split multi-frame DICOM into {view_count} packed views.");
+    }
+
     pub(super) fn apply_loaded_structured_report(
         &mut self,
         path: DicomSource,
@@ -1518,6 +2188,8 @@ impl DicomViewerApp {
         self.history_pushed_for_active_group = false;
         self.clear_single_viewer();
         self.clear_load_error();
+        self.mammo_failed_views.clear();
+        self.dicomweb_failed_downloads.clear();
         self.mammo_group = (0..group_len).map(|_| None).collect();
         self.mammo_selected_index = 0;
         self.cine_mode = false;
@@ -1527,31 +2199,18 @@ impl DicomViewerApp {
             Self::multi_view_layout_label(group_len)
         );
 
-        let (tx, rx) = mpsc::channel::<Result<PendingLoad, String>>();
+        let load_options = self.dicom_load_options();
+        let (tx, rx) = mpsc::channel::<PendingLoad>();
         if group_len == 8 {
             log::info!(target: "perf", "{OPEN_STARTED_EVENT}");
         }
         thread::spawn(move || {
             for path in paths {
-                match load_dicom(&path) {
-                    Ok(image) => {
-                        let pending = match Self::pending_load(path, image) {
-                            Ok(pending) => pending,
-                            Err(err) => {
-                                let _ = tx.send(Err(err));
-                                return;
-                            }
-                        };
-                        if group_len == 8 {
-                            log::info!(target: "perf", "{OPEN_DICOM_LOADED_EVENT}");
-                        }
-                        let _ = tx.send(Ok(pending));
-                    }
-                    Err(err) => {
-                        let _ = tx.send(Err(format!("Error opening DICOM in group: {err:#}")));
-                        return;
-                    }
+                let pending = Self::pending_group_member_load(path, &load_options);
+                if group_len == 8 {
+                    log::info!(target: "perf", "{OPEN_DICOM_LOADED_EVENT}");
                 }
+                let _ = tx.send(pending);
             }
         });
         self.mammo_load_receiver = Some(rx);
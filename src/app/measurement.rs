@@ -11,6 +11,10 @@ const MEASUREMENT_LABEL_OFFSET_X: f32 = 8.0;
 const MEASUREMENT_LABEL_OFFSET_Y: f32 = 8.0;
 const MEASUREMENT_LABEL_PADDING_X: f32 = 4.0;
 const MEASUREMENT_LABEL_PADDING_Y: f32 = 2.0;
+/// Share of the canvas the measured region fills after Zoom to Measurement.
+const MEASUREMENT_ZOOM_FILL: f32 = 0.9;
+/// Smallest region side, in image pixels, Zoom to Measurement frames.
+const MEASUREMENT_ZOOM_MIN_SIDE: f32 = 8.0;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(super) enum MeasurementTarget {
@@ -109,6 +113,10 @@ impl DicomViewerApp {
     }
 
     pub(super) fn set_single_current_frame(&mut self, frame_index: usize) {
+        let frame_index = match self.single_frame_range.as_ref() {
+            Some(range) => frame_index.clamp(*range.start(), *range.end()),
+            None => frame_index,
+        };
         if self.current_frame != frame_index {
             self.clear_live_measurement();
             self.current_frame = frame_index;
@@ -193,6 +201,25 @@ impl DicomViewerApp {
         );
     }
 
+    /// Image-space rectangle spanned by the single view's measurement line,
+    /// which Zoom to Measurement frames.
+    pub(super) fn single_measurement_bounds(&self) -> Option<egui::Rect> {
+        self.live_measurement
+            .as_ref()
+            .filter(|measurement| measurement.target == MeasurementTarget::Single)
+            .filter(|measurement| measurement.anchor_image_pos != measurement.live_image_pos)
+            .map(|measurement| {
+                egui::Rect::from_two_pos(measurement.anchor_image_pos, measurement.live_image_pos)
+            })
+    }
+
+    /// Zooms the single view to the measured region on its next layout.
+    pub(super) fn request_measurement_zoom(&mut self) {
+        if self.single_measurement_bounds().is_some() {
+            self.measurement_zoom_requested = true;
+        }
+    }
+
     pub(super) fn update_measurement_cursor(
         &self,
         ctx: &egui::Context,
@@ -215,6 +242,36 @@ impl DicomViewerApp {
     }
 }
 
+/// Single-view zoom and pan that center `region` (image pixels) and fit it to
+/// `MEASUREMENT_ZOOM_FILL` of the canvas. `fit_scale` is the points per image
+/// pixel at zoom 1.
+pub(super) fn zoom_to_region(
+    region: egui::Rect,
+    image_size: egui::Vec2,
+    content_uv: egui::Rect,
+    canvas_size: egui::Vec2,
+    fit_scale: f32,
+    max_zoom: f32,
+) -> (f32, egui::Vec2) {
+    let region_size = region
+        .size()
+        .max(egui::Vec2::splat(MEASUREMENT_ZOOM_MIN_SIDE));
+    let zoom = (canvas_size.x / (region_size.x * fit_scale))
+        .min(canvas_size.y / (region_size.y * fit_scale))
+        * MEASUREMENT_ZOOM_FILL;
+    let zoom = if zoom.is_finite() {
+        zoom.clamp(1.0, max_zoom)
+    } else {
+        1.0
+    };
+    // The image rect is centered on the canvas plus pan and the content
+    // offset, so a point lands on the canvas center when pan cancels its
+    // offset from the content center.
+    let scale = fit_scale * zoom;
+    let pan = (content_uv.center().to_vec2() * image_size - region.center().to_vec2()) * scale;
+    (zoom, pan)
+}
+
 fn measurement_units(geometry: MeasurementGeometry) -> MeasurementUnits {
     if geometry.pixel_spacing_mm.is_some() {
         MeasurementUnits::Millimeters
@@ -359,6 +416,43 @@ fn clamp_rect_to_clip(rect: egui::Rect, clip_rect: egui::Rect) -> egui::Rect {
 mod tests {
     use super::*;
 
+    #[test]
+    fn zoom_to_region_centers_and_fills_the_canvas() {
+        let image_size = egui::vec2(200.0, 100.0);
+        let full_uv = egui::Rect::from_min_max(egui::Pos2::ZERO, egui::pos2(1.0, 1.0));
+        let region = egui::Rect::from_min_max(egui::pos2(150.0, 25.0), egui::pos2(170.0, 35.0));
+        let (zoom, pan) = zoom_to_region(
+            region,
+            image_size,
+            full_uv,
+            egui::vec2(400.0, 200.0),
+            2.0,
+            12.0,
+        );
+        // 20x10 px region at 2 points per pixel on a 400x200 canvas.
+        assert!((zoom - 9.0).abs() < 1e-4);
+
+        let draw_size = image_size * 2.0 * zoom;
+        let image_rect = DicomViewerApp::single_view_image_rect(
+            egui::pos2(200.0, 100.0),
+            pan,
+            draw_size,
+            full_uv,
+        );
+        let region_center = image_rect.min + region.center().to_vec2() * 2.0 * zoom;
+        assert!((region_center - egui::pos2(200.0, 100.0)).length() < 1e-3);
+
+        let (capped, _) = zoom_to_region(
+            region,
+            image_size,
+            full_uv,
+            egui::vec2(400.0, 200.0),
+            2.0,
+            4.0,
+        );
+        assert_eq!(capped, 4.0);
+    }
+
     #[test]
     fn screen_to_image_pos_clamps_to_image_rect() {
         let geometry = MeasurementGeometry {
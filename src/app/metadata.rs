@@ -6,6 +6,10 @@ use crate::dicom::{
 };
 
 const METADATA_OVERLAY_TEXT_BACKGROUND: egui::Color32 = egui::Color32::from_black_alpha(128);
+const COMPACT_INFO_FIELDS: [&str; 3] = ["PatientID", "StudyDate", "Modality"];
+const COMPACT_INFO_SEPARATOR: &str = " \u{2022} ";
+const HEURISTIC_OPEN_NOTE: &str = "Opened heuristically (no DICOM file header)";
+const WINDOW_LEVEL_LOCKED_NOTE: &str = "W/L locked (K)";
 
 impl DicomViewerApp {
     pub(super) fn active_full_metadata(&self) -> Option<Arc<[FullMetadataField]>> {
@@ -64,17 +68,34 @@ impl DicomViewerApp {
     pub(super) fn show_metadata_ui(&mut self, ctx: &egui::Context) {
         let has_full_metadata = self.has_active_full_metadata();
         let toggle_enabled = has_full_metadata && self.can_toggle_full_metadata_popup();
-        let open_requested = self
-            .active_metadata()
-            .map(|metadata| {
-                Self::show_summary_metadata_overlay(
-                    ctx,
-                    metadata,
-                    &self.visible_metadata_fields,
-                    toggle_enabled,
-                )
-            })
-            .unwrap_or(false);
+        let open_requested = if self.preferences.presentation_mode {
+            false
+        } else if self.preferences.compact_info_hud {
+            if let Some(line) = self.compact_info_line() {
+                Self::show_compact_info_hud(ctx, &line, self.filmstrip_bottom_inset());
+            }
+            false
+        } else {
+            let heuristic_note = self
+                .active_image()
+                .is_some_and(|image| image.opened_heuristically)
+                .then_some(HEURISTIC_OPEN_NOTE);
+            let notes = heuristic_note
+                .into_iter()
+                .chain(self.window_level_lock_note())
+                .collect::<Vec<_>>();
+            self.active_metadata()
+                .map(|metadata| {
+                    Self::show_summary_metadata_overlay(
+                        ctx,
+                        metadata,
+                        &self.visible_metadata_fields,
+                        &notes,
+                        toggle_enabled,
+                    )
+                })
+                .unwrap_or(false)
+        };
 
         if open_requested && toggle_enabled {
             self.full_metadata_popup_open = true;
@@ -94,7 +115,7 @@ impl DicomViewerApp {
             let mut popup_open = self.full_metadata_popup_open;
             Self::show_full_metadata_loading_popup(ctx, &mut popup_open);
             self.full_metadata_popup_open = popup_open;
-            ctx.request_repaint_after(Duration::from_millis(16));
+            ctx.request_repaint_after(IDLE_POLL_REPAINT_INTERVAL);
             return;
         }
 
@@ -141,7 +162,7 @@ impl DicomViewerApp {
             };
             let _ = sender.send(result);
         });
-        ctx.request_repaint_after(Duration::from_millis(16));
+        ctx.request_repaint_after(IDLE_POLL_REPAINT_INTERVAL);
     }
 
     fn apply_full_metadata_load_result(&mut self, result: FullMetadataLoadResult) -> bool {
@@ -187,7 +208,8 @@ impl DicomViewerApp {
     fn show_summary_metadata_overlay(
         ctx: &egui::Context,
         metadata: &[(String, String)],
-        visible_metadata_fields: &HashSet<String>,
+        visible_metadata_fields: &[String],
+        notes: &[&str],
         toggle_enabled: bool,
     ) -> bool {
         let overlay_height = (ctx.content_rect().height() * 0.62).max(180.0);
@@ -202,11 +224,16 @@ impl DicomViewerApp {
                 egui::ScrollArea::vertical()
                     .id_salt("metadata-overlay-scroll")
                     .show(ui, |ui| {
+                        for &note in notes {
+                            ui.label(
+                                Self::metadata_overlay_text(note).color(ui.visuals().warn_fg_color),
+                            );
+                        }
                         let mut shown_count = 0usize;
-                        for (key, value) in metadata {
-                            if !visible_metadata_fields.contains(key.as_str()) {
-                                continue;
-                            }
+                        let visible_entries = visible_metadata_fields
+                            .iter()
+                            .flat_map(|field| metadata.iter().filter(move |(key, _)| key == field));
+                        for (key, value) in visible_entries {
                             shown_count = shown_count.saturating_add(1);
                             ui.horizontal_wrapped(|ui| {
                                 ui.monospace(Self::metadata_overlay_text(key));
@@ -228,6 +255,73 @@ impl DicomViewerApp {
         open_requested
     }
 
+    /// One-line summary of the active image: key identifiers, size, frame
+    /// position, and the current window. `None` without an active image.
+    pub(super) fn compact_info_line(&self) -> Option<String> {
+        let image = self.active_image()?;
+        let mut parts = COMPACT_INFO_FIELDS
+            .iter()
+            .filter_map(|field| {
+                image
+                    .metadata
+                    .iter()
+                    .find(|(key, _)| key == field)
+                    .map(|(_, value)| value.trim())
+                    .filter(|value| !value.is_empty())
+                    .map(str::to_string)
+            })
+            .collect::<Vec<_>>();
+        parts.push(format!("{}x{}", image.width, image.height));
+        if image.opened_heuristically {
+            parts.push(HEURISTIC_OPEN_NOTE.to_string());
+        }
+        if let Some(state) = self.active_viewport_state() {
+            if state.frame_count > 1 {
+                parts.push(format!(
+                    "Frame {}/{}",
+                    state.current_frame + 1,
+                    state.frame_count
+                ));
+            }
+            if let Some(mask_frame) = self.active_subtraction_mask() {
+                parts.push(format!("Minus frame {}", mask_frame + 1));
+            }
+            if state.is_monochrome {
+                parts.push(format!(
+                    "C {:.0} / W {:.0}",
+                    state.window_center, state.window_width
+                ));
+                if let Some(label) = self.active_window_preset_label() {
+                    parts.push(label);
+                }
+            }
+        }
+        if let Some(note) = self.window_level_lock_note() {
+            parts.push(note.to_string());
+        }
+        Some(parts.join(COMPACT_INFO_SEPARATOR))
+    }
+
+    /// Status note for a locked window, so the lock stays visible next to the
+    /// image information and not only on the window/level controls.
+    fn window_level_lock_note(&self) -> Option<&'static str> {
+        let state = self.active_viewport_state()?;
+        (self.window_level_locked && state.is_monochrome).then_some(WINDOW_LEVEL_LOCKED_NOTE)
+    }
+
+    fn show_compact_info_hud(ctx: &egui::Context, line: &str, bottom_inset: f32) {
+        egui::Area::new(egui::Id::new("compact-info-hud"))
+            .order(egui::Order::Foreground)
+            .interactable(false)
+            .anchor(
+                egui::Align2::LEFT_BOTTOM,
+                egui::vec2(10.0, -10.0 - bottom_inset),
+            )
+            .show(ctx, |ui| {
+                ui.add(egui::Label::new(Self::metadata_overlay_text(line)).extend());
+            });
+    }
+
     fn metadata_overlay_text(text: impl Into<String>) -> egui::RichText {
         egui::RichText::new(text).background_color(METADATA_OVERLAY_TEXT_BACKGROUND)
     }
@@ -421,6 +515,94 @@ mod tests {
         }]
     }
 
+    #[test]
+    fn compact_info_line_joins_essentials_for_active_image() {
+        let mut image = DicomImage::test_stub_with_mono_frames(None, 3);
+        image.width = 512;
+        image.height = 256;
+        image.metadata = vec![
+            ("Modality".to_string(), "US".to_string()),
+            ("PatientID".to_string(), "ANON-1".to_string()),
+            ("StudyDate".to_string(), " ".to_string()),
+        ];
+        let app = DicomViewerApp {
+            image: Some(image),
+            current_frame: 1,
+            window_center: 40.0,
+            window_width: 400.0,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            app.compact_info_line().as_deref(),
+            Some("ANON-1 \u{2022} US \u{2022} 512x256 \u{2022} Frame 2/3 \u{2022} C 40 / W 400")
+        );
+        assert_eq!(DicomViewerApp::default().compact_info_line(), None);
+    }
+
+    #[test]
+    fn compact_info_line_names_the_window_preset_shown() {
+        let mut image = DicomImage::test_stub_with_mono_frames(None, 1);
+        image.width = 64;
+        image.height = 32;
+        image.window_presets = vec![
+            WindowPreset {
+                center: 40.0,
+                width: 400.0,
+                label: "SOFT TISSUE".to_string(),
+            },
+            WindowPreset {
+                center: -600.0,
+                width: 1500.0,
+                label: "LUNG".to_string(),
+            },
+        ];
+        let mut app = DicomViewerApp {
+            image: Some(image),
+            window_center: -600.0,
+            window_width: 1500.0,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            app.compact_info_line().as_deref(),
+            Some("64x32 \u{2022} C -600 / W 1500 \u{2022} LUNG")
+        );
+
+        app.window_width = 1400.0;
+        assert_eq!(
+            app.compact_info_line().as_deref(),
+            Some("64x32 \u{2022} C -600 / W 1400")
+        );
+
+        app.window_level_locked = true;
+        assert_eq!(
+            app.compact_info_line().as_deref(),
+            Some("64x32 \u{2022} C -600 / W 1400 \u{2022} W/L locked (K)")
+        );
+    }
+
+    #[test]
+    fn compact_info_line_notes_a_heuristically_opened_image() {
+        let mut image = DicomImage::test_stub_with_mono_frames(None, 1);
+        image.width = 64;
+        image.height = 32;
+        image.opened_heuristically = true;
+        let app = DicomViewerApp {
+            image: Some(image),
+            window_center: 40.0,
+            window_width: 400.0,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            app.compact_info_line().as_deref(),
+            Some(
+                "64x32 \u{2022} Opened heuristically (no DICOM file header) \u{2022} C 40 / W 400"
+            )
+        );
+    }
+
     #[test]
     fn metadata_overlay_text_uses_half_black_background() {
         let mut layout_job = egui::text::LayoutJob::default();
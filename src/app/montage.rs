@@ -0,0 +1,214 @@
+use super::*;
+
+const MONTAGE_TILE_MAX_DIM: usize = 256;
+/// Default sampling keeps a long stack to about this many tiles.
+const MONTAGE_DEFAULT_MAX_TILES: usize = 64;
+const MONTAGE_DEFAULT_COLUMNS: usize = 4;
+const MONTAGE_MAX_COLUMNS: usize = 8;
+/// Tiles rendered per UI frame, so opening the montage stays responsive.
+const MONTAGE_TILES_PER_UPDATE: usize = 4;
+
+/// Tiled view of every `step`th frame of the single multi-frame image.
+pub(super) struct FrameMontage {
+    /// Identity of the image the montage was opened on.
+    key: String,
+    /// Window center/width bits, invert and overlay the tiles were rendered
+    /// with; any change re-renders them to match the single view.
+    render_key: (u32, u32, bool, bool),
+    columns: usize,
+    step: usize,
+    tiles: FrameThumbnails,
+}
+
+/// Step that keeps `frame_count` frames to about `MONTAGE_DEFAULT_MAX_TILES`.
+pub(super) fn default_montage_step(frame_count: usize) -> usize {
+    frame_count.div_ceil(MONTAGE_DEFAULT_MAX_TILES).max(1)
+}
+
+/// Frames shown as tiles: every `step`th frame from `first` through `last`.
+pub(super) fn montage_frames(first: usize, last: usize, step: usize) -> Vec<usize> {
+    (first..=last).step_by(step.max(1)).collect()
+}
+
+impl DicomViewerApp {
+    /// Identity and image the montage tiles: the single image when it has
+    /// more than one frame.
+    fn montage_source(&self) -> Option<(String, &DicomImage)> {
+        let image = self
+            .image
+            .as_ref()
+            .filter(|image| image.frame_count() > 1)?;
+        let key = self
+            .current_single_path
+            .as_ref()
+            .map_or_else(String::new, |path| path.identity_key().to_string());
+        Some((key, image))
+    }
+
+    fn montage_render_key(&self) -> (u32, u32, bool, bool) {
+        (
+            self.window_center.to_bits(),
+            self.window_width.to_bits(),
+            self.image.as_ref().is_some_and(|image| image.invert),
+            self.overlay_visible,
+        )
+    }
+
+    pub(super) fn can_toggle_montage(&self) -> bool {
+        self.montage.is_some() || self.montage_source().is_some()
+    }
+
+    /// Opens or closes the montage. Cine stops while tiles are shown.
+    pub(super) fn toggle_montage(&mut self) {
+        if self.montage.take().is_some() {
+            return;
+        }
+        let Some((key, image)) = self.montage_source() else {
+            return;
+        };
+        let step = default_montage_step(image.frame_count());
+        self.montage = Some(FrameMontage {
+            key,
+            render_key: self.montage_render_key(),
+            columns: MONTAGE_DEFAULT_COLUMNS,
+            step,
+            tiles: FrameThumbnails::default(),
+        });
+        self.cine_mode = false;
+        self.last_cine_advance = None;
+        self.clear_live_measurement();
+    }
+
+    /// Whether the montage replaces the single view this frame. A montage
+    /// left over from a different image is dropped.
+    pub(super) fn montage_active(&mut self) -> bool {
+        let Some(montage) = self.montage.as_ref() else {
+            return false;
+        };
+        let active = self
+            .montage_source()
+            .is_some_and(|(key, _)| key == montage.key);
+        if !active {
+            self.montage = None;
+        }
+        active
+    }
+
+    /// Draws the montage grid. Clicking a tile shows that frame in the single
+    /// view and closes the montage.
+    pub(super) fn show_frame_montage(&mut self, ui: &mut egui::Ui) {
+        let ctx = ui.ctx().clone();
+        let render_key = self.montage_render_key();
+        let Some(image) = self.image.as_ref() else {
+            return;
+        };
+        let Some(montage) = self.montage.as_mut() else {
+            return;
+        };
+        let frame_count = image.frame_count();
+        let (first, last) = match self.single_frame_range.as_ref() {
+            Some(range) => (*range.start(), *range.end()),
+            None => (0, frame_count.saturating_sub(1)),
+        };
+
+        ui.horizontal(|ui| {
+            ui.label("Montage");
+            ui.separator();
+            ui.label("Columns");
+            ui.add(egui::DragValue::new(&mut montage.columns).range(1..=MONTAGE_MAX_COLUMNS));
+            ui.label("Every");
+            ui.add(egui::DragValue::new(&mut montage.step).range(1..=frame_count.max(1)));
+            ui.label("frames");
+        });
+        ui.separator();
+
+        if montage.render_key != render_key {
+            montage.render_key = render_key;
+            montage.tiles.clear();
+        }
+        let frames = montage_frames(first, last, montage.step);
+        let repaint_after = montage.tiles.update(
+            &ctx,
+            image,
+            &frames,
+            MONTAGE_TILES_PER_UPDATE,
+            "frame-montage",
+            |frame| {
+                Self::render_image_frame(
+                    image,
+                    frame,
+                    self.window_center,
+                    self.window_width,
+                    self.overlay_visible,
+                )
+                .map(|color_image| downsample_color_image(&color_image, MONTAGE_TILE_MAX_DIM))
+            },
+        );
+        if let Some(delay) = repaint_after {
+            ctx.request_repaint_after(delay);
+        }
+
+        let columns = montage.columns.max(1);
+        let spacing = ui.spacing().item_spacing.x;
+        let padding = ui.spacing().button_padding * 2.0;
+        let tile_width = ((ui.available_width() - spacing * (columns - 1) as f32) / columns as f32
+            - padding.x)
+            .max(32.0);
+        let display_size = image_display_size(image);
+        let tile_size = egui::vec2(
+            tile_width,
+            tile_width * display_size.y / display_size.x.max(1.0),
+        );
+        let current_frame = self.current_frame;
+        let mut clicked = None;
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                for row in frames.chunks(columns) {
+                    ui.horizontal(|ui| {
+                        for frame in row.iter().copied() {
+                            let button = match montage.tiles.get(frame) {
+                                Some(texture) => egui::Button::image(
+                                    egui::Image::new(texture).fit_to_exact_size(tile_size),
+                                ),
+                                None => egui::Button::new((frame + 1).to_string())
+                                    .min_size(tile_size + padding),
+                            };
+                            let response = ui
+                                .add(button.selected(frame == current_frame))
+                                .on_hover_text(format!("Frame {}", frame + 1));
+                            if response.clicked() {
+                                clicked = Some(frame);
+                            }
+                        }
+                    });
+                }
+            });
+
+        if let Some(frame) = clicked {
+            self.montage = None;
+            self.set_single_current_frame(frame);
+            self.rebuild_texture(&ctx);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn montage_frames_sample_the_frame_range() {
+        assert_eq!(montage_frames(0, 4, 1), vec![0, 1, 2, 3, 4]);
+        assert_eq!(montage_frames(0, 9, 4), vec![0, 4, 8]);
+        assert_eq!(montage_frames(3, 7, 2), vec![3, 5, 7]);
+        assert_eq!(montage_frames(0, 2, 0), vec![0, 1, 2]);
+
+        assert_eq!(default_montage_step(10), 1);
+        assert_eq!(default_montage_step(64), 1);
+        assert_eq!(default_montage_step(300), 5);
+        assert!(
+            montage_frames(0, 299, default_montage_step(300)).len() <= MONTAGE_DEFAULT_MAX_TILES
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::hash_map::Entry;
+
 use super::*;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -17,9 +19,7 @@ impl DicomViewerApp {
             }
 
             if let Some(existing_overlay) = destination.get_mut(sop_uid) {
-                existing_overlay
-                    .graphics
-                    .extend(overlay.graphics.iter().cloned());
+                existing_overlay.merge(overlay);
             } else {
                 destination.insert(sop_uid.clone(), overlay.clone());
             }
@@ -194,6 +194,7 @@ impl DicomViewerApp {
             Self::attach_matching_sr_overlay(&mut viewport.image, &self.pending_sr_overlays);
             Self::attach_matching_pm_overlay(&mut viewport.image, &self.pending_pm_overlays);
         }
+        self.apply_gsps_windows(self.overlay_visible);
     }
 
     pub(super) fn merge_pending_gsps_overlays(&mut self, overlays: HashMap<String, GspsOverlay>) {
@@ -580,9 +581,66 @@ impl DicomViewerApp {
             return false;
         }
         self.overlay_visible = !self.overlay_visible;
+        self.apply_gsps_windows(self.overlay_visible);
         true
     }
 
+    /// Switches images whose presentation state carries a window to it when
+    /// overlays are on, saving the window each showed before, and restores the
+    /// saved window when they turn off unless the user has adjusted it since.
+    /// Images already switched are left alone, so this also catches overlays
+    /// attached while overlays are showing.
+    pub(super) fn apply_gsps_windows(&mut self, visible: bool) {
+        fn apply(
+            image: &DicomImage,
+            window_center: &mut f32,
+            window_width: &mut f32,
+            saved: Entry<'_, String, (f32, f32)>,
+            visible: bool,
+        ) {
+            let voi = image.gsps_overlay.as_ref().and_then(|overlay| overlay.voi);
+            match (saved, voi) {
+                (Entry::Vacant(saved), Some(voi)) if visible => {
+                    saved.insert((*window_center, *window_width));
+                    *window_center = voi.center;
+                    *window_width = voi.width;
+                }
+                (Entry::Occupied(saved), voi) if !visible => {
+                    let (saved_center, saved_width) = saved.remove();
+                    if voi.is_some_and(|voi| {
+                        *window_center == voi.center && *window_width == voi.width
+                    }) {
+                        *window_center = saved_center;
+                        *window_width = saved_width;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let (Some(image), Some(path)) = (self.image.as_ref(), self.current_single_path.as_ref())
+        {
+            apply(
+                image,
+                &mut self.window_center,
+                &mut self.window_width,
+                self.gsps_saved_windows
+                    .entry(path.identity_key().to_string()),
+                visible,
+            );
+        }
+        for viewport in self.mammo_group.iter_mut().filter_map(Option::as_mut) {
+            apply(
+                &viewport.image,
+                &mut viewport.window_center,
+                &mut viewport.window_width,
+                self.gsps_saved_windows
+                    .entry(viewport.path.identity_key().to_string()),
+                visible,
+            );
+        }
+    }
+
     pub(super) fn refresh_active_textures(&mut self, ctx: &egui::Context) {
         if self.image.is_some() {
             self.rebuild_texture(ctx);
@@ -598,7 +656,7 @@ impl DicomViewerApp {
             }
 
             viewport.current_frame = viewport.current_frame.min(frame_count.saturating_sub(1));
-            let Some(color_image) = Self::render_image_frame(
+            let Some(color_image) = Self::render_display_frame(
                 &viewport.image,
                 viewport.current_frame,
                 viewport.window_center,
@@ -612,7 +670,7 @@ impl DicomViewerApp {
         }
         self.frame_wait_pending = missing_any;
         if missing_any {
-            ctx.request_repaint_after(Duration::from_millis(16));
+            ctx.request_repaint_after(ACTIVE_REPAINT_INTERVAL);
         } else {
             ctx.request_repaint();
         }
@@ -632,6 +690,10 @@ impl DicomViewerApp {
             .as_ref()
             .filter(|overlay| !overlay.is_empty())
         {
+            // A presentation state's window and shutter apply to every frame.
+            if overlay.voi.is_some() || overlay.shutter.is_some() {
+                applies_all_frames = true;
+            }
             for graphic in &overlay.graphics {
                 match graphic.referenced_frames.as_ref() {
                     None => applies_all_frames = true,
@@ -781,6 +843,7 @@ impl DicomViewerApp {
 
         let overlay_was_hidden = !self.overlay_visible;
         self.overlay_visible = true;
+        self.apply_gsps_windows(true);
         self.last_cine_advance = Some(Instant::now());
 
         if self.image.is_some() {
@@ -795,7 +858,7 @@ impl DicomViewerApp {
             if overlay_was_hidden {
                 self.refresh_active_textures(ctx);
             } else {
-                ctx.request_repaint_after(Duration::from_millis(16));
+                ctx.request_repaint_after(ACTIVE_REPAINT_INTERVAL);
             }
         } else if overlay_was_hidden {
             self.refresh_active_textures(ctx);
@@ -0,0 +1,209 @@
+use super::*;
+
+/// Mask frame subtraction for the single multi-frame grayscale image, as in
+/// digital subtraction angiography review.
+pub(super) struct FrameSubtraction {
+    /// Baseline frame subtracted from the displayed frame.
+    pub(super) mask_frame: usize,
+    /// Whether the view shows `current - mask` instead of the frame itself.
+    pub(super) enabled: bool,
+    /// Window in use before subtraction was turned on, restored when it is
+    /// turned off.
+    saved_window: (f32, f32),
+}
+
+/// Per-pixel `frame - mask`, or `None` when the frames differ in size.
+pub(super) fn subtract_frames(frame: &[i32], mask: &[i32]) -> Option<Vec<i32>> {
+    (frame.len() == mask.len()).then(|| {
+        frame
+            .iter()
+            .zip(mask)
+            .map(|(value, mask)| value.saturating_sub(*mask))
+            .collect()
+    })
+}
+
+impl DicomViewerApp {
+    pub(super) fn can_subtract_frames(&self) -> bool {
+        self.image
+            .as_ref()
+            .is_some_and(|image| image.is_monochrome() && image.frame_count() > 1)
+    }
+
+    /// Mask frame to subtract when rendering the single view, if subtraction
+    /// is on.
+    pub(super) fn active_subtraction_mask(&self) -> Option<usize> {
+        self.subtraction
+            .as_ref()
+            .filter(|subtraction| subtraction.enabled)
+            .map(|subtraction| subtraction.mask_frame)
+    }
+
+    /// Makes the current frame the subtraction mask.
+    pub(super) fn set_subtraction_mask(&mut self, ctx: &egui::Context) {
+        if !self.can_subtract_frames() {
+            return;
+        }
+        let mask_frame = self.current_frame;
+        match self.subtraction.as_mut() {
+            Some(subtraction) => subtraction.mask_frame = mask_frame,
+            None => {
+                self.subtraction = Some(FrameSubtraction {
+                    mask_frame,
+                    enabled: false,
+                    saved_window: (self.window_center, self.window_width),
+                });
+            }
+        }
+        if self.active_subtraction_mask().is_some() {
+            self.rebuild_texture(ctx);
+        }
+    }
+
+    /// Turns subtraction on or off. Without a mask set, the first frame is
+    /// the mask. While on, the window is centered on zero so unchanged
+    /// pixels show mid-gray.
+    pub(super) fn toggle_subtraction(&mut self, ctx: &egui::Context) {
+        if !self.can_subtract_frames() {
+            return;
+        }
+        let window = (self.window_center, self.window_width);
+        let default_width = self
+            .image
+            .as_ref()
+            .map_or(self.window_width, |image| image.window_width);
+        let subtraction = self.subtraction.get_or_insert(FrameSubtraction {
+            mask_frame: 0,
+            enabled: false,
+            saved_window: window,
+        });
+        subtraction.enabled = !subtraction.enabled;
+        if subtraction.enabled {
+            subtraction.saved_window = window;
+            self.window_center = 0.0;
+            self.window_width = default_width.max(1.0);
+        } else {
+            (self.window_center, self.window_width) = subtraction.saved_window;
+        }
+        self.rebuild_texture(ctx);
+    }
+
+    /// Window of the single image as it shows without subtraction, which is
+    /// the one history keeps; the zero-centered subtraction window is not.
+    pub(super) fn window_without_subtraction(&self) -> (f32, f32) {
+        match self.subtraction.as_ref() {
+            Some(subtraction) if subtraction.enabled => subtraction.saved_window,
+            _ => (self.window_center, self.window_width),
+        }
+    }
+
+    /// Drops subtraction when the single image goes away, restoring the
+    /// window it replaced so that window is what gets remembered.
+    pub(super) fn discard_subtraction(&mut self) {
+        if let Some(subtraction) = self.subtraction.take() {
+            if subtraction.enabled {
+                (self.window_center, self.window_width) = subtraction.saved_window;
+            }
+        }
+    }
+
+    /// `frame_index` minus `mask_frame`, windowed like a grayscale frame.
+    /// `None` until both frames are decoded.
+    pub(super) fn render_subtracted_frame(
+        image: &DicomImage,
+        frame_index: usize,
+        mask_frame: usize,
+        window_center: f32,
+        window_width: f32,
+        show_overlay: bool,
+    ) -> Option<ColorImage> {
+        let frame_pixels = image.frame_mono_pixels(frame_index)?;
+        let mask_frame = mask_frame.min(image.frame_count().saturating_sub(1));
+        let mask_pixels = image.frame_mono_pixels(mask_frame)?;
+        let difference = subtract_frames(&frame_pixels, &mask_pixels)?;
+        if image.width.checked_mul(image.height) != Some(difference.len()) {
+            log::warn!(
+                "Skipping subtraction of frame {frame_index}: {} samples do not fill {}x{}.",
+                difference.len(),
+                image.width,
+                image.height
+            );
+            return None;
+        }
+        let mut color_image = render_window_level(
+            image.width,
+            image.height,
+            &difference,
+            image.invert,
+            window_center,
+            window_width,
+        );
+        if show_overlay {
+            Self::apply_frame_overlays(&mut color_image, image, frame_index);
+        }
+        Some(color_image)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subtract_frames_needs_matching_sizes() {
+        assert_eq!(
+            subtract_frames(&[10, 5, i32::MIN], &[4, 9, 1]),
+            Some(vec![6, -4, i32::MIN])
+        );
+        assert_eq!(subtract_frames(&[1, 2], &[1]), None);
+    }
+
+    #[test]
+    fn toggling_subtraction_restores_the_previous_window() {
+        let ctx = egui::Context::default();
+        let mut image = DicomImage::test_stub_with_mono_frames(None, 3);
+        image.window_width = 200.0;
+        let mut app = DicomViewerApp {
+            image: Some(image),
+            window_center: 40.0,
+            window_width: 400.0,
+            current_frame: 2,
+            ..Default::default()
+        };
+
+        app.set_subtraction_mask(&ctx);
+        assert_eq!(app.active_subtraction_mask(), None);
+        app.toggle_subtraction(&ctx);
+        assert_eq!(app.active_subtraction_mask(), Some(2));
+        assert_eq!((app.window_center, app.window_width), (0.0, 200.0));
+
+        app.toggle_subtraction(&ctx);
+        assert_eq!(app.active_subtraction_mask(), None);
+        assert_eq!((app.window_center, app.window_width), (40.0, 400.0));
+    }
+
+    #[test]
+    fn closing_a_subtracted_image_restores_and_remembers_the_previous_window() {
+        let ctx = egui::Context::default();
+        let mut image = DicomImage::test_stub_with_mono_frames(None, 3);
+        image.window_width = 200.0;
+        image.metadata = vec![("Modality".to_string(), "XA".to_string())];
+        let mut app = DicomViewerApp {
+            image: Some(image),
+            window_center: 40.0,
+            window_width: 400.0,
+            ..Default::default()
+        };
+
+        app.toggle_subtraction(&ctx);
+        assert_eq!((app.window_center, app.window_width), (0.0, 200.0));
+
+        app.clear_single_viewer();
+        assert!(app.subtraction.is_none());
+        assert_eq!((app.window_center, app.window_width), (40.0, 400.0));
+        assert_eq!(
+            app.manual_window_by_modality.get("XA"),
+            Some(&(40.0, 400.0))
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use super::*;
+
+/// How long thumbnails wait for frame decoding to make progress before the
+/// frames still missing are treated as undecodable.
+const THUMBNAIL_DECODE_STALL_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Frame thumbnails rendered a few per UI frame. Frames that never render
+/// are remembered as failed, so they keep their numbered placeholder and stop
+/// driving repaints.
+#[derive(Default)]
+pub(super) struct FrameThumbnails {
+    textures: BTreeMap<usize, TextureHandle>,
+    failed: HashSet<usize>,
+    /// Decoded frame count last seen and when it last grew, to notice when
+    /// frames waiting on decode will never arrive.
+    decode_progress: Option<(usize, Instant)>,
+}
+
+impl FrameThumbnails {
+    pub(super) fn get(&self, frame: usize) -> Option<&TextureHandle> {
+        self.textures.get(&frame)
+    }
+
+    /// Drops every thumbnail and failure so the frames render again.
+    pub(super) fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Renders up to `budget` of `frames` that have no thumbnail yet and
+    /// returns when the caller should repaint to continue, if at all. A frame
+    /// that is decoded but does not render fails at once; frames waiting on
+    /// decode fail together once decoding stops making progress.
+    pub(super) fn update(
+        &mut self,
+        ctx: &egui::Context,
+        image: &DicomImage,
+        frames: &[usize],
+        budget: usize,
+        texture_prefix: &str,
+        render: impl Fn(usize) -> Option<ColorImage>,
+    ) -> Option<Duration> {
+        let missing = frames
+            .iter()
+            .copied()
+            .filter(|frame| !self.textures.contains_key(frame) && !self.failed.contains(frame))
+            .collect::<Vec<_>>();
+        if missing.is_empty() {
+            self.decode_progress = None;
+            return None;
+        }
+
+        let loaded = image.loaded_frames();
+        let is_loaded = |frame: usize| loaded.get(frame).copied().unwrap_or(false);
+        for frame in missing.iter().copied().take(budget) {
+            match render(frame) {
+                Some(color_image) => {
+                    let texture = ctx.load_texture(
+                        format!("{texture_prefix}-{frame}"),
+                        color_image,
+                        TextureOptions::LINEAR,
+                    );
+                    self.textures.insert(frame, texture);
+                }
+                None if is_loaded(frame) => {
+                    self.failed.insert(frame);
+                }
+                None => {}
+            }
+        }
+
+        let remaining = missing
+            .iter()
+            .copied()
+            .filter(|frame| !self.textures.contains_key(frame) && !self.failed.contains(frame))
+            .collect::<Vec<_>>();
+        if remaining.is_empty() {
+            self.decode_progress = None;
+            return None;
+        }
+        if remaining.iter().copied().any(is_loaded) {
+            return Some(ACTIVE_REPAINT_INTERVAL);
+        }
+
+        let loaded_count = loaded.iter().filter(|loaded| **loaded).count();
+        let now = Instant::now();
+        match self.decode_progress {
+            Some((count, since)) if count == loaded_count => {
+                if now.duration_since(since) >= THUMBNAIL_DECODE_STALL_TIMEOUT {
+                    log::warn!(
+                        "Giving up on {} thumbnail(s) whose frames did not decode.",
+                        remaining.len()
+                    );
+                    self.failed.extend(remaining);
+                    self.decode_progress = None;
+                    return None;
+                }
+            }
+            _ => self.decode_progress = Some((loaded_count, now)),
+        }
+        Some(IDLE_POLL_REPAINT_INTERVAL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_thumbnails_stop_retrying_decoded_frames_that_do_not_render() {
+        let ctx = egui::Context::default();
+        let image = DicomImage::test_stub_with_mono_frames(None, 3);
+        let render_calls = std::cell::Cell::new(0);
+        let render = |frame: usize| {
+            render_calls.set(render_calls.get() + 1);
+            (frame != 1).then(|| ColorImage::filled([1, 1], egui::Color32::WHITE))
+        };
+        let mut thumbnails = FrameThumbnails::default();
+
+        assert_eq!(
+            thumbnails.update(&ctx, &image, &[0, 1, 2], 2, "test", render),
+            Some(ACTIVE_REPAINT_INTERVAL)
+        );
+        assert_eq!(
+            thumbnails.update(&ctx, &image, &[0, 1, 2], 2, "test", render),
+            None
+        );
+        assert!(thumbnails.get(0).is_some());
+        assert!(thumbnails.get(1).is_none());
+        assert!(thumbnails.get(2).is_some());
+
+        assert_eq!(
+            thumbnails.update(&ctx, &image, &[0, 1, 2], 2, "test", render),
+            None
+        );
+        assert_eq!(render_calls.get(), 3);
+    }
+}
@@ -3,27 +3,37 @@ use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::{Arc, Mutex, OnceLock};
+use std::sync::{mpsc, Arc, Mutex, OnceLock};
 use std::thread;
 use std::{fs, io::Cursor};
 
 use anyhow::{bail, Context, Result};
 use dicom_core::dictionary::{DataDictionary, DataDictionaryEntry};
 use dicom_core::header::{HasLength, Header, VR};
+use dicom_core::PrimitiveValue;
 use dicom_object::mem::InMemElement;
 use dicom_object::{
-    from_reader, open_file, DefaultDicomObject, InMemDicomObject, ReadError,
-    StandardDataDictionary, Tag,
+    from_reader, open_file, DefaultDicomObject, FileMetaTableBuilder, InMemDicomObject,
+    OpenFileOptions, ReadError, StandardDataDictionary, Tag,
 };
-use dicom_pixeldata::PixelDecoder;
+use dicom_pixeldata::{DecodedPixelData, PixelDecoder};
+use dicom_transfer_syntax_registry::entries::IMPLICIT_VR_LITTLE_ENDIAN;
 
+use crate::mammo::{
+    classify_laterality, classify_laterality_in_fallbacks, classify_view,
+    classify_view_in_fallbacks, normalize_token, MammoViewKey,
+};
+
+mod decode_pool;
 mod gsps;
+mod metadata_json;
 mod parametric_map;
 mod sr;
 
+pub use gsps::{load_gsps_overlays, GspsGraphic, GspsOverlay, GspsShutter, GspsUnits};
 #[allow(unused_imports)]
-pub use gsps::GspsOverlayGraphic;
-pub use gsps::{load_gsps_overlays, GspsGraphic, GspsOverlay, GspsUnits};
+pub use gsps::{GspsOverlayGraphic, GspsVoi};
+pub use metadata_json::metadata_json_from_source;
 pub use parametric_map::{load_parametric_map, load_parametric_map_overlays, ParametricMapOverlay};
 pub use sr::{
     load_mammography_cad_sr_overlays, load_structured_report, SrOverlay, SrOverlayLabel,
@@ -38,20 +48,79 @@ pub enum ImageColorMode {
     Rgb,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresentationIntent {
+    ForPresentation,
+    ForProcessing,
+}
+
+/// The two `PatientOrientation` (0020,0020) values: the patient direction
+/// of the rows (left to right) and of the columns (top to bottom), such as
+/// `A` and `F`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatientOrientation {
+    pub row: String,
+    pub column: String,
+}
+
+impl PatientOrientation {
+    /// Labels for the left, right, top and bottom edges of the image.
+    pub fn edge_labels(&self) -> [String; 4] {
+        [
+            opposite_patient_direction(&self.row),
+            self.row.clone(),
+            opposite_patient_direction(&self.column),
+            self.column.clone(),
+        ]
+    }
+}
+
+fn opposite_patient_direction(direction: &str) -> String {
+    direction
+        .chars()
+        .map(|letter| match letter {
+            'A' => 'P',
+            'P' => 'A',
+            'L' => 'R',
+            'R' => 'L',
+            'H' => 'F',
+            'F' => 'H',
+            other => other,
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct PixelSpacingMm {
     pub row_mm: f32,
     pub col_mm: f32,
 }
 
+/// Pixel rectangle of an image that excludes any uniform black border.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentBounds {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
 pub const METADATA_FIELD_NAMES: &[&str] = &[
     "PatientName",
     "PatientID",
     "PatientSex",
     "PatientBirthDate",
     "StudyDate",
+    "StudyTime",
+    "StudyID",
+    "AccessionNumber",
+    "ReferringPhysicianName",
     "StudyDescription",
+    "SeriesNumber",
     "SeriesDescription",
+    "SeriesDate",
+    "SeriesTime",
+    "AcquisitionDate",
     "Modality",
     "Manufacturer",
     "InstitutionName",
@@ -68,6 +137,8 @@ pub const METADATA_FIELD_NAMES: &[&str] = &[
     "BitsStored",
     "PixelRepresentation",
     "PhotometricInterpretation",
+    "ColorSpace",
+    "ICCProfile",
     "ViewPosition",
     "ImageLaterality",
     "Laterality",
@@ -106,14 +177,57 @@ pub const MAMMOGRAPHY_CAD_SR_SOP_CLASS_UID: &str = "1.2.840.10008.5.1.4.1.1.88.5
 pub const DIGITAL_MAMMOGRAPHY_XRAY_IMAGE_PRESENTATION_SOP_CLASS_UID: &str =
     "1.2.840.10008.5.1.4.1.1.1.2";
 pub const EXPLICIT_VR_LITTLE_ENDIAN_UID: &str = "1.2.840.10008.1.2.1";
+// Digital X-Ray, Mammography and Intra-Oral "For Processing" storage classes carry raw detector data.
+const FOR_PROCESSING_SOP_CLASS_UIDS: &[&str] = &[
+    "1.2.840.10008.5.1.4.1.1.1.1.1",
+    "1.2.840.10008.5.1.4.1.1.1.2.1",
+    "1.2.840.10008.5.1.4.1.1.1.3.1",
+];
+const FOR_PRESENTATION_SOP_CLASS_UIDS: &[&str] = &[
+    "1.2.840.10008.5.1.4.1.1.1.1",
+    "1.2.840.10008.5.1.4.1.1.1.2",
+    "1.2.840.10008.5.1.4.1.1.1.3",
+];
+// Percentile bounds for the fallback window of FOR PROCESSING images, which tend to have a few extreme detector values.
+const FOR_PROCESSING_WINDOW_LOW_PERCENTILE: f32 = 0.01;
+const FOR_PROCESSING_WINDOW_HIGH_PERCENTILE: f32 = 0.99;
+// Upper bound on the pixels sampled for the percentile window; a strided sample of a large detector image gives the same bounds at a fraction of the cost.
+const PERCENTILE_WINDOW_MAX_SAMPLES: usize = 1 << 16;
 const IMPLICIT_VR_LITTLE_ENDIAN_UID: &str = "1.2.840.10008.1.2";
 const EXPLICIT_VR_BIG_ENDIAN_UID: &str = "1.2.840.10008.1.2.2";
 #[cfg(test)]
 pub const BASIC_TEXT_SR_SOP_CLASS_UID: &str = "1.2.840.10008.5.1.4.1.1.88.11";
 #[cfg(all(test, feature = "jpeg_ls"))]
 const SECONDARY_CAPTURE_IMAGE_STORAGE_UID: &str = "1.2.840.10008.5.1.4.1.1.7";
+// Display value for a set pixel in a BitsAllocated=1 image, so masks render as black/white.
+const SINGLE_BIT_ON_VALUE: i32 = 255;
+// Pixels within this fraction of the value range from the black end count as border background.
+const BORDER_BACKGROUND_TOLERANCE: f32 = 0.02;
+// A row or column needs more than 1/200 of its pixels off-background to count as content, so dust and film labels in the border do not defeat the crop.
+const BORDER_CONTENT_MIN_FRACTION_DENOMINATOR: usize = 200;
 // Treat cumulative_delta from read_per_frame_image_positions as meaningful only above 0.001 mm so float noise does not flip reverse-order detection.
 const IMAGE_POSITION_PATIENT_DOMINANT_DELTA_TOLERANCE_MM: f32 = 0.001;
+/// Status text for files wrapped or encrypted under a DICOM security profile,
+/// which this viewer cannot decode.
+const SECURE_DICOM_MESSAGE: &str = "Encrypted or unsupported secure DICOM";
+// Implementation version name that marks the file meta group synthesized for a header-less data set.
+const HEURISTIC_META_IMPLEMENTATION_VERSION_NAME: &str = "PERSPECTA RAW DS";
+/// Set to 0, false, no or off to refuse header-less raw data sets instead of
+/// opening them heuristically.
+const RAW_DATASETS_ENV: &str = "PERSPECTA_OPEN_RAW_DATASETS";
+const ENCRYPTED_ATTRIBUTES_SEQUENCE: Tag = Tag(0x0400, 0x0500);
+const PIXEL_DATA: Tag = Tag(0x7FE0, 0x0010);
+const FLOAT_PIXEL_DATA: Tag = Tag(0x7FE0, 0x0008);
+const DOUBLE_FLOAT_PIXEL_DATA: Tag = Tag(0x7FE0, 0x0009);
+const ICC_PROFILE: Tag = Tag(0x0028, 0x2000);
+const PHOTOMETRIC_INTERPRETATION: Tag = Tag(0x0028, 0x0004);
+/// `acsp` file signature at offset 36 of every ICC profile header.
+const ICC_PROFILE_SIGNATURE: &[u8] = b"acsp";
+/// Float samples whose range spans fewer integer steps than this are scaled
+/// up by powers of ten before quantizing, so fractional data keeps its contrast.
+const FLOAT_PIXEL_MIN_LEVELS: f64 = 4096.0;
+/// Largest power of ten applied when scaling up float samples.
+const FLOAT_PIXEL_MAX_SCALE_EXPONENT: i32 = 6;
 
 #[derive(Debug, Clone)]
 pub enum DicomSource {
@@ -212,6 +326,10 @@ impl DicomSourceMeta {
     pub fn identity_key(&self) -> &str {
         self.identity_key.as_ref()
     }
+
+    pub fn file_path(&self) -> Option<&Path> {
+        self.identity_key.strip_prefix("file:").map(Path::new)
+    }
 }
 
 impl From<DicomSource> for DicomSourceMeta {
@@ -345,8 +463,18 @@ pub enum DicomPathKind {
     Other,
 }
 
-type MonoFrameCache = Arc<Mutex<Vec<Option<Arc<[i32]>>>>>;
-type RgbFrameCache = Arc<Mutex<Vec<Option<Arc<[u8]>>>>>;
+type FrameCache<T> = Arc<Mutex<Vec<Option<Arc<[T]>>>>>;
+type MonoFrameCache = FrameCache<i32>;
+type RgbFrameCache = FrameCache<u8>;
+
+/// A window/level stored in the file, named by `WindowCenterWidthExplanation`
+/// when the file provides one per window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowPreset {
+    pub center: f32,
+    pub width: f32,
+    pub label: String,
+}
 
 #[derive(Debug, Clone)]
 pub struct DicomImage {
@@ -360,15 +488,24 @@ pub struct DicomImage {
     pub invert: bool,
     pub window_center: f32,
     pub window_width: f32,
+    /// Every window stored in the file, in file order. The first is also the
+    /// default `window_center`/`window_width` when present.
+    pub window_presets: Vec<WindowPreset>,
     pub min_value: i32,
     pub max_value: i32,
     pub recommended_cine_fps: Option<f32>,
+    frame_time_offsets_ms: Option<Arc<[f32]>>,
     pub pixel_spacing_mm: Option<PixelSpacingMm>,
     pub view_position: Option<String>,
     pub image_laterality: Option<String>,
+    pub patient_orientation: Option<PatientOrientation>,
+    pub presentation_intent: Option<PresentationIntent>,
     pub instance_number: Option<i32>,
     pub sop_instance_uid: Option<String>,
     reverse_frame_order: bool,
+    /// Stored index of this image's first frame, non-zero only for a view
+    /// split out of a multi-frame instance that packs several views.
+    stored_frame_base: usize,
     pub gsps_overlay: Option<GspsOverlay>,
     pub sr_overlay: Option<SrOverlay>,
     pub pm_overlay: Option<ParametricMapOverlay>,
@@ -377,6 +514,10 @@ pub struct DicomImage {
     full_metadata_source: Option<DicomSource>,
     full_metadata_loaded: bool,
     full_metadata_loading: bool,
+    undecodable_reason: Option<String>,
+    /// Whether the file had no preamble or file meta group and was opened by
+    /// the raw implicit VR heuristic.
+    pub opened_heuristically: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -416,23 +557,154 @@ impl DicomImage {
         self.frame_count
     }
 
+    /// Acquisition time of a displayed frame relative to the first stored frame.
+    pub fn frame_time_offset_ms(&self, frame_index: usize) -> Option<f32> {
+        let stored_index = self.display_frame_index_to_stored(frame_index)?;
+        self.frame_time_offsets_ms
+            .as_ref()?
+            .get(stored_index)
+            .copied()
+    }
+
+    pub fn is_for_processing(&self) -> bool {
+        self.presentation_intent == Some(PresentationIntent::ForProcessing)
+    }
+
+    /// Why this image is a stand-in without real pixel data, if it is one.
+    pub fn undecodable_reason(&self) -> Option<&str> {
+        self.undecodable_reason.as_deref()
+    }
+
+    /// Decodes every remaining frame on the calling thread instead of leaving
+    /// them to the background preload, for callers that show all frames at once.
+    pub fn decode_all_frames(&self) -> Result<()> {
+        if let MonoFrames::Lazy(lazy) = &self.mono_frames {
+            lazy.preload_started.store(true, Ordering::Relaxed);
+            preload_mono_frames_from_source(&lazy.source, &lazy.cache)?;
+        }
+        if let RgbFrames::Lazy(lazy) = &self.rgb_frames {
+            lazy.preload_started.store(true, Ordering::Relaxed);
+            preload_rgb_frames_from_source(&lazy.source, &lazy.cache)?;
+        }
+        Ok(())
+    }
+
+    /// Bounding box of the non-background pixels in a monochrome frame, used
+    /// to crop black film borders for display. Returns `None` when there is no
+    /// border to trim or nothing but background.
+    pub fn detect_content_bounds(&self, pixels: &[i32]) -> Option<ContentBounds> {
+        let (width, height) = (self.width, self.height);
+        if !self.is_monochrome() || width == 0 || height == 0 || pixels.len() < width * height {
+            return None;
+        }
+        let range = (self.max_value as f32 - self.min_value as f32).max(1.0);
+        let tolerance = range * BORDER_BACKGROUND_TOLERANCE;
+        let is_background = |value: i32| {
+            if self.invert {
+                value as f32 >= self.max_value as f32 - tolerance
+            } else {
+                value as f32 <= self.min_value as f32 + tolerance
+            }
+        };
+
+        let mut row_counts = vec![0usize; height];
+        let mut column_counts = vec![0usize; width];
+        for (y, row) in pixels.chunks_exact(width).take(height).enumerate() {
+            for (x, value) in row.iter().enumerate() {
+                if !is_background(*value) {
+                    row_counts[y] += 1;
+                    column_counts[x] += 1;
+                }
+            }
+        }
+
+        let content_span = |counts: &[usize], line_len: usize| {
+            let min_count = line_len / BORDER_CONTENT_MIN_FRACTION_DENOMINATOR;
+            let first = counts.iter().position(|count| *count > min_count)?;
+            let last = counts.iter().rposition(|count| *count > min_count)?;
+            Some((first, last + 1 - first))
+        };
+        let (y, content_height) = content_span(&row_counts, width)?;
+        let (x, content_width) = content_span(&column_counts, height)?;
+        if content_width == width && content_height == height {
+            return None;
+        }
+        Some(ContentBounds {
+            x,
+            y,
+            width: content_width,
+            height: content_height,
+        })
+    }
+
+    /// Splits a multi-frame instance whose frames are separate views, as some
+    /// vendors pack a whole screening into one object, into one single-frame
+    /// image per displayed frame. The views share the decoded pixel cache;
+    /// instance-level view and laterality do not describe any one frame, so
+    /// they are cleared.
+    pub fn split_packed_view_frames(&self) -> Vec<DicomImage> {
+        (0..self.frame_count)
+            .filter_map(|frame_index| {
+                let stored_frame_base = self.display_frame_index_to_stored(frame_index)?;
+                Some(Self {
+                    frame_count: 1,
+                    stored_frame_base,
+                    reverse_frame_order: false,
+                    frame_time_offsets_ms: None,
+                    recommended_cine_fps: None,
+                    view_position: None,
+                    image_laterality: None,
+                    ..self.clone()
+                })
+            })
+            .collect()
+    }
+
+    /// Replaces the pixel data with a single black pixel while keeping the
+    /// identifying attributes, so a multi-view group can still slot and label
+    /// an image whose frames cannot be rendered.
+    pub fn into_undecodable_placeholder(self, reason: impl Into<String>) -> Self {
+        Self {
+            width: 1,
+            height: 1,
+            mono_frames: MonoFrames::Eager(vec![Arc::<[i32]>::from([0])]),
+            rgb_frames: RgbFrames::None,
+            frame_count: 1,
+            color_mode: ImageColorMode::Monochrome,
+            samples_per_pixel: 1,
+            invert: false,
+            window_center: 0.5,
+            window_width: 1.0,
+            min_value: 0,
+            max_value: 0,
+            recommended_cine_fps: None,
+            frame_time_offsets_ms: None,
+            pixel_spacing_mm: None,
+            reverse_frame_order: false,
+            stored_frame_base: 0,
+            window_presets: Vec::new(),
+            undecodable_reason: Some(reason.into()),
+            ..self
+        }
+    }
+
     pub(crate) fn display_frame_index_to_stored(&self, frame_index: usize) -> Option<usize> {
         if frame_index >= self.frame_count {
             return None;
         }
 
-        if self.reverse_frame_order {
-            Some(
-                self.frame_count
-                    .saturating_sub(1)
-                    .saturating_sub(frame_index),
-            )
+        let frame_index = if self.reverse_frame_order {
+            self.frame_count
+                .saturating_sub(1)
+                .saturating_sub(frame_index)
         } else {
-            Some(frame_index)
-        }
+            frame_index
+        };
+        Some(self.stored_frame_base + frame_index)
     }
 
     pub(crate) fn stored_frame_index_to_display(&self, frame_index: usize) -> Option<usize> {
+        let frame_index = frame_index.checked_sub(self.stored_frame_base)?;
         if frame_index >= self.frame_count {
             return None;
         }
@@ -466,6 +738,40 @@ impl DicomImage {
         }
     }
 
+    /// Frames of this image currently decoded and held in memory. Lazy images
+    /// count the filled slots of their frame cache, so this grows as preload
+    /// and scrubbing decode more frames.
+    pub fn loaded_frame_count(&self) -> usize {
+        let stored = self.stored_frame_base..self.stored_frame_base + self.frame_count;
+        match (&self.mono_frames, &self.rgb_frames) {
+            (MonoFrames::Eager(frames), _) => count_present(frames.len(), &stored),
+            (_, RgbFrames::Eager(frames)) => count_present(frames.len(), &stored),
+            (MonoFrames::Lazy(lazy), _) => count_cached(&lazy.cache, stored),
+            (_, RgbFrames::Lazy(lazy)) => count_cached(&lazy.cache, stored),
+            (MonoFrames::None, RgbFrames::None) => 0,
+        }
+    }
+
+    /// Per displayed frame, whether it is decoded and held in memory. The
+    /// cache is locked once for the whole scan.
+    pub fn loaded_frames(&self) -> Vec<bool> {
+        let stored = (0..self.frame_count)
+            .filter_map(|frame_index| self.display_frame_index_to_stored(frame_index));
+        match (&self.mono_frames, &self.rgb_frames) {
+            (MonoFrames::Eager(frames), _) => stored.map(|index| index < frames.len()).collect(),
+            (_, RgbFrames::Eager(frames)) => stored.map(|index| index < frames.len()).collect(),
+            (MonoFrames::Lazy(lazy), _) => lazy.cache.lock().map_or_else(
+                |_| vec![false; self.frame_count],
+                |cache| stored.map(|index| frame_cached(&cache, index)).collect(),
+            ),
+            (_, RgbFrames::Lazy(lazy)) => lazy.cache.lock().map_or_else(
+                |_| vec![false; self.frame_count],
+                |cache| stored.map(|index| frame_cached(&cache, index)).collect(),
+            ),
+            (MonoFrames::None, RgbFrames::None) => vec![false; self.frame_count],
+        }
+    }
+
     pub(crate) fn has_full_metadata(&self) -> bool {
         !self.full_metadata.is_empty()
             || (!self.full_metadata_loaded && self.full_metadata_source.is_some())
@@ -548,6 +854,26 @@ impl DicomImage {
     }
 }
 
+fn frame_cached<T>(cache: &[Option<Arc<[T]>>], stored_frame_index: usize) -> bool {
+    cache
+        .get(stored_frame_index)
+        .is_some_and(|frame| frame.is_some())
+}
+
+fn count_present(len: usize, stored: &std::ops::Range<usize>) -> usize {
+    stored.end.min(len).saturating_sub(stored.start)
+}
+
+fn count_cached<T>(cache: &FrameCache<T>, stored: std::ops::Range<usize>) -> usize {
+    cache.lock().map_or(0, |cache| {
+        cache
+            .get(stored.start.min(cache.len())..stored.end.min(cache.len()))
+            .map_or(0, |frames| {
+                frames.iter().filter(|frame| frame.is_some()).count()
+            })
+    })
+}
+
 impl LazyMonoFrames {
     fn frame(&self, frame_index: usize) -> Option<Arc<[i32]>> {
         if let Ok(cache) = self.cache.lock() {
@@ -557,11 +883,38 @@ impl LazyMonoFrames {
             }
         }
 
-        self.ensure_background_preload();
+        if configured_preload_workers() == PreloadWorkers::Disabled {
+            self.decode_frame_on_demand(frame_index);
+        } else {
+            self.ensure_background_preload();
+        }
         None
     }
 
+    /// With background preload disabled, decodes only the requested frame.
+    /// One decode runs at a time; callers keep polling until it lands.
+    fn decode_frame_on_demand(&self, frame_index: usize) {
+        if self.preload_started.swap(true, Ordering::Relaxed) {
+            return;
+        }
+        let source = self.source.clone();
+        let cache = Arc::clone(&self.cache);
+        let preload_started = Arc::clone(&self.preload_started);
+        decode_pool::submit(move || {
+            let result = open_dicom_object(&source)
+                .and_then(|obj| decode_mono_frame(&obj, frame_index))
+                .and_then(|pixels| store_cached_frame(&cache, frame_index, pixels));
+            if let Err(err) = result {
+                log::warn!("On-demand decode of frame {frame_index} failed for {source}: {err:#}");
+            }
+            preload_started.store(false, Ordering::Relaxed);
+        });
+    }
+
     fn ensure_background_preload(&self) {
+        if configured_preload_workers() == PreloadWorkers::Disabled {
+            return;
+        }
         if self.preload_started.swap(true, Ordering::Relaxed) {
             return;
         }
@@ -586,11 +939,38 @@ impl LazyRgbFrames {
             }
         }
 
-        self.ensure_background_preload();
+        if configured_preload_workers() == PreloadWorkers::Disabled {
+            self.decode_frame_on_demand(frame_index);
+        } else {
+            self.ensure_background_preload();
+        }
         None
     }
 
+    /// With background preload disabled, decodes only the requested frame.
+    /// One decode runs at a time; callers keep polling until it lands.
+    fn decode_frame_on_demand(&self, frame_index: usize) {
+        if self.preload_started.swap(true, Ordering::Relaxed) {
+            return;
+        }
+        let source = self.source.clone();
+        let cache = Arc::clone(&self.cache);
+        let preload_started = Arc::clone(&self.preload_started);
+        decode_pool::submit(move || {
+            let result = open_dicom_object(&source)
+                .and_then(|obj| decode_rgb_frame(&obj, frame_index))
+                .and_then(|pixels| store_cached_frame(&cache, frame_index, pixels));
+            if let Err(err) = result {
+                log::warn!("On-demand decode of frame {frame_index} failed for {source}: {err:#}");
+            }
+            preload_started.store(false, Ordering::Relaxed);
+        });
+    }
+
     fn ensure_background_preload(&self) {
+        if configured_preload_workers() == PreloadWorkers::Disabled {
+            return;
+        }
         if self.preload_started.swap(true, Ordering::Relaxed) {
             return;
         }
@@ -632,6 +1012,33 @@ pub fn classify_dicom_path(source: impl Into<DicomSource>) -> Result<DicomPathKi
     Ok(classify_dicom_object(&obj))
 }
 
+/// View, laterality and instance number as the file's own tags classify them,
+/// consulting `view_fallback_tags` like `load_dicom`. An in-memory source is
+/// parsed only up to its pixel data, falling back to a full (repairing) open if
+/// that fails.
+pub fn read_mammo_view_key(
+    source: impl Into<DicomSource>,
+    view_fallback_tags: &[String],
+) -> Result<MammoViewKey> {
+    let source = source.into();
+    let header = source.bytes().and_then(|bytes| {
+        // Float pixel data sorts first of the pixel data elements.
+        OpenFileOptions::new()
+            .read_until(FLOAT_PIXEL_DATA)
+            .from_reader(Cursor::new(bytes.as_ref()))
+            .ok()
+    });
+    let obj = match header {
+        Some(obj) => obj,
+        None => open_dicom_object(source)?,
+    };
+    Ok(MammoViewKey::classify(
+        read_view_position(&obj, view_fallback_tags).as_deref(),
+        read_laterality(&obj, view_fallback_tags).as_deref(),
+        read_int_first(&obj, "InstanceNumber"),
+    ))
+}
+
 pub fn read_sop_instance_uid(source: impl Into<DicomSource>) -> Result<Option<String>> {
     let obj = open_dicom_object(source)?;
     Ok(read_string(&obj, "SOPInstanceUID"))
@@ -724,9 +1131,345 @@ where
     Ok(cache)
 }
 
-pub fn load_dicom(source: impl Into<DicomSource>) -> Result<DicomImage> {
+/// Builds the stand-in for an image that `load_dicom` rejected, reading the
+/// identifying attributes on a best-effort basis so the placeholder still lands
+/// in the right multi-view slot.
+pub fn load_dicom_placeholder(
+    source: impl Into<DicomSource>,
+    view_fallback_tags: &[String],
+    reason: impl Into<String>,
+) -> DicomImage {
+    let source = source.into();
+    let obj = open_dicom_object(&source).ok();
+
+    DicomImage {
+        width: 0,
+        height: 0,
+        mono_frames: MonoFrames::None,
+        rgb_frames: RgbFrames::None,
+        frame_count: 0,
+        color_mode: ImageColorMode::Monochrome,
+        samples_per_pixel: 1,
+        invert: false,
+        window_center: 0.0,
+        window_width: 1.0,
+        min_value: 0,
+        max_value: 0,
+        recommended_cine_fps: None,
+        frame_time_offsets_ms: None,
+        pixel_spacing_mm: None,
+        view_position: obj
+            .as_ref()
+            .and_then(|obj| read_view_position(obj, view_fallback_tags)),
+        image_laterality: obj
+            .as_ref()
+            .and_then(|obj| read_laterality(obj, view_fallback_tags)),
+        patient_orientation: obj.as_ref().and_then(read_patient_orientation),
+        presentation_intent: obj.as_ref().and_then(read_presentation_intent),
+        instance_number: obj
+            .as_ref()
+            .and_then(|obj| read_int_first(obj, "InstanceNumber")),
+        sop_instance_uid: obj
+            .as_ref()
+            .and_then(|obj| read_string(obj, "SOPInstanceUID")),
+        reverse_frame_order: false,
+        stored_frame_base: 0,
+        gsps_overlay: None,
+        sr_overlay: None,
+        pm_overlay: None,
+        metadata: obj.as_ref().map(collect_metadata).unwrap_or_default(),
+        full_metadata: Arc::default(),
+        full_metadata_source: obj.as_ref().map(|_| source.clone()),
+        full_metadata_loaded: false,
+        full_metadata_loading: false,
+        window_presets: Vec::new(),
+        undecodable_reason: None,
+        opened_heuristically: false,
+    }
+    .into_undecodable_placeholder(reason)
+}
+
+/// Loads a BitsAllocated=1 image such as a segmentation mask. Pixels are
+/// packed eight to a byte, least significant bit first, with no padding at
+/// row or frame ends (PS3.5 8.1.1), so frame `n` starts at bit `n * rows *
+/// columns`. Set bits become 255 and clear bits 0.
+fn load_single_bit_image(
+    obj: &DefaultDicomObject,
+    source: &DicomSource,
+    view_fallback_tags: &[String],
+    width: usize,
+    height: usize,
+    invert: bool,
+) -> Result<DicomImage> {
+    let samples_per_pixel = read_int_first(obj, "SamplesPerPixel").unwrap_or(1);
+    if samples_per_pixel != 1 {
+        bail!(
+            "BitsAllocated=1 requires SamplesPerPixel=1, got {}",
+            samples_per_pixel
+        );
+    }
+    let frame_count = match read_int_first(obj, "NumberOfFrames") {
+        Some(value) if value > 0 => value as usize,
+        Some(value) => bail!("Invalid NumberOfFrames={} (must be >= 1)", value),
+        None => 1,
+    };
+    let packed = obj
+        .element_by_name("PixelData")
+        .context("Missing PixelData")?
+        .to_bytes()
+        .context("BitsAllocated=1 PixelData must be native (not encapsulated)")?;
+    let pixel_count = width
+        .checked_mul(height)
+        .context("Overflow while calculating frame size")?;
+    let frames = (0..frame_count)
+        .map(|frame_index| {
+            unpack_single_bit_frame(&packed, frame_index, pixel_count)
+                .map(|pixels| Arc::<[i32]>::from(pixels.into_boxed_slice()))
+                .with_context(|| format!("PixelData is too short for 1-bit frame {frame_index}"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(DicomImage {
+        width,
+        height,
+        mono_frames: MonoFrames::Eager(frames),
+        rgb_frames: RgbFrames::None,
+        frame_count,
+        color_mode: ImageColorMode::Monochrome,
+        samples_per_pixel: 1,
+        invert,
+        window_center: SINGLE_BIT_ON_VALUE as f32 / 2.0,
+        window_width: SINGLE_BIT_ON_VALUE as f32,
+        min_value: 0,
+        max_value: SINGLE_BIT_ON_VALUE,
+        recommended_cine_fps: None,
+        frame_time_offsets_ms: read_frame_time_offsets_ms(obj, frame_count),
+        pixel_spacing_mm: read_pixel_spacing_mm(obj),
+        view_position: read_view_position(obj, view_fallback_tags),
+        image_laterality: read_laterality(obj, view_fallback_tags),
+        patient_orientation: read_patient_orientation(obj),
+        presentation_intent: read_presentation_intent(obj),
+        instance_number: read_int_first(obj, "InstanceNumber"),
+        sop_instance_uid: read_string(obj, "SOPInstanceUID"),
+        reverse_frame_order: infer_reverse_frame_order(obj, frame_count),
+        stored_frame_base: 0,
+        gsps_overlay: None,
+        sr_overlay: None,
+        pm_overlay: None,
+        metadata: collect_metadata(obj),
+        full_metadata: Arc::default(),
+        full_metadata_source: Some(source.clone()),
+        full_metadata_loaded: false,
+        full_metadata_loading: false,
+        window_presets: Vec::new(),
+        undecodable_reason: None,
+        opened_heuristically: false,
+    })
+}
+
+/// Samples of Float Pixel Data (7FE0,0008) or Double Float Pixel Data
+/// (7FE0,0009), whichever is present, as `f32`.
+pub(crate) fn read_float_pixel_samples(obj: &DefaultDicomObject) -> Result<Option<Vec<f32>>> {
+    if let Ok(element) = obj.element(FLOAT_PIXEL_DATA) {
+        let values = element
+            .to_multi_float32()
+            .context("Could not decode Float Pixel Data")?;
+        return Ok(Some(values));
+    }
+    if let Ok(element) = obj.element(DOUBLE_FLOAT_PIXEL_DATA) {
+        let values = element
+            .to_multi_float64()
+            .context("Could not decode Double Float Pixel Data")?
+            .into_iter()
+            .map(|value| value as f32)
+            .collect();
+        return Ok(Some(values));
+    }
+    Ok(None)
+}
+
+/// Power of ten that spreads `min..=max` over at least
+/// `FLOAT_PIXEL_MIN_LEVELS` integer steps, or 1 for ranges that already do.
+fn float_pixel_scale(min: f32, max: f32) -> f32 {
+    let range = f64::from(max) - f64::from(min);
+    if !range.is_finite() || range <= 0.0 || range >= FLOAT_PIXEL_MIN_LEVELS {
+        return 1.0;
+    }
+    let exponent = (FLOAT_PIXEL_MIN_LEVELS / range)
+        .log10()
+        .ceil()
+        .clamp(0.0, f64::from(FLOAT_PIXEL_MAX_SCALE_EXPONENT));
+    10f32.powi(exponent as i32)
+}
+
+/// Loads an image whose samples are floating point rather than integer
+/// Pixel Data. Samples are quantized into the integer grayscale pipeline,
+/// scaled by `float_pixel_scale` when their range is narrow; a stored window
+/// is scaled to match. Non-finite samples display as the minimum.
+fn load_float_pixel_image(
+    obj: &DefaultDicomObject,
+    source: &DicomSource,
+    view_fallback_tags: &[String],
+    samples: Vec<f32>,
+    width: usize,
+    height: usize,
+    invert: bool,
+) -> Result<DicomImage> {
+    let samples_per_pixel = read_int_first(obj, "SamplesPerPixel").unwrap_or(1);
+    if samples_per_pixel != 1 {
+        bail!(
+            "Float Pixel Data requires SamplesPerPixel=1, got {}",
+            samples_per_pixel
+        );
+    }
+    let frame_count = match read_int_first(obj, "NumberOfFrames") {
+        Some(value) if value > 0 => value as usize,
+        Some(value) => bail!("Invalid NumberOfFrames={} (must be >= 1)", value),
+        None => 1,
+    };
+    let pixel_count = width
+        .checked_mul(height)
+        .context("Overflow while calculating frame size")?;
+    let expected_samples = pixel_count
+        .checked_mul(frame_count)
+        .context("Overflow while calculating float sample count")?;
+    if samples.len() < expected_samples {
+        bail!(
+            "Float pixel sample count mismatch: got {}, expected {}",
+            samples.len(),
+            expected_samples
+        );
+    }
+
+    let (min, max) = samples[..expected_samples]
+        .iter()
+        .copied()
+        .filter(|value| value.is_finite())
+        .fold(None, |range: Option<(f32, f32)>, value| match range {
+            Some((min, max)) => Some((min.min(value), max.max(value))),
+            None => Some((value, value)),
+        })
+        .context("Float Pixel Data has no finite samples")?;
+    let scale = float_pixel_scale(min, max);
+    let quantize = |value: f32| {
+        let value = if value.is_finite() { value } else { min };
+        // `as` saturates, so values beyond the i32 range clamp rather than wrap.
+        (value * scale).round() as i32
+    };
+    let frames = samples[..expected_samples]
+        .chunks_exact(pixel_count.max(1))
+        .map(|frame| Arc::<[i32]>::from(frame.iter().copied().map(quantize).collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+    let (min_value, max_value) = (quantize(min), quantize(max));
+    let window_center = read_float_first(obj, "WindowCenter")
+        .map_or((min_value as f32 + max_value as f32) / 2.0, |center| {
+            center * scale
+        });
+    let window_width = read_float_first(obj, "WindowWidth")
+        .map_or((max_value as f32 - min_value as f32).max(1.0), |width| {
+            width * scale
+        });
+    if scale != 1.0 {
+        log::info!("{source}: float samples scaled by {scale} for display");
+    }
+
+    Ok(DicomImage {
+        width,
+        height,
+        mono_frames: MonoFrames::Eager(frames),
+        rgb_frames: RgbFrames::None,
+        frame_count,
+        color_mode: ImageColorMode::Monochrome,
+        samples_per_pixel: 1,
+        invert,
+        window_center,
+        window_width: window_width.max(1.0),
+        min_value,
+        max_value,
+        recommended_cine_fps: read_recommended_cine_fps(obj),
+        frame_time_offsets_ms: read_frame_time_offsets_ms(obj, frame_count),
+        pixel_spacing_mm: read_pixel_spacing_mm(obj),
+        view_position: read_view_position(obj, view_fallback_tags),
+        image_laterality: read_laterality(obj, view_fallback_tags),
+        patient_orientation: read_patient_orientation(obj),
+        presentation_intent: read_presentation_intent(obj),
+        instance_number: read_int_first(obj, "InstanceNumber"),
+        sop_instance_uid: read_string(obj, "SOPInstanceUID"),
+        reverse_frame_order: infer_reverse_frame_order(obj, frame_count),
+        stored_frame_base: 0,
+        gsps_overlay: None,
+        sr_overlay: None,
+        pm_overlay: None,
+        metadata: collect_metadata(obj),
+        full_metadata: Arc::default(),
+        full_metadata_source: Some(source.clone()),
+        full_metadata_loaded: false,
+        full_metadata_loading: false,
+        window_presets: Vec::new(),
+        undecodable_reason: None,
+        opened_heuristically: false,
+    })
+}
+
+fn unpack_single_bit_frame(
+    packed: &[u8],
+    frame_index: usize,
+    pixel_count: usize,
+) -> Option<Vec<i32>> {
+    let first_bit = frame_index.checked_mul(pixel_count)?;
+    let end_bit = first_bit.checked_add(pixel_count)?;
+    if end_bit.div_ceil(8) > packed.len() {
+        return None;
+    }
+    Some(
+        (first_bit..end_bit)
+            .map(|bit| {
+                if packed[bit / 8] & (1 << (bit % 8)) != 0 {
+                    SINGLE_BIT_ON_VALUE
+                } else {
+                    0
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Viewer settings that change how `load_dicom` decodes an image.
+#[derive(Debug, Clone, Default)]
+pub struct DicomLoadOptions {
+    /// DICOM keywords consulted in order for view and laterality when the
+    /// standard mammography tags are empty, for vendors that only describe the
+    /// view in text such as `AcquisitionDeviceProcessingDescription`.
+    pub view_fallback_tags: Vec<String>,
+    /// Treat images without `PhotometricInterpretation` as MONOCHROME1
+    /// (inverted) instead of MONOCHROME2.
+    pub assume_monochrome1_when_missing: bool,
+}
+
+/// Loads an image for display.
+pub fn load_dicom(
+    source: impl Into<DicomSource>,
+    options: &DicomLoadOptions,
+) -> Result<DicomImage> {
     let source = source.into();
     let obj = open_dicom_object(&source)?;
+    let secure_indicator = secure_dicom_indicator(&obj);
+    let opened_heuristically = is_heuristic_meta(&obj);
+    let mut image =
+        decode_dicom_image(source, obj, options).map_err(|err| match secure_indicator {
+            Some(indicator) => err.context(format!("{SECURE_DICOM_MESSAGE} ({indicator})")),
+            None => err,
+        })?;
+    image.opened_heuristically = opened_heuristically;
+    Ok(image)
+}
+
+fn decode_dicom_image(
+    source: DicomSource,
+    mut obj: DefaultDicomObject,
+    options: &DicomLoadOptions,
+) -> Result<DicomImage> {
+    let view_fallback_tags = options.view_fallback_tags.as_slice();
     match classify_dicom_object(&obj) {
         DicomPathKind::StructuredReport => {
             let sop_class =
@@ -760,8 +1503,40 @@ pub fn load_dicom(source: impl Into<DicomSource>) -> Result<DicomImage> {
         .to_int()
         .context("Invalid Rows value")?;
 
-    let photometric = read_string_or_default(&obj, "PhotometricInterpretation", "MONOCHROME2");
-    let invert = photometric.eq_ignore_ascii_case("MONOCHROME1");
+    let photometric = read_string(&obj, "PhotometricInterpretation");
+    let invert = photometric_inverts(
+        photometric.as_deref(),
+        options.assume_monochrome1_when_missing,
+    );
+
+    if read_int_first(&obj, "BitsAllocated") == Some(1) {
+        return load_single_bit_image(&obj, &source, view_fallback_tags, width, height, invert);
+    }
+    if obj.element(PIXEL_DATA).is_err() {
+        if let Some(samples) = read_float_pixel_samples(&obj)? {
+            return load_float_pixel_image(
+                &obj,
+                &source,
+                view_fallback_tags,
+                samples,
+                width,
+                height,
+                invert,
+            );
+        }
+        bail!("{source} does not contain Pixel Data, Float Pixel Data, or Double Float Pixel Data");
+    }
+
+    // Collected before the decoder placeholder below so the overlay shows the file's own tags.
+    let metadata = collect_metadata(&obj);
+    if photometric.is_none() && read_int_first(&obj, "SamplesPerPixel").unwrap_or(1) == 1 {
+        // The pixel decoder requires the tag; polarity is carried by `invert`.
+        obj.put(InMemElement::new(
+            PHOTOMETRIC_INTERPRETATION,
+            VR::CS,
+            "MONOCHROME2",
+        ));
+    }
 
     let decoded = obj
         .decode_pixel_data_frame(0)
@@ -785,21 +1560,41 @@ pub fn load_dicom(source: impl Into<DicomSource>) -> Result<DicomImage> {
         None => 1,
     };
 
-    let samples_per_pixel = decoded.samples_per_pixel();
-    let recommended_cine_fps = read_float_first(&obj, "FrameTime")
-        .filter(|value| *value > 0.0)
-        .map(|frame_time_ms| 1000.0 / frame_time_ms)
-        .or_else(|| read_float_first(&obj, "CineRate").filter(|value| *value > 0.0));
+    let decoded_samples_per_pixel = decoded.samples_per_pixel();
+    let palette = if decoded_samples_per_pixel == 1 {
+        read_palette_color_lut(&obj).unwrap_or_else(|err| {
+            log::warn!("{source}: could not read the PALETTE COLOR lookup tables: {err:#}");
+            None
+        })
+    } else {
+        None
+    };
+    let color_mode = color_mode_from_decoded_layout(
+        &source,
+        photometric.as_deref(),
+        read_int_first(&obj, "SamplesPerPixel"),
+        decoded_samples_per_pixel,
+        palette.is_some(),
+    );
+    // A palette expands each index into an RGB triplet.
+    let samples_per_pixel = if palette.is_some() {
+        3
+    } else {
+        decoded_samples_per_pixel
+    };
+    let recommended_cine_fps = read_recommended_cine_fps(&obj);
+    let frame_time_offsets_ms = read_frame_time_offsets_ms(&obj, frame_count);
     let pixel_spacing_mm = read_pixel_spacing_mm(&obj);
-    let view_position = read_view_position(&obj);
-    let image_laterality = read_laterality(&obj);
+    let view_position = read_view_position(&obj, view_fallback_tags);
+    let image_laterality = read_laterality(&obj, view_fallback_tags);
+    let patient_orientation = read_patient_orientation(&obj);
+    let presentation_intent = read_presentation_intent(&obj);
     let instance_number = read_int_first(&obj, "InstanceNumber");
     let sop_instance_uid = read_string(&obj, "SOPInstanceUID");
     let reverse_frame_order = infer_reverse_frame_order(&obj, frame_count);
-    let metadata = collect_metadata(&obj);
 
-    match samples_per_pixel {
-        1 => {
+    match color_mode {
+        Some(ImageColorMode::Monochrome) => {
             let bits_allocated = decoded.bits_allocated();
             if bits_allocated != 8 && bits_allocated != 16 {
                 bail!("BitsAllocated={} is not supported (only 8/16)", bits_allocated);
@@ -819,10 +1614,25 @@ pub fn load_dicom(source: impl Into<DicomSource>) -> Result<DicomImage> {
             let (min_value, max_value) =
                 min_max(&first_frame_pixels).context("No pixels available for rendering")?;
 
-            let default_center = read_float_first(&obj, "WindowCenter")
-                .unwrap_or_else(|| (min_value + max_value) as f32 / 2.0);
-            let default_width = read_float_first(&obj, "WindowWidth")
-                .unwrap_or_else(|| (max_value - min_value).max(1) as f32);
+            let explicit_center = read_float_first(&obj, "WindowCenter");
+            let explicit_width = read_float_first(&obj, "WindowWidth");
+            let (fallback_center, fallback_width) =
+                if presentation_intent == Some(PresentationIntent::ForProcessing)
+                    && (explicit_center.is_none() || explicit_width.is_none())
+                {
+                    log::warn!(
+                        "{source} is a FOR PROCESSING image without a stored window; using a percentile window because raw detector data displays poorly with min/max windowing"
+                    );
+                    percentile_window(&first_frame_pixels)
+                        .context("No pixels available for rendering")?
+                } else {
+                    (
+                        (min_value + max_value) as f32 / 2.0,
+                        (max_value - min_value).max(1) as f32,
+                    )
+                };
+            let default_center = explicit_center.unwrap_or(fallback_center);
+            let default_width = explicit_width.unwrap_or(fallback_width);
 
             let first_frame_pixels = Arc::<[i32]>::from(first_frame_pixels.into_boxed_slice());
 
@@ -888,12 +1698,16 @@ pub fn load_dicom(source: impl Into<DicomSource>) -> Result<DicomImage> {
                 min_value,
                 max_value,
                 recommended_cine_fps,
+                frame_time_offsets_ms,
                 pixel_spacing_mm,
                 view_position,
                 image_laterality,
+                patient_orientation,
+                presentation_intent,
                 instance_number,
                 sop_instance_uid,
                 reverse_frame_order,
+                stored_frame_base: 0,
                 gsps_overlay: None,
                 sr_overlay: None,
                 pm_overlay: None,
@@ -902,9 +1716,12 @@ pub fn load_dicom(source: impl Into<DicomSource>) -> Result<DicomImage> {
                 full_metadata_source: Some(source.clone()),
                 full_metadata_loaded: false,
                 full_metadata_loading: false,
+                window_presets: read_window_presets(&obj),
+                undecodable_reason: None,
+                opened_heuristically: false,
             })
         }
-        spp if spp >= 3 => {
+        Some(ImageColorMode::Rgb) => {
             let bits_allocated = decoded.bits_allocated();
             if bits_allocated != 8 && bits_allocated != 16 {
                 bail!("BitsAllocated={} is not supported for color images (only 8/16)", bits_allocated);
@@ -914,21 +1731,8 @@ pub fn load_dicom(source: impl Into<DicomSource>) -> Result<DicomImage> {
                 .checked_mul(height)
                 .and_then(|v| v.checked_mul(samples_per_pixel as usize))
                 .context("Overflow while calculating color frame size")?;
-            let bits_shift = decoded.bits_stored().saturating_sub(8);
-
-            let first_frame_pixels: Vec<u8> = if bits_allocated == 8 {
-                decoded
-                    .to_vec_frame(0)
-                    .context("Could not convert decoded frame 0 to u8 samples")?
-            } else {
-                let frame_pixels_u16: Vec<u16> = decoded
-                    .to_vec_frame(0)
-                    .context("Could not convert decoded frame 0 to u16 samples")?;
-                frame_pixels_u16
-                    .into_iter()
-                    .map(|sample| (sample >> bits_shift) as u8)
-                    .collect()
-            };
+            let first_frame_pixels = rgb_samples_from_decoded(&decoded, palette.as_ref())
+                .context("Could not convert decoded frame 0 to RGB samples")?;
 
             if first_frame_pixels.len() != expected_len {
                 bail!(
@@ -956,28 +1760,14 @@ pub fn load_dicom(source: impl Into<DicomSource>) -> Result<DicomImage> {
                                         initial_display_frame
                                     )
                                 })?;
-                        let initial_display_pixels: Vec<u8> = if bits_allocated == 8 {
-                            decoded_initial_display.to_vec_frame(0).with_context(|| {
-                                format!(
-                                    "Could not convert decoded frame {} to u8 samples for initial reverse-order preview",
-                                    initial_display_frame
-                                )
-                            })?
-                        } else {
-                            let bits_shift =
-                                decoded_initial_display.bits_stored().saturating_sub(8);
-                            let frame_pixels_u16: Vec<u16> =
-                                decoded_initial_display.to_vec_frame(0).with_context(|| {
+                        let initial_display_pixels =
+                            rgb_samples_from_decoded(&decoded_initial_display, palette.as_ref())
+                                .with_context(|| {
                                     format!(
-                                        "Could not convert decoded frame {} to u16 samples for initial reverse-order preview",
+                                        "Could not convert decoded frame {} to RGB samples for initial reverse-order preview",
                                         initial_display_frame
                                     )
                                 })?;
-                            frame_pixels_u16
-                                .into_iter()
-                                .map(|sample| (sample >> bits_shift) as u8)
-                                .collect()
-                        };
 
                         if initial_display_pixels.len() != expected_len {
                             bail!(
@@ -1011,12 +1801,16 @@ pub fn load_dicom(source: impl Into<DicomSource>) -> Result<DicomImage> {
                 min_value: 0,
                 max_value: 255,
                 recommended_cine_fps,
+                frame_time_offsets_ms,
                 pixel_spacing_mm,
                 view_position,
                 image_laterality,
+                patient_orientation,
+                presentation_intent,
                 instance_number,
                 sop_instance_uid,
                 reverse_frame_order,
+                stored_frame_base: 0,
                 gsps_overlay: None,
                 sr_overlay: None,
                 pm_overlay: None,
@@ -1025,208 +1819,355 @@ pub fn load_dicom(source: impl Into<DicomSource>) -> Result<DicomImage> {
                 full_metadata_source: Some(source.clone()),
                 full_metadata_loaded: false,
                 full_metadata_loading: false,
+                window_presets: Vec::new(),
+                undecodable_reason: None,
+                opened_heuristically: false,
             })
         }
-        other => bail!(
+        None => bail!(
             "Unsupported SamplesPerPixel={} (currently supports 1 for monochrome and >=3 for color)",
-            other
+            samples_per_pixel
         ),
     }
 }
 
-fn preload_mono_frames_from_source(source: &DicomSource, cache: &MonoFrameCache) -> Result<()> {
-    let frame_count = match cache.lock() {
-        Ok(guard) => guard.len(),
-        Err(err) => {
-            bail!("Background monochrome preload cache lock poisoned: {err}");
-        }
-    };
-    if frame_count <= 1 {
-        return Ok(());
-    }
-
-    let worker_count = preload_worker_count(frame_count);
-    let mut workers = Vec::with_capacity(worker_count);
-
-    for worker_id in 0..worker_count {
-        let source = source.clone();
-        let cache = Arc::clone(cache);
-        workers.push(thread::spawn(move || -> Result<()> {
-            let obj = open_dicom_object(&source)?;
-            for frame_index in (worker_id..frame_count).step_by(worker_count) {
-                let already_loaded = match cache.lock() {
-                    Ok(guard) => guard
-                        .get(frame_index)
-                        .and_then(|slot| slot.as_ref())
-                        .is_some(),
-                    Err(err) => {
-                        bail!(
-                            "Background monochrome preload cache lock poisoned while checking frame {}: {err}",
-                            frame_index
-                        );
-                    }
-                };
-                if already_loaded {
-                    continue;
-                }
+fn is_palette_color(photometric: &str) -> bool {
+    photometric.trim().eq_ignore_ascii_case("PALETTE COLOR")
+}
 
-                let decoded = obj
-                    .decode_pixel_data_frame(frame_index as u32)
-                    .with_context(|| {
-                        format!(
-                            "Failed to decode PixelData frame {} for background preload",
-                            frame_index
-                        )
-                    })?;
-                if decoded.samples_per_pixel() != 1 {
-                    bail!(
-                        "Background preload expected monochrome pixels, got SamplesPerPixel={}",
-                        decoded.samples_per_pixel()
-                    );
-                }
-                let frame_pixels: Vec<i32> = decoded.to_vec_frame(0).with_context(|| {
-                    format!(
-                        "Could not convert decoded frame {} to i32 samples",
-                        frame_index
-                    )
-                })?;
-                let frame_pixels = Arc::<[i32]>::from(frame_pixels.into_boxed_slice());
-
-                match cache.lock() {
-                    Ok(mut guard) => {
-                        if let Some(slot) = guard.get_mut(frame_index) {
-                            if slot.is_none() {
-                                *slot = Some(frame_pixels);
-                            }
-                        }
-                    }
-                    Err(err) => {
-                        bail!(
-                            "Background monochrome preload cache lock poisoned while storing frame {}: {err}",
-                            frame_index
-                        );
-                    }
-                }
-            }
-            Ok(())
-        }));
-    }
+/// Red, green and blue Palette Color Lookup Tables, scaled to 8 bits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PaletteColorLut {
+    first_mapped: i32,
+    red: Vec<u8>,
+    green: Vec<u8>,
+    blue: Vec<u8>,
+}
 
-    for worker in workers {
-        match worker.join() {
-            Ok(result) => result?,
-            Err(_) => bail!("Background monochrome preload worker panicked"),
+impl PaletteColorLut {
+    /// Interleaved RGB samples for `indices`. Indices below the first mapped
+    /// value use the first entry and those past the table use the last.
+    fn expand(&self, indices: &[i32]) -> Vec<u8> {
+        let last = self.red.len().saturating_sub(1);
+        let mut samples = Vec::with_capacity(indices.len() * 3);
+        for &index in indices {
+            let entry = (index.saturating_sub(self.first_mapped)).max(0) as usize;
+            let entry = entry.min(last);
+            samples.extend([self.red[entry], self.green[entry], self.blue[entry]]);
         }
+        samples
     }
-
-    Ok(())
 }
 
-fn preload_rgb_frames_from_source(source: &DicomSource, cache: &RgbFrameCache) -> Result<()> {
-    let frame_count = match cache.lock() {
-        Ok(guard) => guard.len(),
+/// The palette of a PALETTE COLOR image, or `None` for other photometric
+/// interpretations. Segmented palettes are not supported.
+fn read_palette_color_lut(obj: &DefaultDicomObject) -> Result<Option<PaletteColorLut>> {
+    const DESCRIPTORS: [Tag; 3] = [
+        Tag(0x0028, 0x1101),
+        Tag(0x0028, 0x1102),
+        Tag(0x0028, 0x1103),
+    ];
+    const DATA: [Tag; 3] = [
+        Tag(0x0028, 0x1201),
+        Tag(0x0028, 0x1202),
+        Tag(0x0028, 0x1203),
+    ];
+
+    if !read_string(obj, "PhotometricInterpretation").is_some_and(|value| is_palette_color(&value))
+    {
+        return Ok(None);
+    }
+    let mut first_mapped = 0;
+    let mut channels: [Vec<u8>; 3] = Default::default();
+    for (channel, (descriptor_tag, data_tag)) in DESCRIPTORS.into_iter().zip(DATA).enumerate() {
+        let descriptor: Vec<i32> = obj
+            .element(descriptor_tag)
+            .with_context(|| format!("Missing palette descriptor {descriptor_tag}"))?
+            .to_multi_int()
+            .with_context(|| format!("Invalid palette descriptor {descriptor_tag}"))?;
+        let &[entries, first, bits] = descriptor.as_slice() else {
+            bail!("Palette descriptor {descriptor_tag} must hold 3 values");
+        };
+        // An entry count of 0 means 2^16 entries; an SS-encoded count above 32767 reads negative.
+        let entries = if entries == 0 {
+            65_536
+        } else {
+            entries as u16 as usize
+        };
+        if channel == 0 {
+            first_mapped = first;
+        }
+        let value = obj
+            .element(data_tag)
+            .with_context(|| format!("Missing palette data {data_tag}"))?
+            .value()
+            .primitive()
+            .with_context(|| format!("Palette data {data_tag} is not a primitive value"))?;
+        let words: Vec<u16> = match value {
+            PrimitiveValue::U16(words) => words.to_vec(),
+            other => other
+                .to_bytes()
+                .chunks_exact(2)
+                .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+                .collect(),
+        };
+        let table: Vec<u8> = match bits {
+            16 => words.iter().map(|word| (word >> 8) as u8).collect(),
+            // 8-bit entries sit one per word or two per word, low byte first.
+            8 if words.len() >= entries => words.iter().map(|word| *word as u8).collect(),
+            8 => words.iter().flat_map(|word| word.to_le_bytes()).collect(),
+            _ => bail!("Palette descriptor {descriptor_tag} has unsupported {bits} bits per entry"),
+        };
+        if table.len() < entries {
+            bail!(
+                "Palette data {data_tag} holds {} entries, descriptor expects {entries}",
+                table.len()
+            );
+        }
+        channels[channel] = table[..entries].to_vec();
+    }
+    let [red, green, blue] = channels;
+    if red.len() != green.len() || red.len() != blue.len() {
+        bail!("Palette lookup tables differ in length");
+    }
+    Ok(Some(PaletteColorLut {
+        first_mapped,
+        red,
+        green,
+        blue,
+    }))
+}
+
+/// Samples per pixel a photometric interpretation implies, when known.
+fn photometric_samples_per_pixel(photometric: &str) -> Option<u16> {
+    let photometric = photometric.trim().to_ascii_uppercase();
+    match photometric.as_str() {
+        "MONOCHROME1" | "MONOCHROME2" | "PALETTE COLOR" => Some(1),
+        "RGB" | "HSV" | "YBR_FULL" | "YBR_FULL_422" | "YBR_PARTIAL_420" | "YBR_PARTIAL_422"
+        | "YBR_ICT" | "YBR_RCT" => Some(3),
+        "ARGB" | "CMYK" => Some(4),
+        _ => None,
+    }
+}
+
+/// Chooses the monochrome or color decode path from the decoded layout,
+/// since that is what the pixel buffer holds. A PALETTE COLOR image with
+/// readable lookup tables (`has_palette`) takes the color path, as its
+/// indices expand to RGB. Otherwise the `SamplesPerPixel` tag and the
+/// photometric interpretation never change the choice; disagreements are
+/// only logged because they usually mean the image renders differently than
+/// its header describes.
+fn color_mode_from_decoded_layout(
+    source: &DicomSource,
+    photometric: Option<&str>,
+    tag_samples_per_pixel: Option<i32>,
+    decoded_samples_per_pixel: u16,
+    has_palette: bool,
+) -> Option<ImageColorMode> {
+    if let Some(tag) = tag_samples_per_pixel {
+        if tag != i32::from(decoded_samples_per_pixel) {
+            log::warn!(
+                "{source}: SamplesPerPixel tag is {tag} but the decoder produced {decoded_samples_per_pixel} samples per pixel"
+            );
+        }
+    }
+    let decoded_mode = match decoded_samples_per_pixel {
+        1 => Some(ImageColorMode::Monochrome),
+        spp if spp >= 3 => Some(ImageColorMode::Rgb),
+        _ => None,
+    };
+    let Some(photometric) = photometric else {
+        return decoded_mode;
+    };
+    let is_palette = is_palette_color(photometric);
+    if is_palette && has_palette && decoded_samples_per_pixel == 1 {
+        return Some(ImageColorMode::Rgb);
+    }
+    let Some(expected) = photometric_samples_per_pixel(photometric) else {
+        return decoded_mode;
+    };
+    let photometric_mode = if expected == 1 {
+        ImageColorMode::Monochrome
+    } else {
+        ImageColorMode::Rgb
+    };
+    if decoded_mode != Some(photometric_mode) {
+        log::warn!(
+            "{source}: PhotometricInterpretation {photometric} implies {expected} samples per pixel but the decoder produced {decoded_samples_per_pixel}; rendering the decoded samples as-is"
+        );
+    } else if is_palette {
+        log::warn!(
+            "{source}: PALETTE COLOR image has no usable lookup tables; showing palette indices in grayscale"
+        );
+    }
+    decoded_mode
+}
+
+fn preload_mono_frames_from_source(source: &DicomSource, cache: &MonoFrameCache) -> Result<()> {
+    preload_frames_from_source(source, cache, "monochrome", decode_mono_frame)
+}
+
+fn preload_rgb_frames_from_source(source: &DicomSource, cache: &RgbFrameCache) -> Result<()> {
+    preload_frames_from_source(source, cache, "RGB", decode_rgb_frame)
+}
+
+/// Decodes every frame not yet in `cache` on the shared decode pool and waits
+/// for the jobs to finish.
+fn preload_frames_from_source<T>(
+    source: &DicomSource,
+    cache: &FrameCache<T>,
+    kind: &'static str,
+    decode: fn(&DefaultDicomObject, usize) -> Result<Arc<[T]>>,
+) -> Result<()>
+where
+    [T]: Send + Sync + 'static,
+{
+    let frame_count = match cache.lock() {
+        Ok(guard) => guard.len(),
         Err(err) => {
-            bail!("Background RGB preload cache lock poisoned: {err}");
+            bail!("Background {kind} preload cache lock poisoned: {err}");
         }
     };
     if frame_count <= 1 {
         return Ok(());
     }
 
-    let worker_count = preload_worker_count(frame_count);
-    let mut workers = Vec::with_capacity(worker_count);
+    let job_count = preload_worker_count(frame_count);
+    let (result_tx, result_rx) = mpsc::channel::<Result<()>>();
 
-    for worker_id in 0..worker_count {
+    for job_id in 0..job_count {
         let source = source.clone();
         let cache = Arc::clone(cache);
-        workers.push(thread::spawn(move || -> Result<()> {
-            let obj = open_dicom_object(&source)?;
-            for frame_index in (worker_id..frame_count).step_by(worker_count) {
-                let already_loaded = match cache.lock() {
-                    Ok(guard) => guard
-                        .get(frame_index)
-                        .and_then(|slot| slot.as_ref())
-                        .is_some(),
-                    Err(err) => {
-                        bail!(
-                            "Background RGB preload cache lock poisoned while checking frame {}: {err}",
-                            frame_index
-                        );
+        let result_tx = result_tx.clone();
+        decode_pool::submit(move || {
+            let result = (|| -> Result<()> {
+                let obj = open_dicom_object(&source)?;
+                for frame_index in (job_id..frame_count).step_by(job_count) {
+                    if shutdown_requested() {
+                        return Ok(());
                     }
-                };
-                if already_loaded {
-                    continue;
-                }
-
-                let decoded = obj
-                    .decode_pixel_data_frame(frame_index as u32)
-                    .with_context(|| {
-                        format!(
-                            "Failed to decode PixelData frame {} for background preload",
-                            frame_index
-                        )
-                    })?;
-                let bits_allocated = decoded.bits_allocated();
-                if bits_allocated != 8 && bits_allocated != 16 {
-                    bail!(
-                        "BitsAllocated={} is not supported for color images (only 8/16)",
-                        bits_allocated
-                    );
-                }
-
-                let frame_pixels: Vec<u8> = if bits_allocated == 8 {
-                    decoded.to_vec_frame(0).with_context(|| {
-                        format!(
-                            "Could not convert decoded frame {} to u8 samples",
-                            frame_index
-                        )
-                    })?
-                } else {
-                    let bits_shift = decoded.bits_stored().saturating_sub(8);
-                    let frame_pixels_u16: Vec<u16> =
-                        decoded.to_vec_frame(0).with_context(|| {
-                            format!(
-                                "Could not convert decoded frame {} to u16 samples",
+                    let already_loaded = match cache.lock() {
+                        Ok(guard) => guard
+                            .get(frame_index)
+                            .and_then(|slot| slot.as_ref())
+                            .is_some(),
+                        Err(err) => {
+                            bail!(
+                                "Background {kind} preload cache lock poisoned while checking frame {}: {err}",
                                 frame_index
-                            )
-                        })?;
-                    frame_pixels_u16
-                        .into_iter()
-                        .map(|sample| (sample >> bits_shift) as u8)
-                        .collect()
-                };
-                let frame_pixels = Arc::<[u8]>::from(frame_pixels.into_boxed_slice());
-
-                match cache.lock() {
-                    Ok(mut guard) => {
-                        if let Some(slot) = guard.get_mut(frame_index) {
-                            if slot.is_none() {
-                                *slot = Some(frame_pixels);
-                            }
+                            );
                         }
+                    };
+                    if already_loaded {
+                        continue;
                     }
-                    Err(err) => {
-                        bail!(
-                            "Background RGB preload cache lock poisoned while storing frame {}: {err}",
-                            frame_index
-                        );
-                    }
+
+                    let frame_pixels = decode(&obj, frame_index)?;
+                    store_cached_frame(&cache, frame_index, frame_pixels)?;
                 }
-            }
-            Ok(())
-        }));
+                Ok(())
+            })();
+            let _ = result_tx.send(result);
+        });
     }
+    drop(result_tx);
 
-    for worker in workers {
-        match worker.join() {
+    for _ in 0..job_count {
+        match result_rx.recv() {
             Ok(result) => result?,
-            Err(_) => bail!("Background RGB preload worker panicked"),
+            Err(_) => bail!("Background {kind} preload job panicked"),
         }
     }
 
     Ok(())
 }
 
+/// Decodes one monochrome frame with its modality rescale applied. A
+/// top-level `RescaleSlope`/`RescaleIntercept` wins; otherwise the frame's own
+/// Pixel Value Transformation is used, then the shared functional group's, so
+/// enhanced multi-frame images whose rescale varies per frame stay correct.
+fn decode_mono_frame(obj: &DefaultDicomObject, frame_index: usize) -> Result<Arc<[i32]>> {
+    let decoded = obj
+        .decode_pixel_data_frame(frame_index as u32)
+        .with_context(|| format!("Failed to decode PixelData frame {}", frame_index))?;
+    if decoded.samples_per_pixel() != 1 {
+        bail!(
+            "Frame decode expected monochrome pixels, got SamplesPerPixel={}",
+            decoded.samples_per_pixel()
+        );
+    }
+    let frame_pixels: Vec<i32> = decoded.to_vec_frame(0).with_context(|| {
+        format!(
+            "Could not convert decoded frame {} to i32 samples",
+            frame_index
+        )
+    })?;
+    Ok(Arc::<[i32]>::from(frame_pixels.into_boxed_slice()))
+}
+
+fn decode_rgb_frame(obj: &DefaultDicomObject, frame_index: usize) -> Result<Arc<[u8]>> {
+    let decoded = obj
+        .decode_pixel_data_frame(frame_index as u32)
+        .with_context(|| format!("Failed to decode PixelData frame {}", frame_index))?;
+    let palette = read_palette_color_lut(obj)?;
+    let frame_pixels = rgb_samples_from_decoded(&decoded, palette.as_ref()).with_context(|| {
+        format!(
+            "Could not convert decoded frame {} to RGB samples",
+            frame_index
+        )
+    })?;
+    Ok(Arc::<[u8]>::from(frame_pixels.into_boxed_slice()))
+}
+
+/// 8-bit interleaved color samples of decoded frame 0, expanding palette
+/// indices through `palette` when given.
+fn rgb_samples_from_decoded(
+    decoded: &DecodedPixelData<'_>,
+    palette: Option<&PaletteColorLut>,
+) -> Result<Vec<u8>> {
+    let bits_allocated = decoded.bits_allocated();
+    if bits_allocated != 8 && bits_allocated != 16 {
+        bail!(
+            "BitsAllocated={} is not supported for color images (only 8/16)",
+            bits_allocated
+        );
+    }
+    if let Some(palette) = palette {
+        let indices: Vec<i32> = decoded.to_vec_frame(0)?;
+        return Ok(palette.expand(&indices));
+    }
+    if bits_allocated == 8 {
+        return Ok(decoded.to_vec_frame(0)?);
+    }
+    let bits_shift = decoded.bits_stored().saturating_sub(8);
+    let samples: Vec<u16> = decoded.to_vec_frame(0)?;
+    Ok(samples
+        .into_iter()
+        .map(|sample| (sample >> bits_shift) as u8)
+        .collect())
+}
+
+fn store_cached_frame<T: ?Sized>(
+    cache: &Mutex<Vec<Option<Arc<T>>>>,
+    frame_index: usize,
+    frame_pixels: Arc<T>,
+) -> Result<()> {
+    match cache.lock() {
+        Ok(mut guard) => {
+            if let Some(slot) = guard.get_mut(frame_index) {
+                if slot.is_none() {
+                    *slot = Some(frame_pixels);
+                }
+            }
+            Ok(())
+        }
+        Err(err) => bail!(
+            "Frame cache lock poisoned while storing frame {}: {err}",
+            frame_index
+        ),
+    }
+}
+
 fn open_dicom_object(source: impl Into<DicomSource>) -> Result<DefaultDicomObject> {
     let source = source.into();
     if let Some(bytes) = source.bytes() {
@@ -1272,6 +2213,9 @@ fn try_open_dicom_object_with_repairs(
     source_label: &str,
     original_error: ReadError,
 ) -> Result<DefaultDicomObject> {
+    if is_cms_wrapped(bytes) {
+        bail!("{SECURE_DICOM_MESSAGE}: {source_label} is wrapped in a CMS (PKCS #7) envelope");
+    }
     let mut repaired_meta = None;
 
     if is_missing_meta_group_length_error(&original_error) {
@@ -1298,9 +2242,114 @@ fn try_open_dicom_object_with_repairs(
         });
     }
 
+    if raw_datasets_enabled() {
+        if let Some(obj) = open_raw_implicit_vr_dataset(bytes, source_label) {
+            return Ok(obj);
+        }
+    }
+
     Err(original_error).with_context(|| format!("Could not open {source_label}"))
 }
 
+fn raw_datasets_enabled() -> bool {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    *ENABLED
+        .get_or_init(|| parse_raw_datasets_enabled(std::env::var(RAW_DATASETS_ENV).ok().as_deref()))
+}
+
+fn parse_raw_datasets_enabled(raw: Option<&str>) -> bool {
+    !raw.is_some_and(|raw| {
+        matches!(
+            raw.trim().to_ascii_lowercase().as_str(),
+            "0" | "false" | "no" | "off"
+        )
+    })
+}
+
+/// Whether a file opens as DICOM, probed the same way [`open_dicom_object`]
+/// opens it: Part 10 files, the repairs, and the raw data set heuristic. A
+/// file without a DICOM prefix is read whole.
+pub fn can_open_dicom_source(source: impl Into<DicomSource>) -> bool {
+    open_dicom_object(source).is_ok()
+}
+
+/// Heuristic open for legacy exports that hold a bare implicit VR little
+/// endian data set with no preamble, `DICM` marker or file meta group. A file
+/// meta group is synthesized so the rest of the loader can treat it normally.
+fn open_raw_implicit_vr_dataset(bytes: &[u8], source_label: &str) -> Option<DefaultDicomObject> {
+    if !looks_like_raw_implicit_vr_dataset(bytes) {
+        return None;
+    }
+
+    let dataset = InMemDicomObject::read_dataset_with_ts(
+        Cursor::new(bytes),
+        &IMPLICIT_VR_LITTLE_ENDIAN.erased(),
+    )
+    .ok()?;
+    if dataset.element(Tag(0x0008, 0x0016)).is_err()
+        && dataset.element(Tag(0x7FE0, 0x0010)).is_err()
+    {
+        return None;
+    }
+    let obj = dataset
+        .with_meta(
+            FileMetaTableBuilder::new()
+                .transfer_syntax(IMPLICIT_VR_LITTLE_ENDIAN_UID)
+                .implementation_class_uid(dicom_object::IMPLEMENTATION_CLASS_UID)
+                .implementation_version_name(HEURISTIC_META_IMPLEMENTATION_VERSION_NAME),
+        )
+        .ok()?;
+    log::warn!(
+        "Opened {source_label} heuristically as a raw implicit VR little endian data set (no DICOM preamble or file meta group)"
+    );
+    Some(obj)
+}
+
+/// Whether `obj` carries the file meta group synthesized by
+/// [`open_raw_implicit_vr_dataset`].
+fn is_heuristic_meta(obj: &DefaultDicomObject) -> bool {
+    obj.meta()
+        .implementation_version_name()
+        .is_some_and(|name| {
+            name.trim_end_matches(['\0', ' ']) == HEURISTIC_META_IMPLEMENTATION_VERSION_NAME
+        })
+}
+
+/// Secure DICOM files (PS3.10 section 7.4) are a CMS signed or enveloped data
+/// structure around the DICOM file: a DER SEQUENCE whose content type OID is
+/// 1.2.840.113549.1.7.x rather than a preamble and `DICM` marker.
+fn is_cms_wrapped(bytes: &[u8]) -> bool {
+    const PKCS7_OID_PREFIX: [u8; 10] = [0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x07];
+    bytes.first() == Some(&0x30)
+        && bytes
+            .get(..16)
+            .unwrap_or(bytes)
+            .windows(PKCS7_OID_PREFIX.len())
+            .any(|window| window == PKCS7_OID_PREFIX)
+}
+
+/// Why an opened object may not decode: it carries encrypted attributes
+/// (0400,0500), whose protected values this viewer cannot decrypt.
+fn secure_dicom_indicator(obj: &DefaultDicomObject) -> Option<&'static str> {
+    obj.element(ENCRYPTED_ATTRIBUTES_SEQUENCE)
+        .is_ok()
+        .then_some("Encrypted Attributes Sequence present")
+}
+
+/// A raw data set starts directly with an even, non-meta group tag whose
+/// implicit VR value length fits in the file (or is undefined).
+fn looks_like_raw_implicit_vr_dataset(bytes: &[u8]) -> bool {
+    if bytes.len() < 8 || detect_dicom_prefix_offset(bytes).is_some() {
+        return false;
+    }
+    let group = u16::from_le_bytes([bytes[0], bytes[1]]);
+    let value_len = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+    group % 2 == 0
+        && (0x0008..=0x7FE0).contains(&group)
+        && (value_len == u32::MAX
+            || usize::try_from(value_len).is_ok_and(|len| len <= bytes.len() - 8))
+}
+
 fn sanitize_known_empty_optional_attributes(obj: &mut DefaultDicomObject, source_label: &str) {
     remove_empty_optional_string_attribute(
         obj,
@@ -1417,28 +2466,104 @@ fn fallback_memory_identity_key(preferred_name: &str, bytes: &[u8]) -> String {
 }
 
 fn preload_worker_count(frame_count: usize) -> usize {
+    preload_pool_size().min(frame_count.max(1))
+}
+
+/// DICOM JSON key (`GGGGEEEE`) of a standard keyword, for reading a keyword
+/// setting out of DICOMweb metadata.
+pub fn dicom_json_tag(keyword: &str) -> Option<String> {
+    let tag = StandardDataDictionary.by_name(keyword)?.tag();
+    Some(format!("{:04X}{:04X}", tag.group(), tag.element()))
+}
+
+/// Parses a comma-separated list of DICOM keywords, rejecting unknown ones.
+pub fn parse_view_fallback_tags(value: &str) -> Result<Vec<String>, String> {
+    let mut tags = Vec::new();
+    for keyword in value
+        .split(',')
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+    {
+        let entry = StandardDataDictionary
+            .by_name(keyword)
+            .ok_or_else(|| format!("{keyword} is not a DICOM keyword"))?;
+        let keyword = entry.alias().to_string();
+        if !tags.contains(&keyword) {
+            tags.push(keyword);
+        }
+    }
+    Ok(tags)
+}
+
+static SHUTDOWN_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Tells background frame preload and DICOMweb downloads to stop at their next
+/// check. Set once when the window is closing; there is no way back.
+pub fn request_shutdown() {
+    SHUTDOWN_REQUESTED.store(true, Ordering::Relaxed);
+}
+
+pub fn shutdown_requested() -> bool {
+    SHUTDOWN_REQUESTED.load(Ordering::Relaxed)
+}
+
+/// How frame preload is configured, for diagnostics: the resolved
+/// `PERSPECTA_PRELOAD_WORKERS` mode and the decode pool size in effect.
+pub fn preload_configuration_summary() -> String {
+    match configured_preload_workers() {
+        PreloadWorkers::Auto => format!("auto, {} decode workers", preload_pool_size()),
+        PreloadWorkers::Disabled => "disabled, frames decode on demand".to_string(),
+        PreloadWorkers::Fixed(_) => format!("fixed, {} decode workers", preload_pool_size()),
+    }
+}
+
+/// Whether frames decode in the background. When off, each frame decodes
+/// only when it is shown, one at a time.
+pub fn frame_preload_enabled() -> bool {
+    configured_preload_workers() != PreloadWorkers::Disabled
+}
+
+/// Number of shared decode workers; this caps decode parallelism across all
+/// open images, not per image.
+fn preload_pool_size() -> usize {
     let auto_workers = thread::available_parallelism()
         .map(|n| n.get())
         .unwrap_or(2)
         .clamp(1, 4);
 
-    let configured = configured_preload_workers().unwrap_or(auto_workers);
-    configured.clamp(1, 32).min(frame_count.max(1))
+    let configured = match configured_preload_workers() {
+        PreloadWorkers::Fixed(count) => count,
+        PreloadWorkers::Auto | PreloadWorkers::Disabled => auto_workers,
+    };
+    configured.clamp(1, 32)
+}
+
+/// Background frame preload setting from `PERSPECTA_PRELOAD_WORKERS`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PreloadWorkers {
+    /// Unset or unparsable: size the pool from available parallelism.
+    Auto,
+    /// `0`: never preload; decode each frame only when it is requested.
+    Disabled,
+    Fixed(usize),
 }
 
-fn configured_preload_workers() -> Option<usize> {
-    static CONFIG: OnceLock<Option<usize>> = OnceLock::new();
+fn configured_preload_workers() -> PreloadWorkers {
+    static CONFIG: OnceLock<PreloadWorkers> = OnceLock::new();
 
     *CONFIG.get_or_init(|| {
-        let raw = std::env::var("PERSPECTA_PRELOAD_WORKERS").ok()?;
-        let value = raw.trim().parse::<usize>().ok()?;
-        if value == 0 {
-            return None;
-        }
-        Some(value)
+        parse_preload_workers(std::env::var("PERSPECTA_PRELOAD_WORKERS").ok().as_deref())
     })
 }
 
+fn parse_preload_workers(raw: Option<&str>) -> PreloadWorkers {
+    match raw.and_then(|raw| raw.trim().parse::<usize>().ok()) {
+        None => PreloadWorkers::Auto,
+        Some(0) => PreloadWorkers::Disabled,
+        Some(count) => PreloadWorkers::Fixed(count),
+    }
+}
+
 fn is_missing_meta_group_length_error(error: &ReadError) -> bool {
     matches!(
         error,
@@ -1924,6 +3049,10 @@ fn collect_metadata(obj: &DefaultDicomObject) -> Vec<(String, String)> {
     METADATA_FIELD_NAMES
         .iter()
         .filter_map(|name| {
+            if *name == "ICCProfile" {
+                let bytes = obj.element(ICC_PROFILE).ok()?.to_bytes().ok()?;
+                return Some((*name, summarize_icc_profile(&bytes)));
+            }
             obj.element_by_name(name)
                 .ok()
                 .and_then(|el| el.to_str().ok().map(|v| (*name, v.to_string())))
@@ -1932,6 +3061,37 @@ fn collect_metadata(obj: &DefaultDicomObject) -> Vec<(String, String)> {
         .collect()
 }
 
+/// Describes an embedded ICC profile from its header, for example
+/// `RGB display profile v4.3 (3144 bytes)`. The profile is reported only;
+/// color samples are still rendered as sRGB.
+fn summarize_icc_profile(bytes: &[u8]) -> String {
+    if bytes.get(36..40) != Some(ICC_PROFILE_SIGNATURE) {
+        return format!("Unrecognized profile ({} bytes)", bytes.len());
+    }
+    let signature = |range: std::ops::Range<usize>| {
+        bytes
+            .get(range)
+            .map(|raw| String::from_utf8_lossy(raw).trim().to_string())
+            .unwrap_or_default()
+    };
+    let class = match signature(12..16).as_str() {
+        "mntr" => "display".to_string(),
+        "scnr" => "input".to_string(),
+        "prtr" => "output".to_string(),
+        "spac" => "color space".to_string(),
+        "link" => "device link".to_string(),
+        "abst" => "abstract".to_string(),
+        other => other.to_string(),
+    };
+    let major = bytes[8];
+    let minor = bytes[9] >> 4;
+    format!(
+        "{} {class} profile v{major}.{minor} ({} bytes)",
+        signature(16..20),
+        bytes.len()
+    )
+}
+
 fn collect_full_metadata(obj: &DefaultDicomObject) -> Vec<FullMetadataField> {
     obj.into_iter().map(collect_full_metadata_field).collect()
 }
@@ -2019,12 +3179,13 @@ fn is_binary_metadata_vr(vr: VR) -> bool {
     )
 }
 
-fn read_string_or_default(obj: &DefaultDicomObject, name: &str, default: &str) -> String {
-    obj.element_by_name(name)
-        .ok()
-        .and_then(|el| el.to_str().ok())
-        .map(|v| v.to_string())
-        .unwrap_or_else(|| default.to_string())
+/// Whether grayscale values render inverted. A missing photometric
+/// interpretation is MONOCHROME2 unless the site setting says otherwise.
+fn photometric_inverts(photometric: Option<&str>, assume_monochrome1_when_missing: bool) -> bool {
+    match photometric {
+        Some(photometric) => photometric.eq_ignore_ascii_case("MONOCHROME1"),
+        None => assume_monochrome1_when_missing,
+    }
 }
 
 fn read_string(obj: &DefaultDicomObject, name: &str) -> Option<String> {
@@ -2069,30 +3230,17 @@ fn read_nested_string2(
     .filter(|value| !value.is_empty())
 }
 
+/// Canonical `CC`/`MLO` when the value is recognised, otherwise the trimmed
+/// original so unusual views still show up in labels.
 fn normalize_view_position(raw: &str) -> Option<String> {
-    let token = raw
-        .trim()
-        .to_ascii_uppercase()
-        .chars()
-        .filter(|c| c.is_ascii_alphanumeric())
-        .collect::<String>();
-
-    if token.is_empty() {
+    let raw = raw.trim();
+    if normalize_token(Some(raw)).is_empty() {
         return None;
     }
-
-    if token.contains("R10242") || token.contains("CRANIOCAUDAL") || token.contains("CC") {
-        return Some("CC".to_string());
-    }
-
-    if token.contains("R10226") || token.contains("MEDIOLATERALOBLIQUE") || token.contains("MLO") {
-        return Some("MLO".to_string());
-    }
-
-    Some(raw.trim().to_string())
+    Some(classify_view(Some(raw)).unwrap_or(raw).to_string())
 }
 
-fn read_view_position(obj: &DefaultDicomObject) -> Option<String> {
+fn read_view_position(obj: &DefaultDicomObject, fallback_tags: &[String]) -> Option<String> {
     const VIEW_CODE_SEQUENCE: Tag = Tag(0x0054, 0x0220);
     const CODE_MEANING: Tag = Tag(0x0008, 0x0104);
     const CODE_VALUE: Tag = Tag(0x0008, 0x0100);
@@ -2100,34 +3248,31 @@ fn read_view_position(obj: &DefaultDicomObject) -> Option<String> {
     read_string(obj, "ViewPosition")
         .or_else(|| read_nested_string(obj, VIEW_CODE_SEQUENCE, 0, CODE_MEANING))
         .or_else(|| read_nested_string(obj, VIEW_CODE_SEQUENCE, 0, CODE_VALUE))
+        .or_else(|| {
+            let values = read_fallback_tag_values(obj, fallback_tags);
+            classify_view_in_fallbacks(values.iter().map(String::as_str)).map(str::to_string)
+        })
         .or_else(|| read_string(obj, "SeriesDescription"))
         .and_then(|raw| normalize_view_position(&raw))
 }
 
-fn normalize_laterality(raw: &str) -> Option<String> {
-    let token = raw
-        .trim()
-        .to_ascii_uppercase()
-        .chars()
-        .filter(|c| c.is_ascii_alphanumeric())
-        .collect::<String>();
+/// Values of the view fallback tags the object carries, in setting order.
+fn read_fallback_tag_values(obj: &DefaultDicomObject, fallback_tags: &[String]) -> Vec<String> {
+    fallback_tags
+        .iter()
+        .filter_map(|tag| read_string(obj, tag))
+        .collect()
+}
 
-    if token.is_empty() {
+fn normalize_laterality(raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    if normalize_token(Some(raw)).is_empty() {
         return None;
     }
-
-    if token.starts_with('R') || token.contains("RIGHT") {
-        return Some("R".to_string());
-    }
-
-    if token.starts_with('L') || token.contains("LEFT") {
-        return Some("L".to_string());
-    }
-
-    Some(raw.trim().to_string())
+    Some(classify_laterality(Some(raw)).unwrap_or(raw).to_string())
 }
 
-fn read_laterality(obj: &DefaultDicomObject) -> Option<String> {
+fn read_laterality(obj: &DefaultDicomObject, fallback_tags: &[String]) -> Option<String> {
     const FRAME_ANATOMY_SEQUENCE: Tag = Tag(0x0020, 0x9071);
     const FRAME_LATERALITY: Tag = Tag(0x0020, 0x9072);
     const SHARED_FUNCTIONAL_GROUPS_SEQUENCE: Tag = Tag(0x5200, 0x9229);
@@ -2146,10 +3291,71 @@ fn read_laterality(obj: &DefaultDicomObject) -> Option<String> {
                 FRAME_LATERALITY,
             )
         })
+        .or_else(|| {
+            let values = read_fallback_tag_values(obj, fallback_tags);
+            classify_laterality_in_fallbacks(values.iter().map(String::as_str)).map(str::to_string)
+        })
         .or_else(|| read_string(obj, "ImageType"))
         .and_then(|raw| normalize_laterality(&raw))
 }
 
+/// Reads the two-value `PatientOrientation` when both values use only the
+/// human direction letters (A, P, L, R, H, F).
+fn read_patient_orientation(obj: &DefaultDicomObject) -> Option<PatientOrientation> {
+    let values = obj
+        .element_by_name("PatientOrientation")
+        .ok()?
+        .to_multi_str()
+        .ok()?;
+    let [row, column] = values.as_ref() else {
+        return None;
+    };
+    let normalize = |value: &str| {
+        let value = value.trim().to_ascii_uppercase();
+        (!value.is_empty() && value.chars().all(|letter| "APLRHF".contains(letter)))
+            .then_some(value)
+    };
+    Some(PatientOrientation {
+        row: normalize(row)?,
+        column: normalize(column)?,
+    })
+}
+
+fn read_presentation_intent(obj: &DefaultDicomObject) -> Option<PresentationIntent> {
+    if let Some(intent) =
+        read_string(obj, "PresentationIntentType").and_then(|raw| parse_presentation_intent(&raw))
+    {
+        return Some(intent);
+    }
+
+    if let Some(sop_class_uid) = read_string(obj, "SOPClassUID") {
+        if FOR_PROCESSING_SOP_CLASS_UIDS.contains(&sop_class_uid.as_str()) {
+            return Some(PresentationIntent::ForProcessing);
+        }
+        if FOR_PRESENTATION_SOP_CLASS_UIDS.contains(&sop_class_uid.as_str()) {
+            return Some(PresentationIntent::ForPresentation);
+        }
+    }
+
+    let image_type = read_string(obj, "ImageType")?;
+    image_type.split('\\').find_map(parse_presentation_intent)
+}
+
+fn parse_presentation_intent(raw: &str) -> Option<PresentationIntent> {
+    let token = raw
+        .trim()
+        .to_ascii_uppercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>();
+
+    match token.as_str() {
+        "FORPROCESSING" => Some(PresentationIntent::ForProcessing),
+        "FORPRESENTATION" => Some(PresentationIntent::ForPresentation),
+        _ => None,
+    }
+}
+
 fn read_per_frame_image_positions(obj: &DefaultDicomObject) -> Vec<[f32; 3]> {
     const PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE: Tag = Tag(0x5200, 0x9230);
     const PLANE_POSITION_SEQUENCE: Tag = Tag(0x0020, 0x9113);
@@ -2199,6 +3405,57 @@ fn infer_reverse_frame_order(obj: &DefaultDicomObject, frame_count: usize) -> bo
     dominant_delta > IMAGE_POSITION_PATIENT_DOMINANT_DELTA_TOLERANCE_MM
 }
 
+/// Cumulative per-frame acquisition offsets in stored order, built from the
+/// `FrameTimeVector` increments or, failing that, a constant `FrameTime`.
+fn read_frame_time_offsets_ms(obj: &DefaultDicomObject, frame_count: usize) -> Option<Arc<[f32]>> {
+    if frame_count <= 1 {
+        return None;
+    }
+
+    let increments = obj
+        .element_by_name("FrameTimeVector")
+        .ok()
+        .and_then(|el| el.to_str().ok())
+        .map(|value| {
+            value
+                .split('\\')
+                .filter_map(|token| token.trim().parse::<f32>().ok())
+                .collect::<Vec<_>>()
+        })
+        .filter(|increments| increments.len() == frame_count);
+    let offsets = match increments {
+        Some(increments) => increments
+            .iter()
+            .scan(0.0_f32, |elapsed, increment| {
+                *elapsed += increment.max(0.0);
+                Some(*elapsed)
+            })
+            .collect::<Vec<_>>(),
+        None => {
+            let frame_time = read_float_first(obj, "FrameTime").filter(|value| *value > 0.0)?;
+            (0..frame_count)
+                .map(|frame_index| frame_index as f32 * frame_time)
+                .collect()
+        }
+    };
+    Some(Arc::from(offsets))
+}
+
+/// Playback rate the file asks for, clamped to 1-120 fps.
+/// `RecommendedDisplayFrameRate` is authoritative when present; a derived
+/// `FrameTime` rate and then `CineRate` are fallbacks.
+fn read_recommended_cine_fps(obj: &DefaultDicomObject) -> Option<f32> {
+    read_float_first(obj, "RecommendedDisplayFrameRate")
+        .filter(|value| *value > 0.0)
+        .or_else(|| {
+            read_float_first(obj, "FrameTime")
+                .filter(|value| *value > 0.0)
+                .map(|frame_time_ms| 1000.0 / frame_time_ms)
+        })
+        .or_else(|| read_float_first(obj, "CineRate").filter(|value| *value > 0.0))
+        .map(|fps| fps.clamp(1.0, 120.0))
+}
+
 fn read_float_first(obj: &DefaultDicomObject, name: &str) -> Option<f32> {
     obj.element_by_name(name)
         .ok()
@@ -2217,25 +3474,98 @@ fn read_int_first(obj: &DefaultDicomObject, name: &str) -> Option<i32> {
     obj.element_by_name(name)
         .ok()
         .and_then(|el| el.to_str().ok())
-        .and_then(|value| {
-            value
-                .split('\\')
-                .next()
-                .and_then(|v| v.trim().parse::<i32>().ok())
-        })
-}
-
-fn parse_multi_valued_number(value: &str) -> Option<f32> {
-    value.split('\\').next()?.trim().parse::<f32>().ok()
+        .and_then(|value| parse_first_int(&value))
 }
 
-fn parse_multi_valued_pair(value: &str) -> Option<[f32; 2]> {
-    let values = value
+/// Parses the first value of a backslash-delimited integer string. Padding
+/// (spaces or NULs), a leading `+` and leading zeros are accepted, as is an
+/// integral decimal such as `3.0` that some writers emit for IS values; an
+/// empty or non-numeric first value yields `None`.
+pub(crate) fn parse_first_int(value: &str) -> Option<i32> {
+    let first = value
         .split('\\')
-        .map(str::trim)
-        .filter(|token| !token.is_empty())
-        .collect::<Vec<_>>();
-    let [first, second] = values.as_slice() else {
+        .next()?
+        .trim_matches(|ch: char| ch.is_whitespace() || ch == '\0');
+    if first.is_empty() {
+        return None;
+    }
+    first.parse::<i32>().ok().or_else(|| {
+        let parsed = first.parse::<f64>().ok()?;
+        (parsed.is_finite()
+            && parsed.fract() == 0.0
+            && parsed >= f64::from(i32::MIN)
+            && parsed <= f64::from(i32::MAX))
+        .then_some(parsed as i32)
+    })
+}
+
+fn read_window_presets(obj: &DefaultDicomObject) -> Vec<WindowPreset> {
+    let read = |name: &str| {
+        obj.element_by_name(name)
+            .ok()
+            .and_then(|el| el.to_str().ok())
+            .map(|value| value.into_owned())
+    };
+    window_presets_from_values(
+        read("WindowCenter").as_deref(),
+        read("WindowWidth").as_deref(),
+        read("WindowCenterWidthExplanation").as_deref(),
+    )
+}
+
+/// Pairs the multi-valued center and width strings into presets. Explanations
+/// only name them when there is exactly one non-empty explanation per window;
+/// otherwise every preset is labelled by its values.
+fn window_presets_from_values(
+    centers: Option<&str>,
+    widths: Option<&str>,
+    explanations: Option<&str>,
+) -> Vec<WindowPreset> {
+    let parse_all = |value: Option<&str>| {
+        value?
+            .split('\\')
+            .map(|token| token.trim().parse::<f32>().ok().filter(|v| v.is_finite()))
+            .collect::<Option<Vec<_>>>()
+    };
+    let (Some(centers), Some(widths)) = (parse_all(centers), parse_all(widths)) else {
+        return Vec::new();
+    };
+    if centers.len() != widths.len() {
+        return Vec::new();
+    }
+
+    let explanations = explanations
+        .map(|value| value.split('\\').map(str::trim).collect::<Vec<_>>())
+        .filter(|labels| {
+            labels.len() == centers.len() && labels.iter().all(|label| !label.is_empty())
+        });
+    centers
+        .into_iter()
+        .zip(widths)
+        .enumerate()
+        .map(|(index, (center, width))| WindowPreset {
+            center,
+            width,
+            label: explanations
+                .as_ref()
+                .map(|labels| labels[index].to_string())
+                .unwrap_or_else(|| format!("C {center} / W {width}")),
+        })
+        .filter(|preset| preset.width > 0.0)
+        .collect()
+}
+
+fn parse_multi_valued_number(value: &str) -> Option<f32> {
+    value.split('\\').next()?.trim().parse::<f32>().ok()
+}
+
+fn parse_multi_valued_pair(value: &str) -> Option<[f32; 2]> {
+    let values = value
+        .split('\\')
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .collect::<Vec<_>>();
+    let [first, second] = values.as_slice() else {
         return None;
     };
     let first = first.parse::<f32>().ok()?;
@@ -2275,6 +3605,22 @@ fn min_max(values: &[i32]) -> Option<(i32, i32)> {
     Some((min_v, max_v))
 }
 
+fn percentile_window(values: &[i32]) -> Option<(f32, f32)> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let stride = values.len().div_ceil(PERCENTILE_WINDOW_MAX_SAMPLES);
+    let mut sample = values.iter().step_by(stride).copied().collect::<Vec<_>>();
+    let last_index = sample.len() - 1;
+    let low_index = (last_index as f32 * FOR_PROCESSING_WINDOW_LOW_PERCENTILE).round() as usize;
+    let high_index = (last_index as f32 * FOR_PROCESSING_WINDOW_HIGH_PERCENTILE).round() as usize;
+    let (_, &mut high, _) = sample.select_nth_unstable(high_index.min(last_index));
+    let (_, &mut low, _) = sample.select_nth_unstable(low_index.min(last_index));
+
+    Some(((low as f32 + high as f32) / 2.0, (high - low).max(1) as f32))
+}
+
 #[cfg(test)]
 impl DicomImage {
     pub(crate) fn test_stub(gsps_overlay: Option<GspsOverlay>) -> Self {
@@ -2316,12 +3662,16 @@ impl DicomImage {
             min_value: 0,
             max_value: 0,
             recommended_cine_fps: None,
+            frame_time_offsets_ms: None,
             pixel_spacing_mm: None,
             view_position: None,
             image_laterality: None,
+            patient_orientation: None,
+            presentation_intent: None,
             instance_number: None,
             sop_instance_uid: None,
             reverse_frame_order,
+            stored_frame_base: 0,
             gsps_overlay,
             sr_overlay: None,
             pm_overlay: None,
@@ -2330,6 +3680,9 @@ impl DicomImage {
             full_metadata_source: None,
             full_metadata_loaded: false,
             full_metadata_loading: false,
+            window_presets: Vec::new(),
+            undecodable_reason: None,
+            opened_heuristically: false,
         }
     }
 
@@ -2364,12 +3717,16 @@ impl DicomImage {
             min_value: 0,
             max_value: 0,
             recommended_cine_fps: None,
+            frame_time_offsets_ms: None,
             pixel_spacing_mm: None,
             view_position: None,
             image_laterality: None,
+            patient_orientation: None,
+            presentation_intent: None,
             instance_number: None,
             sop_instance_uid: None,
             reverse_frame_order: false,
+            stored_frame_base: 0,
             gsps_overlay: None,
             sr_overlay: None,
             pm_overlay: None,
@@ -2378,6 +3735,9 @@ impl DicomImage {
             full_metadata_source: None,
             full_metadata_loaded: false,
             full_metadata_loading: false,
+            window_presets: Vec::new(),
+            undecodable_reason: None,
+            opened_heuristically: false,
         }
     }
 }
@@ -2389,7 +3749,6 @@ mod tests {
     use dicom_core::{DataElement, PrimitiveValue, VR};
     #[cfg(feature = "jpeg_ls")]
     use dicom_encoding::adapters::EncodeOptions;
-    use dicom_object::FileMetaTableBuilder;
     #[cfg(feature = "jpeg_ls")]
     use dicom_pixeldata::Transcode;
     #[cfg(feature = "jpeg_ls")]
@@ -2753,6 +4112,112 @@ mod tests {
         assert!(repair_missing_meta_group_length(&bytes).is_none());
     }
 
+    #[test]
+    fn open_dicom_object_reads_raw_implicit_vr_dataset_without_preamble() {
+        let dataset = InMemDicomObject::from_element_iter([
+            DataElement::new(Tag(0x0008, 0x0016), VR::UI, "1.2.840.10008.5.1.4.1.1.7"),
+            DataElement::new(Tag(0x0008, 0x0018), VR::UI, "1.2.3.4"),
+            DataElement::new(Tag(0x0008, 0x0060), VR::CS, "OT"),
+        ]);
+        let mut bytes = Vec::new();
+        dataset
+            .write_dataset_with_ts(&mut bytes, &IMPLICIT_VR_LITTLE_ENDIAN.erased())
+            .expect("raw data set should serialize");
+        assert!(looks_like_raw_implicit_vr_dataset(&bytes));
+
+        let obj = open_dicom_object_from_bytes(&bytes, "raw-dataset-test")
+            .expect("raw implicit VR data set should open heuristically");
+        assert_eq!(read_string(&obj, "Modality").as_deref(), Some("OT"));
+        assert_eq!(obj.meta().transfer_syntax(), IMPLICIT_VR_LITTLE_ENDIAN_UID);
+        assert_eq!(obj.meta().media_storage_sop_instance_uid(), "1.2.3.4");
+        assert!(is_heuristic_meta(&obj));
+
+        assert!(open_dicom_object_from_bytes(b"not a dicom file at all", "text").is_err());
+    }
+
+    #[test]
+    fn raw_dataset_heuristic_can_be_turned_off() {
+        assert!(parse_raw_datasets_enabled(None));
+        assert!(parse_raw_datasets_enabled(Some("1")));
+        assert!(!parse_raw_datasets_enabled(Some(" Off ")));
+        assert!(!parse_raw_datasets_enabled(Some("0")));
+    }
+
+    #[test]
+    fn secure_dicom_files_report_a_clear_error() {
+        // DER SEQUENCE holding the CMS envelopedData content type.
+        let cms = [
+            0x30, 0x80, 0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x07, 0x03, 0xA0,
+            0x80, 0x30, 0x80,
+        ];
+        let err = load_dicom(
+            DicomSource::from_memory("cms-wrapped", cms.to_vec()),
+            &DicomLoadOptions::default(),
+        )
+        .expect_err("CMS wrapped file should not open");
+        assert!(format!("{err:#}").starts_with(SECURE_DICOM_MESSAGE));
+
+        let encrypted = InMemDicomObject::from_element_iter([
+            DataElement::new(Tag(0x0008, 0x0016), VR::UI, "1.2.840.10008.5.1.4.1.1.7"),
+            DataElement::new(Tag(0x0008, 0x0018), VR::UI, "1.2.3.4"),
+            DataElement::new(Tag(0x0028, 0x0010), VR::US, PrimitiveValue::from(2_u16)),
+            DataElement::new(Tag(0x0028, 0x0011), VR::US, PrimitiveValue::from(2_u16)),
+            DataElement::new(
+                ENCRYPTED_ATTRIBUTES_SEQUENCE,
+                VR::SQ,
+                dicom_core::value::DataSetSequence::from(vec![InMemDicomObject::new_empty()]),
+            ),
+        ])
+        .with_meta(FileMetaTableBuilder::new().transfer_syntax(EXPLICIT_VR_LITTLE_ENDIAN_UID))
+        .expect("encrypted test object should build file meta");
+        let mut bytes = Vec::new();
+        encrypted
+            .write_all(&mut bytes)
+            .expect("encrypted test object should serialize");
+        let err = load_dicom(
+            DicomSource::from_memory("encrypted-attributes", bytes),
+            &DicomLoadOptions::default(),
+        )
+        .expect_err("image without decodable pixel data should not load");
+        assert!(format!("{err:#}").starts_with(SECURE_DICOM_MESSAGE));
+
+        assert!(!is_cms_wrapped(b"not a dicom file at all"));
+    }
+
+    #[test]
+    fn icc_profile_is_reported_in_metadata() {
+        let mut profile = vec![0_u8; 128];
+        profile[8] = 4;
+        profile[9] = 0x30;
+        profile[12..16].copy_from_slice(b"mntr");
+        profile[16..20].copy_from_slice(b"RGB ");
+        profile[36..40].copy_from_slice(ICC_PROFILE_SIGNATURE);
+        let obj = InMemDicomObject::from_element_iter([
+            DataElement::new(Tag(0x0008, 0x0016), VR::UI, "1.2.840.10008.5.1.4.1.1.7"),
+            DataElement::new(Tag(0x0028, 0x2002), VR::CS, "SRGB"),
+            DataElement::new(ICC_PROFILE, VR::OB, PrimitiveValue::from(profile)),
+        ])
+        .with_meta(FileMetaTableBuilder::new().transfer_syntax(EXPLICIT_VR_LITTLE_ENDIAN_UID))
+        .expect("ICC test object should build file meta");
+
+        let metadata = collect_metadata(&obj);
+        let value = |name: &str| {
+            metadata
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str())
+        };
+        assert_eq!(value("ColorSpace"), Some("SRGB"));
+        assert_eq!(
+            value("ICCProfile"),
+            Some("RGB display profile v4.3 (128 bytes)")
+        );
+        assert_eq!(
+            summarize_icc_profile(&[0; 16]),
+            "Unrecognized profile (16 bytes)"
+        );
+    }
+
     #[test]
     fn open_dicom_object_degrades_private_text_value_mislabelled_as_fd_to_un() {
         let mut bytes = private_text_test_bytes("123.45", EXPLICIT_VR_LITTLE_ENDIAN_UID);
@@ -2964,6 +4429,320 @@ mod tests {
         }
     }
 
+    #[test]
+    fn load_dicom_quantizes_float_pixel_data() {
+        let float_object = |pixel_data: DataElement<InMemDicomObject>| {
+            let object = InMemDicomObject::from_element_iter([
+                DataElement::new(Tag(0x0008, 0x0016), VR::UI, "1.2.840.10008.5.1.4.1.1.7"),
+                DataElement::new(Tag(0x0008, 0x0018), VR::UI, "4.3.2.14"),
+                DataElement::new(Tag(0x0028, 0x0002), VR::US, PrimitiveValue::from(1u16)),
+                DataElement::new(Tag(0x0028, 0x0004), VR::CS, "MONOCHROME2"),
+                DataElement::new(Tag(0x0028, 0x0010), VR::US, PrimitiveValue::from(2u16)),
+                DataElement::new(Tag(0x0028, 0x0011), VR::US, PrimitiveValue::from(2u16)),
+                DataElement::new(Tag(0x0028, 0x0100), VR::US, PrimitiveValue::from(32u16)),
+                pixel_data,
+            ])
+            .with_meta(
+                FileMetaTableBuilder::new()
+                    .transfer_syntax(EXPLICIT_VR_LITTLE_ENDIAN_UID)
+                    .media_storage_sop_class_uid("1.2.840.10008.5.1.4.1.1.7")
+                    .media_storage_sop_instance_uid("4.3.2.14"),
+            )
+            .expect("float test object should build file meta");
+            let mut bytes = Vec::new();
+            object
+                .write_all(&mut bytes)
+                .expect("float test object should serialize");
+            bytes
+        };
+
+        let bytes = float_object(DataElement::new(
+            FLOAT_PIXEL_DATA,
+            VR::OF,
+            PrimitiveValue::F32(vec![0.0f32, 0.25, f32::NAN, 1.0].into()),
+        ));
+        let image = load_dicom(
+            DicomSource::from_memory("float-pixels", bytes),
+            &DicomLoadOptions::default(),
+        )
+        .expect("float image should load");
+        assert!(image.is_monochrome());
+        assert_eq!((image.min_value, image.max_value), (0, 10_000));
+        assert_eq!(
+            image.frame_mono_pixels(0).as_deref(),
+            Some([0, 2_500, 0, 10_000].as_slice())
+        );
+        assert_eq!(
+            (image.window_center, image.window_width),
+            (5_000.0, 10_000.0)
+        );
+
+        let bytes = float_object(DataElement::new(
+            DOUBLE_FLOAT_PIXEL_DATA,
+            VR::OD,
+            PrimitiveValue::F64(vec![-1000.0f64, 0.0, 2000.0, 4000.4].into()),
+        ));
+        let image = load_dicom(
+            DicomSource::from_memory("double-pixels", bytes),
+            &DicomLoadOptions::default(),
+        )
+        .expect("double float image should load");
+        assert_eq!(
+            image.frame_mono_pixels(0).as_deref(),
+            Some([-1000, 0, 2000, 4000].as_slice())
+        );
+
+        let bytes = float_object(DataElement::new(
+            Tag(0x0028, 0x0101),
+            VR::US,
+            PrimitiveValue::from(32u16),
+        ));
+        let err = load_dicom(
+            DicomSource::from_memory("no-pixels", bytes),
+            &DicomLoadOptions::default(),
+        )
+        .expect_err("object without pixel data should not load");
+        assert!(format!("{err:#}").contains("does not contain Pixel Data"));
+    }
+
+    #[test]
+    fn load_dicom_unpacks_single_bit_frames_across_byte_boundaries() {
+        // Two 2x5 frames: 20 packed bits, least significant bit first, with
+        // frame 1 starting mid-byte at bit 10.
+        let object = InMemDicomObject::from_element_iter([
+            DataElement::new(Tag(0x0008, 0x0016), VR::UI, "1.2.840.10008.5.1.4.1.1.66.4"),
+            DataElement::new(Tag(0x0008, 0x0018), VR::UI, "4.3.2.13"),
+            DataElement::new(Tag(0x0008, 0x0060), VR::CS, "SEG"),
+            DataElement::new(Tag(0x0028, 0x0002), VR::US, PrimitiveValue::from(1u16)),
+            DataElement::new(Tag(0x0028, 0x0004), VR::CS, "MONOCHROME2"),
+            DataElement::new(Tag(0x0028, 0x0008), VR::IS, "2"),
+            DataElement::new(Tag(0x0028, 0x0010), VR::US, PrimitiveValue::from(2u16)),
+            DataElement::new(Tag(0x0028, 0x0011), VR::US, PrimitiveValue::from(5u16)),
+            DataElement::new(Tag(0x0028, 0x0100), VR::US, PrimitiveValue::from(1u16)),
+            DataElement::new(Tag(0x0028, 0x0101), VR::US, PrimitiveValue::from(1u16)),
+            DataElement::new(Tag(0x0028, 0x0102), VR::US, PrimitiveValue::from(0u16)),
+            DataElement::new(Tag(0x0028, 0x0103), VR::US, PrimitiveValue::from(0u16)),
+            DataElement::new(
+                Tag(0x7FE0, 0x0010),
+                VR::OB,
+                PrimitiveValue::from(vec![0b0000_0101u8, 0b0000_1110, 0b0000_1000, 0]),
+            ),
+        ])
+        .with_meta(
+            FileMetaTableBuilder::new()
+                .transfer_syntax(EXPLICIT_VR_LITTLE_ENDIAN_UID)
+                .media_storage_sop_class_uid("1.2.840.10008.5.1.4.1.1.66.4")
+                .media_storage_sop_instance_uid("4.3.2.13"),
+        )
+        .expect("1-bit test object should build file meta");
+        let mut bytes = Vec::new();
+        object
+            .write_all(&mut bytes)
+            .expect("1-bit test object should serialize");
+
+        let image = load_dicom(
+            DicomSource::from_memory("single-bit-mask", bytes),
+            &DicomLoadOptions::default(),
+        )
+        .expect("1-bit image should load");
+
+        assert_eq!(image.frame_count(), 2);
+        assert_eq!((image.min_value, image.max_value), (0, 255));
+        assert_eq!(
+            image.frame_mono_pixels(0).as_deref(),
+            Some([255, 0, 255, 0, 0, 0, 0, 0, 0, 255].as_slice())
+        );
+        assert_eq!(
+            image.frame_mono_pixels(1).as_deref(),
+            Some([255, 255, 0, 0, 0, 0, 0, 0, 0, 255].as_slice())
+        );
+    }
+
+    #[test]
+    fn loaded_frame_count_counts_only_cached_lazy_frames() {
+        let image = DicomImage::test_stub_with_lazy_mono_cache(&[(0, 1), (2, 3)]);
+        assert_eq!(image.frame_count(), 3);
+        assert_eq!(image.loaded_frame_count(), 2);
+    }
+
+    #[test]
+    fn loaded_frames_marks_cached_lazy_frames_in_display_order() {
+        let image = DicomImage::test_stub_with_lazy_mono_cache(&[(0, 1), (2, 3)]);
+        assert_eq!(image.loaded_frames(), vec![true, false, true]);
+    }
+
+    #[test]
+    fn window_presets_use_explanations_only_when_counts_match() {
+        let presets =
+            window_presets_from_values(Some("40\\300"), Some("80\\1500"), Some("BRAIN\\BONE"));
+        assert_eq!(
+            presets
+                .iter()
+                .map(|preset| (preset.center, preset.width, preset.label.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(40.0, 80.0, "BRAIN"), (300.0, 1500.0, "BONE")]
+        );
+
+        let unnamed = window_presets_from_values(Some("40\\300"), Some("80\\1500"), Some("BRAIN"));
+        assert_eq!(unnamed[0].label, "C 40 / W 80");
+        assert_eq!(unnamed[1].label, "C 300 / W 1500");
+
+        assert!(window_presets_from_values(Some("40\\300"), Some("80"), None).is_empty());
+        assert!(window_presets_from_values(None, Some("80"), None).is_empty());
+    }
+
+    #[test]
+    fn parse_first_int_tolerates_padding_and_rejects_empty_values() {
+        for (value, expected) in [
+            ("7", Some(7)),
+            (" 007 ", Some(7)),
+            ("+3", Some(3)),
+            ("-2\\5", Some(-2)),
+            ("12\0", Some(12)),
+            ("4.0", Some(4)),
+            ("4.5", None),
+            ("", None),
+            ("   ", None),
+            ("\\9", None),
+            ("n/a", None),
+            ("99999999999", None),
+        ] {
+            assert_eq!(parse_first_int(value), expected, "{value:?}");
+        }
+    }
+
+    #[test]
+    fn color_mode_follows_decoded_layout_whatever_the_header_says() {
+        let source = DicomSource::from(PathBuf::from("spp-test.dcm"));
+        let mode = |photometric, tag, decoded| {
+            color_mode_from_decoded_layout(&source, photometric, tag, decoded, false)
+        };
+
+        assert_eq!(
+            mode(Some("MONOCHROME2"), Some(1), 1),
+            Some(ImageColorMode::Monochrome)
+        );
+        assert_eq!(
+            mode(Some("YBR_FULL_422"), Some(3), 3),
+            Some(ImageColorMode::Rgb)
+        );
+        assert_eq!(
+            mode(Some("PALETTE COLOR"), Some(1), 1),
+            Some(ImageColorMode::Monochrome)
+        );
+        // Photometric interpretation and the tag only warn when they disagree.
+        assert_eq!(
+            mode(Some("PALETTE COLOR"), Some(1), 3),
+            Some(ImageColorMode::Rgb)
+        );
+        assert_eq!(
+            mode(Some("RGB"), Some(3), 1),
+            Some(ImageColorMode::Monochrome)
+        );
+        assert_eq!(mode(None, Some(3), 3), Some(ImageColorMode::Rgb));
+        assert_eq!(
+            mode(Some("UNKNOWN"), None, 1),
+            Some(ImageColorMode::Monochrome)
+        );
+        assert_eq!(mode(Some("MONOCHROME2"), Some(2), 2), None);
+    }
+
+    #[test]
+    fn palette_color_expands_indices_to_rgb() {
+        let palette = |data: Vec<u16>| {
+            [0x1101u16, 0x1102, 0x1103]
+                .into_iter()
+                .zip([0x1201u16, 0x1202, 0x1203])
+                .enumerate()
+                .flat_map(move |(channel, (descriptor, table))| {
+                    let table_data = data
+                        .iter()
+                        .map(|index| index.wrapping_mul(channel as u16 + 1) << 8)
+                        .collect::<Vec<_>>();
+                    [
+                        DataElement::new(
+                            Tag(0x0028, descriptor),
+                            VR::US,
+                            PrimitiveValue::from([256u16, 0, 16]),
+                        ),
+                        DataElement::new(
+                            Tag(0x0028, table),
+                            VR::OW,
+                            PrimitiveValue::U16(table_data.into()),
+                        ),
+                    ]
+                })
+                .collect::<Vec<_>>()
+        };
+        let mut elements = palette((0..256).collect());
+        elements.push(DataElement::new(
+            Tag(0x0028, 0x0004),
+            VR::CS,
+            "PALETTE COLOR",
+        ));
+        let source = DicomSource::from_memory("palette", basic_image_test_bytes(elements));
+        let image = load_dicom(&source, &DicomLoadOptions::default())
+            .expect("palette color image should load");
+        assert_eq!(image.color_mode, ImageColorMode::Rgb);
+        assert_eq!(image.samples_per_pixel, 3);
+        // The stored index is 64; each channel's table scales it differently.
+        assert_eq!(
+            image.frame_rgb_pixels(0).as_deref(),
+            Some([64u8, 128, 192].as_slice())
+        );
+
+        // Without lookup tables the indices still show in grayscale.
+        let source = DicomSource::from_memory(
+            "palette-without-tables",
+            basic_image_test_bytes(vec![DataElement::new(
+                Tag(0x0028, 0x0004),
+                VR::CS,
+                "PALETTE COLOR",
+            )]),
+        );
+        let image = load_dicom(&source, &DicomLoadOptions::default())
+            .expect("palette color image without tables should load");
+        assert_eq!(image.color_mode, ImageColorMode::Monochrome);
+
+        let lut = PaletteColorLut {
+            first_mapped: 10,
+            red: vec![1, 2, 3],
+            green: vec![4, 5, 6],
+            blue: vec![7, 8, 9],
+        };
+        assert_eq!(lut.expand(&[0, 11, 99]), vec![1, 4, 7, 2, 5, 8, 3, 6, 9]);
+    }
+
+    #[test]
+    fn missing_photometric_interpretation_follows_site_default() {
+        assert!(!photometric_inverts(None, false));
+        assert!(photometric_inverts(None, true));
+        assert!(photometric_inverts(Some("monochrome1"), false));
+        assert!(!photometric_inverts(Some("MONOCHROME2"), true));
+
+        let mut object = basic_image_test_object(Vec::new());
+        object.remove_element(Tag(0x0028, 0x0004));
+        let mut bytes = Vec::new();
+        object
+            .write_all(&mut bytes)
+            .expect("test object should serialize");
+        let source = DicomSource::from_memory("no-photometric", bytes);
+        let image = load_dicom(&source, &DicomLoadOptions::default())
+            .expect("image without photometric interpretation should load");
+        assert!(!image.invert);
+        assert!(!image
+            .metadata
+            .iter()
+            .any(|(_, value)| value == "MONOCHROME2"));
+        let options = DicomLoadOptions {
+            assume_monochrome1_when_missing: true,
+            ..Default::default()
+        };
+        let image = load_dicom(&source, &options)
+            .expect("image without photometric interpretation should load");
+        assert!(image.invert);
+    }
+
     #[test]
     fn load_dicom_loads_full_metadata_on_demand() {
         let bytes = basic_image_test_bytes(vec![DataElement::new(
@@ -2972,8 +4751,11 @@ mod tests {
             "Doe^Jane",
         )]);
 
-        let mut image = load_dicom(DicomSource::from_memory("full-metadata-image", bytes))
-            .expect("image should load");
+        let mut image = load_dicom(
+            DicomSource::from_memory("full-metadata-image", bytes),
+            &DicomLoadOptions::default(),
+        )
+        .expect("image should load");
 
         assert!(image.full_metadata.is_empty());
         assert!(image.has_full_metadata());
@@ -2987,6 +4769,95 @@ mod tests {
         assert!(image.full_metadata_source.is_none());
     }
 
+    #[test]
+    fn load_dicom_collects_study_context_metadata_fields() {
+        let bytes = basic_image_test_bytes(vec![
+            DataElement::new(Tag(0x0008, 0x0022), VR::DA, "20240102"),
+            DataElement::new(Tag(0x0008, 0x0050), VR::SH, "ACC123"),
+            DataElement::new(Tag(0x0008, 0x0090), VR::PN, "Smith^Alex"),
+            DataElement::new(Tag(0x0020, 0x0010), VR::SH, "STUDY7"),
+            DataElement::new(Tag(0x0020, 0x0011), VR::IS, "3"),
+        ]);
+
+        let image = load_dicom(
+            DicomSource::from_memory("study-context", bytes),
+            &DicomLoadOptions::default(),
+        )
+        .expect("image should load");
+        let value = |name: &str| {
+            image
+                .metadata
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.trim().to_string())
+        };
+
+        assert_eq!(value("AcquisitionDate").as_deref(), Some("20240102"));
+        assert_eq!(value("AccessionNumber").as_deref(), Some("ACC123"));
+        assert_eq!(
+            value("ReferringPhysicianName").as_deref(),
+            Some("Smith^Alex")
+        );
+        assert_eq!(value("StudyID").as_deref(), Some("STUDY7"));
+        assert_eq!(value("SeriesNumber").as_deref(), Some("3"));
+    }
+
+    #[test]
+    fn read_frame_time_offsets_accumulates_frame_time_vector_or_frame_time() {
+        let vector_obj = basic_image_test_object(vec![DataElement::new(
+            Tag(0x0018, 0x1065),
+            VR::DS,
+            "0\\20\\30",
+        )]);
+        assert_eq!(
+            read_frame_time_offsets_ms(&vector_obj, 3).as_deref(),
+            Some([0.0, 20.0, 50.0].as_slice())
+        );
+
+        let frame_time_obj =
+            basic_image_test_object(vec![DataElement::new(Tag(0x0018, 0x1063), VR::DS, "25")]);
+        assert_eq!(
+            read_frame_time_offsets_ms(&frame_time_obj, 3).as_deref(),
+            Some([0.0, 25.0, 50.0].as_slice())
+        );
+
+        assert!(read_frame_time_offsets_ms(&basic_image_test_object(Vec::new()), 3).is_none());
+    }
+
+    #[test]
+    fn recommended_display_frame_rate_takes_precedence_over_frame_time() {
+        let frame_time = DataElement::new(Tag(0x0018, 0x1063), VR::DS, "40");
+        let cine_rate = DataElement::new(Tag(0x0018, 0x0040), VR::IS, "10");
+        let recommended = DataElement::new(Tag(0x0008, 0x2144), VR::IS, "15");
+
+        let all = basic_image_test_object(vec![
+            recommended.clone(),
+            frame_time.clone(),
+            cine_rate.clone(),
+        ]);
+        assert_eq!(read_recommended_cine_fps(&all), Some(15.0));
+
+        let derived = basic_image_test_object(vec![frame_time, cine_rate.clone()]);
+        assert_eq!(read_recommended_cine_fps(&derived), Some(25.0));
+
+        let cine_only = basic_image_test_object(vec![cine_rate]);
+        assert_eq!(read_recommended_cine_fps(&cine_only), Some(10.0));
+
+        let too_fast =
+            basic_image_test_object(vec![DataElement::new(Tag(0x0008, 0x2144), VR::IS, "500")]);
+        assert_eq!(read_recommended_cine_fps(&too_fast), Some(120.0));
+
+        let zero = basic_image_test_object(vec![
+            DataElement::new(Tag(0x0008, 0x2144), VR::IS, "0"),
+            DataElement::new(Tag(0x0018, 0x1063), VR::DS, "50"),
+        ]);
+        assert_eq!(read_recommended_cine_fps(&zero), Some(20.0));
+        assert_eq!(
+            read_recommended_cine_fps(&basic_image_test_object(Vec::new())),
+            None
+        );
+    }
+
     #[test]
     fn load_dicom_treats_empty_voi_lut_function_as_absent() {
         let bytes = basic_image_test_bytes(vec![DataElement::new(
@@ -2995,8 +4866,11 @@ mod tests {
             PrimitiveValue::Empty,
         )]);
 
-        let image = load_dicom(DicomSource::from_memory("empty-voi-lut-function", bytes))
-            .expect("empty VOILUTFunction should not block decoding");
+        let image = load_dicom(
+            DicomSource::from_memory("empty-voi-lut-function", bytes),
+            &DicomLoadOptions::default(),
+        )
+        .expect("empty VOILUTFunction should not block decoding");
 
         assert_eq!(image.width, 1);
         assert_eq!(image.height, 1);
@@ -3011,8 +4885,11 @@ mod tests {
             DataElement::new(Tag(0x0018, 0x1164), VR::DS, "1.25\\1.50"),
         ]);
 
-        let image = load_dicom(DicomSource::from_memory("pixel-spacing-priority", bytes))
-            .expect("failed to load DICOM: pixel-spacing-priority");
+        let image = load_dicom(
+            DicomSource::from_memory("pixel-spacing-priority", bytes),
+            &DicomLoadOptions::default(),
+        )
+        .expect("failed to load DICOM: pixel-spacing-priority");
 
         assert_eq!(
             image.pixel_spacing_mm,
@@ -3031,10 +4908,10 @@ mod tests {
             "0.80\\0.60",
         )]);
 
-        let image = load_dicom(DicomSource::from_memory(
-            "pixel-spacing-imager-fallback",
-            bytes,
-        ))
+        let image = load_dicom(
+            DicomSource::from_memory("pixel-spacing-imager-fallback", bytes),
+            &DicomLoadOptions::default(),
+        )
         .expect("failed to load DICOM: pixel-spacing-imager-fallback");
 
         assert_eq!(
@@ -3054,10 +4931,10 @@ mod tests {
             DataElement::new(Tag(0x0018, 0x2010), VR::DS, "0.12\\0.34"),
         ]);
 
-        let image = load_dicom(DicomSource::from_memory(
-            "pixel-spacing-nominal-fallback",
-            bytes,
-        ))
+        let image = load_dicom(
+            DicomSource::from_memory("pixel-spacing-nominal-fallback", bytes),
+            &DicomLoadOptions::default(),
+        )
         .expect("failed to load DICOM: pixel-spacing-nominal-fallback");
 
         assert_eq!(
@@ -3069,6 +4946,152 @@ mod tests {
         );
     }
 
+    #[test]
+    fn load_dicom_reads_presentation_intent_type() {
+        let bytes = basic_image_test_bytes(vec![DataElement::new(
+            Tag(0x0008, 0x0068),
+            VR::CS,
+            "FOR PROCESSING",
+        )]);
+
+        let image = load_dicom(
+            DicomSource::from_memory("for-processing", bytes),
+            &DicomLoadOptions::default(),
+        )
+        .expect("failed to load DICOM: for-processing");
+
+        assert_eq!(
+            image.presentation_intent,
+            Some(PresentationIntent::ForProcessing)
+        );
+        assert!(image.is_for_processing());
+    }
+
+    #[test]
+    fn load_dicom_reads_patient_orientation_edge_labels() {
+        let bytes =
+            basic_image_test_bytes(vec![DataElement::new(Tag(0x0020, 0x0020), VR::CS, "a\\FL")]);
+
+        let image = load_dicom(
+            DicomSource::from_memory("patient-orientation", bytes),
+            &DicomLoadOptions::default(),
+        )
+        .expect("failed to load DICOM: patient-orientation");
+
+        let orientation = image
+            .patient_orientation
+            .expect("PatientOrientation should be read");
+        assert_eq!(orientation.edge_labels(), ["P", "A", "HR", "FL"]);
+
+        let quadruped =
+            basic_image_test_bytes(vec![DataElement::new(Tag(0x0020, 0x0020), VR::CS, "CR\\M")]);
+        let image = load_dicom(
+            DicomSource::from_memory("quadruped-orientation", quadruped),
+            &DicomLoadOptions::default(),
+        )
+        .expect("failed to load DICOM: quadruped-orientation");
+        assert_eq!(image.patient_orientation, None);
+    }
+
+    #[test]
+    fn view_fallback_tags_fill_in_missing_view_and_laterality() {
+        assert_eq!(
+            parse_view_fallback_tags(" AcquisitionDeviceProcessingDescription, ,"),
+            Ok(vec!["AcquisitionDeviceProcessingDescription".to_string()])
+        );
+        assert!(parse_view_fallback_tags("NotAKeyword").is_err());
+
+        let bytes = basic_image_test_bytes(vec![DataElement::new(
+            Tag(0x0018, 0x1400),
+            VR::LO,
+            "L CC 2D",
+        )]);
+        let source = DicomSource::from_memory("view-fallback", bytes);
+        let image = load_dicom(&source, &DicomLoadOptions::default())
+            .expect("failed to load DICOM: view-fallback");
+        assert_eq!(image.view_position, None);
+        assert_eq!(image.image_laterality, None);
+
+        let fallback_tags = ["AcquisitionDeviceProcessingDescription".to_string()];
+        let options = DicomLoadOptions {
+            view_fallback_tags: fallback_tags.to_vec(),
+            ..Default::default()
+        };
+        let image = load_dicom(&source, &options).expect("failed to load DICOM: view-fallback");
+        assert_eq!(image.view_position.as_deref(), Some("CC"));
+        assert_eq!(image.image_laterality.as_deref(), Some("L"));
+        let key = read_mammo_view_key(&source, &fallback_tags).expect("view tags should read");
+        assert_eq!((key.view, key.laterality), (Some("CC"), Some("L")));
+    }
+
+    #[test]
+    fn load_dicom_falls_back_to_image_type_for_presentation_intent() {
+        let bytes = basic_image_test_bytes(vec![DataElement::new(
+            Tag(0x0008, 0x0008),
+            VR::CS,
+            "DERIVED\\SECONDARY\\FOR PRESENTATION",
+        )]);
+
+        let image = load_dicom(
+            DicomSource::from_memory("image-type-intent", bytes),
+            &DicomLoadOptions::default(),
+        )
+        .expect("failed to load DICOM: image-type-intent");
+
+        assert_eq!(
+            image.presentation_intent,
+            Some(PresentationIntent::ForPresentation)
+        );
+        assert!(!image.is_for_processing());
+    }
+
+    #[test]
+    fn load_dicom_placeholder_keeps_identity_when_pixel_data_is_missing() {
+        let mut object = basic_image_test_object(vec![
+            DataElement::new(Tag(0x0018, 0x5101), VR::CS, "CC"),
+            DataElement::new(Tag(0x0020, 0x0062), VR::CS, "R"),
+        ]);
+        object.remove_element(Tag(0x7FE0, 0x0010));
+        let mut bytes = Vec::new();
+        object
+            .write_all(&mut bytes)
+            .expect("test object should serialize");
+        let source = DicomSource::from_memory("no-pixel-data", bytes);
+        let err = load_dicom(source.clone(), &DicomLoadOptions::default())
+            .expect_err("missing PixelData should not load");
+
+        let placeholder = load_dicom_placeholder(source, &[], format!("{err:#}"));
+
+        assert!(placeholder.undecodable_reason().is_some());
+        assert_eq!(placeholder.frame_count(), 1);
+        assert_eq!(placeholder.view_position.as_deref(), Some("CC"));
+        assert_eq!(placeholder.image_laterality.as_deref(), Some("R"));
+        assert_eq!(placeholder.sop_instance_uid.as_deref(), Some("4.3.2.12"));
+    }
+
+    #[test]
+    fn percentile_window_ignores_extreme_outliers() {
+        let mut values = (100..=199).collect::<Vec<i32>>();
+        values[0] = -30_000;
+        values[99] = 30_000;
+
+        let (center, width) = percentile_window(&values).expect("window should be computed");
+
+        assert!(center > 100.0 && center < 200.0, "center={center}");
+        assert!(width < 200.0, "width={width}");
+        assert_eq!(percentile_window(&[]), None);
+    }
+
+    #[test]
+    fn percentile_window_samples_large_frames() {
+        let values = (0..1_000_000).collect::<Vec<i32>>();
+
+        let (center, width) = percentile_window(&values).expect("window should be computed");
+
+        assert!((center - 500_000.0).abs() < 2_000.0, "center={center}");
+        assert!((width - 980_000.0).abs() < 2_000.0, "width={width}");
+    }
+
     #[test]
     fn finish_full_metadata_load_requires_exact_memory_source_match() {
         let source = DicomSource::from_memory_with_identity("memory.dcm", "same-id", vec![1, 2, 3]);
@@ -3123,7 +5146,29 @@ mod tests {
         }
 
         let obj = open_dicom_object(path).expect("sample0/1.dcm should open");
-        assert_eq!(read_view_position(&obj).as_deref(), Some("CC"));
+        assert_eq!(read_view_position(&obj, &[]).as_deref(), Some("CC"));
+    }
+
+    #[test]
+    fn normalized_view_position_classifies_like_dicomweb_metadata() {
+        for (raw, expected) in [
+            ("CRANIOCAUDAL", "CC"),
+            ("MEDIO LATERAL OBLIQUE", "MLO"),
+            ("medio-lateral oblique", "MLO"),
+            ("R CC", "CC"),
+        ] {
+            let normalized = normalize_view_position(raw);
+            assert_eq!(normalized.as_deref(), Some(expected), "{raw}");
+            // DICOMweb metadata classifies the raw tag value; local images
+            // re-classify the stored normalized value. Both must agree.
+            assert_eq!(
+                crate::mammo::MammoViewKey::classify(normalized.as_deref(), None, None),
+                crate::mammo::MammoViewKey::classify(Some(raw), None, None),
+                "{raw}"
+            );
+        }
+        assert_eq!(normalize_view_position("SCOUT").as_deref(), Some("SCOUT"));
+        assert_eq!(normalize_view_position("  "), None);
     }
 
     #[test]
@@ -3136,8 +5181,8 @@ mod tests {
 
         let cc_obj = open_dicom_object(cc_path).expect("sample2/D0000006 should open");
         let mlo_obj = open_dicom_object(mlo_path).expect("sample2/D0000010 should open");
-        assert_eq!(read_view_position(&cc_obj).as_deref(), Some("CC"));
-        assert_eq!(read_view_position(&mlo_obj).as_deref(), Some("MLO"));
+        assert_eq!(read_view_position(&cc_obj, &[]).as_deref(), Some("CC"));
+        assert_eq!(read_view_position(&mlo_obj, &[]).as_deref(), Some("MLO"));
     }
 
     #[test]
@@ -3150,8 +5195,8 @@ mod tests {
 
         let left_obj = open_dicom_object(left_path).expect("sample3 left image should open");
         let right_obj = open_dicom_object(right_path).expect("sample3 right image should open");
-        assert_eq!(read_laterality(&left_obj).as_deref(), Some("L"));
-        assert_eq!(read_laterality(&right_obj).as_deref(), Some("R"));
+        assert_eq!(read_laterality(&left_obj, &[]).as_deref(), Some("L"));
+        assert_eq!(read_laterality(&right_obj, &[]).as_deref(), Some("R"));
     }
 
     #[test]
@@ -3217,7 +5262,8 @@ mod tests {
             .write_to_file(&path)
             .expect("SR test object should write to disk");
 
-        let err = load_dicom(&path).expect_err("load_dicom should reject structured reports");
+        let err = load_dicom(&path, &DicomLoadOptions::default())
+            .expect_err("load_dicom should reject structured reports");
         let _ = std::fs::remove_file(&path);
 
         let message = format!("{err:#}");
@@ -3244,7 +5290,8 @@ mod tests {
             .expect("Parametric Map test object should build file meta");
 
         let source = DicomSource::from_memory("parametric-map.dcm", object_bytes(&pm_obj));
-        let err = load_dicom(source).expect_err("load_dicom should reject parametric maps");
+        let err = load_dicom(source, &DicomLoadOptions::default())
+            .expect_err("load_dicom should reject parametric maps");
 
         let message = format!("{err:#}");
         assert!(message.contains("Parametric Map object"));
@@ -3406,6 +5453,101 @@ mod tests {
         assert_eq!(image.stored_frame_index_to_display(3), Some(0));
     }
 
+    #[test]
+    fn detect_content_bounds_trims_black_border() {
+        let mut image = DicomImage::test_stub(None);
+        image.width = 6;
+        image.height = 4;
+        image.max_value = 1000;
+        let mut pixels = vec![0; 24];
+        for y in 1..3 {
+            for x in 2..5 {
+                pixels[y * 6 + x] = 800;
+            }
+        }
+
+        let expected = Some(ContentBounds {
+            x: 2,
+            y: 1,
+            width: 3,
+            height: 2,
+        });
+        assert_eq!(image.detect_content_bounds(&pixels), expected);
+
+        image.invert = true;
+        let inverted = pixels.iter().map(|value| 1000 - value).collect::<Vec<_>>();
+        assert_eq!(image.detect_content_bounds(&inverted), expected);
+        assert_eq!(image.detect_content_bounds(&[0; 24]), None);
+        assert_eq!(image.detect_content_bounds(&[1000; 24]), None);
+    }
+
+    #[test]
+    fn split_packed_view_frames_yields_one_view_per_display_frame() {
+        let mut image = DicomImage::test_stub_with_mono_frames_and_reverse(None, 4, true);
+        image.view_position = Some("CC".to_string());
+        image.image_laterality = Some("R".to_string());
+
+        let views = image.split_packed_view_frames();
+
+        assert_eq!(views.len(), 4);
+        for (display_index, view) in views.iter().enumerate() {
+            let stored_index = 3 - display_index;
+            assert_eq!(view.frame_count(), 1);
+            assert_eq!(view.view_position, None);
+            assert_eq!(view.image_laterality, None);
+            assert_eq!(
+                view.frame_mono_pixels(0).as_deref(),
+                Some([stored_index as i32].as_slice())
+            );
+            assert_eq!(view.frame_mono_pixels(1), None);
+            assert_eq!(view.stored_frame_index_to_display(stored_index), Some(0));
+            assert_eq!(view.display_frame_index_to_stored(0), Some(stored_index));
+        }
+        assert_eq!(views[0].stored_frame_index_to_display(0), None);
+    }
+
+    #[test]
+    fn preload_workers_zero_disables_background_preload() {
+        assert_eq!(parse_preload_workers(None), PreloadWorkers::Auto);
+        assert_eq!(parse_preload_workers(Some("auto")), PreloadWorkers::Auto);
+        assert_eq!(parse_preload_workers(Some(" 0 ")), PreloadWorkers::Disabled);
+        assert_eq!(parse_preload_workers(Some("3")), PreloadWorkers::Fixed(3));
+    }
+
+    #[test]
+    fn on_demand_frame_decode_fills_only_the_requested_frame() {
+        let mut object =
+            basic_image_test_object(vec![DataElement::new(Tag(0x0028, 0x0008), VR::IS, "3")]);
+        object.put(DataElement::new(
+            Tag(0x7FE0, 0x0010),
+            VR::OB,
+            PrimitiveValue::from(vec![10u8, 20, 30, 0]),
+        ));
+        let mut bytes = Vec::new();
+        object
+            .write_all(&mut bytes)
+            .expect("multi-frame test object should serialize");
+        let lazy = LazyMonoFrames {
+            source: DicomSource::from_memory("on-demand", bytes),
+            cache: Arc::new(Mutex::new(vec![None; 3])),
+            preload_started: Arc::new(AtomicBool::new(false)),
+        };
+
+        lazy.decode_frame_on_demand(2);
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        while lazy.preload_started.load(Ordering::Relaxed) && std::time::Instant::now() < deadline {
+            thread::sleep(std::time::Duration::from_millis(5));
+        }
+
+        let cache = lazy
+            .cache
+            .lock()
+            .expect("cache lock should not be poisoned");
+        assert_eq!(cache[2].as_deref(), Some([30].as_slice()));
+        assert!(cache[0].is_none());
+        assert!(cache[1].is_none());
+    }
+
     #[test]
     fn frame_index_mapping_rejects_out_of_range_inputs() {
         let image = DicomImage::test_stub_with_mono_frames_and_reverse(None, 4, true);
@@ -3415,12 +5557,86 @@ mod tests {
         assert_eq!(image.frame_mono_pixels(4), None);
     }
 
+    #[test]
+    fn load_dicom_applies_each_frames_own_rescale() {
+        let frame_item = |intercept: &str| {
+            let transformation = InMemDicomObject::from_element_iter([
+                DataElement::new(Tag(0x0028, 0x1052), VR::DS, intercept),
+                DataElement::new(Tag(0x0028, 0x1053), VR::DS, "2"),
+                DataElement::new(Tag(0x0028, 0x1054), VR::LO, "HU"),
+            ]);
+            InMemDicomObject::from_element_iter([DataElement::new(
+                Tag(0x0028, 0x9145),
+                VR::SQ,
+                DataSetSequence::from(vec![transformation]),
+            )])
+        };
+        let object = InMemDicomObject::from_element_iter([
+            DataElement::new(Tag(0x0008, 0x0016), VR::UI, "1.2.840.10008.5.1.4.1.1.2.1"),
+            DataElement::new(Tag(0x0008, 0x0060), VR::CS, "CT"),
+            DataElement::new(Tag(0x0028, 0x0002), VR::US, PrimitiveValue::from(1u16)),
+            DataElement::new(Tag(0x0028, 0x0004), VR::CS, "MONOCHROME2"),
+            DataElement::new(Tag(0x0028, 0x0008), VR::IS, "3"),
+            DataElement::new(Tag(0x0028, 0x0010), VR::US, PrimitiveValue::from(1u16)),
+            DataElement::new(Tag(0x0028, 0x0011), VR::US, PrimitiveValue::from(1u16)),
+            DataElement::new(Tag(0x0028, 0x0100), VR::US, PrimitiveValue::from(8u16)),
+            DataElement::new(Tag(0x0028, 0x0101), VR::US, PrimitiveValue::from(8u16)),
+            DataElement::new(Tag(0x0028, 0x0102), VR::US, PrimitiveValue::from(7u16)),
+            DataElement::new(Tag(0x0028, 0x0103), VR::US, PrimitiveValue::from(0u16)),
+            DataElement::new(
+                Tag(0x5200, 0x9230),
+                VR::SQ,
+                DataSetSequence::from(vec![
+                    frame_item("0"),
+                    frame_item("-100"),
+                    frame_item("1000"),
+                ]),
+            ),
+            DataElement::new(
+                Tag(0x7FE0, 0x0010),
+                VR::OB,
+                PrimitiveValue::from(vec![10u8, 10, 10]),
+            ),
+        ])
+        .with_meta(
+            FileMetaTableBuilder::new()
+                .transfer_syntax(EXPLICIT_VR_LITTLE_ENDIAN_UID)
+                .media_storage_sop_class_uid("1.2.840.10008.5.1.4.1.1.2.1")
+                .media_storage_sop_instance_uid("4.3.2.12"),
+        )
+        .expect("per-frame rescale test object should build file meta");
+        let mut bytes = Vec::new();
+        object
+            .write_all(&mut bytes)
+            .expect("per-frame rescale test object should serialize");
+
+        let image = load_dicom(
+            DicomSource::from_memory("per-frame-rescale.dcm", bytes),
+            &DicomLoadOptions::default(),
+        )
+        .expect("per-frame rescale test object should load");
+        image
+            .decode_all_frames()
+            .expect("per-frame rescale frames should decode");
+
+        assert_eq!(image.frame_mono_pixels(0).as_deref(), Some([20].as_slice()));
+        assert_eq!(
+            image.frame_mono_pixels(1).as_deref(),
+            Some([-80].as_slice())
+        );
+        assert_eq!(
+            image.frame_mono_pixels(2).as_deref(),
+            Some([1020].as_slice())
+        );
+    }
+
     #[test]
     fn load_dicom_primes_initial_display_frame_for_reversed_multiframe_images() {
         let bytes = multiframe_mono_test_bytes(&["0\\0\\1", "0\\0\\2", "0\\0\\3"], &[11, 22, 33]);
         let source = DicomSource::from_memory("reverse-multiframe.dcm", bytes);
 
-        let image = load_dicom(source).expect("reverse multiframe test object should load");
+        let image = load_dicom(source, &DicomLoadOptions::default())
+            .expect("reverse multiframe test object should load");
 
         assert_eq!(image.frame_count(), 3);
         assert_eq!(image.frame_mono_pixels(1).as_deref(), None);
@@ -3443,7 +5659,8 @@ mod tests {
             DicomPathKind::Image
         );
 
-        let image = load_dicom(source).expect("generated JPEG-LS lossless should decode");
+        let image = load_dicom(source, &DicomLoadOptions::default())
+            .expect("generated JPEG-LS lossless should decode");
 
         assert_eq!(image.width, 3);
         assert_eq!(image.height, 2);
@@ -3470,7 +5687,8 @@ mod tests {
             .expect("JPEG-LS near-lossless transcode should succeed");
 
         let source = DicomSource::from_memory("jpeg-ls-near-lossless.dcm", object_bytes(&obj));
-        let image = load_dicom(source).expect("generated JPEG-LS near-lossless should decode");
+        let image = load_dicom(source, &DicomLoadOptions::default())
+            .expect("generated JPEG-LS near-lossless should decode");
         let decoded_pixels = image
             .frame_mono_pixels(0)
             .expect("decoded near-lossless frame should be available");
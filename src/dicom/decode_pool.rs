@@ -0,0 +1,106 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
+
+use super::preload_pool_size;
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// Process-wide frame decode workers shared by every image's preload, so the
+/// total decode parallelism stays at `preload_pool_size` however many images
+/// are open.
+struct DecodePool {
+    sender: Sender<Job>,
+}
+
+impl DecodePool {
+    fn start(worker_count: usize) -> Option<Self> {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let mut started = 0;
+        for worker_id in 0..worker_count {
+            let receiver = Arc::clone(&receiver);
+            match thread::Builder::new()
+                .name(format!("perspecta-decode-{worker_id}"))
+                .spawn(move || run_worker(&receiver))
+            {
+                Ok(_) => started += 1,
+                Err(err) => log::warn!("Could not start decode worker {worker_id}: {err}"),
+            }
+        }
+        (started > 0).then_some(Self { sender })
+    }
+}
+
+fn run_worker(receiver: &Mutex<Receiver<Job>>) {
+    loop {
+        let job = match receiver.lock() {
+            Ok(guard) => guard.recv(),
+            Err(_) => return,
+        };
+        let Ok(job) = job else {
+            return;
+        };
+        // A panicking decode must not take the shared worker down with it; the
+        // submitter notices the missing result instead.
+        if panic::catch_unwind(AssertUnwindSafe(job)).is_err() {
+            log::warn!("Decode job panicked");
+        }
+    }
+}
+
+fn pool() -> Option<&'static DecodePool> {
+    static POOL: OnceLock<Option<DecodePool>> = OnceLock::new();
+    POOL.get_or_init(|| DecodePool::start(preload_pool_size()))
+        .as_ref()
+}
+
+/// Queues a decode job on the shared pool. If no worker could be started the
+/// job runs on the calling thread instead of being dropped.
+pub(super) fn submit(job: impl FnOnce() + Send + 'static) {
+    let job: Job = Box::new(job);
+    let job = match pool() {
+        Some(pool) => match pool.sender.send(job) {
+            Ok(()) => return,
+            Err(mpsc::SendError(job)) => job,
+        },
+        None => job,
+    };
+    job();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[test]
+    fn submitted_jobs_run_with_bounded_parallelism() {
+        let active = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let (done_tx, done_rx) = mpsc::channel();
+        let job_count = preload_pool_size() * 3;
+
+        for _ in 0..job_count {
+            let active = Arc::clone(&active);
+            let peak = Arc::clone(&peak);
+            let done_tx = done_tx.clone();
+            submit(move || {
+                let now_active = active.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now_active, Ordering::SeqCst);
+                thread::sleep(Duration::from_millis(5));
+                active.fetch_sub(1, Ordering::SeqCst);
+                let _ = done_tx.send(());
+            });
+        }
+
+        for _ in 0..job_count {
+            done_rx
+                .recv_timeout(Duration::from_secs(5))
+                .expect("every submitted job should run");
+        }
+        assert!(peak.load(Ordering::SeqCst) <= preload_pool_size());
+    }
+}
@@ -34,14 +34,82 @@ pub struct GspsOverlayGraphic {
     pub referenced_frames: Option<Vec<usize>>,
 }
 
+/// Window from a presentation state's `SoftcopyVOILUTSequence`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GspsVoi {
+    pub center: f32,
+    pub width: f32,
+}
+
+/// One display shutter shape, in 1-based pixel coordinates.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GspsShutterShape {
+    Rectangular {
+        left: f32,
+        right: f32,
+        upper: f32,
+        lower: f32,
+    },
+    Circular {
+        center_column: f32,
+        center_row: f32,
+        radius: f32,
+    },
+    /// Vertices as `(column, row)` pairs.
+    Polygonal(Vec<(f32, f32)>),
+}
+
+/// Display shutter: pixels outside any of the shapes are painted `gray`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GspsShutter {
+    pub shapes: Vec<GspsShutterShape>,
+    pub gray: u8,
+}
+
+impl GspsShutter {
+    /// Whether the pixel at 0-based `(x, y)` is masked by the shutter.
+    pub fn hides(&self, x: usize, y: usize) -> bool {
+        let column = x as f32 + 1.0;
+        let row = y as f32 + 1.0;
+        self.shapes.iter().any(|shape| match shape {
+            GspsShutterShape::Rectangular {
+                left,
+                right,
+                upper,
+                lower,
+            } => column < *left || column > *right || row < *upper || row > *lower,
+            GspsShutterShape::Circular {
+                center_column,
+                center_row,
+                radius,
+            } => (column - center_column).powi(2) + (row - center_row).powi(2) > radius.powi(2),
+            GspsShutterShape::Polygonal(vertices) => !polygon_contains(vertices, (column, row)),
+        })
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct GspsOverlay {
     pub graphics: Vec<GspsOverlayGraphic>,
+    pub voi: Option<GspsVoi>,
+    pub shutter: Option<GspsShutter>,
 }
 
 impl GspsOverlay {
     pub fn is_empty(&self) -> bool {
-        self.graphics.is_empty()
+        self.graphics.is_empty() && self.voi.is_none() && self.shutter.is_none()
+    }
+
+    /// Adds another presentation state's content for the same image; the
+    /// first VOI and shutter seen are kept.
+    pub fn merge(&mut self, other: &GspsOverlay) {
+        self.graphics.extend(other.graphics.iter().cloned());
+        if self.voi.is_none() {
+            self.voi = other.voi;
+        }
+        if self.shutter.is_none() {
+            self.shutter = other.shutter.clone();
+        }
     }
 
     #[cfg(test)]
@@ -54,6 +122,7 @@ impl GspsOverlay {
                     referenced_frames: None,
                 })
                 .collect(),
+            ..Self::default()
         }
     }
 
@@ -99,10 +168,8 @@ pub(crate) fn parse_gsps_overlays(obj: &DefaultDicomObject) -> HashMap<String, G
     let mut overlays_by_uid = HashMap::<String, GspsOverlay>::new();
     let default_refs = collect_root_referenced_image_targets(obj, REFERENCED_SERIES_SEQUENCE);
 
-    let Some(annotations) = sequence_items_from_object(obj, GRAPHIC_ANNOTATION_SEQUENCE) else {
-        return overlays_by_uid;
-    };
-
+    let annotations =
+        sequence_items_from_object(obj, GRAPHIC_ANNOTATION_SEQUENCE).unwrap_or_default();
     for annotation in annotations {
         let references = collect_item_referenced_image_targets(annotation);
         let target_refs = if references.is_empty() {
@@ -131,10 +198,137 @@ pub(crate) fn parse_gsps_overlays(obj: &DefaultDicomObject) -> HashMap<String, G
         }
     }
 
+    for (targets, voi) in collect_softcopy_vois(obj) {
+        let target_refs = if targets.is_empty() {
+            &default_refs
+        } else {
+            &targets
+        };
+        for target in target_refs {
+            overlays_by_uid
+                .entry(target.sop_instance_uid.clone())
+                .or_default()
+                .voi
+                .get_or_insert(voi);
+        }
+    }
+
+    if let Some(shutter) = parse_display_shutter(obj) {
+        for target in &default_refs {
+            overlays_by_uid
+                .entry(target.sop_instance_uid.clone())
+                .or_default()
+                .shutter
+                .get_or_insert_with(|| shutter.clone());
+        }
+    }
+
     overlays_by_uid.retain(|_, overlay| !overlay.is_empty());
     overlays_by_uid
 }
 
+/// Windows of the `SoftcopyVOILUTSequence` with the images each applies to;
+/// an empty target list means every image the presentation state references.
+fn collect_softcopy_vois(obj: &DefaultDicomObject) -> Vec<(Vec<ReferencedImageTarget>, GspsVoi)> {
+    const SOFTCOPY_VOI_LUT_SEQUENCE: Tag = Tag(0x0028, 0x3110);
+    const WINDOW_CENTER: Tag = Tag(0x0028, 0x1050);
+    const WINDOW_WIDTH: Tag = Tag(0x0028, 0x1051);
+
+    sequence_items_from_object(obj, SOFTCOPY_VOI_LUT_SEQUENCE)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|item| {
+            let center = *read_item_multi_float(item, WINDOW_CENTER)?.first()?;
+            let width = *read_item_multi_float(item, WINDOW_WIDTH)?.first()?;
+            if !center.is_finite() || !width.is_finite() || width < 1.0 {
+                return None;
+            }
+            Some((
+                collect_item_referenced_image_targets(item),
+                GspsVoi { center, width },
+            ))
+        })
+        .collect()
+}
+
+fn parse_display_shutter(obj: &DefaultDicomObject) -> Option<GspsShutter> {
+    const SHUTTER_SHAPE: Tag = Tag(0x0018, 0x1600);
+    const SHUTTER_LEFT_VERTICAL_EDGE: Tag = Tag(0x0018, 0x1602);
+    const SHUTTER_RIGHT_VERTICAL_EDGE: Tag = Tag(0x0018, 0x1604);
+    const SHUTTER_UPPER_HORIZONTAL_EDGE: Tag = Tag(0x0018, 0x1606);
+    const SHUTTER_LOWER_HORIZONTAL_EDGE: Tag = Tag(0x0018, 0x1608);
+    const CENTER_OF_CIRCULAR_SHUTTER: Tag = Tag(0x0018, 0x1610);
+    const RADIUS_OF_CIRCULAR_SHUTTER: Tag = Tag(0x0018, 0x1612);
+    const VERTICES_OF_THE_POLYGONAL_SHUTTER: Tag = Tag(0x0018, 0x1620);
+    const SHUTTER_PRESENTATION_VALUE: Tag = Tag(0x0018, 0x1622);
+
+    let first_float =
+        |tag: Tag| read_item_multi_float(obj, tag).and_then(|values| values.first().copied());
+    let shapes = read_item_string(obj, SHUTTER_SHAPE)?
+        .split('\\')
+        .filter_map(|shape| match shape.trim().to_ascii_uppercase().as_str() {
+            "RECTANGULAR" => Some(GspsShutterShape::Rectangular {
+                left: first_float(SHUTTER_LEFT_VERTICAL_EDGE)?,
+                right: first_float(SHUTTER_RIGHT_VERTICAL_EDGE)?,
+                upper: first_float(SHUTTER_UPPER_HORIZONTAL_EDGE)?,
+                lower: first_float(SHUTTER_LOWER_HORIZONTAL_EDGE)?,
+            }),
+            "CIRCULAR" => {
+                let center = read_item_multi_float(obj, CENTER_OF_CIRCULAR_SHUTTER)?;
+                Some(GspsShutterShape::Circular {
+                    center_row: *center.first()?,
+                    center_column: *center.get(1)?,
+                    radius: first_float(RADIUS_OF_CIRCULAR_SHUTTER)?,
+                })
+            }
+            "POLYGONAL" => {
+                // Vertices are stored row first.
+                let vertices = parse_graphic_points(read_item_multi_float(
+                    obj,
+                    VERTICES_OF_THE_POLYGONAL_SHUTTER,
+                )?)
+                .into_iter()
+                .map(|(row, column)| (column, row))
+                .collect::<Vec<_>>();
+                (vertices.len() >= 3).then_some(GspsShutterShape::Polygonal(vertices))
+            }
+            other => {
+                log::warn!("Ignoring unsupported GSPS shutter shape {other:?}.");
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    if shapes.is_empty() {
+        return None;
+    }
+
+    // ShutterPresentationValue is a P-value from 0 (black) to 0xFFFF (white).
+    let gray = read_item_multi_int(obj, SHUTTER_PRESENTATION_VALUE)
+        .and_then(|values| values.first().copied())
+        .map_or(0, |value| (value.clamp(0, 0xFFFF) >> 8) as u8);
+    Some(GspsShutter { shapes, gray })
+}
+
+/// Even-odd point-in-polygon test.
+fn polygon_contains(vertices: &[(f32, f32)], point: (f32, f32)) -> bool {
+    let mut inside = false;
+    let mut previous = match vertices.last() {
+        Some(vertex) => *vertex,
+        None => return false,
+    };
+    for &current in vertices {
+        if (current.1 > point.1) != (previous.1 > point.1) {
+            let crossing_x = current.0
+                + (point.1 - current.1) * (previous.0 - current.0) / (previous.1 - current.1);
+            if point.0 < crossing_x {
+                inside = !inside;
+            }
+        }
+        previous = current;
+    }
+    inside
+}
+
 fn collect_root_referenced_image_targets(
     obj: &DefaultDicomObject,
     referenced_series_sequence: Tag,
@@ -656,4 +850,101 @@ mod tests {
             .expect("Overlay should exist for root-level referenced SOP instance");
         assert_eq!(overlay.graphics[0].referenced_frames, Some(vec![3]));
     }
+
+    #[test]
+    fn parse_gsps_overlays_reads_voi_and_display_shutter_without_annotations() {
+        let series_item = InMemDicomObject::from_element_iter([DataElement::new(
+            Tag(0x0008, 0x1140),
+            VR::SQ,
+            DataSetSequence::from(vec![referenced_image_item("7.7.7.7")]),
+        )]);
+        let voi_item = InMemDicomObject::from_element_iter([
+            DataElement::new(
+                Tag(0x0028, 0x1050),
+                VR::DS,
+                PrimitiveValue::Strs(["40", "300"].into_iter().map(String::from).collect()),
+            ),
+            DataElement::new(
+                Tag(0x0028, 0x1051),
+                VR::DS,
+                PrimitiveValue::Strs(["400", "1500"].into_iter().map(String::from).collect()),
+            ),
+        ]);
+
+        let gsps_obj = InMemDicomObject::from_element_iter([
+            DataElement::new(Tag(0x0008, 0x0016), VR::UI, GSPS_SOP_CLASS_UID),
+            DataElement::new(Tag(0x0008, 0x0018), VR::UI, "8.8.8.8"),
+            DataElement::new(
+                Tag(0x0008, 0x1115),
+                VR::SQ,
+                DataSetSequence::from(vec![series_item]),
+            ),
+            DataElement::new(Tag(0x0018, 0x1600), VR::CS, "RECTANGULAR"),
+            DataElement::new(Tag(0x0018, 0x1602), VR::IS, "2"),
+            DataElement::new(Tag(0x0018, 0x1604), VR::IS, "9"),
+            DataElement::new(Tag(0x0018, 0x1606), VR::IS, "3"),
+            DataElement::new(Tag(0x0018, 0x1608), VR::IS, "8"),
+            DataElement::new(
+                Tag(0x0018, 0x1622),
+                VR::US,
+                PrimitiveValue::from(0xFFFF_u16),
+            ),
+            DataElement::new(
+                Tag(0x0028, 0x3110),
+                VR::SQ,
+                DataSetSequence::from(vec![voi_item]),
+            ),
+        ])
+        .with_meta(
+            FileMetaTableBuilder::new()
+                .transfer_syntax(EXPLICIT_VR_LITTLE_ENDIAN_UID)
+                .media_storage_sop_class_uid(GSPS_SOP_CLASS_UID)
+                .media_storage_sop_instance_uid("8.8.8.8"),
+        )
+        .expect("GSPS test object should build file meta");
+
+        let overlays = parse_gsps_overlays(&gsps_obj);
+        let overlay = overlays
+            .get("7.7.7.7")
+            .expect("VOI and shutter should map to the referenced image");
+        assert!(overlay.graphics.is_empty());
+        assert_eq!(
+            overlay.voi,
+            Some(GspsVoi {
+                center: 40.0,
+                width: 400.0
+            })
+        );
+        let shutter = overlay.shutter.as_ref().expect("shutter should parse");
+        assert_eq!(shutter.gray, 255);
+        assert!(shutter.hides(0, 5));
+        assert!(!shutter.hides(1, 2));
+        assert!(!shutter.hides(8, 7));
+        assert!(shutter.hides(9, 7));
+    }
+
+    #[test]
+    fn polygonal_and_circular_shutters_hide_pixels_outside_the_shape() {
+        let triangle = GspsShutter {
+            shapes: vec![GspsShutterShape::Polygonal(vec![
+                (1.0, 1.0),
+                (11.0, 1.0),
+                (1.0, 11.0),
+            ])],
+            gray: 0,
+        };
+        assert!(!triangle.hides(1, 1));
+        assert!(triangle.hides(9, 9));
+
+        let circle = GspsShutter {
+            shapes: vec![GspsShutterShape::Circular {
+                center_column: 6.0,
+                center_row: 6.0,
+                radius: 3.0,
+            }],
+            gray: 0,
+        };
+        assert!(!circle.hides(5, 5));
+        assert!(circle.hides(0, 0));
+    }
 }
@@ -0,0 +1,156 @@
+use anyhow::Result;
+
+use super::{
+    collect_full_metadata, collect_metadata, open_dicom_object, DicomSource, FullMetadataField,
+    FullMetadataValue,
+};
+
+/// Renders a file's metadata as JSON for `--dump-meta`. The default output is
+/// one object holding the summary fields the metadata panel shows; `all_tags`
+/// switches to every element in file order, sequences included.
+pub fn metadata_json_from_source(source: &DicomSource, all_tags: bool) -> Result<String> {
+    let obj = open_dicom_object(source)?;
+    Ok(if all_tags {
+        full_metadata_json(&collect_full_metadata(&obj))
+    } else {
+        summary_metadata_json(&collect_metadata(&obj))
+    })
+}
+
+fn summary_metadata_json(fields: &[(String, String)]) -> String {
+    if fields.is_empty() {
+        return "{}".to_string();
+    }
+    let entries = fields
+        .iter()
+        .map(|(name, value)| format!("  {}: {}", json_string(name), json_string(value)))
+        .collect::<Vec<_>>();
+    format!("{{\n{}\n}}", entries.join(",\n"))
+}
+
+fn full_metadata_json(fields: &[FullMetadataField]) -> String {
+    let mut out = String::new();
+    write_fields(&mut out, fields, 0);
+    out
+}
+
+fn write_fields(out: &mut String, fields: &[FullMetadataField], depth: usize) {
+    if fields.is_empty() {
+        out.push_str("[]");
+        return;
+    }
+    let indent = "  ".repeat(depth);
+    out.push_str("[\n");
+    for (index, field) in fields.iter().enumerate() {
+        if index > 0 {
+            out.push_str(",\n");
+        }
+        out.push_str(&indent);
+        out.push_str("  {");
+        out.push_str(&format!(
+            "\"tag\": {}, \"keyword\": {}, \"vr\": {}, ",
+            json_string(&field.tag),
+            json_string(&field.keyword),
+            json_string(&field.vr)
+        ));
+        match &field.value {
+            FullMetadataValue::Scalar(value) => {
+                out.push_str(&format!("\"value\": {}", json_string(value)));
+            }
+            FullMetadataValue::Sequence(items) => {
+                out.push_str("\"items\": [");
+                for (item_index, item) in items.iter().enumerate() {
+                    if item_index > 0 {
+                        out.push_str(", ");
+                    }
+                    write_fields(out, &item.fields, depth + 1);
+                }
+                out.push(']');
+            }
+        }
+        out.push('}');
+    }
+    out.push('\n');
+    out.push_str(&indent);
+    out.push(']');
+}
+
+fn json_string(value: &str) -> String {
+    serde_json::Value::from(value).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dicom::FullMetadataItem;
+
+    #[test]
+    fn json_string_escapes_quotes_backslashes_and_control_characters() {
+        assert_eq!(json_string("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+        assert_eq!(json_string("Müller^Anna"), "\"Müller^Anna\"");
+
+        let every_ascii = (0_u8..0x80).map(char::from).collect::<String>() + "\u{2028}€😀";
+        let parsed: serde_json::Value =
+            serde_json::from_str(&json_string(&every_ascii)).expect("escaped string should parse");
+        assert_eq!(parsed.as_str(), Some(every_ascii.as_str()));
+    }
+
+    #[test]
+    fn full_metadata_json_is_valid_json_for_awkward_values() {
+        let fields = vec![FullMetadataField {
+            keyword: "PatientName".to_string(),
+            tag: "(0010,0010)".to_string(),
+            vr: "PN".to_string(),
+            value: FullMetadataValue::Scalar("O\"Brien\\\u{0}\u{1f}\u{7f}".to_string()),
+        }];
+        let parsed: serde_json::Value =
+            serde_json::from_str(&full_metadata_json(&fields)).expect("metadata JSON should parse");
+        assert_eq!(
+            parsed[0]["value"].as_str(),
+            Some("O\"Brien\\\u{0}\u{1f}\u{7f}")
+        );
+    }
+
+    #[test]
+    fn summary_metadata_json_keeps_field_order() {
+        let fields = vec![
+            ("Modality".to_string(), "MG".to_string()),
+            ("Rows".to_string(), "2294".to_string()),
+        ];
+        assert_eq!(
+            summary_metadata_json(&fields),
+            "{\n  \"Modality\": \"MG\",\n  \"Rows\": \"2294\"\n}"
+        );
+        assert_eq!(summary_metadata_json(&[]), "{}");
+    }
+
+    #[test]
+    fn full_metadata_json_nests_sequence_items() {
+        let fields = vec![
+            FullMetadataField {
+                keyword: "Modality".to_string(),
+                tag: "(0008,0060)".to_string(),
+                vr: "CS".to_string(),
+                value: FullMetadataValue::Scalar("MG".to_string()),
+            },
+            FullMetadataField {
+                keyword: "ViewCodeSequence".to_string(),
+                tag: "(0054,0220)".to_string(),
+                vr: "SQ".to_string(),
+                value: FullMetadataValue::Sequence(vec![FullMetadataItem {
+                    fields: vec![FullMetadataField {
+                        keyword: "CodeValue".to_string(),
+                        tag: "(0008,0100)".to_string(),
+                        vr: "SH".to_string(),
+                        value: FullMetadataValue::Scalar("R-10226".to_string()),
+                    }],
+                }]),
+            },
+        ];
+
+        assert_eq!(
+            full_metadata_json(&fields),
+            "[\n  {\"tag\": \"(0008,0060)\", \"keyword\": \"Modality\", \"vr\": \"CS\", \"value\": \"MG\"},\n  {\"tag\": \"(0054,0220)\", \"keyword\": \"ViewCodeSequence\", \"vr\": \"SQ\", \"items\": [[\n    {\"tag\": \"(0008,0100)\", \"keyword\": \"CodeValue\", \"vr\": \"SH\", \"value\": \"R-10226\"}\n  ]]}\n]"
+        );
+    }
+}
@@ -6,14 +6,13 @@ use dicom_object::{DefaultDicomObject, Tag};
 use dicom_pixeldata::PixelDecoder;
 
 use super::{
-    classify_dicom_object, collect_metadata, open_dicom_object, read_int_first,
-    read_item_multi_int, read_item_string, read_laterality, read_pixel_spacing_mm, read_string,
-    read_view_position, sequence_items_from_item, sequence_items_from_object, DicomImage,
-    DicomPathKind, DicomSource, ImageColorMode, MonoFrames, RgbFrames,
+    classify_dicom_object, collect_metadata, open_dicom_object, read_float_pixel_samples,
+    read_int_first, read_item_multi_int, read_item_string, read_laterality,
+    read_patient_orientation, read_pixel_spacing_mm, read_string, read_view_position,
+    sequence_items_from_item, sequence_items_from_object, DicomImage, DicomPathKind, DicomSource,
+    ImageColorMode, MonoFrames, RgbFrames,
 };
 
-const FLOAT_PIXEL_DATA: Tag = Tag(0x7FE0, 0x0008);
-const DOUBLE_FLOAT_PIXEL_DATA: Tag = Tag(0x7FE0, 0x0009);
 const REFERENCED_IMAGE_SEQUENCE: Tag = Tag(0x0008, 0x1140);
 const SOURCE_IMAGE_SEQUENCE: Tag = Tag(0x0008, 0x2112);
 const REFERENCED_SOP_INSTANCE_UID: Tag = Tag(0x0008, 0x1155);
@@ -145,10 +144,13 @@ struct ParsedParametricMap {
     references: HashMap<String, Option<SourceFrameRgbaIndices>>,
 }
 
-pub fn load_parametric_map(source: impl Into<DicomSource>) -> Result<DicomImage> {
+pub fn load_parametric_map(
+    source: impl Into<DicomSource>,
+    view_fallback_tags: &[String],
+) -> Result<DicomImage> {
     let source = source.into();
     let obj = open_dicom_object(&source)?;
-    let parsed = parse_parametric_map(&obj, &source)?;
+    let parsed = parse_parametric_map(&obj, &source, view_fallback_tags)?;
     Ok(parsed.display_image)
 }
 
@@ -157,7 +159,8 @@ pub fn load_parametric_map_overlays(
 ) -> Result<HashMap<String, ParametricMapOverlay>> {
     let source = source.into();
     let obj = open_dicom_object(&source)?;
-    let parsed = parse_parametric_map(&obj, &source)?;
+    // Only the overlay layers are kept, so the display image's view is unused.
+    let parsed = parse_parametric_map(&obj, &source, &[])?;
 
     let mut overlays = HashMap::<String, ParametricMapOverlay>::new();
     for (sop_instance_uid, source_frame_rgba_indices) in parsed.references {
@@ -176,6 +179,7 @@ pub fn load_parametric_map_overlays(
 fn parse_parametric_map(
     obj: &DefaultDicomObject,
     source_label: &DicomSource,
+    view_fallback_tags: &[String],
 ) -> Result<ParsedParametricMap> {
     if classify_dicom_object(obj) != DicomPathKind::ParametricMap {
         let sop_class = read_string(obj, "SOPClassUID").unwrap_or_else(|| "unknown".to_string());
@@ -264,12 +268,16 @@ fn parse_parametric_map(
         min_value: 0,
         max_value: 255,
         recommended_cine_fps: None,
+        frame_time_offsets_ms: None,
         pixel_spacing_mm: read_pixel_spacing_mm(obj),
-        view_position: read_view_position(obj),
-        image_laterality: read_laterality(obj),
+        view_position: read_view_position(obj, view_fallback_tags),
+        image_laterality: read_laterality(obj, view_fallback_tags),
+        patient_orientation: read_patient_orientation(obj),
+        presentation_intent: None,
         instance_number: read_int_first(obj, "InstanceNumber"),
         sop_instance_uid: read_string(obj, "SOPInstanceUID"),
         reverse_frame_order: false,
+        stored_frame_base: 0,
         gsps_overlay: None,
         sr_overlay: None,
         pm_overlay: None,
@@ -278,6 +286,9 @@ fn parse_parametric_map(
         full_metadata_source: Some(source_label.clone()),
         full_metadata_loaded: false,
         full_metadata_loading: false,
+        window_presets: Vec::new(),
+        undecodable_reason: None,
+        opened_heuristically: false,
     };
 
     Ok(ParsedParametricMap {
@@ -302,20 +313,7 @@ fn decode_parametric_map_frames(
         .checked_mul(height)
         .context("Overflow while calculating Parametric Map frame size")?;
 
-    if let Ok(element) = obj.element(FLOAT_PIXEL_DATA) {
-        let values = element
-            .to_multi_float32()
-            .context("Could not decode Float Pixel Data")?;
-        return split_scalar_frames(values, frame_count, expected_samples);
-    }
-
-    if let Ok(element) = obj.element(DOUBLE_FLOAT_PIXEL_DATA) {
-        let values = element
-            .to_multi_float64()
-            .context("Could not decode Double Float Pixel Data")?
-            .into_iter()
-            .map(|value| value as f32)
-            .collect::<Vec<_>>();
+    if let Some(values) = read_float_pixel_samples(obj)? {
         return split_scalar_frames(values, frame_count, expected_samples);
     }
 
@@ -574,6 +572,7 @@ fn build_reference_maps(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dicom::FLOAT_PIXEL_DATA;
     use dicom_core::{DataElement, PrimitiveValue, VR};
     use dicom_object::{mem::InMemElement, FileMetaTableBuilder};
 
@@ -692,7 +691,7 @@ mod tests {
             None,
         );
 
-        let image = parse_parametric_map(&obj, &DicomSource::from_memory("pm", Vec::new()))
+        let image = parse_parametric_map(&obj, &DicomSource::from_memory("pm", Vec::new()), &[])
             .expect("Parametric Map should parse")
             .display_image;
         assert_eq!(image.frame_count(), 2);
@@ -701,6 +700,34 @@ mod tests {
         assert!(image.frame_rgb_pixels(0).is_some());
     }
 
+    #[test]
+    fn parse_parametric_map_reads_the_view_from_configured_fallback_tags() {
+        let mut obj = build_parametric_map_test_object(
+            DataElement::new(
+                FLOAT_PIXEL_DATA,
+                VR::OF,
+                PrimitiveValue::F32(vec![0.5f32].into()),
+            ),
+            1,
+            None,
+            None,
+        );
+        obj.put(DataElement::new(Tag(0x0018, 0x1400), VR::LO, "R MLO"));
+        let source = DicomSource::from_memory("pm", Vec::new());
+
+        let image = parse_parametric_map(&obj, &source, &[])
+            .expect("Parametric Map should parse")
+            .display_image;
+        assert_eq!(image.view_position, None);
+
+        let fallback_tags = ["AcquisitionDeviceProcessingDescription".to_string()];
+        let image = parse_parametric_map(&obj, &source, &fallback_tags)
+            .expect("Parametric Map should parse")
+            .display_image;
+        assert_eq!(image.view_position.as_deref(), Some("MLO"));
+        assert_eq!(image.image_laterality.as_deref(), Some("R"));
+    }
+
     #[test]
     fn parse_parametric_map_populates_full_metadata() {
         let obj = build_parametric_map_test_object(
@@ -717,7 +744,7 @@ mod tests {
         obj.write_all(&mut bytes)
             .expect("Parametric Map test object should serialize");
 
-        let mut image = parse_parametric_map(&obj, &DicomSource::from_memory("pm", bytes))
+        let mut image = parse_parametric_map(&obj, &DicomSource::from_memory("pm", bytes), &[])
             .expect("Parametric Map should parse")
             .display_image;
 
@@ -748,7 +775,7 @@ mod tests {
         );
 
         let source = DicomSource::from_memory("pm", Vec::new());
-        let parsed = parse_parametric_map(&obj, &source).expect("Parametric Map should parse");
+        let parsed = parse_parametric_map(&obj, &source, &[]).expect("Parametric Map should parse");
         assert!(parsed.references.contains_key("1.2.3"));
 
         let overlays = parsed
@@ -799,7 +826,7 @@ mod tests {
             Some(&[1, 2]),
         );
 
-        let parsed = parse_parametric_map(&obj, &DicomSource::from_memory("pm", Vec::new()))
+        let parsed = parse_parametric_map(&obj, &DicomSource::from_memory("pm", Vec::new()), &[])
             .expect("constant Parametric Map should parse");
         let frame_0 = parsed
             .display_image
@@ -840,7 +867,7 @@ mod tests {
             ],
         );
 
-        let parsed = parse_parametric_map(&obj, &DicomSource::from_memory("pm", Vec::new()))
+        let parsed = parse_parametric_map(&obj, &DicomSource::from_memory("pm", Vec::new()), &[])
             .expect("multi-SOP Parametric Map should parse");
         assert!(parsed.references.contains_key("1.2.3"));
         assert!(parsed.references.contains_key("4.5.6"));
@@ -4,6 +4,8 @@ use std::sync::Arc;
 use anyhow::{bail, Result};
 use dicom_object::{DefaultDicomObject, InMemDicomObject, Tag};
 
+use crate::mammo::classify_view;
+
 use super::{
     classify_dicom_object, collect_full_metadata, collect_metadata, open_dicom_object,
     read_item_multi_float, read_item_multi_int, read_item_string, read_string,
@@ -840,7 +842,7 @@ fn sr_overlay_context_label(reference: &ReferencedImageTarget) -> Option<String>
     if let Some(view) = reference
         .view
         .as_deref()
-        .and_then(mammography_view_abbreviation)
+        .and_then(|view| classify_view(Some(view)))
     {
         parts.push(view);
     }
@@ -861,18 +863,6 @@ fn mammography_laterality_abbreviation(value: &str) -> Option<&'static str> {
     }
 }
 
-fn mammography_view_abbreviation(value: &str) -> Option<&'static str> {
-    match normalize_code_token(value).as_str() {
-        "CRANIOCAUDAL" => Some("CC"),
-        "MEDIOLATERALOBLIQUE" => Some("MLO"),
-        "MEDIOLATERAL" => Some("ML"),
-        "LATEROMEDIAL" => Some("LM"),
-        "EXAGGERATEDCRANIOCAUDALLATERAL" => Some("XCCL"),
-        "EXAGGERATEDCRANIOCAUDALMEDIAL" => Some("XCCM"),
-        _ => None,
-    }
-}
-
 fn format_sr_overlay_number(value: f32) -> String {
     if (value.fract()).abs() < 0.01 {
         format!("{value:.0}")
@@ -1,22 +1,32 @@
-use std::collections::BTreeMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{mpsc, OnceLock};
+use std::time::Duration;
 
 use anyhow::{bail, Context, Result};
 use reqwest::blocking::Client;
 use reqwest::header::ACCEPT;
 
 use crate::dicom::{
-    dicom_identity_key_from_parts, dicom_source_from_bytes_with_identity, is_gsps_sop_class_uid,
-    is_parametric_map_sop_class_uid, is_structured_report_sop_class_uid, DicomPathKind,
-    DicomSource,
+    dicom_identity_key_from_parts, dicom_json_tag, dicom_source_from_bytes_with_identity,
+    is_gsps_sop_class_uid, is_parametric_map_sop_class_uid, is_structured_report_sop_class_uid,
+    parse_first_int, read_mammo_view_key, shutdown_requested, DicomPathKind, DicomSource,
+};
+use crate::launch::{DicomWebGroupedLaunchRequest, DicomWebLaunchRequest, RemoteFileLaunchRequest};
+use crate::mammo::{
+    classify_laterality_in_fallbacks, classify_view_in_fallbacks, mammo_quartet_indices,
+    mammo_triplet_indices, MammoViewKey,
 };
-use crate::launch::{DicomWebGroupedLaunchRequest, DicomWebLaunchRequest};
-use crate::mammo::{classify_laterality, classify_view};
 
+const DEFAULT_HTTP_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const DEFAULT_HTTP_TIMEOUT: Duration = Duration::from_secs(120);
 const TAG_SOP_CLASS_UID: &str = "00080016";
 const TAG_SOP_INSTANCE_UID: &str = "00080018";
 const TAG_MODALITY: &str = "00080060";
 const TAG_SERIES_INSTANCE_UID: &str = "0020000E";
 const TAG_INSTANCE_NUMBER: &str = "00200013";
+const TAG_SERIES_NUMBER: &str = "00200011";
+const TAG_SERIES_DESCRIPTION: &str = "0008103E";
 const TAG_VIEW_POSITION: &str = "00185101";
 const TAG_IMAGE_LATERALITY: &str = "00200062";
 const TAG_LATERALITY: &str = "00200060";
@@ -30,6 +40,11 @@ struct MetadataInstance {
     view_position: Option<String>,
     laterality: Option<String>,
     instance_number: Option<i32>,
+    series_number: Option<i32>,
+    series_description: Option<String>,
+    /// Values of the view fallback tags this instance carries, in setting
+    /// order.
+    view_fallback_values: Vec<String>,
 }
 
 #[derive(Clone, Copy)]
@@ -38,6 +53,7 @@ struct DownloadInstanceRequest<'a> {
     series_uid: Option<&'a str>,
     sop_class_uid: Option<&'a str>,
     instance_uid: &'a str,
+    wado_uri: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -47,23 +63,129 @@ pub enum DicomWebDownloadResult {
         groups: Vec<Vec<DicomSource>>,
         open_group: usize,
     },
+    /// One representative image per requested series, in request order.
+    SeriesTabs(Vec<DicomWebSeriesTab>),
+}
+
+/// A series opened as a tab: its representative image and the tab label.
+#[derive(Debug, Clone)]
+pub struct DicomWebSeriesTab {
+    pub label: String,
+    pub source: DicomSource,
 }
 
 #[derive(Debug, Clone)]
 pub enum DicomWebGroupStreamUpdate {
     ActiveGroupInstanceCount(usize),
     ActivePath(DicomSource),
+    /// Displayable image count of a non-open group, sent before its
+    /// instances start downloading.
+    BackgroundGroupInstanceCount {
+        group_index: usize,
+        count: usize,
+    },
+    /// One instance of a non-open group, in arrival order.
+    BackgroundGroupPath {
+        group_index: usize,
+        path: DicomSource,
+    },
     BackgroundGroupReady {
         group_index: usize,
         paths: Vec<DicomSource>,
     },
+    /// An image of the open group that could not be downloaded. `path` is a
+    /// stand-in without bytes that keeps the group's shape, and `retry`
+    /// downloads the instance again.
+    ActiveDownloadFailed {
+        path: DicomSource,
+        error: String,
+        retry: Box<InstanceRedownload>,
+    },
+}
+
+/// What it takes to download one instance again after its first download
+/// failed, so the viewer can retry a cell without restarting the launch.
+#[derive(Clone)]
+pub struct InstanceRedownload {
+    client: Client,
+    base: String,
+    study_uid: String,
+    auth: Option<(String, String)>,
+    instance: MetadataInstance,
+}
+
+impl InstanceRedownload {
+    pub fn download(&self) -> Result<DicomSource> {
+        let auth = self
+            .auth
+            .as_ref()
+            .map(|(username, password)| (username.as_str(), password.as_str()));
+        download_metadata_instance(
+            &self.client,
+            &self.base,
+            &self.study_uid,
+            auth,
+            &self.instance,
+        )
+    }
+
+    #[cfg(test)]
+    pub(crate) fn test_stub(instance_uid: &str) -> Self {
+        Self {
+            client: Client::new(),
+            base: "https://pacs.example/dicom-web".to_string(),
+            study_uid: "study_a".to_string(),
+            auth: None,
+            instance: MetadataInstance {
+                series_uid: None,
+                instance_uid: instance_uid.to_string(),
+                sop_class_uid: None,
+                modality: Some("MG".to_string()),
+                view_position: None,
+                laterality: None,
+                instance_number: None,
+                series_number: None,
+                series_description: None,
+                view_fallback_values: Vec::new(),
+            },
+        }
+    }
+
+    /// An empty source with the identity a successful download gets, so the
+    /// downloaded instance replaces it in place.
+    fn stand_in(&self) -> DicomSource {
+        dicom_source_from_bytes_with_identity(
+            &self.instance.instance_uid,
+            instance_identity_key(&self.study_uid, &self.instance.instance_uid),
+            Vec::new(),
+        )
+    }
 }
 
+impl std::fmt::Debug for InstanceRedownload {
+    // Leaves out the credentials.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InstanceRedownload")
+            .field("base", &self.base)
+            .field("study_uid", &self.study_uid)
+            .field("instance_uid", &self.instance.instance_uid)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Downloads what a DICOMweb launch references. `view_fallback_tags` are the
+/// keywords `load_dicom` consults for view and laterality, applied here to the
+/// metadata JSON so instance selection classifies views the same way.
 pub fn download_dicomweb_request(
     request: &DicomWebLaunchRequest,
+    view_fallback_tags: &[String],
 ) -> Result<DicomWebDownloadResult> {
     let client = build_http_client()?;
-    let base = normalize_base_url(&request.base_url);
+    let base = if request.wado_uri {
+        request.base_url.trim().trim_end_matches('/').to_string()
+    } else {
+        normalize_base_url(&request.base_url)
+    };
     let auth = request.username.as_deref().zip(request.password.as_deref());
 
     if let Some(instance_uid) = request.instance_uid.as_ref() {
@@ -75,31 +197,154 @@ pub fn download_dicomweb_request(
                 series_uid: request.series_uid.as_deref(),
                 sop_class_uid: None,
                 instance_uid,
+                wado_uri: request.wado_uri,
             },
             auth,
         )?;
         return Ok(DicomWebDownloadResult::Single(vec![path]));
     }
 
+    let mut views = ViewClassifier::new(view_fallback_tags);
+    if !request.series_tabs.is_empty() {
+        return download_series_tabs(&client, &base, request, auth, &views);
+    }
+
     let metadata_instances = fetch_instance_metadata(
         &client,
         &base,
         &request.study_uid,
         request.series_uid.as_deref(),
         auth,
+        &views.view_fallback_tags,
     )?;
     if metadata_instances.is_empty() {
         bail!("DICOMweb metadata query returned no instances");
     }
 
-    let selected = select_instances_for_viewer(metadata_instances, request.series_uid.as_deref())?;
-    let paths = download_instances_parallel(&client, &base, &request.study_uid, auth, &selected)?;
+    let selected = select_instances_for_viewer(
+        metadata_instances,
+        request.series_uid.as_deref(),
+        &mut |instances| {
+            views.correct(instances, |instance| {
+                download_metadata_instance(&client, &base, &request.study_uid, auth, instance)
+            })
+        },
+    )?;
+    let paths = download_instances_parallel(
+        &client,
+        &base,
+        &request.study_uid,
+        auth,
+        &selected,
+        &views,
+        None,
+    )?;
 
     Ok(DicomWebDownloadResult::Single(paths))
 }
 
+/// Downloads the representative image of every `series_tabs` entry. A
+/// series without a displayable image fails the launch, so a tab is never
+/// silently missing.
+fn download_series_tabs(
+    client: &Client,
+    base: &str,
+    request: &DicomWebLaunchRequest,
+    auth: Option<(&str, &str)>,
+    views: &ViewClassifier,
+) -> Result<DicomWebDownloadResult> {
+    let mut representatives = Vec::with_capacity(request.series_tabs.len());
+    for series_uid in &request.series_tabs {
+        ensure_not_shutting_down()?;
+        let instances = fetch_instance_metadata(
+            client,
+            base,
+            &request.study_uid,
+            Some(series_uid),
+            auth,
+            &views.view_fallback_tags,
+        )?;
+        let representative = representative_series_instance(instances)
+            .with_context(|| format!("Series {series_uid} has no displayable image"))?;
+        representatives.push(representative);
+    }
+
+    let sources = download_instances_parallel(
+        client,
+        base,
+        &request.study_uid,
+        auth,
+        &representatives,
+        views,
+        None,
+    )?;
+    Ok(DicomWebDownloadResult::SeriesTabs(
+        representatives
+            .iter()
+            .zip(sources)
+            .enumerate()
+            .map(|(index, (instance, source))| DicomWebSeriesTab {
+                label: series_tab_label(instance, index),
+                source,
+            })
+            .collect(),
+    ))
+}
+
+/// The first image of a series in display order, skipping presentation
+/// states, reports and parametric maps.
+fn representative_series_instance(instances: Vec<MetadataInstance>) -> Option<MetadataInstance> {
+    let mut images = instances
+        .into_iter()
+        .filter(|instance| {
+            !matches!(
+                metadata_instance_kind(instance),
+                DicomPathKind::Gsps
+                    | DicomPathKind::StructuredReport
+                    | DicomPathKind::ParametricMap
+            )
+        })
+        .collect::<Vec<_>>();
+    sort_instances_for_mammo(&mut images);
+    images.into_iter().next()
+}
+
+fn series_tab_label(instance: &MetadataInstance, index: usize) -> String {
+    match (
+        instance.series_number,
+        instance.series_description.as_deref(),
+    ) {
+        (Some(number), Some(description)) => format!("{number}: {description}"),
+        (None, Some(description)) => description.to_string(),
+        (Some(number), None) => format!("Series {number}"),
+        (None, None) => format!("Series {}", index + 1),
+    }
+}
+
+/// Fetches one DICOM file from a plain HTTP(S) URL with the same client,
+/// timeouts and basic auth as DICOMweb downloads.
+pub fn download_remote_file(request: &RemoteFileLaunchRequest) -> Result<DicomWebDownloadResult> {
+    let client = build_http_client()?;
+    let auth = request.username.as_deref().zip(request.password.as_deref());
+    let bytes = http_get_bytes(&client, &request.url, "application/dicom, */*", auth)?;
+    // Presigned links differ per request only in their query, so the bare
+    // object URL identifies the file.
+    let identity = strip_query_and_fragment(request.url.trim());
+    Ok(DicomWebDownloadResult::Single(vec![
+        dicom_source_from_bytes_with_identity(remote_file_name(identity), identity, bytes),
+    ]))
+}
+
+fn remote_file_name(url: &str) -> &str {
+    url.rsplit('/')
+        .next()
+        .filter(|name| !name.is_empty() && !name.contains(':'))
+        .unwrap_or("remote.dcm")
+}
+
 pub fn download_dicomweb_group_request<F>(
     request: &DicomWebGroupedLaunchRequest,
+    view_fallback_tags: &[String],
     mut on_active_path: F,
 ) -> Result<DicomWebDownloadResult>
 where
@@ -121,6 +366,7 @@ where
         .map(|_| None::<Vec<DicomSource>>)
         .collect::<Vec<_>>();
 
+    let mut views = ViewClassifier::new(view_fallback_tags);
     let active_group_instances = resolve_group_instances(
         &client,
         &base,
@@ -128,6 +374,7 @@ where
         auth,
         open_group,
         &request.groups[open_group],
+        &mut views,
     )?;
 
     if let Some(count) = active_group_instance_count(&active_group_instances) {
@@ -139,6 +386,7 @@ where
         &request.study_uid,
         auth,
         &active_group_instances,
+        &views,
         &mut on_active_path,
     )?);
 
@@ -146,6 +394,7 @@ where
         .into_iter()
         .skip(1)
     {
+        ensure_not_shutting_down()?;
         let selected_instances = resolve_group_instances(
             &client,
             &base,
@@ -153,13 +402,27 @@ where
             auth,
             group_index,
             &request.groups[group_index],
+            &mut views,
         )?;
+        if let Some(count) = active_group_instance_count(&selected_instances) {
+            on_active_path(DicomWebGroupStreamUpdate::BackgroundGroupInstanceCount {
+                group_index,
+                count,
+            });
+        }
         let group_paths = download_instances_parallel(
             &client,
             &base,
             &request.study_uid,
             auth,
             &selected_instances,
+            &views,
+            Some(&mut |path: &DicomSource| {
+                on_active_path(DicomWebGroupStreamUpdate::BackgroundGroupPath {
+                    group_index,
+                    path: path.clone(),
+                });
+            }),
         )?;
         on_active_path(DicomWebGroupStreamUpdate::BackgroundGroupReady {
             group_index,
@@ -194,6 +457,7 @@ fn resolve_group_instances(
     auth: Option<(&str, &str)>,
     group_index: usize,
     group_series_uids: &[String],
+    views: &mut ViewClassifier,
 ) -> Result<Vec<MetadataInstance>> {
     if group_series_uids.is_empty() {
         bail!(
@@ -205,14 +469,20 @@ fn resolve_group_instances(
     let mut reduced_by_series = Vec::<Vec<MetadataInstance>>::new();
 
     for series_uid in group_series_uids {
-        let metadata_instances =
-            fetch_instance_metadata(client, base, study_uid, Some(series_uid.as_str()), auth)
-                .with_context(|| {
-                    format!(
-                        "Failed fetching DICOMweb metadata for group {} series {}",
-                        group_index, series_uid
-                    )
-                })?;
+        let mut metadata_instances = fetch_instance_metadata(
+            client,
+            base,
+            study_uid,
+            Some(series_uid.as_str()),
+            auth,
+            &views.view_fallback_tags,
+        )
+        .with_context(|| {
+            format!(
+                "Failed fetching DICOMweb metadata for group {} series {}",
+                group_index, series_uid
+            )
+        })?;
 
         if metadata_instances.is_empty() {
             bail!(
@@ -222,6 +492,9 @@ fn resolve_group_instances(
             );
         }
 
+        views.correct(&mut metadata_instances, |instance| {
+            download_metadata_instance(client, base, study_uid, auth, instance)
+        })?;
         let reduced = reduce_series_instances(metadata_instances).with_context(|| {
             format!(
                 "Group {} series {} did not resolve to a supported instance set",
@@ -365,36 +638,48 @@ fn active_group_instance_count(instances: &[MetadataInstance]) -> Option<usize>
     (image_count > 0).then_some(image_count)
 }
 
+/// Downloads the open group in order, reporting each instance as it lands.
+/// In a multi-view group an image that fails to download is reported as
+/// `ActiveDownloadFailed` and the rest keep coming, so one flaky instance does
+/// not take the whole group down.
 fn download_instances_streaming<F>(
     client: &Client,
     base: &str,
     study_uid: &str,
     auth: Option<(&str, &str)>,
     instances: &[MetadataInstance],
+    views: &ViewClassifier,
     on_path: &mut F,
 ) -> Result<Vec<DicomSource>>
 where
     F: FnMut(DicomWebGroupStreamUpdate),
 {
-    download_instances_streaming_with(instances, on_path, |instance| {
-        download_instance(
-            client,
-            base,
-            DownloadInstanceRequest {
-                study_uid,
-                series_uid: instance.series_uid.as_deref(),
-                sop_class_uid: instance.sop_class_uid.as_deref(),
-                instance_uid: &instance.instance_uid,
-            },
-            auth,
-        )
-    })
+    let redownload = |instance: &MetadataInstance| InstanceRedownload {
+        client: client.clone(),
+        base: base.to_string(),
+        study_uid: study_uid.to_string(),
+        auth: auth.map(|(username, password)| (username.to_string(), password.to_string())),
+        instance: instance.clone(),
+    };
+    let multi_view = displayable_group_image_count(instances) > 1;
+    download_instances_streaming_with(
+        instances,
+        on_path,
+        |instance| {
+            views.prefetched(instance).map_or_else(
+                || download_metadata_instance(client, base, study_uid, auth, instance),
+                Ok,
+            )
+        },
+        multi_view.then_some(&redownload as &dyn Fn(&MetadataInstance) -> InstanceRedownload),
+    )
 }
 
 fn download_instances_streaming_with<F, D>(
     instances: &[MetadataInstance],
     on_path: &mut F,
     mut downloader: D,
+    redownload: Option<&dyn Fn(&MetadataInstance) -> InstanceRedownload>,
 ) -> Result<Vec<DicomSource>>
 where
     F: FnMut(DicomWebGroupStreamUpdate),
@@ -402,21 +687,79 @@ where
 {
     let mut paths = Vec::with_capacity(instances.len());
     for instance in instances {
-        let path = downloader(instance)?;
-        on_path(DicomWebGroupStreamUpdate::ActivePath(path.clone()));
-        paths.push(path);
+        ensure_not_shutting_down()?;
+        match downloader(instance) {
+            Ok(path) => {
+                on_path(DicomWebGroupStreamUpdate::ActivePath(path.clone()));
+                paths.push(path);
+            }
+            Err(err) => {
+                let Some(redownload) =
+                    redownload.filter(|_| metadata_instance_kind(instance) == DicomPathKind::Image)
+                else {
+                    return Err(err);
+                };
+                log::warn!(
+                    "Could not download instance {} of the open group, showing a placeholder: {err:#}",
+                    instance.instance_uid
+                );
+                let retry = Box::new(redownload(instance));
+                let path = retry.stand_in();
+                on_path(DicomWebGroupStreamUpdate::ActiveDownloadFailed {
+                    path: path.clone(),
+                    error: format!("{err:#}"),
+                    retry,
+                });
+                paths.push(path);
+            }
+        }
     }
     Ok(paths)
 }
 
+/// Stops download loops between instances once the viewer is closing, so no
+/// requests keep running after the window is gone.
+fn ensure_not_shutting_down() -> Result<()> {
+    if shutdown_requested() {
+        bail!("DICOMweb download cancelled because Perspecta is closing");
+    }
+    Ok(())
+}
+
 fn build_http_client() -> Result<Client> {
     Client::builder()
-        .connect_timeout(std::time::Duration::from_secs(10))
-        .timeout(std::time::Duration::from_secs(120))
+        .connect_timeout(configured_http_connect_timeout().unwrap_or(DEFAULT_HTTP_CONNECT_TIMEOUT))
+        .timeout(configured_http_timeout().unwrap_or(DEFAULT_HTTP_TIMEOUT))
         .build()
         .context("Could not initialize HTTP client for DICOMweb")
 }
 
+fn configured_http_connect_timeout() -> Option<Duration> {
+    static CONFIG: OnceLock<Option<Duration>> = OnceLock::new();
+
+    *CONFIG.get_or_init(|| {
+        let raw = std::env::var("PERSPECTA_HTTP_CONNECT_TIMEOUT").ok()?;
+        parse_timeout_secs(&raw)
+    })
+}
+
+fn configured_http_timeout() -> Option<Duration> {
+    static CONFIG: OnceLock<Option<Duration>> = OnceLock::new();
+
+    *CONFIG.get_or_init(|| {
+        let raw = std::env::var("PERSPECTA_HTTP_TIMEOUT").ok()?;
+        parse_timeout_secs(&raw)
+    })
+}
+
+fn parse_timeout_secs(raw: &str) -> Option<Duration> {
+    let value = raw.trim().parse::<u64>().ok()?;
+    if value == 0 {
+        return None;
+    }
+    Some(Duration::from_secs(value))
+}
+
 fn normalize_base_url(base_url: &str) -> String {
     let trimmed = strip_query_and_fragment(base_url.trim())
         .trim()
@@ -438,6 +781,17 @@ fn strip_query_and_fragment(value: &str) -> &str {
     &value[..query_index.min(fragment_index)]
 }
 
+/// `url` for error and log text, with any query string and fragment replaced
+/// by a marker: signed download URLs carry their credentials there.
+fn redacted_url(url: &str) -> Cow<'_, str> {
+    let base = strip_query_and_fragment(url);
+    if base.len() == url.len() {
+        Cow::Borrowed(url)
+    } else {
+        Cow::Owned(format!("{base}?<redacted>"))
+    }
+}
+
 fn has_root_only_path(url: &str) -> bool {
     if let Some((_, rest)) = url.split_once("://") {
         match rest.find('/') {
@@ -455,12 +809,13 @@ fn fetch_instance_metadata(
     study_uid: &str,
     series_uid: Option<&str>,
     auth: Option<(&str, &str)>,
+    view_fallback_tags: &[String],
 ) -> Result<Vec<MetadataInstance>> {
     let url = metadata_url(base, study_uid, series_uid);
 
     let metadata_json = http_get_text(client, &url, "application/dicom+json", auth)
         .with_context(|| format!("Failed fetching DICOMweb metadata from {url}"))?;
-    parse_metadata_instances(&metadata_json)
+    parse_metadata_instances(&metadata_json, view_fallback_tags)
 }
 
 fn metadata_url(base: &str, study_uid: &str, series_uid: Option<&str>) -> String {
@@ -471,9 +826,16 @@ fn metadata_url(base: &str, study_uid: &str, series_uid: Option<&str>) -> String
     }
 }
 
-fn parse_metadata_instances(json: &str) -> Result<Vec<MetadataInstance>> {
+fn parse_metadata_instances(
+    json: &str,
+    view_fallback_tags: &[String],
+) -> Result<Vec<MetadataInstance>> {
     let object_slices = split_top_level_json_objects(json)
         .with_context(|| "DICOMweb metadata JSON parsing failed".to_string())?;
+    let fallback_json_tags = view_fallback_tags
+        .iter()
+        .filter_map(|keyword| dicom_json_tag(keyword))
+        .collect::<Vec<_>>();
     let mut instances = Vec::new();
 
     for obj in object_slices {
@@ -490,7 +852,17 @@ fn parse_metadata_instances(json: &str) -> Result<Vec<MetadataInstance>> {
             laterality: first_tag_string(obj, TAG_IMAGE_LATERALITY)
                 .or_else(|| first_tag_string(obj, TAG_LATERALITY)),
             instance_number: first_tag_string(obj, TAG_INSTANCE_NUMBER)
-                .and_then(|value| value.parse::<i32>().ok()),
+                .and_then(|value| parse_first_int(&value)),
+            series_number: first_tag_string(obj, TAG_SERIES_NUMBER)
+                .and_then(|value| parse_first_int(&value)),
+            series_description: normalize_metadata_string(first_tag_string(
+                obj,
+                TAG_SERIES_DESCRIPTION,
+            )),
+            view_fallback_values: fallback_json_tags
+                .iter()
+                .filter_map(|tag| first_tag_string(obj, tag))
+                .collect(),
         };
         instances.push(metadata);
     }
@@ -762,35 +1134,55 @@ fn unescape_json_string(input: &str) -> String {
     output
 }
 
+/// Picks the instances to open from study metadata. `correct_views` runs on
+/// each series before its views decide the selection.
 fn select_instances_for_viewer(
     instances: Vec<MetadataInstance>,
     requested_series_uid: Option<&str>,
+    correct_views: &mut dyn FnMut(&mut [MetadataInstance]) -> Result<()>,
 ) -> Result<Vec<MetadataInstance>> {
     if let Some(series_uid) = requested_series_uid {
-        let filtered = instances
+        let mut filtered = instances
             .into_iter()
             .filter(|instance| instance.series_uid.as_deref() == Some(series_uid))
             .collect::<Vec<_>>();
         if filtered.is_empty() {
             bail!("Requested series UID was not found in study metadata");
         }
+        correct_views(&mut filtered)?;
         return reduce_series_instances(filtered);
     }
 
-    let grouped = group_by_series(instances);
+    let mut grouped = group_by_series(instances);
     if grouped.is_empty() {
         bail!("Study metadata contains no series");
     }
     if grouped.len() == 1 {
-        let (_, only_series) = grouped.into_iter().next().expect("len checked");
+        let (_, mut only_series) = grouped.into_iter().next().expect("len checked");
+        correct_views(&mut only_series)?;
         return reduce_series_instances(only_series);
     }
 
-    for series_instances in grouped.values() {
+    // A 4-instance localizer or scout can sit beside the real screening, so a
+    // series that classifies as a full RCC/LCC/RMLO/LMLO quartet wins first.
+    for series_instances in grouped.values_mut() {
         if series_instances.len() == 4 {
-            return reduce_series_instances(series_instances.clone());
+            correct_views(series_instances)?;
         }
     }
+    let four_instance_series = grouped
+        .values()
+        .filter(|series_instances| series_instances.len() == 4)
+        .collect::<Vec<_>>();
+    if let Some(quartet) = four_instance_series
+        .iter()
+        .find_map(|series_instances| pick_mammo_quartet(series_instances))
+    {
+        return Ok(quartet);
+    }
+    if let Some(series_instances) = four_instance_series.first() {
+        return reduce_series_instances((*series_instances).clone());
+    }
 
     bail!(
         "Study contains multiple series. Add series UID in the perspecta URL for deterministic loading."
@@ -835,75 +1227,67 @@ fn reduce_series_instances(mut instances: Vec<MetadataInstance>) -> Result<Vec<M
 }
 
 fn pick_mammo_quartet(instances: &[MetadataInstance]) -> Option<Vec<MetadataInstance>> {
-    let mut rcc = None::<MetadataInstance>;
-    let mut lcc = None::<MetadataInstance>;
-    let mut rmlo = None::<MetadataInstance>;
-    let mut lmlo = None::<MetadataInstance>;
-
-    for instance in instances {
-        let key = mammo_sort_key(instance);
-        match key.0 {
-            0 if key.1 == 0 && rcc.is_none() => rcc = Some(instance.clone()),
-            0 if key.1 == 1 && lcc.is_none() => lcc = Some(instance.clone()),
-            1 if key.1 == 0 && rmlo.is_none() => rmlo = Some(instance.clone()),
-            1 if key.1 == 1 && lmlo.is_none() => lmlo = Some(instance.clone()),
-            _ => {}
-        }
-    }
-
-    Some(vec![rcc?, lcc?, rmlo?, lmlo?])
+    let quartet = mammo_quartet_indices(&mammo_view_keys(instances))?;
+    Some(
+        quartet
+            .iter()
+            .map(|index| instances[*index].clone())
+            .collect(),
+    )
 }
 
 fn pick_mammo_triplet(instances: &[MetadataInstance]) -> Option<Vec<MetadataInstance>> {
-    let mut rcc = None::<MetadataInstance>;
-    let mut lcc = None::<MetadataInstance>;
-    let mut rmlo = None::<MetadataInstance>;
-    let mut lmlo = None::<MetadataInstance>;
+    let triplet = mammo_triplet_indices(&mammo_view_keys(instances))?;
+    Some(
+        triplet
+            .iter()
+            .map(|index| instances[*index].clone())
+            .collect(),
+    )
+}
 
-    for instance in instances {
-        let key = mammo_sort_key(instance);
-        match key.0 {
-            0 if key.1 == 0 && rcc.is_none() => rcc = Some(instance.clone()),
-            0 if key.1 == 1 && lcc.is_none() => lcc = Some(instance.clone()),
-            1 if key.1 == 0 && rmlo.is_none() => rmlo = Some(instance.clone()),
-            1 if key.1 == 1 && lmlo.is_none() => lmlo = Some(instance.clone()),
-            _ => {}
-        }
-    }
+fn sort_instances_for_mammo(instances: &mut [MetadataInstance]) {
+    instances.sort_by(|a, b| {
+        mammo_sort_key(a)
+            .cmp(&mammo_sort_key(b))
+            .then_with(|| compare_instance_uids(&a.instance_uid, &b.instance_uid))
+    });
+}
 
-    let ordered = [rcc, lcc, rmlo, lmlo]
-        .into_iter()
-        .flatten()
-        .collect::<Vec<_>>();
-    if ordered.len() == 3 {
-        Some(ordered)
-    } else {
-        None
+/// Orders instance UIDs component by component numerically, so instances
+/// with equal or missing InstanceNumbers keep a stable order that follows the
+/// usual incrementing UID suffix (`...9` before `...10`) whatever order the
+/// server listed them in.
+fn compare_instance_uids(a: &str, b: &str) -> std::cmp::Ordering {
+    a.split('.')
+        .map(|component| (component.len(), component))
+        .cmp(b.split('.').map(|component| (component.len(), component)))
+}
+
+/// Classifies an instance from its metadata, consulting the view fallback
+/// tags where the standard tags are empty, as `load_dicom` does for files.
+fn mammo_view_key(instance: &MetadataInstance) -> MammoViewKey {
+    let fallbacks = || instance.view_fallback_values.iter().map(String::as_str);
+    let key = MammoViewKey::classify(
+        instance.view_position.as_deref(),
+        instance.laterality.as_deref(),
+        instance.instance_number,
+    );
+    MammoViewKey {
+        view: key.view.or_else(|| classify_view_in_fallbacks(fallbacks())),
+        laterality: key
+            .laterality
+            .or_else(|| classify_laterality_in_fallbacks(fallbacks())),
+        ..key
     }
 }
 
-fn sort_instances_for_mammo(instances: &mut [MetadataInstance]) {
-    instances.sort_by_key(mammo_sort_key);
+fn mammo_view_keys(instances: &[MetadataInstance]) -> Vec<MammoViewKey> {
+    instances.iter().map(mammo_view_key).collect()
 }
 
-fn mammo_sort_key(instance: &MetadataInstance) -> (u8, u8, i32, String) {
-    let view_rank = match classify_view(instance.view_position.as_deref()) {
-        Some("CC") => 0,
-        Some("MLO") => 1,
-        _ => 2,
-    };
-    let laterality_rank = match classify_laterality(instance.laterality.as_deref()) {
-        Some("R") => 0,
-        Some("L") => 1,
-        _ => 2,
-    };
-    let instance_number = instance.instance_number.unwrap_or(i32::MAX);
-    (
-        view_rank,
-        laterality_rank,
-        instance_number,
-        instance.instance_uid.clone(),
-    )
+fn mammo_sort_key(instance: &MetadataInstance) -> (u8, u8, i32) {
+    mammo_view_key(instance).sort_key()
 }
 
 fn download_instance(
@@ -917,18 +1301,32 @@ fn download_instance(
         series_uid,
         sop_class_uid,
         instance_uid,
+        wado_uri,
     } = request;
     let mut urls = Vec::with_capacity(2);
-    if let Some(series_uid) = series_uid {
+    if wado_uri {
+        urls.push(wado_uri_instance_url(
+            base,
+            study_uid,
+            series_uid,
+            instance_uid,
+        ));
+    } else {
+        if let Some(series_uid) = series_uid {
+            urls.push(format!(
+                "{base}/studies/{study_uid}/series/{series_uid}/instances/{instance_uid}"
+            ));
+        }
         urls.push(format!(
-            "{base}/studies/{study_uid}/series/{series_uid}/instances/{instance_uid}"
+            "{base}/studies/{study_uid}/instances/{instance_uid}"
         ));
     }
-    urls.push(format!(
-        "{base}/studies/{study_uid}/instances/{instance_uid}"
-    ));
 
-    let accepts = preferred_accepts_for_instance(sop_class_uid);
+    let accepts: &[&str] = if wado_uri {
+        &["application/dicom"]
+    } else {
+        preferred_accepts_for_instance(sop_class_uid)
+    };
 
     let mut last_error = None::<String>;
     let mut bytes = None::<Vec<u8>>;
@@ -941,7 +1339,10 @@ fn download_instance(
                     break 'attempts;
                 }
                 Err(err) => {
-                    last_error = Some(format!("{url} (Accept: {accept}) => {err:#}"));
+                    last_error = Some(format!(
+                        "{} (Accept: {accept}) => {err:#}",
+                        redacted_url(url)
+                    ));
                 }
             }
         }
@@ -955,16 +1356,35 @@ fn download_instance(
         );
     };
 
-    let identity_key =
-        dicom_identity_key_from_parts(Some(study_uid), None, Some(instance_uid), None, None);
-
     Ok(dicom_source_from_bytes_with_identity(
         instance_uid,
-        identity_key,
+        instance_identity_key(study_uid, instance_uid),
         bytes,
     ))
 }
 
+fn instance_identity_key(study_uid: &str, instance_uid: &str) -> String {
+    dicom_identity_key_from_parts(Some(study_uid), None, Some(instance_uid), None, None)
+}
+
+/// Builds a legacy WADO-URI retrieve URL for servers that do not speak WADO-RS.
+fn wado_uri_instance_url(
+    base: &str,
+    study_uid: &str,
+    series_uid: Option<&str>,
+    instance_uid: &str,
+) -> String {
+    let separator = if base.contains('?') { '&' } else { '?' };
+    let mut url = format!("{base}{separator}requestType=WADO&studyUID={study_uid}");
+    if let Some(series_uid) = series_uid {
+        url.push_str(&format!("&seriesUID={series_uid}"));
+    }
+    url.push_str(&format!(
+        "&objectUID={instance_uid}&contentType=application%2Fdicom"
+    ));
+    url
+}
+
 fn preferred_accepts_for_instance(sop_class_uid: Option<&str>) -> &'static [&'static str] {
     if sop_class_uid.is_some_and(is_gsps_sop_class_uid) {
         &[
@@ -996,7 +1416,158 @@ fn download_instances_parallel(
     study_uid: &str,
     auth: Option<(&str, &str)>,
     instances: &[MetadataInstance],
+    views: &ViewClassifier,
+    on_arrival: Option<&mut dyn FnMut(&DicomSource)>,
 ) -> Result<Vec<DicomSource>> {
+    download_instances_parallel_with(instances, on_arrival, |instance| {
+        views.prefetched(instance).map_or_else(
+            || download_metadata_instance(client, base, study_uid, auth, instance),
+            Ok,
+        )
+    })
+}
+
+fn download_metadata_instance(
+    client: &Client,
+    base: &str,
+    study_uid: &str,
+    auth: Option<(&str, &str)>,
+    instance: &MetadataInstance,
+) -> Result<DicomSource> {
+    download_instance(
+        client,
+        base,
+        DownloadInstanceRequest {
+            study_uid,
+            series_uid: instance.series_uid.as_deref(),
+            sop_class_uid: instance.sop_class_uid.as_deref(),
+            instance_uid: &instance.instance_uid,
+            wado_uri: false,
+        },
+        auth,
+    )
+}
+
+/// How instances are classified by view and laterality for selection: the
+/// view fallback tags, read from the metadata JSON and from files alike, and,
+/// when `PERSPECTA_VERIFY_DICOMWEB_VIEWS` is set, a check of multi-image
+/// series against the downloaded files. With the check on, the images are
+/// downloaded up front and the view and laterality their own tags give replace
+/// what the metadata JSON said, so quartet and triplet selection follow the
+/// files. The downloads are kept and reused instead of being fetched again.
+#[derive(Default)]
+struct ViewClassifier {
+    view_fallback_tags: Vec<String>,
+    verify_files: bool,
+    prefetched: HashMap<String, DicomSource>,
+}
+
+impl ViewClassifier {
+    fn new(view_fallback_tags: &[String]) -> Self {
+        static VERIFY_FILES: OnceLock<bool> = OnceLock::new();
+
+        let verify_files = *VERIFY_FILES.get_or_init(|| {
+            std::env::var("PERSPECTA_VERIFY_DICOMWEB_VIEWS")
+                .is_ok_and(|raw| matches!(raw.trim(), "1" | "true" | "yes" | "on"))
+        });
+        Self {
+            view_fallback_tags: view_fallback_tags.to_vec(),
+            verify_files,
+            ..Self::default()
+        }
+    }
+
+    fn prefetched(&self, instance: &MetadataInstance) -> Option<DicomSource> {
+        self.prefetched.get(&instance.instance_uid).cloned()
+    }
+
+    /// Downloads the images of `instances` not fetched yet and corrects each
+    /// one's view and laterality from the file, logging every discrepancy. A
+    /// file whose tags cannot be read keeps its metadata classification.
+    fn correct<D>(&mut self, instances: &mut [MetadataInstance], downloader: D) -> Result<()>
+    where
+        D: Fn(&MetadataInstance) -> Result<DicomSource> + Sync,
+    {
+        if !self.verify_files || instances.len() < 2 {
+            return Ok(());
+        }
+        let pending = instances
+            .iter()
+            .filter(|instance| {
+                metadata_instance_kind(instance) == DicomPathKind::Image
+                    && !self.prefetched.contains_key(&instance.instance_uid)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        let sources = download_instances_parallel_with(&pending, None, downloader)?;
+        for (instance, source) in pending.into_iter().zip(sources) {
+            self.prefetched.insert(instance.instance_uid, source);
+        }
+
+        for instance in instances.iter_mut() {
+            let Some(source) = self.prefetched.get(&instance.instance_uid) else {
+                continue;
+            };
+            match read_mammo_view_key(source, &self.view_fallback_tags) {
+                Ok(file_key) => correct_view_from_file(instance, file_key),
+                Err(err) => log::debug!(
+                    "Could not read view tags of instance {}: {err:#}",
+                    instance.instance_uid
+                ),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Replaces the metadata view and laterality of `instance` with the ones its
+/// file classifies, where the file has them. Logs the change.
+fn correct_view_from_file(instance: &mut MetadataInstance, file_key: MammoViewKey) {
+    let json_key = mammo_view_key(instance);
+    let corrected = MammoViewKey {
+        view: file_key.view.or(json_key.view),
+        laterality: file_key.laterality.or(json_key.laterality),
+        instance_number: json_key.instance_number,
+    };
+    let Some(detail) = view_classification_mismatch(json_key, corrected) else {
+        return;
+    };
+    log::warn!(
+        "DICOMweb metadata for instance {} disagrees with the file: {detail}; using the file's tags.",
+        instance.instance_uid
+    );
+    instance.view_position = corrected.view.map(str::to_string);
+    instance.laterality = corrected.laterality.map(str::to_string);
+}
+
+fn view_classification_mismatch(json: MammoViewKey, file: MammoViewKey) -> Option<String> {
+    let describe = |key: MammoViewKey| {
+        format!(
+            "{} {}",
+            key.laterality.unwrap_or("?"),
+            key.view.unwrap_or("unknown view")
+        )
+    };
+    (json.view != file.view || json.laterality != file.laterality).then(|| {
+        format!(
+            "metadata says {}, file says {}",
+            describe(json),
+            describe(file)
+        )
+    })
+}
+
+/// Downloads every instance on its own thread and returns the paths in
+/// instance order. `on_arrival` runs on the calling thread as each download
+/// finishes, so callers can surface instances before the whole set is done.
+fn download_instances_parallel_with<D>(
+    instances: &[MetadataInstance],
+    mut on_arrival: Option<&mut dyn FnMut(&DicomSource)>,
+    downloader: D,
+) -> Result<Vec<DicomSource>>
+where
+    D: Fn(&MetadataInstance) -> Result<DicomSource> + Sync,
+{
     if instances.is_empty() {
         return Ok(Vec::new());
     }
@@ -1005,31 +1576,32 @@ fn download_instances_parallel(
         .map(|_| None::<Result<DicomSource>>)
         .collect::<Vec<_>>();
     std::thread::scope(|scope| {
+        let (arrival_tx, arrival_rx) = mpsc::channel::<(usize, Result<DicomSource>)>();
+        let downloader = &downloader;
         let mut jobs = Vec::with_capacity(instances.len());
         for (index, instance) in instances.iter().enumerate() {
+            let arrival_tx = arrival_tx.clone();
             jobs.push((
                 index,
                 scope.spawn(move || {
-                    download_instance(
-                        client,
-                        base,
-                        DownloadInstanceRequest {
-                            study_uid,
-                            series_uid: instance.series_uid.as_deref(),
-                            sop_class_uid: instance.sop_class_uid.as_deref(),
-                            instance_uid: &instance.instance_uid,
-                        },
-                        auth,
-                    )
+                    let result = ensure_not_shutting_down().and_then(|()| downloader(instance));
+                    let _ = arrival_tx.send((index, result));
                 }),
             ));
         }
+        drop(arrival_tx);
+
+        for (index, result) in arrival_rx {
+            if let (Ok(path), Some(on_arrival)) = (&result, on_arrival.as_mut()) {
+                on_arrival(path);
+            }
+            outputs[index] = Some(result);
+        }
 
         for (index, job) in jobs {
-            outputs[index] = Some(
-                job.join()
-                    .unwrap_or_else(|_| bail!("DICOMweb download worker panicked")),
-            );
+            if job.join().is_err() {
+                outputs[index] = Some(Err(anyhow::anyhow!("DICOMweb download worker panicked")));
+            }
         }
     });
 
@@ -1045,8 +1617,33 @@ fn download_instances_parallel(
 }
 
 fn extract_dicom_from_multipart(body: &[u8]) -> Option<Vec<u8>> {
+    // A Part 10 file is never wrapped, even if its bytes happen to contain
+    // something that looks like a delimiter line.
+    if body.get(128..132) == Some(b"DICM".as_slice()) {
+        return None;
+    }
+    // RFC 2046 allows an arbitrary preamble before the first delimiter, and
+    // the boundary is not known without the Content-Type header, so every line
+    // that starts with `--` is tried as the first delimiter in turn.
+    let mut line_start = 0;
+    while line_start < body.len() {
+        if let Some(payload) = extract_multipart_part_at(&body[line_start..]) {
+            return Some(payload);
+        }
+        let (line_len, separator_len) = find_line_end(&body[line_start..])?;
+        line_start += line_len + separator_len;
+    }
+    None
+}
+
+/// First part's payload when `body` starts with a delimiter line.
+fn extract_multipart_part_at(body: &[u8]) -> Option<Vec<u8>> {
+    if !body.starts_with(b"--") {
+        return None;
+    }
     let (line_end, line_sep_len) = find_line_end(body)?;
-    let first_line = &body[..line_end];
+    // A delimiter line may carry trailing transport padding (RFC 2046).
+    let first_line = trim_transport_padding(&body[..line_end]);
     if !first_line.starts_with(b"--") || first_line.len() <= 2 {
         return None;
     }
@@ -1060,36 +1657,97 @@ fn extract_dicom_from_multipart(body: &[u8]) -> Option<Vec<u8>> {
     Some(body[payload_start..payload_end].to_vec())
 }
 
+fn trim_transport_padding(line: &[u8]) -> &[u8] {
+    let padding = line
+        .iter()
+        .rev()
+        .take_while(|byte| matches!(byte, b' ' | b'\t'))
+        .count();
+    &line[..line.len() - padding]
+}
+
+/// First line break as (line length, separator length); a CR is only part of
+/// the separator when it directly precedes the LF.
 fn find_line_end(bytes: &[u8]) -> Option<(usize, usize)> {
-    if let Some(index) = find_subslice(bytes, b"\r\n") {
-        return Some((index, 2));
-    }
-    find_subslice(bytes, b"\n").map(|index| (index, 1))
+    let newline = bytes.iter().position(|byte| *byte == b'\n')?;
+    Some(if newline > 0 && bytes[newline - 1] == b'\r' {
+        (newline - 1, 2)
+    } else {
+        (newline, 1)
+    })
 }
 
+/// The part headers end at the first empty line; returns its offset and
+/// line-break length.
 fn find_headers_end(bytes: &[u8]) -> Option<(usize, usize)> {
-    if let Some(index) = find_subslice(bytes, b"\r\n\r\n") {
-        return Some((index, 4));
+    let mut line_start = 0;
+    loop {
+        let (line_len, separator_len) = find_line_end(&bytes[line_start..])?;
+        if line_len == 0 {
+            return Some((line_start, separator_len));
+        }
+        line_start += line_len + separator_len;
     }
-    find_subslice(bytes, b"\n\n").map(|index| (index, 2))
 }
 
+/// End of the first part's payload: the line break before the next real
+/// delimiter. LF-only servers are handled by matching on `\n--boundary` and
+/// dropping a preceding `\r`. Boundary bytes that merely occur inside binary
+/// data are skipped because a delimiter must be followed by `--`, padding or a
+/// line break.
 fn find_boundary_after_payload(
     body: &[u8],
     payload_start: usize,
     boundary: &[u8],
 ) -> Option<usize> {
-    let mut marker = Vec::with_capacity(boundary.len() + 4);
-    marker.extend_from_slice(b"\r\n--");
+    let mut marker = Vec::with_capacity(boundary.len() + 3);
+    marker.extend_from_slice(b"\n--");
     marker.extend_from_slice(boundary);
-    if let Some(index) = find_subslice(&body[payload_start..], &marker) {
-        return Some(payload_start + index);
+
+    let mut search_from = payload_start;
+    while let Some(relative) = find_subslice(&body[search_from..], &marker) {
+        let newline = search_from + relative;
+        if is_multipart_delimiter_end(&body[newline + marker.len()..]) {
+            return Some(if newline > payload_start && body[newline - 1] == b'\r' {
+                newline - 1
+            } else {
+                newline
+            });
+        }
+        search_from = newline + 1;
+    }
+
+    // Some servers write the close delimiter straight after the payload with
+    // no line break in front of it.
+    let mut close_marker = Vec::with_capacity(boundary.len() + 4);
+    close_marker.extend_from_slice(b"--");
+    close_marker.extend_from_slice(boundary);
+    close_marker.extend_from_slice(b"--");
+    let payload = &body[payload_start..];
+    let index = rfind_subslice(payload, &close_marker)?;
+    is_multipart_delimiter_end(&payload[index + close_marker.len()..])
+        .then_some(payload_start + index)
+}
+
+fn is_multipart_delimiter_end(rest: &[u8]) -> bool {
+    if rest.starts_with(b"--") {
+        return true;
     }
+    let padding = rest
+        .iter()
+        .take_while(|byte| matches!(byte, b' ' | b'\t'))
+        .count();
+    let rest = &rest[padding..];
+    rest.is_empty() || rest.starts_with(b"\r\n") || rest.starts_with(b"\n")
+}
 
-    let mut marker_lf = Vec::with_capacity(boundary.len() + 3);
-    marker_lf.extend_from_slice(b"\n--");
-    marker_lf.extend_from_slice(boundary);
-    find_subslice(&body[payload_start..], &marker_lf).map(|index| payload_start + index)
+fn rfind_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() || needle.len() > haystack.len() {
+        return None;
+    }
+    haystack
+        .windows(needle.len())
+        .rposition(|window| window == needle)
 }
 
 fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
@@ -1122,30 +1780,37 @@ fn http_get_bytes(
         request = request.basic_auth(username, Some(password));
     }
 
+    let shown_url = redacted_url(url);
     let response = request
         .send()
-        .with_context(|| format!("HTTP request failed for {url}"))?;
+        .map_err(reqwest::Error::without_url)
+        .with_context(|| format!("HTTP request failed for {shown_url}"))?;
     let status = response.status();
     if !status.is_success() {
         let detail = response
             .text()
             .unwrap_or_else(|_| String::from("unable to read error body"));
-        bail!("HTTP {status} for {url}: {detail}");
+        bail!("HTTP {status} for {shown_url}: {detail}");
     }
 
     response
         .bytes()
         .map(|body| body.to_vec())
-        .with_context(|| format!("Could not read response body from {url}"))
+        .map_err(reqwest::Error::without_url)
+        .with_context(|| format!("Could not read response body from {shown_url}"))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dicom::DicomLoadOptions;
     use std::path::PathBuf;
 
+    use dicom_core::{DataElement, PrimitiveValue, Tag, VR};
+    use dicom_object::{mem::InMemElement, FileMetaTableBuilder, InMemDicomObject};
+
     use crate::dicom::{
-        BASIC_TEXT_SR_SOP_CLASS_UID, GSPS_SOP_CLASS_UID, PARAMETRIC_MAP_SOP_CLASS_UID,
+        load_dicom, BASIC_TEXT_SR_SOP_CLASS_UID, GSPS_SOP_CLASS_UID, PARAMETRIC_MAP_SOP_CLASS_UID,
     };
 
     fn metadata_instance(
@@ -1162,9 +1827,168 @@ mod tests {
             view_position: view_position.map(|value| value.to_string()),
             laterality: laterality.map(|value| value.to_string()),
             instance_number,
+            series_number: None,
+            series_description: None,
+            view_fallback_values: Vec::new(),
         }
     }
 
+    #[test]
+    fn select_instances_for_viewer_prefers_mammo_quartet_over_decoy_series() {
+        let mut instances = (1..=4)
+            .map(|number| {
+                let mut instance =
+                    metadata_instance(&format!("scout-{number}"), None, None, Some(number));
+                instance.series_uid = Some("series_0_localizer".to_string());
+                instance
+            })
+            .collect::<Vec<_>>();
+        for (uid, view, laterality) in [
+            ("lmlo", "MLO", "L"),
+            ("rcc", "CC", "R"),
+            ("rmlo", "MLO", "R"),
+            ("lcc", "CC", "L"),
+        ] {
+            let mut instance = metadata_instance(uid, Some(view), Some(laterality), None);
+            instance.series_uid = Some("series_1_mammo".to_string());
+            instances.push(instance);
+        }
+
+        let selected = select_instances_for_viewer(instances, None, &mut |_| Ok(()))
+            .expect("quartet series should be chosen");
+        let selected_uids = selected
+            .iter()
+            .map(|instance| instance.instance_uid.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(selected_uids, vec!["rcc", "lcc", "rmlo", "lmlo"]);
+    }
+
+    /// Serialized Part 10 file with the given SOP class and instance UID plus
+    /// `elements`.
+    fn part10_test_bytes(
+        sop_class_uid: &str,
+        instance_uid: &str,
+        elements: impl IntoIterator<Item = InMemElement>,
+    ) -> Vec<u8> {
+        let object = InMemDicomObject::from_element_iter(
+            [
+                DataElement::new(Tag(0x0008, 0x0016), VR::UI, sop_class_uid),
+                DataElement::new(Tag(0x0008, 0x0018), VR::UI, instance_uid),
+            ]
+            .into_iter()
+            .chain(elements),
+        )
+        .with_meta(
+            FileMetaTableBuilder::new()
+                .transfer_syntax("1.2.840.10008.1.2.1")
+                .media_storage_sop_class_uid(sop_class_uid)
+                .media_storage_sop_instance_uid(instance_uid),
+        )
+        .expect("test object should build file meta");
+        let mut bytes = Vec::new();
+        object
+            .write_all(&mut bytes)
+            .expect("test object should serialize");
+        bytes
+    }
+
+    fn mammo_file(instance_uid: &str, view_position: &str, laterality: &str) -> DicomSource {
+        let bytes = part10_test_bytes(
+            "1.2.840.10008.5.1.4.1.1.1.2",
+            instance_uid,
+            [
+                DataElement::new(Tag(0x0008, 0x0060), VR::CS, "MG"),
+                DataElement::new(Tag(0x0018, 0x5101), VR::CS, view_position),
+                DataElement::new(Tag(0x0020, 0x0062), VR::CS, laterality),
+            ],
+        );
+        dicom_source_from_bytes_with_identity(instance_uid, instance_uid, bytes)
+    }
+
+    #[test]
+    fn file_views_selects_by_laterality_missing_from_metadata() {
+        let files = [
+            ("rcc", "CC", "R"),
+            ("lcc", "CC", "L"),
+            ("rmlo", "MLO", "R"),
+            ("lmlo", "MLO", "L"),
+            ("rxccl", "XCCL", "R"),
+        ];
+        let mut instances = files
+            .iter()
+            .zip(1..)
+            .map(|((uid, view, _), number)| metadata_instance(uid, Some(view), None, Some(number)))
+            .collect::<Vec<_>>();
+        assert!(reduce_series_instances(instances.clone()).is_err());
+
+        let download = |instance: &MetadataInstance| {
+            let (uid, view, laterality) = files
+                .iter()
+                .find(|(uid, ..)| *uid == instance.instance_uid)
+                .context("unknown instance")?;
+            Ok(mammo_file(uid, view, laterality))
+        };
+        ViewClassifier::default()
+            .correct(&mut instances, |_| {
+                bail!("a disabled check downloads nothing")
+            })
+            .expect("disabled check is a no-op");
+        let mut check = ViewClassifier {
+            verify_files: true,
+            ..ViewClassifier::default()
+        };
+        check
+            .correct(&mut instances, download)
+            .expect("files should be checked");
+        assert_eq!(instances[0].laterality.as_deref(), Some("R"));
+
+        let selected =
+            reduce_series_instances(instances).expect("file tags should complete the quartet");
+        let selected_uids = selected
+            .iter()
+            .map(|instance| instance.instance_uid.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(selected_uids, vec!["rcc", "lcc", "rmlo", "lmlo"]);
+        // The selected files are reused rather than downloaded again.
+        assert!(selected
+            .iter()
+            .all(|instance| check.prefetched(instance).is_some()));
+    }
+
+    #[test]
+    fn view_classification_mismatch_reports_view_or_laterality_differences() {
+        let json = MammoViewKey::classify(Some("CC"), None, Some(1));
+        let file = MammoViewKey::classify(Some("CC"), Some("L"), Some(1));
+        assert_eq!(
+            view_classification_mismatch(json, file).as_deref(),
+            Some("metadata says ? CC, file says L CC")
+        );
+        assert_eq!(view_classification_mismatch(file, file), None);
+        // Instance numbers do not affect placement and are not compared.
+        let renumbered = MammoViewKey::classify(Some("CC"), Some("L"), Some(7));
+        assert_eq!(view_classification_mismatch(file, renumbered), None);
+    }
+
+    #[test]
+    fn wado_uri_instance_url_appends_legacy_query() {
+        assert_eq!(
+            wado_uri_instance_url("http://pacs.local/wado", "1.2", Some("1.2.3"), "1.2.3.4"),
+            "http://pacs.local/wado?requestType=WADO&studyUID=1.2&seriesUID=1.2.3&objectUID=1.2.3.4&contentType=application%2Fdicom"
+        );
+        assert_eq!(
+            wado_uri_instance_url("http://pacs.local/wado?key=abc", "1.2", None, "1.2.3.4"),
+            "http://pacs.local/wado?key=abc&requestType=WADO&studyUID=1.2&objectUID=1.2.3.4&contentType=application%2Fdicom"
+        );
+    }
+
+    #[test]
+    fn parse_timeout_secs_accepts_positive_seconds_only() {
+        assert_eq!(parse_timeout_secs(" 600 "), Some(Duration::from_secs(600)));
+        assert_eq!(parse_timeout_secs("0"), None);
+        assert_eq!(parse_timeout_secs("-5"), None);
+        assert_eq!(parse_timeout_secs("fast"), None);
+    }
+
     #[test]
     fn split_top_level_objects_works() {
         let text = r#"[{"a":1},{"b":2},{"c":{"x":3}}]"#;
@@ -1207,7 +2031,7 @@ mod tests {
             BASIC_TEXT_SR_SOP_CLASS_UID
         );
 
-        let instances = parse_metadata_instances(&json).expect("metadata should parse");
+        let instances = parse_metadata_instances(&json, &[]).expect("metadata should parse");
 
         assert_eq!(instances.len(), 1);
         assert_eq!(
@@ -1217,6 +2041,21 @@ mod tests {
         assert_eq!(instances[0].modality.as_deref(), Some("MG"));
     }
 
+    #[test]
+    fn metadata_view_key_consults_view_fallback_tags() {
+        let json = r#"[{"00080018":{"vr":"UI","Value":["inst"]},"00080060":{"vr":"CS","Value":["MG"]},"00181400":{"vr":"LO","Value":["R MLO 2D"]}}]"#;
+
+        let instances = parse_metadata_instances(json, &[]).expect("metadata should parse");
+        let key = mammo_view_key(&instances[0]);
+        assert_eq!((key.view, key.laterality), (None, None));
+
+        let fallback_tags = ["AcquisitionDeviceProcessingDescription".to_string()];
+        let instances =
+            parse_metadata_instances(json, &fallback_tags).expect("metadata should parse");
+        let key = mammo_view_key(&instances[0]);
+        assert_eq!((key.view, key.laterality), (Some("MLO"), Some("R")));
+    }
+
     #[test]
     fn parse_metadata_instances_prefers_top_level_series_uid_for_gsps() {
         let json = format!(
@@ -1230,7 +2069,7 @@ mod tests {
             GSPS_SOP_CLASS_UID
         );
 
-        let instances = parse_metadata_instances(&json).expect("metadata should parse");
+        let instances = parse_metadata_instances(&json, &[]).expect("metadata should parse");
 
         assert_eq!(instances.len(), 1);
         assert_eq!(
@@ -1245,6 +2084,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn remote_file_name_uses_last_path_segment() {
+        assert_eq!(
+            remote_file_name(strip_query_and_fragment(
+                "https://bucket.example.com/studies/scan.dcm?X-Amz-Signature=abc"
+            )),
+            "scan.dcm"
+        );
+        assert_eq!(
+            remote_file_name("https://bucket.example.com/"),
+            "remote.dcm"
+        );
+    }
+
+    #[test]
+    fn url_errors_redact_the_query_string() {
+        assert_eq!(
+            redacted_url("https://bucket.example.com/scan.dcm?X-Amz-Signature=abc"),
+            "https://bucket.example.com/scan.dcm?<redacted>"
+        );
+        assert_eq!(
+            redacted_url("https://bucket.example.com/scan.dcm"),
+            "https://bucket.example.com/scan.dcm"
+        );
+
+        // Nothing listens on port 1, so the request fails before any response.
+        let client = Client::builder()
+            .timeout(Duration::from_secs(5))
+            .build()
+            .expect("test client should build");
+        let err = http_get_bytes(
+            &client,
+            "http://127.0.0.1:1/scan.dcm?X-Amz-Signature=secret",
+            "application/dicom",
+            None,
+        )
+        .expect_err("request to a closed port should fail");
+        let message = format!("{err:#}");
+        assert!(!message.contains("secret"), "{message}");
+        assert!(message.contains("http://127.0.0.1:1/scan.dcm?<redacted>"));
+    }
+
     #[test]
     fn normalize_base_url_adds_dicomweb_path_for_root_url() {
         assert_eq!(
@@ -1298,6 +2179,139 @@ mod tests {
         assert_eq!(extracted, payload);
     }
 
+    #[test]
+    fn extract_dicom_from_multipart_handles_lf_only_servers() {
+        let payload = b"DICOM\r\nBYTES";
+        let body = [
+            b"--b\nContent-Type: application/dicom\n\n".as_slice(),
+            payload.as_slice(),
+            b"\n--b--\n".as_slice(),
+        ]
+        .concat();
+        let extracted = extract_dicom_from_multipart(&body).expect("LF multipart should parse");
+        assert_eq!(extracted, payload);
+    }
+
+    #[test]
+    fn extract_dicom_from_multipart_skips_boundary_bytes_inside_payload() {
+        let payload = b"\x00\x01\r\n--my-boundaryXYZ\x02\x03\n--my-boundary\x04";
+        let body = [
+            b"--my-boundary\r\nContent-Type: application/dicom\r\n\r\n".as_slice(),
+            payload.as_slice(),
+            b"\r\n--my-boundary--\r\n".as_slice(),
+        ]
+        .concat();
+        let extracted =
+            extract_dicom_from_multipart(&body).expect("multipart with boundary-like data");
+        assert_eq!(extracted, payload);
+    }
+
+    #[test]
+    fn extract_dicom_from_multipart_accepts_close_delimiter_without_line_break() {
+        let payload = b"DICOM-BYTES";
+        let body = [
+            b"--my-boundary\r\nContent-Type: application/dicom\r\n\r\n".as_slice(),
+            payload.as_slice(),
+            b"--my-boundary--".as_slice(),
+        ]
+        .concat();
+        let extracted = extract_dicom_from_multipart(&body)
+            .expect("close delimiter without a leading line break should parse");
+        assert_eq!(extracted, payload);
+    }
+
+    #[test]
+    fn extract_dicom_from_multipart_skips_leading_blank_lines_and_padding() {
+        let payload = b"DICOM-BYTES";
+        let body = [
+            b"\r\n--my-boundary \t\r\nContent-Type: application/dicom\r\n\r\n".as_slice(),
+            payload.as_slice(),
+            b"\r\n--my-boundary--\r\n".as_slice(),
+        ]
+        .concat();
+        let extracted = extract_dicom_from_multipart(&body)
+            .expect("blank lines and padding around the first delimiter should parse");
+        assert_eq!(extracted, payload);
+    }
+
+    #[test]
+    fn downloaded_multipart_instance_without_meta_group_length_opens() {
+        let mut dicom_bytes = part10_test_bytes(
+            "1.2.840.10008.5.1.4.1.1.7",
+            "1.2.3.4",
+            [
+                DataElement::new(Tag(0x0008, 0x0060), VR::CS, "OT"),
+                DataElement::new(Tag(0x0028, 0x0002), VR::US, PrimitiveValue::from(1_u16)),
+                DataElement::new(Tag(0x0028, 0x0004), VR::CS, "MONOCHROME2"),
+                DataElement::new(Tag(0x0028, 0x0010), VR::US, PrimitiveValue::from(1_u16)),
+                DataElement::new(Tag(0x0028, 0x0011), VR::US, PrimitiveValue::from(1_u16)),
+                DataElement::new(Tag(0x0028, 0x0100), VR::US, PrimitiveValue::from(8_u16)),
+                DataElement::new(Tag(0x0028, 0x0101), VR::US, PrimitiveValue::from(8_u16)),
+                DataElement::new(Tag(0x0028, 0x0102), VR::US, PrimitiveValue::from(7_u16)),
+                DataElement::new(Tag(0x0028, 0x0103), VR::US, PrimitiveValue::from(0_u16)),
+                DataElement::new(
+                    Tag(0x7FE0, 0x0010),
+                    VR::OB,
+                    PrimitiveValue::from(vec![64_u8]),
+                ),
+            ],
+        );
+        // Drop (0002,0000) FileMetaInformationGroupLength, which directly
+        // follows the preamble and the DICM prefix.
+        assert_eq!(&dicom_bytes[132..136], &[0x02, 0x00, 0x00, 0x00]);
+        dicom_bytes.drain(132..144);
+
+        let body = [
+            b"This is a multi-part message in MIME format.\r\n".as_slice(),
+            b"--wado-boundary\r\nContent-Type: application/dicom\r\n\r\n".as_slice(),
+            dicom_bytes.as_slice(),
+            b"\r\n--wado-boundary--\r\n".as_slice(),
+        ]
+        .concat();
+        let normalized = unwrap_dicom_multipart(body);
+        assert_eq!(normalized, dicom_bytes);
+
+        let source =
+            dicom_source_from_bytes_with_identity("1.2.3.4", "1.2.3.4", normalized.clone());
+        let image = load_dicom(source, &DicomLoadOptions::default())
+            .expect("repaired download should open");
+        assert_eq!((image.width, image.height), (1, 1));
+
+        let path = std::env::temp_dir().join(format!(
+            "perspecta-multipart-download-test-{}-{}.dcm",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos()
+        ));
+        std::fs::write(&path, &normalized).expect("test file should write");
+        let image = load_dicom(&path, &DicomLoadOptions::default());
+        let _ = std::fs::remove_file(&path);
+        let image = image.expect("repaired download should open from a file");
+        assert_eq!((image.width, image.height), (1, 1));
+    }
+
+    #[test]
+    fn extract_dicom_from_multipart_skips_an_arbitrary_preamble() {
+        let payload = b"DICOM-BYTES";
+        let body = [
+            b"preamble text\r\n--not the boundary line\r\n".as_slice(),
+            b"--my-boundary\r\nContent-Type: application/dicom\r\n\r\n".as_slice(),
+            payload.as_slice(),
+            b"\r\n--my-boundary--\r\n".as_slice(),
+        ]
+        .concat();
+        let extracted = extract_dicom_from_multipart(&body)
+            .expect("a preamble before the delimiter is allowed");
+        assert_eq!(extracted, payload);
+
+        let mut part10 = vec![0_u8; 128];
+        part10.extend_from_slice(b"DICM");
+        part10.extend_from_slice(&body);
+        assert!(extract_dicom_from_multipart(&part10).is_none());
+    }
+
     #[test]
     fn extract_dicom_from_multipart_ignores_plain_payload() {
         let body = b"plain-dicom-payload".to_vec();
@@ -1338,6 +2352,67 @@ mod tests {
         assert_eq!(ordered_uids, vec!["inst_rcc", "inst_rmlo_1", "inst_lmlo_1"]);
     }
 
+    #[test]
+    fn sort_instances_for_mammo_breaks_instance_number_ties_by_numeric_uid() {
+        let mut instances = vec![
+            metadata_instance("1.2.10", None, None, None),
+            metadata_instance("1.2.3.2", None, None, Some(-1)),
+            metadata_instance("1.2.9", None, None, None),
+            metadata_instance("1.2.11", None, None, Some(5)),
+            metadata_instance("1.2.3.1", None, None, Some(5)),
+        ];
+        let expected = vec!["1.2.3.2", "1.2.3.1", "1.2.11", "1.2.9", "1.2.10"];
+
+        sort_instances_for_mammo(&mut instances);
+        let ordered = instances
+            .iter()
+            .map(|instance| instance.instance_uid.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ordered, expected);
+
+        instances.reverse();
+        sort_instances_for_mammo(&mut instances);
+        let reordered = instances
+            .iter()
+            .map(|instance| instance.instance_uid.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(reordered, expected);
+    }
+
+    #[test]
+    fn representative_series_instance_skips_non_images_and_takes_first_in_order() {
+        let mut gsps = metadata_instance("1.2.1", None, None, Some(1));
+        gsps.sop_class_uid = Some(GSPS_SOP_CLASS_UID.to_string());
+        let instances = vec![
+            metadata_instance("1.2.4", None, None, Some(3)),
+            gsps,
+            metadata_instance("1.2.3", None, None, Some(2)),
+        ];
+
+        let representative =
+            representative_series_instance(instances).expect("image instance should be chosen");
+        assert_eq!(representative.instance_uid, "1.2.3");
+
+        let mut report = metadata_instance("1.2.5", None, None, None);
+        report.sop_class_uid = Some(BASIC_TEXT_SR_SOP_CLASS_UID.to_string());
+        assert!(representative_series_instance(vec![report]).is_none());
+    }
+
+    #[test]
+    fn series_tab_label_prefers_number_and_description() {
+        let mut instance = metadata_instance("1.2.3", None, None, None);
+        assert_eq!(series_tab_label(&instance, 2), "Series 3");
+
+        instance.series_number = Some(7);
+        assert_eq!(series_tab_label(&instance, 2), "Series 7");
+
+        instance.series_description = Some("Tomo L CC".to_string());
+        assert_eq!(series_tab_label(&instance, 2), "7: Tomo L CC");
+
+        instance.series_number = None;
+        assert_eq!(series_tab_label(&instance, 2), "Tomo L CC");
+    }
+
     #[test]
     fn select_group_instances_single_reduced_set_keeps_all_in_order() {
         let selected = select_group_instances_from_reduced_sets(vec![vec![
@@ -1538,6 +2613,9 @@ mod tests {
             view_position: None,
             laterality: None,
             instance_number: Some(1),
+            series_number: None,
+            series_description: None,
+            view_fallback_values: Vec::new(),
         };
 
         assert_eq!(metadata_instance_kind(&instance), DicomPathKind::Other);
@@ -1554,6 +2632,9 @@ mod tests {
                 view_position: Some("CC".to_string()),
                 laterality: Some("R".to_string()),
                 instance_number: Some(1),
+                series_number: None,
+                series_description: None,
+                view_fallback_values: Vec::new(),
             },
             MetadataInstance {
                 series_uid: Some("series_a".to_string()),
@@ -1563,17 +2644,25 @@ mod tests {
                 view_position: Some("MLO".to_string()),
                 laterality: Some("L".to_string()),
                 instance_number: Some(2),
+                series_number: None,
+                series_description: None,
+                view_fallback_values: Vec::new(),
             },
         ];
 
         let mut updates = Vec::<DicomWebGroupStreamUpdate>::new();
         let mut on_path = |update: DicomWebGroupStreamUpdate| updates.push(update);
-        let result = download_instances_streaming_with(&instances, &mut on_path, |instance| {
-            Ok(DicomSource::from(PathBuf::from(format!(
-                "{}.dcm",
-                instance.instance_uid
-            ))))
-        })
+        let result = download_instances_streaming_with(
+            &instances,
+            &mut on_path,
+            |instance| {
+                Ok(DicomSource::from(PathBuf::from(format!(
+                    "{}.dcm",
+                    instance.instance_uid
+                ))))
+            },
+            None,
+        )
         .expect("streaming should succeed");
 
         let callback_paths = updates
@@ -1581,7 +2670,10 @@ mod tests {
             .filter_map(|update| match update {
                 DicomWebGroupStreamUpdate::ActivePath(path) => Some(path),
                 DicomWebGroupStreamUpdate::ActiveGroupInstanceCount(_)
-                | DicomWebGroupStreamUpdate::BackgroundGroupReady { .. } => None,
+                | DicomWebGroupStreamUpdate::BackgroundGroupInstanceCount { .. }
+                | DicomWebGroupStreamUpdate::BackgroundGroupPath { .. }
+                | DicomWebGroupStreamUpdate::BackgroundGroupReady { .. }
+                | DicomWebGroupStreamUpdate::ActiveDownloadFailed { .. } => None,
             })
             .collect::<Vec<_>>();
 
@@ -1596,21 +2688,28 @@ mod tests {
 
         let mut memory_updates = Vec::<DicomWebGroupStreamUpdate>::new();
         let mut on_memory_path = |update: DicomWebGroupStreamUpdate| memory_updates.push(update);
-        let memory_result =
-            download_instances_streaming_with(&instances, &mut on_memory_path, |instance| {
+        let memory_result = download_instances_streaming_with(
+            &instances,
+            &mut on_memory_path,
+            |instance| {
                 Ok(DicomSource::from_memory(
                     &instance.instance_uid,
                     instance.instance_uid.as_bytes().to_vec(),
                 ))
-            })
-            .expect("memory-backed streaming should succeed");
+            },
+            None,
+        )
+        .expect("memory-backed streaming should succeed");
 
         let memory_callback_paths = memory_updates
             .into_iter()
             .filter_map(|update| match update {
                 DicomWebGroupStreamUpdate::ActivePath(path) => Some(path),
                 DicomWebGroupStreamUpdate::ActiveGroupInstanceCount(_)
-                | DicomWebGroupStreamUpdate::BackgroundGroupReady { .. } => None,
+                | DicomWebGroupStreamUpdate::BackgroundGroupInstanceCount { .. }
+                | DicomWebGroupStreamUpdate::BackgroundGroupPath { .. }
+                | DicomWebGroupStreamUpdate::BackgroundGroupReady { .. }
+                | DicomWebGroupStreamUpdate::ActiveDownloadFailed { .. } => None,
             })
             .collect::<Vec<_>>();
 
@@ -1635,4 +2734,128 @@ mod tests {
             .iter()
             .all(|path| matches!(path, DicomSource::Memory { .. })));
     }
+
+    #[test]
+    fn download_instances_streaming_keeps_going_past_a_failed_image_when_retryable() {
+        let instances = vec![
+            metadata_instance("inst_1", Some("CC"), Some("R"), Some(1)),
+            metadata_instance("inst_2", Some("MLO"), Some("R"), Some(2)),
+            metadata_instance("inst_3", Some("CC"), Some("L"), Some(3)),
+        ];
+        let downloader = |instance: &MetadataInstance| {
+            if instance.instance_uid == "inst_2" {
+                bail!("connection reset");
+            }
+            Ok(DicomSource::from(PathBuf::from(format!(
+                "{}.dcm",
+                instance.instance_uid
+            ))))
+        };
+        let redownload = |instance: &MetadataInstance| InstanceRedownload {
+            instance: instance.clone(),
+            ..InstanceRedownload::test_stub(&instance.instance_uid)
+        };
+
+        let mut updates = Vec::<DicomWebGroupStreamUpdate>::new();
+        let mut on_path = |update: DicomWebGroupStreamUpdate| updates.push(update);
+        let result = download_instances_streaming_with(
+            &instances,
+            &mut on_path,
+            downloader,
+            Some(&redownload),
+        )
+        .expect("a failed image should not fail the group");
+
+        assert_eq!(result.len(), 3);
+        let failed = updates
+            .iter()
+            .filter_map(|update| match update {
+                DicomWebGroupStreamUpdate::ActiveDownloadFailed { path, error, retry } => {
+                    Some((path, error, retry))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(failed.len(), 1);
+        let (path, error, retry) = failed[0];
+        assert!(error.contains("connection reset"));
+        assert_eq!(retry.instance.instance_uid, "inst_2");
+        assert_eq!(path, &result[1]);
+        assert_eq!(
+            path.identity_key(),
+            instance_identity_key("study_a", "inst_2").as_str()
+        );
+
+        let mut on_path = |_: DicomWebGroupStreamUpdate| {};
+        assert!(
+            download_instances_streaming_with(&instances, &mut on_path, downloader, None).is_err()
+        );
+    }
+
+    #[test]
+    fn download_instances_parallel_reports_each_arrival_and_keeps_instance_order() {
+        let instances = ["inst_1", "inst_2", "inst_3"]
+            .into_iter()
+            .map(|instance_uid| MetadataInstance {
+                series_uid: Some("series_a".to_string()),
+                instance_uid: instance_uid.to_string(),
+                sop_class_uid: None,
+                modality: Some("MG".to_string()),
+                view_position: None,
+                laterality: None,
+                instance_number: None,
+                series_number: None,
+                series_description: None,
+                view_fallback_values: Vec::new(),
+            })
+            .collect::<Vec<_>>();
+
+        // The first instance finishes only after both later arrivals have
+        // been reported, so arrival order differs from instance order.
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let release_rx = std::sync::Mutex::new(release_rx);
+        let mut arrivals = Vec::new();
+        let mut on_arrival = |path: &DicomSource| {
+            arrivals.push(path.clone());
+            let _ = release_tx.send(());
+        };
+        let result =
+            download_instances_parallel_with(&instances, Some(&mut on_arrival), |instance| {
+                if instance.instance_uid == "inst_1" {
+                    let release_rx = release_rx.lock().expect("release receiver should lock");
+                    for _ in 0..2 {
+                        release_rx
+                            .recv_timeout(Duration::from_secs(10))
+                            .expect("later instances should be reported first");
+                    }
+                }
+                Ok(DicomSource::from(PathBuf::from(format!(
+                    "{}.dcm",
+                    instance.instance_uid
+                ))))
+            })
+            .expect("parallel download should succeed");
+
+        assert_eq!(
+            result,
+            vec![
+                PathBuf::from("inst_1.dcm"),
+                PathBuf::from("inst_2.dcm"),
+                PathBuf::from("inst_3.dcm")
+            ]
+        );
+        assert_eq!(arrivals.len(), 3);
+        assert_eq!(
+            arrivals.last(),
+            Some(&DicomSource::from(PathBuf::from("inst_1.dcm")))
+        );
+
+        let failed = download_instances_parallel_with(&instances, None, |instance| {
+            if instance.instance_uid == "inst_2" {
+                bail!("boom");
+            }
+            Ok(DicomSource::from(PathBuf::from("ok.dcm")))
+        });
+        assert!(failed.is_err());
+    }
 }
@@ -1,4 +1,5 @@
-use std::path::PathBuf;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DicomWebLaunchRequest {
@@ -8,6 +9,11 @@ pub struct DicomWebLaunchRequest {
     pub instance_uid: Option<String>,
     pub username: Option<String>,
     pub password: Option<String>,
+    /// Retrieve the instance through legacy WADO-URI instead of WADO-RS.
+    pub wado_uri: bool,
+    /// Series opened as switchable tabs, one representative image each, from
+    /// a comma-separated `series=`. `series_uid` is unset when this is used.
+    pub series_tabs: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -20,6 +26,15 @@ pub struct DicomWebGroupedLaunchRequest {
     pub password: Option<String>,
 }
 
+/// A single DICOM file fetched with a plain HTTP(S) GET, such as a presigned
+/// object-store link, rather than through DICOMweb.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteFileLaunchRequest {
+    pub url: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LaunchRequest {
     LocalPaths(Vec<PathBuf>),
@@ -29,6 +44,294 @@ pub enum LaunchRequest {
     },
     DicomWebGroups(DicomWebGroupedLaunchRequest),
     DicomWeb(DicomWebLaunchRequest),
+    RemoteFile(RemoteFileLaunchRequest),
+}
+
+/// Startup appearance that a deep link may force with `theme=` (or `ui_theme=`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UiTheme {
+    Dark,
+    Light,
+    #[default]
+    Black,
+}
+
+impl UiTheme {
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "dark" => Some(Self::Dark),
+            "light" => Some(Self::Light),
+            "black" => Some(Self::Black),
+            _ => None,
+        }
+    }
+}
+
+pub fn parse_launch_theme_from_args(args: &[String]) -> Result<Option<UiTheme>, String> {
+    if args.len() == 1 && is_perspecta_uri(&args[0]) {
+        return parse_perspecta_uri_theme(&args[0]);
+    }
+    Ok(None)
+}
+
+pub fn parse_perspecta_uri_theme(uri: &str) -> Result<Option<UiTheme>, String> {
+    let mut theme = None;
+    for value in query_values(uri, &["theme", "ui_theme"])? {
+        theme = Some(
+            UiTheme::parse(&value)
+                .ok_or_else(|| "theme must be one of dark, light, or black.".to_string())?,
+        );
+    }
+    Ok(theme)
+}
+
+/// Reads `cine=` from a single perspecta:// argument: whether multi-frame
+/// images start cine playback when they open, overriding the saved setting
+/// for this session.
+pub fn parse_launch_cine_from_args(args: &[String]) -> Result<Option<bool>, String> {
+    if args.len() == 1 && is_perspecta_uri(&args[0]) {
+        return parse_perspecta_uri_cine(&args[0]);
+    }
+    Ok(None)
+}
+
+pub fn parse_perspecta_uri_cine(uri: &str) -> Result<Option<bool>, String> {
+    let mut cine = None;
+    for value in query_values(uri, &["cine"])? {
+        cine = Some(match value.trim().to_ascii_lowercase().as_str() {
+            "on" => true,
+            "off" => false,
+            other => parse_flag_value(other)
+                .ok_or_else(|| "cine must be 1/0, true/false, yes/no, or on/off.".to_string())?,
+        });
+    }
+    Ok(cine)
+}
+
+/// Reads `frames=start-end` from a single perspecta:// argument: the 1-based,
+/// inclusive frame range the opened image is trimmed to, returned as 0-based
+/// frame indices.
+pub fn parse_launch_frames_from_args(
+    args: &[String],
+) -> Result<Option<RangeInclusive<usize>>, String> {
+    if args.len() == 1 && is_perspecta_uri(&args[0]) {
+        return parse_perspecta_uri_frames(&args[0]);
+    }
+    Ok(None)
+}
+
+pub fn parse_perspecta_uri_frames(uri: &str) -> Result<Option<RangeInclusive<usize>>, String> {
+    let mut frames = None;
+    for value in query_values(uri, &["frames"])? {
+        let invalid = || "frames must be a range of frame numbers such as 20-45.".to_string();
+        let (start, end) = value.split_once('-').ok_or_else(invalid)?;
+        let start = start.trim().parse::<usize>().map_err(|_| invalid())?;
+        let end = end.trim().parse::<usize>().map_err(|_| invalid())?;
+        if start == 0 || end < start {
+            return Err(
+                "frames must start at frame 1 or later and end at or after its start.".to_string(),
+            );
+        }
+        frames = Some(start - 1..=end - 1);
+    }
+    Ok(frames)
+}
+
+/// Decoded, non-empty values of the query parameters named by one of `keys`
+/// (case-insensitive), in URL order; every other parameter is ignored.
+fn query_values(uri: &str, keys: &[&str]) -> Result<Vec<String>, String> {
+    let rest = strip_perspecta_scheme(uri)
+        .ok_or_else(|| "URL must start with perspecta://".to_string())?;
+    let (_, query) = split_location_and_query(rest);
+    let Some(query_string) = query else {
+        return Ok(Vec::new());
+    };
+
+    let mut values = Vec::new();
+    for pair in query_string.split('&') {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let key = key.trim().to_ascii_lowercase();
+        if !keys.contains(&key.as_str()) {
+            continue;
+        }
+        let decoded_value = percent_decode(value)?;
+        if decoded_value.trim().is_empty() {
+            continue;
+        }
+        values.push(decoded_value);
+    }
+    Ok(values)
+}
+
+/// Parses `--prefetch <perspecta://...>`, which downloads a DICOMweb launch
+/// headlessly instead of opening the viewer. Returns `Ok(None)` when the
+/// arguments are not a prefetch invocation.
+pub fn parse_prefetch_request_from_args(args: &[String]) -> Result<Option<LaunchRequest>, String> {
+    if args.first().map(String::as_str) != Some("--prefetch") {
+        return Ok(None);
+    }
+    let [_, uri] = args else {
+        return Err("--prefetch expects exactly one perspecta:// DICOMweb URL.".to_string());
+    };
+    if !is_perspecta_uri(uri) {
+        return Err("--prefetch expects a perspecta:// URL.".to_string());
+    }
+    match parse_perspecta_uri(uri)? {
+        request @ (LaunchRequest::DicomWeb(_) | LaunchRequest::DicomWebGroups(_)) => {
+            Ok(Some(request))
+        }
+        LaunchRequest::LocalPaths(_)
+        | LaunchRequest::LocalGroups { .. }
+        | LaunchRequest::RemoteFile(_) => {
+            Err("--prefetch only supports DICOMweb launch URLs.".to_string())
+        }
+    }
+}
+
+/// A headless `--dump-meta [--all-tags] <file>` invocation that prints a
+/// file's metadata as JSON instead of opening the viewer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DumpMetaRequest {
+    pub path: PathBuf,
+    pub all_tags: bool,
+}
+
+/// Parses `--dump-meta [--all-tags] <file>`. Returns `Ok(None)` when the
+/// arguments are not a metadata dump invocation.
+pub fn parse_dump_meta_request_from_args(
+    args: &[String],
+) -> Result<Option<DumpMetaRequest>, String> {
+    if args.first().map(String::as_str) != Some("--dump-meta") {
+        return Ok(None);
+    }
+    match &args[1..] {
+        [path] if path != "--all-tags" => Ok(Some(DumpMetaRequest {
+            path: PathBuf::from(path),
+            all_tags: false,
+        })),
+        [flag, path] | [path, flag] if flag == "--all-tags" && path != "--all-tags" => {
+            Ok(Some(DumpMetaRequest {
+                path: PathBuf::from(path),
+                all_tags: true,
+            }))
+        }
+        _ => Err("--dump-meta expects one DICOM file path and optionally --all-tags.".to_string()),
+    }
+}
+
+/// A `--worklist <file> [--dicomweb <base-url>]` invocation: a reading list
+/// of studies stepped through one at a time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorklistArgs {
+    pub path: PathBuf,
+    /// DICOMweb server that bare study UIDs in the list are retrieved from.
+    pub dicomweb_base: Option<String>,
+}
+
+/// Parses `--worklist <file> [--dicomweb <base-url>]`, with the flags in any
+/// order. Returns `Ok(None)` when the arguments are not a worklist invocation.
+pub fn parse_worklist_args(args: &[String]) -> Result<Option<WorklistArgs>, String> {
+    if !args.iter().any(|arg| arg == "--worklist") {
+        return Ok(None);
+    }
+    let usage = || {
+        "--worklist expects one list file and optionally --dicomweb <http(s) base URL>.".to_string()
+    };
+    let mut path = None;
+    let mut dicomweb_base = None;
+    let mut rest = args.iter();
+    while let Some(flag) = rest.next() {
+        let value = rest
+            .next()
+            .filter(|value| !value.starts_with("--"))
+            .ok_or_else(usage)?;
+        match flag.as_str() {
+            "--worklist" if path.is_none() => path = Some(PathBuf::from(value)),
+            "--dicomweb" if dicomweb_base.is_none() && is_http_url(value) => {
+                dicomweb_base = Some(value.trim().trim_end_matches('/').to_string());
+            }
+            _ => return Err(usage()),
+        }
+    }
+    Ok(Some(WorklistArgs {
+        path: path.ok_or_else(usage)?,
+        dicomweb_base,
+    }))
+}
+
+/// Reads a worklist: one study per line, as a perspecta:// URL, a WADO-RS
+/// study URL, or a bare StudyInstanceUID retrieved from `dicomweb_base`.
+/// Blank lines and lines starting with `#` are skipped. Relative local paths
+/// are resolved against `list_dir`, the directory holding the list file.
+pub fn parse_worklist(
+    text: &str,
+    dicomweb_base: Option<&str>,
+    list_dir: &Path,
+) -> Result<Vec<LaunchRequest>, String> {
+    let mut requests = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let request = if is_perspecta_uri(line) {
+            parse_perspecta_uri(line)
+        } else if is_http_url(line) {
+            parse_dicomweb_url(line)
+        } else if is_dicom_uid(line) {
+            match dicomweb_base {
+                Some(base_url) => Ok(LaunchRequest::DicomWeb(DicomWebLaunchRequest {
+                    base_url: base_url.to_string(),
+                    study_uid: line.to_string(),
+                    series_uid: None,
+                    instance_uid: None,
+                    username: None,
+                    password: None,
+                    wado_uri: false,
+                    series_tabs: Vec::new(),
+                })),
+                None => Err("a bare study UID needs --dicomweb <base-url>".to_string()),
+            }
+        } else {
+            Err("expected a perspecta:// URL, a DICOMweb URL, or a study UID".to_string())
+        };
+        let request = request.map_err(|err| format!("Worklist line {}: {err}", index + 1))?;
+        requests.push(resolve_local_paths(request, list_dir));
+    }
+    if requests.is_empty() {
+        return Err("Worklist has no studies.".to_string());
+    }
+    Ok(requests)
+}
+
+fn resolve_local_paths(request: LaunchRequest, base_dir: &Path) -> LaunchRequest {
+    let resolve = |path: PathBuf| {
+        if path.is_relative() {
+            base_dir.join(path)
+        } else {
+            path
+        }
+    };
+    match request {
+        LaunchRequest::LocalPaths(paths) => {
+            LaunchRequest::LocalPaths(paths.into_iter().map(resolve).collect())
+        }
+        LaunchRequest::LocalGroups { groups, open_group } => LaunchRequest::LocalGroups {
+            groups: groups
+                .into_iter()
+                .map(|group| group.into_iter().map(resolve).collect())
+                .collect(),
+            open_group,
+        },
+        other => other,
+    }
+}
+
+fn is_dicom_uid(value: &str) -> bool {
+    value.len() <= 64
+        && value.split('.').all(|component| {
+            !component.is_empty() && component.bytes().all(|byte| byte.is_ascii_digit())
+        })
 }
 
 pub fn parse_launch_request_from_args(args: &[String]) -> Result<Option<LaunchRequest>, String> {
@@ -40,6 +343,10 @@ pub fn parse_launch_request_from_args(args: &[String]) -> Result<Option<LaunchRe
         return parse_perspecta_uri(&args[0]).map(Some);
     }
 
+    if args.len() == 1 && is_http_url(&args[0]) {
+        return parse_dicomweb_url(&args[0]).map(Some);
+    }
+
     if args[0] == "--open" {
         if args.len() == 1 {
             return Err("Missing file path(s) after --open.".to_string());
@@ -60,15 +367,18 @@ pub fn parse_perspecta_uri(uri: &str) -> Result<LaunchRequest, String> {
 
     let (location, query) = split_location_and_query(rest);
     let mut raw_paths = Vec::new();
+    let mut remote_urls = Vec::new();
     let mut grouped_paths = Vec::<Vec<String>>::new();
     let mut grouped_series_uids = Vec::<Vec<String>>::new();
     let mut dicomweb_base = None::<String>;
     let mut study_uid = None::<String>;
     let mut series_uid = None::<String>;
+    let mut series_tabs = Vec::<String>::new();
     let mut instance_uid = None::<String>;
     let mut dicomweb_username = None::<String>;
     let mut dicomweb_password = None::<String>;
     let mut open_group = None::<usize>;
+    let mut wado_uri = false;
 
     if let Some(path_from_location) = parse_location_path(location)? {
         raw_paths.push(path_from_location);
@@ -83,9 +393,18 @@ pub fn parse_perspecta_uri(uri: &str) -> Result<LaunchRequest, String> {
             let key = key.trim().to_ascii_lowercase();
             let decoded_value = percent_decode(value)?;
             match key.as_str() {
+                "path" | "file" if is_http_url(&decoded_value) => {
+                    remote_urls.push(decoded_value.trim().to_string());
+                }
                 "path" | "file" if !decoded_value.trim().is_empty() => {
                     raw_paths.push(decoded_value);
                 }
+                "url" if !decoded_value.trim().is_empty() => {
+                    if !is_http_url(&decoded_value) {
+                        return Err("url= must be an http:// or https:// URL.".to_string());
+                    }
+                    remote_urls.push(decoded_value.trim().to_string());
+                }
                 "paths" | "files" => {
                     let split_paths = split_path_list(&decoded_value);
                     for p in split_paths {
@@ -163,7 +482,18 @@ pub fn parse_perspecta_uri(uri: &str) -> Result<LaunchRequest, String> {
                 "series" | "seriesuid" | "seriesinstanceuid" | "series_instance_uid"
                     if !decoded_value.trim().is_empty() =>
                 {
-                    series_uid = Some(decoded_value.trim().to_string());
+                    let uids = split_path_list(&decoded_value)
+                        .into_iter()
+                        .map(|uid| uid.trim().to_string())
+                        .filter(|uid| !uid.is_empty())
+                        .collect::<Vec<_>>();
+                    if uids.len() > 1 {
+                        series_uid = None;
+                        series_tabs = uids;
+                    } else {
+                        series_uid = uids.into_iter().next();
+                        series_tabs.clear();
+                    }
                 }
                 "instance" | "instanceuid" | "sopinstanceuid" | "sop_instance_uid"
                     if !decoded_value.trim().is_empty() =>
@@ -180,6 +510,10 @@ pub fn parse_perspecta_uri(uri: &str) -> Result<LaunchRequest, String> {
                 {
                     dicomweb_password = Some(decoded_value.trim().to_string());
                 }
+                "wado_uri" | "wadouri" => {
+                    wado_uri = parse_flag_value(&decoded_value)
+                        .ok_or_else(|| "wado_uri must be 1, 0, true, or false.".to_string())?;
+                }
                 "auth" | "dicomweb_auth" => {
                     let trimmed = decoded_value.trim();
                     if trimmed.is_empty() {
@@ -203,6 +537,30 @@ pub fn parse_perspecta_uri(uri: &str) -> Result<LaunchRequest, String> {
         }
     }
 
+    if !remote_urls.is_empty() {
+        if remote_urls.len() > 1 {
+            return Err("Only one remote file URL (url=...) can be opened per launch.".to_string());
+        }
+        if !raw_paths.is_empty()
+            || !grouped_paths.is_empty()
+            || !grouped_series_uids.is_empty()
+            || dicomweb_base.is_some()
+        {
+            return Err(
+                "Cannot mix a remote file URL (url=...) with local paths or DICOMweb parameters."
+                    .to_string(),
+            );
+        }
+        if dicomweb_username.is_some() ^ dicomweb_password.is_some() {
+            return Err("Credentials must include both user and password.".to_string());
+        }
+        return Ok(LaunchRequest::RemoteFile(RemoteFileLaunchRequest {
+            url: remote_urls.remove(0),
+            username: dicomweb_username,
+            password: dicomweb_password,
+        }));
+    }
+
     if !grouped_paths.is_empty() {
         if !raw_paths.is_empty() {
             return Err(
@@ -227,13 +585,19 @@ pub fn parse_perspecta_uri(uri: &str) -> Result<LaunchRequest, String> {
         return Ok(LaunchRequest::LocalGroups { groups, open_group });
     }
 
+    if wado_uri && (!grouped_series_uids.is_empty() || dicomweb_base.is_none()) {
+        return Err(
+            "wado_uri=1 applies only to a single-instance DICOMweb launch (dicomweb=, study=, series=, instance=).".to_string(),
+        );
+    }
+
     if !grouped_series_uids.is_empty() {
         if !raw_paths.is_empty() {
             return Err(
                 "Cannot mix grouped DICOMweb launch (group_series=...) with path=/paths= parameters.".to_string(),
             );
         }
-        if series_uid.is_some() || instance_uid.is_some() {
+        if series_uid.is_some() || !series_tabs.is_empty() || instance_uid.is_some() {
             return Err(
                 "Cannot mix grouped DICOMweb launch (group_series=...) with series=/instance= parameters.".to_string(),
             );
@@ -273,6 +637,17 @@ pub fn parse_perspecta_uri(uri: &str) -> Result<LaunchRequest, String> {
         let Some(study_uid) = study_uid else {
             return Err("DICOMweb launch requires 'study' (StudyInstanceUID).".to_string());
         };
+        if wado_uri && (series_uid.is_none() || instance_uid.is_none()) {
+            return Err(
+                "wado_uri=1 requires series= and instance= because WADO-URI cannot list study contents.".to_string(),
+            );
+        }
+        if !series_tabs.is_empty() && instance_uid.is_some() {
+            return Err(
+                "instance= cannot be combined with several series= UIDs, which open as tabs."
+                    .to_string(),
+            );
+        }
         return Ok(LaunchRequest::DicomWeb(DicomWebLaunchRequest {
             base_url,
             study_uid,
@@ -280,6 +655,8 @@ pub fn parse_perspecta_uri(uri: &str) -> Result<LaunchRequest, String> {
             instance_uid,
             username: dicomweb_username,
             password: dicomweb_password,
+            wado_uri,
+            series_tabs,
         }));
     }
 
@@ -299,6 +676,98 @@ pub fn parse_perspecta_uri(uri: &str) -> Result<LaunchRequest, String> {
     ))
 }
 
+/// Builds a `perspecta://open?...` URL that reopens `request`.
+///
+/// DICOMweb and remote file credentials are intentionally left out so shared
+/// links never leak them. A presigned `url=` is kept whole, signature included.
+pub fn build_perspecta_uri(request: &LaunchRequest) -> String {
+    let mut params = Vec::<(&str, String)>::new();
+    match request {
+        LaunchRequest::LocalPaths(paths) => {
+            for path in paths {
+                params.push(("path", percent_encode(&path.to_string_lossy())));
+            }
+        }
+        LaunchRequest::LocalGroups { groups, open_group } => {
+            for group in groups {
+                let paths = group
+                    .iter()
+                    .map(|path| path.to_string_lossy().into_owned())
+                    .collect::<Vec<_>>();
+                params.push(("group", encode_path_list(&paths)));
+            }
+            params.push(("open_group", open_group.to_string()));
+        }
+        LaunchRequest::DicomWebGroups(request) => {
+            params.push(("dicomweb", percent_encode(&request.base_url)));
+            params.push(("study", percent_encode(&request.study_uid)));
+            for group in &request.groups {
+                params.push(("group_series", encode_path_list(group)));
+            }
+            params.push(("open_group", request.open_group.to_string()));
+        }
+        LaunchRequest::DicomWeb(request) => {
+            params.push(("dicomweb", percent_encode(&request.base_url)));
+            params.push(("study", percent_encode(&request.study_uid)));
+            if let Some(series_uid) = request.series_uid.as_deref() {
+                params.push(("series", percent_encode(series_uid)));
+            } else if !request.series_tabs.is_empty() {
+                params.push(("series", encode_path_list(&request.series_tabs)));
+            }
+            if let Some(instance_uid) = request.instance_uid.as_deref() {
+                params.push(("instance", percent_encode(instance_uid)));
+            }
+            if request.wado_uri {
+                params.push(("wado_uri", "1".to_string()));
+            }
+        }
+        LaunchRequest::RemoteFile(request) => {
+            params.push(("url", percent_encode(&request.url)));
+        }
+    }
+
+    let query = params
+        .into_iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join("&");
+    format!("perspecta://open?{query}")
+}
+
+fn parse_flag_value(value: &str) -> Option<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "" | "1" | "true" | "yes" => Some(true),
+        "0" | "false" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+fn is_http_url(value: &str) -> bool {
+    let value = value.trim().to_ascii_lowercase();
+    value.starts_with("http://") || value.starts_with("https://")
+}
+
+/// Builds a launch from a bare WADO-RS URL such as
+/// `https://pacs/dicom-web/studies/<uid>[/series/<uid>[/instances/<uid>]]`.
+fn parse_dicomweb_url(url: &str) -> Result<LaunchRequest, String> {
+    let parsed = parse_dicomweb_value(url);
+    let Some(study_uid) = parsed.study_uid else {
+        return Err(
+            "A DICOMweb URL argument must contain /studies/<StudyInstanceUID>.".to_string(),
+        );
+    };
+    Ok(LaunchRequest::DicomWeb(DicomWebLaunchRequest {
+        base_url: parsed.base_url,
+        study_uid,
+        series_uid: parsed.series_uid,
+        instance_uid: parsed.instance_uid,
+        username: None,
+        password: None,
+        wado_uri: false,
+        series_tabs: Vec::new(),
+    }))
+}
+
 fn is_perspecta_uri(value: &str) -> bool {
     strip_perspecta_scheme(value).is_some()
 }
@@ -447,13 +916,13 @@ fn percent_decode(value: &str) -> Result<String, String> {
                 index += 1;
             }
             b'%' => {
-                if index + 2 >= bytes.len() {
+                // Both hex digits must be present, including at the very end.
+                let digits = bytes.get(index + 1..index + 3).and_then(|pair| {
+                    Some((decode_hex_digit(pair[0])?, decode_hex_digit(pair[1])?))
+                });
+                let Some((hi, lo)) = digits else {
                     return Err("Invalid percent-encoding in URL.".to_string());
-                }
-                let hi = decode_hex_digit(bytes[index + 1])
-                    .ok_or_else(|| "Invalid percent-encoding in URL.".to_string())?;
-                let lo = decode_hex_digit(bytes[index + 2])
-                    .ok_or_else(|| "Invalid percent-encoding in URL.".to_string())?;
+                };
                 decoded.push((hi << 4) | lo);
                 index += 3;
             }
@@ -467,6 +936,31 @@ fn percent_decode(value: &str) -> Result<String, String> {
     String::from_utf8(decoded).map_err(|_| "URL contains invalid UTF-8 after decoding.".to_string())
 }
 
+fn encode_path_list(values: &[String]) -> String {
+    let mut encoded = values
+        .iter()
+        .map(|value| percent_encode(value))
+        .collect::<Vec<_>>()
+        .join("|");
+    // A lone entry containing a comma would otherwise be split by `split_path_list`.
+    if values.len() == 1 && values[0].contains(',') {
+        encoded.push('|');
+    }
+    encoded
+}
+
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
 fn decode_hex_digit(value: u8) -> Option<u8> {
     match value {
         b'0'..=b'9' => Some(value - b'0'),
@@ -490,6 +984,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_theme_query_alongside_launch_request() {
+        let uri = "perspecta://open?path=example-data%2Fa.dcm&theme=Light";
+
+        assert_eq!(parse_perspecta_uri_theme(uri), Ok(Some(UiTheme::Light)));
+        assert!(parse_perspecta_uri(uri).is_ok());
+        assert_eq!(
+            parse_perspecta_uri_theme("perspecta://open?path=a.dcm&ui_theme=dark"),
+            Ok(Some(UiTheme::Dark))
+        );
+        assert_eq!(
+            parse_perspecta_uri_theme("perspecta://open?path=a.dcm"),
+            Ok(None)
+        );
+        assert!(parse_perspecta_uri_theme("perspecta://open?path=a.dcm&theme=sepia").is_err());
+    }
+
+    #[test]
+    fn parse_cine_query_alongside_launch_request() {
+        let uri = "perspecta://open?path=example-data%2Fa.dcm&cine=1";
+
+        assert_eq!(parse_perspecta_uri_cine(uri), Ok(Some(true)));
+        assert!(parse_perspecta_uri(uri).is_ok());
+        assert_eq!(
+            parse_perspecta_uri_cine("perspecta://open?path=a.dcm&CINE=Off"),
+            Ok(Some(false))
+        );
+        assert_eq!(
+            parse_perspecta_uri_cine("perspecta://open?path=a.dcm&cine="),
+            Ok(None)
+        );
+        assert_eq!(
+            parse_perspecta_uri_cine("perspecta://open?path=a.dcm"),
+            Ok(None)
+        );
+        assert!(parse_perspecta_uri_cine("perspecta://open?path=a.dcm&cine=fast").is_err());
+    }
+
+    #[test]
+    fn parse_frames_param_as_zero_based_inclusive_range() {
+        let uri = "perspecta://open?path=example-data%2Fa.dcm&frames=20-45";
+
+        assert_eq!(parse_perspecta_uri_frames(uri), Ok(Some(19..=44)));
+        assert!(parse_perspecta_uri(uri).is_ok());
+        assert_eq!(
+            parse_perspecta_uri_frames("perspecta://open?path=a.dcm&FRAMES=%203-3"),
+            Ok(Some(2..=2))
+        );
+        assert_eq!(
+            parse_perspecta_uri_frames("perspecta://open?path=a.dcm"),
+            Ok(None)
+        );
+        assert!(parse_perspecta_uri_frames("perspecta://open?path=a.dcm&frames=45-20").is_err());
+        assert!(parse_perspecta_uri_frames("perspecta://open?path=a.dcm&frames=0-5").is_err());
+        assert!(parse_perspecta_uri_frames("perspecta://open?path=a.dcm&frames=20").is_err());
+    }
+
     #[test]
     fn parse_repeated_path_params() {
         let request = parse_perspecta_uri(
@@ -522,10 +1073,37 @@ mod tests {
                 instance_uid: None,
                 username: None,
                 password: None,
+                wado_uri: false,
+                series_tabs: Vec::new(),
             })
         );
     }
 
+    #[test]
+    fn parse_dicomweb_request_with_several_series_opens_tabs() {
+        let uri = "perspecta://open?dicomweb=http%3A%2F%2Flocalhost%3A8042%2Fdicom-web&study=study_uid_alpha&series=series_uid_beta,series_uid_gamma";
+        let request = parse_perspecta_uri(uri).expect("URI should parse");
+        let LaunchRequest::DicomWeb(dicomweb) = &request else {
+            panic!("expected DICOMweb request");
+        };
+        assert_eq!(dicomweb.series_uid, None);
+        assert_eq!(
+            dicomweb.series_tabs,
+            vec![
+                "series_uid_beta".to_string(),
+                "series_uid_gamma".to_string()
+            ]
+        );
+        assert_eq!(
+            parse_perspecta_uri(&build_perspecta_uri(&request)).expect("rebuilt URI should parse"),
+            request
+        );
+
+        let err = parse_perspecta_uri(&format!("{uri}&instance=instance_uid_delta"))
+            .expect_err("instance= with series tabs should be rejected");
+        assert!(err.contains("instance="));
+    }
+
     #[test]
     fn parse_dicomweb_root_with_auth() {
         let request = parse_perspecta_uri(
@@ -541,6 +1119,8 @@ mod tests {
                 instance_uid: None,
                 username: Some("vieweruser".to_string()),
                 password: Some("viewerpass".to_string()),
+                wado_uri: false,
+                series_tabs: Vec::new(),
             })
         );
     }
@@ -560,6 +1140,8 @@ mod tests {
                 instance_uid: Some("instance_uid_gamma".to_string()),
                 username: None,
                 password: None,
+                wado_uri: false,
+                series_tabs: Vec::new(),
             })
         );
     }
@@ -835,6 +1417,149 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_perspecta_uri_roundtrips_local_groups() {
+        let request = LaunchRequest::LocalGroups {
+            groups: vec![
+                vec![
+                    PathBuf::from("example-data/r cc.dcm"),
+                    PathBuf::from("example-data/lcc+1.dcm"),
+                ],
+                vec![PathBuf::from("example-data/a,b.dcm")],
+            ],
+            open_group: 1,
+        };
+
+        let uri = build_perspecta_uri(&request);
+        let reparsed = parse_perspecta_uri(&uri).expect("built URI should parse");
+        assert_eq!(reparsed, request);
+    }
+
+    #[test]
+    fn build_perspecta_uri_roundtrips_local_paths() {
+        let request = LaunchRequest::LocalPaths(vec![
+            PathBuf::from("example-data/a.dcm"),
+            PathBuf::from("example-data/b.dcm"),
+        ]);
+
+        let uri = build_perspecta_uri(&request);
+        assert_eq!(
+            uri,
+            "perspecta://open?path=example-data%2Fa.dcm&path=example-data%2Fb.dcm"
+        );
+        assert_eq!(parse_perspecta_uri(&uri), Ok(request));
+    }
+
+    #[test]
+    fn build_perspecta_uri_omits_dicomweb_credentials() {
+        let request = LaunchRequest::DicomWeb(DicomWebLaunchRequest {
+            base_url: "http://localhost:8042/dicom-web".to_string(),
+            study_uid: "study_uid_alpha".to_string(),
+            series_uid: Some("series_uid_beta".to_string()),
+            instance_uid: None,
+            username: Some("vieweruser".to_string()),
+            password: Some("viewerpass".to_string()),
+            wado_uri: false,
+            series_tabs: Vec::new(),
+        });
+
+        let uri = build_perspecta_uri(&request);
+        assert!(!uri.contains("vieweruser"));
+        assert!(!uri.contains("viewerpass"));
+        assert_eq!(
+            parse_perspecta_uri(&uri),
+            Ok(LaunchRequest::DicomWeb(DicomWebLaunchRequest {
+                base_url: "http://localhost:8042/dicom-web".to_string(),
+                study_uid: "study_uid_alpha".to_string(),
+                series_uid: Some("series_uid_beta".to_string()),
+                instance_uid: None,
+                username: None,
+                password: None,
+                wado_uri: false,
+                series_tabs: Vec::new(),
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_wado_uri_flag_requires_single_instance_dicomweb_launch() {
+        let request = parse_perspecta_uri(
+            "perspecta://open?dicomweb=http%3A%2F%2Fpacs.local%2Fwado&study=1.2&series=1.2.3&instance=1.2.3.4&wado_uri=1",
+        )
+        .expect("URI should parse");
+        let LaunchRequest::DicomWeb(request) = request else {
+            panic!("expected DICOMweb request");
+        };
+        assert!(request.wado_uri);
+        assert!(build_perspecta_uri(&LaunchRequest::DicomWeb(request)).contains("wado_uri=1"));
+
+        assert!(parse_perspecta_uri(
+            "perspecta://open?dicomweb=http%3A%2F%2Fpacs.local%2Fwado&study=1.2&wado_uri=1"
+        )
+        .is_err());
+        assert!(parse_perspecta_uri("perspecta://open?path=a.dcm&wado_uri=1").is_err());
+    }
+
+    #[test]
+    fn parse_remote_file_url_from_url_or_file_param() {
+        let expected = LaunchRequest::RemoteFile(RemoteFileLaunchRequest {
+            url: "https://bucket.example.com/scan.dcm?X-Amz-Signature=abc&X-Amz-Expires=60"
+                .to_string(),
+            username: None,
+            password: None,
+        });
+        let uri = "perspecta://open?url=https%3A%2F%2Fbucket.example.com%2Fscan.dcm%3FX-Amz-Signature%3Dabc%26X-Amz-Expires%3D60";
+        assert_eq!(parse_perspecta_uri(uri), Ok(expected.clone()));
+        assert_eq!(
+            parse_perspecta_uri(&uri.replace("?url=", "?file=")),
+            Ok(expected.clone())
+        );
+        assert_eq!(
+            parse_perspecta_uri(&build_perspecta_uri(&expected)),
+            Ok(expected)
+        );
+
+        let with_auth = parse_perspecta_uri(
+            "perspecta://open?url=http%3A%2F%2Fhost.local%2Fa.dcm&auth=viewer%3Asecret",
+        );
+        assert!(matches!(
+            with_auth,
+            Ok(LaunchRequest::RemoteFile(RemoteFileLaunchRequest {
+                username: Some(_),
+                password: Some(_),
+                ..
+            }))
+        ));
+
+        assert!(parse_perspecta_uri("perspecta://open?url=ftp%3A%2F%2Fhost%2Fa.dcm").is_err());
+        assert!(parse_perspecta_uri(
+            "perspecta://open?url=https%3A%2F%2Fhost%2Fa.dcm&path=example-data%2Fb.dcm"
+        )
+        .is_err());
+        assert!(parse_perspecta_uri(
+            "perspecta://open?url=https%3A%2F%2Fhost%2Fa.dcm&url=https%3A%2F%2Fhost%2Fb.dcm"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn build_perspecta_uri_roundtrips_grouped_dicomweb() {
+        let request = LaunchRequest::DicomWebGroups(DicomWebGroupedLaunchRequest {
+            base_url: "http://localhost:8042/dicom-web".to_string(),
+            study_uid: "study_uid_alpha".to_string(),
+            groups: vec![
+                vec!["series_a".to_string(), "series_b".to_string()],
+                vec!["series_report".to_string()],
+            ],
+            open_group: 1,
+            username: None,
+            password: None,
+        });
+
+        let uri = build_perspecta_uri(&request);
+        assert_eq!(parse_perspecta_uri(&uri), Ok(request));
+    }
+
     #[test]
     fn parse_cli_falls_back_to_raw_paths() {
         let args = vec![
@@ -854,4 +1579,177 @@ mod tests {
             ]))
         );
     }
+
+    #[test]
+    fn parse_cli_routes_bare_dicomweb_url_to_dicomweb_launch() {
+        let args = vec![
+            "HTTPS://pacs.example/dicom-web/studies/1.2.3/series/1.2.3.4?accept=x".to_string(),
+        ];
+        assert_eq!(
+            parse_launch_request_from_args(&args),
+            Ok(Some(LaunchRequest::DicomWeb(DicomWebLaunchRequest {
+                base_url: "HTTPS://pacs.example/dicom-web".to_string(),
+                study_uid: "1.2.3".to_string(),
+                series_uid: Some("1.2.3.4".to_string()),
+                instance_uid: None,
+                username: None,
+                password: None,
+                wado_uri: false,
+                series_tabs: Vec::new(),
+            })))
+        );
+
+        let err = parse_launch_request_from_args(&["http://pacs.example/dicom-web".to_string()])
+            .expect_err("a URL without a study should be rejected");
+        assert!(err.contains("/studies/"));
+    }
+
+    #[test]
+    fn prefetch_args_accept_only_dicomweb_urls() {
+        let args = vec![
+            "--prefetch".to_string(),
+            "perspecta://open?dicomweb=http%3A%2F%2Flocalhost%3A8042&study=1.2.3".to_string(),
+        ];
+        let parsed = parse_prefetch_request_from_args(&args).expect("prefetch should parse");
+        assert!(matches!(parsed, Some(LaunchRequest::DicomWeb(_))));
+
+        let local = vec![
+            "--prefetch".to_string(),
+            "perspecta://open?path=example-data%2Fimage.dcm".to_string(),
+        ];
+        assert!(parse_prefetch_request_from_args(&local).is_err());
+        assert!(parse_prefetch_request_from_args(&["--prefetch".to_string()]).is_err());
+        assert_eq!(
+            parse_prefetch_request_from_args(&["example-data/a.dcm".to_string()]),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn dump_meta_args_take_one_path_and_optional_all_tags_flag() {
+        let args = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            parse_dump_meta_request_from_args(&args(&["--dump-meta", "example-data/a.dcm"])),
+            Ok(Some(DumpMetaRequest {
+                path: PathBuf::from("example-data/a.dcm"),
+                all_tags: false,
+            }))
+        );
+        let expected_all = Ok(Some(DumpMetaRequest {
+            path: PathBuf::from("example-data/a.dcm"),
+            all_tags: true,
+        }));
+        assert_eq!(
+            parse_dump_meta_request_from_args(&args(&[
+                "--dump-meta",
+                "--all-tags",
+                "example-data/a.dcm"
+            ])),
+            expected_all
+        );
+        assert_eq!(
+            parse_dump_meta_request_from_args(&args(&[
+                "--dump-meta",
+                "example-data/a.dcm",
+                "--all-tags"
+            ])),
+            expected_all
+        );
+        assert!(parse_dump_meta_request_from_args(&args(&["--dump-meta"])).is_err());
+        assert!(parse_dump_meta_request_from_args(&args(&["--dump-meta", "--all-tags"])).is_err());
+        assert!(
+            parse_dump_meta_request_from_args(&args(&["--dump-meta", "a.dcm", "b.dcm"])).is_err()
+        );
+        assert_eq!(
+            parse_dump_meta_request_from_args(&args(&["example-data/a.dcm"])),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn worklist_args_take_a_list_file_and_optional_dicomweb_base() {
+        let args = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            parse_worklist_args(&args(&["--worklist", "reading.txt"])),
+            Ok(Some(WorklistArgs {
+                path: PathBuf::from("reading.txt"),
+                dicomweb_base: None,
+            }))
+        );
+        assert_eq!(
+            parse_worklist_args(&args(&[
+                "--worklist",
+                "reading.txt",
+                "--dicomweb",
+                "http://localhost:8042/dicom-web/"
+            ])),
+            Ok(Some(WorklistArgs {
+                path: PathBuf::from("reading.txt"),
+                dicomweb_base: Some("http://localhost:8042/dicom-web".to_string()),
+            }))
+        );
+        assert_eq!(
+            parse_worklist_args(&args(&[
+                "--dicomweb",
+                "http://localhost:8042/dicom-web",
+                "--worklist",
+                "reading.txt",
+            ])),
+            Ok(Some(WorklistArgs {
+                path: PathBuf::from("reading.txt"),
+                dicomweb_base: Some("http://localhost:8042/dicom-web".to_string()),
+            }))
+        );
+        assert!(parse_worklist_args(&args(&["--worklist"])).is_err());
+        assert!(parse_worklist_args(&args(&["--worklist", "a.txt", "b.txt"])).is_err());
+        assert!(
+            parse_worklist_args(&args(&["--worklist", "a.txt", "--worklist", "b.txt"])).is_err()
+        );
+        assert!(
+            parse_worklist_args(&args(&["--worklist", "a.txt", "--dicomweb", "pacs"])).is_err()
+        );
+        assert_eq!(parse_worklist_args(&args(&["a.dcm"])), Ok(None));
+    }
+
+    #[test]
+    fn parse_worklist_reads_urls_and_bare_study_uids() {
+        let text = "# Monday reading list\n\
+            perspecta://open?path=a.dcm\n\
+            \n\
+            https://pacs/dicom-web/studies/1.2.3\n\
+            1.2.840.113619.2.55\n";
+        let list_dir = Path::new("lists");
+        let requests = parse_worklist(text, Some("http://localhost:8042/dicom-web"), list_dir)
+            .expect("worklist should parse");
+        assert_eq!(requests.len(), 3);
+        assert_eq!(
+            requests[0],
+            LaunchRequest::LocalPaths(vec![list_dir.join("a.dcm")])
+        );
+        let LaunchRequest::DicomWeb(request) = &requests[2] else {
+            panic!("bare UID should become a DICOMweb launch");
+        };
+        assert_eq!(request.base_url, "http://localhost:8042/dicom-web");
+        assert_eq!(request.study_uid, "1.2.840.113619.2.55");
+
+        let missing_base =
+            parse_worklist("1.2.3\n", None, list_dir).expect_err("UID needs a server");
+        assert!(missing_base.starts_with("Worklist line 1:"));
+        assert!(parse_worklist("not a study\n", None, list_dir).is_err());
+        assert!(parse_worklist("# only comments\n", None, list_dir).is_err());
+    }
+
+    #[test]
+    fn percent_decode_handles_escapes_at_end_of_string() {
+        assert!(percent_decode("%").is_err());
+        assert!(percent_decode("%4").is_err());
+        assert!(percent_decode("abc%4").is_err());
+        assert!(percent_decode("%4G").is_err());
+        assert_eq!(percent_decode("%41").as_deref(), Ok("A"));
+        assert_eq!(percent_decode("abc%41").as_deref(), Ok("abcA"));
+        assert_eq!(
+            percent_decode("%41%"),
+            Err("Invalid percent-encoding in URL.".to_string())
+        );
+    }
 }
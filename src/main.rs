@@ -7,12 +7,91 @@ mod mammo;
 mod renderer;
 
 use std::io;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use dicomweb::{
+    download_dicomweb_group_request, download_dicomweb_request, DicomWebDownloadResult,
+};
+use launch::{DumpMetaRequest, LaunchRequest, WorklistArgs};
 
 fn main() -> eframe::Result<()> {
     logging::init().map_err(|err| eframe::Error::AppCreation(Box::new(err)))?;
 
     let cli_args = std::env::args().skip(1).collect::<Vec<_>>();
-    let initial_request = launch::parse_launch_request_from_args(&cli_args).map_err(|err| {
+    let prefetch_request = launch::parse_prefetch_request_from_args(&cli_args).map_err(|err| {
+        eframe::Error::AppCreation(Box::new(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Launch URL/args error: {err}"),
+        )))
+    })?;
+    if let Some(request) = prefetch_request {
+        return run_prefetch(&request).map_err(|err| {
+            eframe::Error::AppCreation(Box::new(io::Error::new(
+                io::ErrorKind::Other,
+                format!("Prefetch failed: {err:#}"),
+            )))
+        });
+    }
+
+    let dump_meta_request =
+        launch::parse_dump_meta_request_from_args(&cli_args).map_err(|err| {
+            eframe::Error::AppCreation(Box::new(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Launch URL/args error: {err}"),
+            )))
+        })?;
+    if let Some(request) = dump_meta_request {
+        return run_dump_meta(&request).map_err(|err| {
+            eframe::Error::AppCreation(Box::new(io::Error::new(
+                io::ErrorKind::Other,
+                format!("Metadata dump failed: {err:#}"),
+            )))
+        });
+    }
+
+    let worklist_args = launch::parse_worklist_args(&cli_args).map_err(|err| {
+        eframe::Error::AppCreation(Box::new(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Launch URL/args error: {err}"),
+        )))
+    })?;
+    let worklist = match worklist_args {
+        Some(args) => load_worklist(&args).map_err(|err| {
+            eframe::Error::AppCreation(Box::new(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Worklist error: {err:#}"),
+            )))
+        })?,
+        None => Vec::new(),
+    };
+
+    let initial_request = if worklist.is_empty() {
+        launch::parse_launch_request_from_args(&cli_args).map_err(|err| {
+            eframe::Error::AppCreation(Box::new(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Launch URL/args error: {err}"),
+            )))
+        })?
+    } else {
+        None
+    };
+    let launch_theme = launch::parse_launch_theme_from_args(&cli_args).map_err(|err| {
+        eframe::Error::AppCreation(Box::new(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Launch URL/args error: {err}"),
+        )))
+    })?;
+
+    let launch_cine = launch::parse_launch_cine_from_args(&cli_args).map_err(|err| {
+        eframe::Error::AppCreation(Box::new(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Launch URL/args error: {err}"),
+        )))
+    })?;
+
+    let launch_frames = launch::parse_launch_frames_from_args(&cli_args).map_err(|err| {
         eframe::Error::AppCreation(Box::new(io::Error::new(
             io::ErrorKind::InvalidInput,
             format!("Launch URL/args error: {err}"),
@@ -27,9 +106,79 @@ fn main() -> eframe::Result<()> {
         ..Default::default()
     };
 
+    let renderer_name = native_options.renderer.to_string();
     eframe::run_native(
         "Perspecta Viewer",
         native_options,
-        Box::new(move |_cc| Ok(Box::new(app::DicomViewerApp::new(initial_request.clone())))),
+        Box::new(move |_cc| {
+            let mut app = app::DicomViewerApp::new(initial_request.clone(), launch_theme);
+            app.set_renderer_name(renderer_name.clone());
+            if let Some(autoplay) = launch_cine {
+                app.set_session_autoplay_cine(autoplay);
+            }
+            if let Some(frames) = launch_frames.clone() {
+                app.set_launch_frame_range(frames);
+            }
+            if !worklist.is_empty() {
+                app.set_worklist(worklist.clone());
+            }
+            Ok(Box::new(app))
+        }),
     )
 }
+
+/// Downloads every instance a DICOMweb launch references without opening a
+/// window, then lists them on stdout so scripts can confirm what was fetched.
+fn run_prefetch(request: &LaunchRequest) -> Result<()> {
+    let result = match request {
+        LaunchRequest::DicomWeb(request) => {
+            download_dicomweb_request(request, &app::saved_view_fallback_tags())?
+        }
+        LaunchRequest::DicomWebGroups(request) => {
+            download_dicomweb_group_request(request, &app::saved_view_fallback_tags(), |_| {})?
+        }
+        LaunchRequest::LocalPaths(_)
+        | LaunchRequest::LocalGroups { .. }
+        | LaunchRequest::RemoteFile(_) => {
+            anyhow::bail!("prefetch only supports DICOMweb launches")
+        }
+    };
+    let sources = match result {
+        DicomWebDownloadResult::Single(sources) => sources,
+        DicomWebDownloadResult::Grouped { groups, .. } => groups.into_iter().flatten().collect(),
+        DicomWebDownloadResult::SeriesTabs(tabs) => {
+            tabs.into_iter().map(|tab| tab.source).collect()
+        }
+    };
+    for source in &sources {
+        println!("{}", source.short_label());
+    }
+    log::info!(
+        "Prefetched {} DICOMweb instance(s); no persistent local cache exists yet, so nothing was written to disk",
+        sources.len()
+    );
+    Ok(())
+}
+
+/// Reads the `--worklist` file into the launches it lists, in order.
+fn load_worklist(args: &WorklistArgs) -> Result<Vec<LaunchRequest>> {
+    let text = std::fs::read_to_string(&args.path)
+        .with_context(|| format!("Could not read {}", args.path.display()))?;
+    let list_dir = args.path.parent().unwrap_or(Path::new(""));
+    let requests = launch::parse_worklist(&text, args.dicomweb_base.as_deref(), list_dir)
+        .map_err(anyhow::Error::msg)?;
+    log::info!(
+        "Loaded a worklist of {} stud{}.",
+        requests.len(),
+        if requests.len() == 1 { "y" } else { "ies" }
+    );
+    Ok(requests)
+}
+
+/// Prints a local file's parsed metadata as JSON on stdout without opening a
+/// window, for scripting and debugging tag extraction.
+fn run_dump_meta(request: &DumpMetaRequest) -> Result<()> {
+    let json = dicom::metadata_json_from_source(&request.path.as_path().into(), request.all_tags)?;
+    println!("{json}");
+    Ok(())
+}
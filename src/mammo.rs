@@ -5,14 +5,23 @@ use eframe::egui;
 
 use crate::dicom::{DicomImage, DicomSourceMeta};
 
+/// View codes recognised on their own or behind a laterality prefix (`RXCCL`).
+/// Order matters only for readability; matching is exact.
+const MAMMO_VIEW_CODES: &[&str] = &["CC", "MLO", "XCCL", "XCCM", "ML", "LM", "LMO", "FB", "SIO"];
+
+/// Uppercased value with everything but ASCII letters and digits removed, so
+/// `"r-10242"`, `"R 10242"` and `"R10242"` compare equal.
 pub fn normalize_token(value: Option<&str>) -> String {
     value
         .unwrap_or_default()
-        .trim()
-        .to_ascii_uppercase()
-        .replace(' ', "")
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
 }
 
+/// Canonical laterality (`R` or `L`) of an `ImageLaterality`,
+/// `FrameLaterality` or free-text value.
 pub fn classify_laterality(value: Option<&str>) -> Option<&'static str> {
     let token = normalize_token(value);
     if token.starts_with('R') || token.contains("RIGHT") {
@@ -24,43 +33,192 @@ pub fn classify_laterality(value: Option<&str>) -> Option<&'static str> {
     }
 }
 
+/// Laterality named by a whole word of free text such as a series or
+/// processing description: `R`/`RIGHT`, `L`/`LEFT`, or a view code with a
+/// laterality prefix (`RCC`, `LMLO`). Stricter than `classify_laterality`, so
+/// words that merely start with R or L do not count.
+pub fn classify_laterality_in_text(value: Option<&str>) -> Option<&'static str> {
+    value?
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .map(|word| normalize_token(Some(word)))
+        .find_map(|word| match word.as_str() {
+            "R" | "RIGHT" => Some("R"),
+            "L" | "LEFT" => Some("L"),
+            _ if word.len() > 1 && view_from_code(&word[1..]).is_some() => {
+                classify_laterality(Some(&word[..1]))
+            }
+            _ => None,
+        })
+}
+
+/// Canonical view code (`CC`, `MLO`, `XCCL`, `ML`, ...) of a `ViewPosition`,
+/// view code meaning or value, or series description.
+///
+/// Matching is by whole word, never by substring: `XCCL` is an exaggerated
+/// view and must not count as `CC`. A code may carry a laterality prefix
+/// (`RCC`, `LMLO`), and long spellings and code values only match when they
+/// are the entire value.
 pub fn classify_view(value: Option<&str>) -> Option<&'static str> {
-    let token = normalize_token(value);
-    if token == "MLO" {
-        Some("MLO")
-    } else if token == "CC" {
-        Some("CC")
-    } else {
-        None
-    }
+    let value = value?;
+    let token = normalize_token(Some(value));
+    view_from_code(&token)
+        .or_else(|| view_from_full_value(&token))
+        .or_else(|| {
+            value
+                .split(|c: char| !c.is_ascii_alphanumeric())
+                .find_map(|word| view_from_code(&normalize_token(Some(word))))
+        })
 }
 
-fn mammo_slot_index(image: &DicomImage) -> Option<usize> {
-    match (
-        classify_view(image.view_position.as_deref()),
-        classify_laterality(image.image_laterality.as_deref()),
-    ) {
-        (Some("CC"), Some("R")) => Some(0),
-        (Some("CC"), Some("L")) => Some(1),
-        (Some("MLO"), Some("R")) => Some(2),
-        (Some("MLO"), Some("L")) => Some(3),
+/// View named by the first of `values` that names one, for fallback tags
+/// consulted in order when the standard view tags are empty.
+pub fn classify_view_in_fallbacks<'a>(
+    values: impl IntoIterator<Item = &'a str>,
+) -> Option<&'static str> {
+    values
+        .into_iter()
+        .find_map(|value| classify_view(Some(value)))
+}
+
+/// Laterality named by the first of `values` that names one, matched as
+/// whole words like `classify_laterality_in_text`.
+pub fn classify_laterality_in_fallbacks<'a>(
+    values: impl IntoIterator<Item = &'a str>,
+) -> Option<&'static str> {
+    values
+        .into_iter()
+        .find_map(|value| classify_laterality_in_text(Some(value)))
+}
+
+fn view_from_code(token: &str) -> Option<&'static str> {
+    let exact = |token: &str| MAMMO_VIEW_CODES.iter().copied().find(|code| *code == token);
+    exact(token).or_else(|| token.strip_prefix(&['R', 'L'][..]).and_then(exact))
+}
+
+fn view_from_full_value(token: &str) -> Option<&'static str> {
+    match token {
+        "CRANIOCAUDAL" | "R10242" => Some("CC"),
+        "MEDIOLATERALOBLIQUE" | "R10226" => Some("MLO"),
+        "CRANIOCAUDALEXAGGERATEDLATERALLY" | "EXAGGERATEDCRANIOCAUDALLATERAL" | "R1024A" => {
+            Some("XCCL")
+        }
+        "CRANIOCAUDALEXAGGERATEDMEDIALLY" | "EXAGGERATEDCRANIOCAUDALMEDIAL" | "R1024B" => {
+            Some("XCCM")
+        }
+        "MEDIOLATERAL" | "R10224" => Some("ML"),
+        "LATEROMEDIAL" | "R10228" => Some("LM"),
+        "LATEROMEDIALOBLIQUE" | "R10230" => Some("LMO"),
+        "CAUDOCRANIAL" | "CAUDOCRANIALFROMBELOW" | "R10244" => Some("FB"),
+        "SUPEROLATERALTOINFEROMEDIALOBLIQUE" | "R102D0" => Some("SIO"),
         _ => None,
     }
 }
 
-fn three_up_sort_key(image: &DicomImage) -> (u8, u8, i32) {
-    let view_rank = match classify_view(image.view_position.as_deref()) {
+/// Grid ordering of a classified view: the screening views first, then
+/// exaggerated CCs, straight laterals and the remaining supplementary views.
+/// Only `CC` (0) and `MLO` (1) have fixed quartet slots; other views fill the
+/// free slots on their breast's side.
+pub fn mammo_view_rank(view: Option<&str>) -> u8 {
+    match view {
         Some("CC") => 0,
         Some("MLO") => 1,
-        _ => 2,
-    };
-    let laterality_rank = match classify_laterality(image.image_laterality.as_deref()) {
-        Some("R") => 0,
-        Some("L") => 1,
-        _ => 2,
-    };
-    let instance_number = image.instance_number.unwrap_or(i32::MAX);
-    (view_rank, laterality_rank, instance_number)
+        Some("XCCL" | "XCCM") => 2,
+        Some("ML" | "LM") => 3,
+        Some(_) => 4,
+        None => 5,
+    }
+}
+
+/// Classified view, laterality and instance number of one mammogram. DICOMweb
+/// metadata and decoded local images both reduce to this, so quartet
+/// detection and ordering follow the same rules for either source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MammoViewKey {
+    pub view: Option<&'static str>,
+    pub laterality: Option<&'static str>,
+    pub instance_number: Option<i32>,
+}
+
+impl MammoViewKey {
+    pub fn classify(
+        view_position: Option<&str>,
+        laterality: Option<&str>,
+        instance_number: Option<i32>,
+    ) -> Self {
+        Self {
+            view: classify_view(view_position),
+            laterality: classify_laterality(laterality),
+            instance_number,
+        }
+    }
+
+    pub fn from_image(image: &DicomImage) -> Self {
+        Self::classify(
+            image.view_position.as_deref(),
+            image.image_laterality.as_deref(),
+            image.instance_number,
+        )
+    }
+
+    /// Sort key placing screening views first, right before left, then by
+    /// instance number.
+    pub fn sort_key(&self) -> (u8, u8, i32) {
+        let laterality_rank = match self.laterality {
+            Some("R") => 0,
+            Some("L") => 1,
+            _ => 2,
+        };
+        (
+            mammo_view_rank(self.view),
+            laterality_rank,
+            self.instance_number.unwrap_or(i32::MAX),
+        )
+    }
+
+    /// Fixed quartet slot: RCC, LCC, RMLO, LMLO.
+    pub fn quartet_slot(&self) -> Option<usize> {
+        match (self.view, self.laterality) {
+            (Some("CC"), Some("R")) => Some(0),
+            (Some("CC"), Some("L")) => Some(1),
+            (Some("MLO"), Some("R")) => Some(2),
+            (Some("MLO"), Some("L")) => Some(3),
+            _ => None,
+        }
+    }
+}
+
+/// First index of each screening view, in RCC/LCC/RMLO/LMLO slot order.
+fn screening_view_indices(keys: &[MammoViewKey]) -> [Option<usize>; 4] {
+    let mut slots = [None; 4];
+    for (index, key) in keys.iter().enumerate() {
+        if let Some(slot) = key.quartet_slot() {
+            slots[slot].get_or_insert(index);
+        }
+    }
+    slots
+}
+
+/// Indices of a complete RCC/LCC/RMLO/LMLO quartet, in display order.
+pub fn mammo_quartet_indices(keys: &[MammoViewKey]) -> Option<[usize; 4]> {
+    let [rcc, lcc, rmlo, lmlo] = screening_view_indices(keys);
+    Some([rcc?, lcc?, rmlo?, lmlo?])
+}
+
+/// Indices of exactly three distinct screening views, in display order.
+pub fn mammo_triplet_indices(keys: &[MammoViewKey]) -> Option<Vec<usize>> {
+    let found = screening_view_indices(keys)
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+    (found.len() == 3).then_some(found)
+}
+
+fn mammo_slot_index(image: &DicomImage) -> Option<usize> {
+    MammoViewKey::from_image(image).quartet_slot()
+}
+
+fn three_up_sort_key(image: &DicomImage) -> (u8, u8, i32) {
+    MammoViewKey::from_image(image).sort_key()
 }
 
 fn study_date_value(image: &DicomImage) -> Option<String> {
@@ -153,23 +311,40 @@ pub fn order_mammo_indices<T>(
         return keyed.into_iter().map(|(index, _)| index).collect();
     }
 
+    if items.len() == 4 {
+        let keys = items
+            .iter()
+            .map(|item| MammoViewKey::from_image(image_of(item)))
+            .collect::<Vec<_>>();
+        if let Some(quartet) = mammo_quartet_indices(&keys) {
+            return quartet.to_vec();
+        }
+    }
+
     let mut ordered = vec![None; items.len()];
     let mut fallback = VecDeque::new();
 
+    // Screening views claim their fixed slots first so a supplementary view
+    // listed earlier (an LML, say) cannot take the LCC's place.
+    let mut supplementary = Vec::new();
     for (index, item) in items.iter().enumerate() {
-        let image = image_of(item);
+        match mammo_slot_index(image_of(item)) {
+            Some(slot) if slot < ordered.len() && ordered[slot].is_none() => {
+                ordered[slot] = Some(index);
+            }
+            _ => supplementary.push(index),
+        }
+    }
+
+    for index in supplementary {
+        let image = image_of(&items[index]);
         let slot = preferred_mammo_slot(image, ordered.len(), |slot_index| {
             ordered.get(slot_index).and_then(Option::as_ref).is_none()
         });
 
-        if let Some(slot) = slot {
-            if ordered[slot].is_none() {
-                ordered[slot] = Some(index);
-            } else {
-                fallback.push_back(index);
-            }
-        } else {
-            fallback.push_back(index);
+        match slot {
+            Some(slot) => ordered[slot] = Some(index),
+            None => fallback.push_back(index),
         }
     }
 
@@ -200,7 +375,9 @@ pub fn mammo_image_align(index: usize) -> egui::Align {
     }
 }
 
-pub fn mammo_label(image: &DicomImage, source: &DicomSourceMeta) -> String {
+/// Short view code such as `RCC` or `LMLO`, flagged when the image is
+/// FOR PROCESSING. Empty when neither laterality nor view is known.
+pub fn mammo_view_code(image: &DicomImage) -> String {
     let laterality = classify_laterality(image.image_laterality.as_deref());
     let view = classify_view(image.view_position.as_deref());
     let code = match (laterality, view) {
@@ -210,6 +387,20 @@ pub fn mammo_label(image: &DicomImage, source: &DicomSourceMeta) -> String {
         _ => String::new(),
     };
 
+    if image.is_for_processing() {
+        // Raw detector data rarely matches the look readers expect, so flag it in the cell label.
+        [code.as_str(), "For Processing"]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    } else {
+        code
+    }
+}
+
+pub fn mammo_label(image: &DicomImage, source: &DicomSourceMeta) -> String {
+    let code = mammo_view_code(image);
     let file_name = source.display_label();
 
     if code.is_empty() {
@@ -218,3 +409,137 @@ pub fn mammo_label(image: &DicomImage, source: &DicomSourceMeta) -> String {
         format!("{code} ({file_name})")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_view_matches_whole_codes_only() {
+        for (value, expected) in [
+            (Some("CC"), Some("CC")),
+            (Some(" mlo "), Some("MLO")),
+            (Some("RCC"), Some("CC")),
+            (Some("L MLO"), Some("MLO")),
+            (Some("L CC Tomo"), Some("CC")),
+            (Some("cranio-caudal"), Some("CC")),
+            (Some("medio-lateral oblique"), Some("MLO")),
+            (Some("R-10242"), Some("CC")),
+            (Some("R-10226"), Some("MLO")),
+            (Some("XCCL"), Some("XCCL")),
+            (Some("RXCCL"), Some("XCCL")),
+            (Some("cranio-caudal exaggerated laterally"), Some("XCCL")),
+            (Some("ML"), Some("ML")),
+            (Some("LML"), Some("ML")),
+            (Some("LM"), Some("LM")),
+            (Some("R-10228"), Some("LM")),
+            (Some("L LMO"), Some("LMO")),
+            (Some("caudo-cranial (from below)"), Some("FB")),
+            (Some("SCOUT"), None),
+            (Some("XCC"), None),
+            (Some(""), None),
+            (None, None),
+        ] {
+            assert_eq!(classify_view(value), expected, "{value:?}");
+        }
+    }
+
+    #[test]
+    fn supplementary_views_fill_free_slots_on_their_side() {
+        let image = |view: &str, laterality: &str| {
+            let mut image = DicomImage::test_stub(None);
+            image.view_position = Some(view.to_string());
+            image.image_laterality = Some(laterality.to_string());
+            image
+        };
+        let images = [
+            image("ML", "L"),
+            image("CC", "R"),
+            image("XCCL", "R"),
+            image("CC", "L"),
+        ];
+
+        assert_eq!(
+            order_mammo_indices(&images, |image| image),
+            vec![1, 3, 2, 0]
+        );
+        assert_eq!(
+            order_mammo_indices(&images[1..], |image| image),
+            vec![0, 2, 1]
+        );
+    }
+
+    #[test]
+    fn quartet_and_triplet_indices_take_the_first_of_each_screening_view() {
+        let key = |view: &str, laterality: &str| {
+            MammoViewKey::classify(Some(view), Some(laterality), None)
+        };
+        let keys = [
+            key("MLO", "L"),
+            key("CC", "R"),
+            key("CC", "R"),
+            key("MLO", "R"),
+            key("ML", "L"),
+            key("CC", "L"),
+        ];
+        assert_eq!(mammo_quartet_indices(&keys), Some([1, 5, 3, 0]));
+        assert_eq!(mammo_quartet_indices(&keys[..5]), None);
+        assert_eq!(mammo_triplet_indices(&keys[..5]), Some(vec![1, 3, 0]));
+        assert_eq!(mammo_triplet_indices(&keys), None);
+    }
+
+    #[test]
+    fn local_quartet_orders_like_dicomweb_metadata() {
+        let image = |view: &str, laterality: &str| {
+            let mut image = DicomImage::test_stub(None);
+            image.view_position = Some(view.to_string());
+            image.image_laterality = Some(laterality.to_string());
+            image
+        };
+        let images = [
+            image("MLO", "L"),
+            image("CC", "L"),
+            image("MLO", "R"),
+            image("CC", "R"),
+        ];
+        let keys = images
+            .iter()
+            .map(MammoViewKey::from_image)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            order_mammo_indices(&images, |image| image),
+            mammo_quartet_indices(&keys)
+                .expect("complete quartet")
+                .to_vec()
+        );
+    }
+
+    #[test]
+    fn classify_laterality_reads_codes_and_words() {
+        for (value, expected) in [
+            (Some("R"), Some("R")),
+            (Some("l"), Some("L")),
+            (Some("Right breast"), Some("R")),
+            (Some("BILATERAL LEFT"), Some("L")),
+            (Some("B"), None),
+            (None, None),
+        ] {
+            assert_eq!(classify_laterality(value), expected, "{value:?}");
+        }
+    }
+
+    #[test]
+    fn classify_laterality_in_text_needs_whole_words() {
+        for (value, expected) in [
+            (Some("R CC"), Some("R")),
+            (Some("LMLO 2D"), Some("L")),
+            (Some("Left breast"), Some("L")),
+            (Some("Routine"), None),
+            (Some("Lateral"), None),
+            (None, None),
+        ] {
+            assert_eq!(classify_laterality_in_text(value), expected, "{value:?}");
+        }
+    }
+}
@@ -8,6 +8,11 @@ pub fn render_window_level(
     center: f32,
     width: f32,
 ) -> ColorImage {
+    debug_assert_eq!(
+        Some(frame_pixels.len()),
+        width_px.checked_mul(height_px),
+        "frame samples must match the image dimensions"
+    );
     let effective_width = width.max(1.0);
     let low = center - effective_width / 2.0;
     let high = center + effective_width / 2.0;